@@ -5,8 +5,9 @@
 use tauri::{AppHandle, Emitter};
 
 use crate::app::state::{HotkeyServiceState, HotkeyIntegrationState, ProductionState};
-use crate::events::{self, RecordingErrorPayload};
+use crate::events::{self, RecordingErrorPayload, INTERNAL_ERROR_MESSAGE};
 use crate::hotkey::{RecordingMode, ShortcutBackendExt};
+use crate::last_error::{last_error, ErrorCategory};
 
 /// Register hotkey with press and release callbacks using CGEventTap backend.
 ///
@@ -52,6 +53,7 @@ pub fn register_hotkey_with_release(
                 tauri::async_runtime::spawn(async move {
                     match integration.lock() {
                         Ok(mut guard) => {
+                            guard.apply_pending_recording_mode(&state);
                             let mode = guard.recording_mode();
                             crate::debug!("Hotkey pressed (mode: {:?})", mode);
                             match mode {
@@ -65,11 +67,11 @@ pub fn register_hotkey_with_release(
                         }
                         Err(e) => {
                             crate::error!("Failed to acquire integration lock: {}", e);
+                            last_error().record(ErrorCategory::Recording, INTERNAL_ERROR_MESSAGE);
                             let _ = app_handle.emit(
                                 events::event_names::RECORDING_ERROR,
                                 RecordingErrorPayload {
-                                    message: "Internal error: please restart the application"
-                                        .to_string(),
+                                    message: INTERNAL_ERROR_MESSAGE.to_string(),
                                 },
                             );
                         }
@@ -101,11 +103,11 @@ pub fn register_hotkey_with_release(
                         }
                         Err(e) => {
                             crate::error!("Failed to acquire integration lock: {}", e);
+                            last_error().record(ErrorCategory::Recording, INTERNAL_ERROR_MESSAGE);
                             let _ = app_handle.emit(
                                 events::event_names::RECORDING_ERROR,
                                 RecordingErrorPayload {
-                                    message: "Internal error: please restart the application"
-                                        .to_string(),
+                                    message: INTERNAL_ERROR_MESSAGE.to_string(),
                                 },
                             );
                         }
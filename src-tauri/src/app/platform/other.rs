@@ -6,8 +6,9 @@ use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter};
 
 use crate::app::state::{HotkeyIntegrationState, HotkeyServiceState, ProductionState};
-use crate::events::{self, RecordingErrorPayload};
+use crate::events::{self, RecordingErrorPayload, INTERNAL_ERROR_MESSAGE};
 use crate::hotkey::{RecordingMode, ShortcutBackendExt};
+use crate::last_error::{last_error, ErrorCategory};
 
 /// Register hotkey with press and release callbacks using rdev backend.
 ///
@@ -53,6 +54,7 @@ pub fn register_hotkey_with_release(
                 tauri::async_runtime::spawn(async move {
                     match integration.lock() {
                         Ok(mut guard) => {
+                            guard.apply_pending_recording_mode(&state);
                             let mode = guard.recording_mode();
                             crate::debug!("Hotkey pressed (mode: {:?})", mode);
                             match mode {
@@ -66,11 +68,11 @@ pub fn register_hotkey_with_release(
                         }
                         Err(e) => {
                             crate::error!("Failed to acquire integration lock: {}", e);
+                            last_error().record(ErrorCategory::Recording, INTERNAL_ERROR_MESSAGE);
                             let _ = app_handle.emit(
                                 events::event_names::RECORDING_ERROR,
                                 RecordingErrorPayload {
-                                    message: "Internal error: please restart the application"
-                                        .to_string(),
+                                    message: INTERNAL_ERROR_MESSAGE.to_string(),
                                 },
                             );
                         }
@@ -97,11 +99,11 @@ pub fn register_hotkey_with_release(
                         }
                         Err(e) => {
                             crate::error!("Failed to acquire integration lock: {}", e);
+                            last_error().record(ErrorCategory::Recording, INTERNAL_ERROR_MESSAGE);
                             let _ = app_handle.emit(
                                 events::event_names::RECORDING_ERROR,
                                 RecordingErrorPayload {
-                                    message: "Internal error: please restart the application"
-                                        .to_string(),
+                                    message: INTERNAL_ERROR_MESSAGE.to_string(),
                                 },
                             );
                         }
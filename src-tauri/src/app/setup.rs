@@ -46,6 +46,9 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Set dynamic window title based on worktree context
     setup_window_title(app, &worktree_context, &settings_file);
 
+    // Show the splash window until the frontend is ready (unless disabled)
+    setup_splash_window(app, &settings_file);
+
     // Check for collision with another running instance
     check_instance_collision(&worktree_context)?;
 
@@ -57,7 +60,10 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     app.manage(turso_client.clone());
 
     // Create shared state for recording manager
-    let recording_state = Arc::new(Mutex::new(recording::RecordingManager::new()));
+    // State changes are emitted as recording_state_changed for the frontend
+    let recording_state = Arc::new(Mutex::new(recording::RecordingManager::with_state_emitter(
+        Arc::new(commands::TauriEventEmitter::new(app.handle().clone())),
+    )));
     app.manage(recording_state.clone());
 
     // Create and manage audio monitor state for device testing
@@ -74,11 +80,46 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Create and manage recording detectors (for silence detection during recording)
     let recordings_dir = paths::get_recordings_dir(worktree_context.as_ref())
         .unwrap_or_else(|_| std::path::PathBuf::from(".").join("heycat").join("recordings"));
-    let recording_detectors = Arc::new(Mutex::new(
-        recording::RecordingDetectors::with_recordings_dir(recordings_dir.clone()),
-    ));
+    let silence_timing = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(recording::SILENCE_TIMING_KEY))
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    let vad_model_path = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(recording::VAD_MODEL_PATH_KEY))
+        .and_then(|v| v.as_str().map(|s| s.trim().to_string()))
+        .filter(|s| !s.is_empty())
+        .map(std::path::PathBuf::from);
+    let vad_threshold = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(recording::SILENCE_VAD_THRESHOLD_KEY))
+        .and_then(|v| v.as_f64());
+    let silence_config = recording::SilenceConfig {
+        timing: recording::SilenceTiming::from_setting(silence_timing.as_deref()),
+        vad_speech_threshold: recording::silence_vad_threshold_from_setting(vad_threshold),
+        vad_model_path,
+        ..Default::default()
+    };
+    let wav_bit_depth = audio::WavBitDepth::from_setting(
+        app.store(&settings_file)
+            .ok()
+            .and_then(|store| store.get(audio::WAV_BIT_DEPTH_KEY))
+            .and_then(|v| v.as_u64()),
+    );
+    let mut detectors = recording::RecordingDetectors::with_config_and_recordings_dir(
+        silence_config,
+        recordings_dir.clone(),
+    );
+    detectors.set_wav_bit_depth(wav_bit_depth);
+    let recording_detectors = Arc::new(Mutex::new(detectors));
     app.manage(recording_detectors.clone());
 
+    // Recover recordings left on disk without a database row (e.g., after a crash)
+    spawn_recording_reconciliation(app, &turso_client, &recordings_dir);
+
     // Create audio thread
     crate::debug!("Creating audio thread...");
     let audio_thread = Arc::new(audio::AudioThreadHandle::spawn());
@@ -88,6 +129,9 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Manage shared transcription model for Tauri commands
     app.manage(shared_transcription_model.clone());
 
+    // Secondary model used when the primary fails (loaded lazily if configured)
+    app.manage(Arc::new(crate::transcription::fallback::FallbackModel::new()));
+
     // Create and manage voice command executor and registry
     let (command_matcher, action_dispatcher) = setup_voice_commands(app)?;
 
@@ -104,6 +148,8 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
         action_dispatcher.as_ref(),
     )?;
     app.manage(transcription_service.clone());
+    // Other output paths write through the same clipboard as the service
+    app.manage(transcription_service.clipboard());
     crate::debug!("RecordingTranscriptionService created and managed");
 
     // Create SINGLE shared shortcut backend for all hotkeys
@@ -140,9 +186,40 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
         .and_then(|v| serde_json::from_value(v.clone()).ok())
         .unwrap_or_default();
 
+    let toggle_debounce_ms = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(hotkey::integration::TOGGLE_DEBOUNCE_MS_KEY))
+        .and_then(|v| v.as_u64());
+
+    let cancel_shortcut = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(hotkey::integration::CANCEL_SHORTCUT_KEY))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.trim().is_empty());
+
+    let cancel_grace_ms = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(hotkey::integration::CANCEL_GRACE_MS_KEY))
+        .and_then(|v| v.as_u64());
+
     // Set recording mode on the integration
     if let Ok(mut guard) = integration.lock() {
         guard.set_recording_mode(recording_mode);
+        if let Some(ms) = toggle_debounce_ms {
+            guard.set_debounce_duration(std::time::Duration::from_millis(ms));
+        }
+        if let Some(shortcut) = cancel_shortcut {
+            guard.set_cancel_shortcut(shortcut);
+        }
+        if let Some(ms) = cancel_grace_ms {
+            guard.set_cancel_grace(std::time::Duration::from_millis(ms));
+        } else {
+            // Keep the default grace covering a timed-out primary plus a fallback attempt
+            guard.set_cancel_grace(transcription_service.transcription_timeout() * 2);
+        }
     }
 
     // Reuse shared_backend for main hotkey registration
@@ -157,6 +234,7 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
             app.handle().clone(),
             recording_mode,
         );
+        service.set_recording_registered(registered);
 
         if !registered {
             crate::warn!("Backend doesn't support key release detection");
@@ -220,6 +298,42 @@ fn setup_worktree(app: &App) -> Result<Option<worktree::WorktreeContext>, Box<dy
     Ok(worktree_context)
 }
 
+/// Create the splash window from its config entry, unless the splash is disabled.
+///
+/// The splash is skipped when `HEYCAT_NO_SPLASH` is set or the
+/// `app.skipSplash` setting is true; only the main window is created then.
+fn setup_splash_window(app: &App, settings_file: &str) {
+    let skip_splash = std::env::var(commands::window::SKIP_SPLASH_ENV).is_ok()
+        || app
+            .store(settings_file)
+            .ok()
+            .and_then(|store| store.get(commands::window::SKIP_SPLASH_KEY))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+    if skip_splash {
+        crate::info!("Splash window disabled, starting with main window only");
+        return;
+    }
+
+    let Some(config) = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|w| w.label == commands::window::SPLASH_WINDOW_LABEL)
+    else {
+        crate::debug!("No splash window configured");
+        return;
+    };
+
+    match tauri::WebviewWindowBuilder::from_config(app.handle(), config)
+        .and_then(|builder| builder.build())
+    {
+        Ok(_) => crate::debug!("Splash window created"),
+        Err(e) => crate::warn!("Failed to create splash window: {}", e),
+    }
+}
+
 /// Set dynamic window title based on worktree context.
 fn setup_window_title(
     app: &App,
@@ -300,6 +414,35 @@ fn create_instance_lock(worktree_context: &Option<worktree::WorktreeContext>) {
     }
 }
 
+/// Reconcile the recordings directory with Turso in the background.
+///
+/// Skipped when persistence is off, since those recordings are meant to stay
+/// out of the database.
+fn spawn_recording_reconciliation(
+    app: &App,
+    turso_client: &Arc<turso::TursoClient>,
+    recordings_dir: &std::path::Path,
+) {
+    let app_handle = app.handle().clone();
+    if !crate::storage::persist_transcriptions(&app_handle) {
+        crate::debug!("Persistence disabled, skipping recording reconciliation");
+        return;
+    }
+    let client = turso_client.clone();
+    let recordings_dir = recordings_dir.to_path_buf();
+
+    tauri::async_runtime::spawn(async move {
+        match crate::storage::reconcile_orphaned_recordings(&client, &recordings_dir).await {
+            Ok(recovered) if !recovered.is_empty() => {
+                crate::info!("Recovered {} orphaned recording(s)", recovered.len());
+                turso::events::emit_recordings_updated(&app_handle, "add", None);
+            }
+            Ok(_) => crate::debug!("No orphaned recordings found"),
+            Err(e) => crate::warn!("Recording reconciliation failed: {}", e),
+        }
+    });
+}
+
 /// Initialize Turso/libsql embedded database client.
 fn setup_turso_database(
     worktree_context: &Option<worktree::WorktreeContext>,
@@ -341,22 +484,67 @@ fn setup_audio_engine(
     worktree_context: &Option<worktree::WorktreeContext>,
     audio_monitor: &Arc<audio::AudioMonitorHandle>,
 ) {
-    let saved_device = {
+    let (
+        saved_device,
+        input_gain,
+        channel_selection,
+        prefer_native_rate,
+        wav_bit_depth,
+        device_retry,
+    ) = {
         let settings_file = worktree_context
             .as_ref()
             .map(|ctx| ctx.settings_file_name())
             .unwrap_or_else(|| worktree::DEFAULT_SETTINGS_FILE.to_string());
-        app.store(&settings_file)
-            .ok()
+        let store = app.store(&settings_file).ok();
+        let device = store
+            .as_ref()
             .and_then(|store| store.get("audio.selectedDevice"))
-            .and_then(|v| v.as_str().map(|s| s.to_string()))
+            .and_then(|v| v.as_str().map(|s| s.to_string()));
+        let gain = audio::gain::input_gain_from_setting(
+            store
+                .as_ref()
+                .and_then(|store| store.get(audio::gain::INPUT_GAIN_KEY))
+                .and_then(|v| v.as_f64()),
+        );
+        let channel = audio::channel::ChannelSelection::from_setting(
+            store
+                .as_ref()
+                .and_then(|store| store.get(audio::channel::CHANNEL_SELECTION_KEY))
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .as_deref(),
+        );
+        let prefer_native = store
+            .as_ref()
+            .and_then(|store| store.get(audio::resample::PREFER_NATIVE_SAMPLE_RATE_KEY))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let bit_depth = audio::WavBitDepth::from_setting(
+            store
+                .as_ref()
+                .and_then(|store| store.get(audio::WAV_BIT_DEPTH_KEY))
+                .and_then(|v| v.as_u64()),
+        );
+        let retry = audio::device_check::retry_interval_from_setting(
+            store
+                .as_ref()
+                .and_then(|store| store.get(audio::device_check::NO_DEVICE_RETRY_SECS_KEY))
+                .and_then(|v| v.as_u64()),
+        );
+        (device, gain, channel, prefer_native, bit_depth, retry)
     };
 
+    // Set before the engine starts so the first buffers are already processed
+    crate::swift::audio_engine_set_input_gain(input_gain);
+    crate::swift::audio_engine_set_channel_selection(channel_selection);
+    crate::swift::audio_engine_set_prefer_native_sample_rate(prefer_native_rate);
+    crate::swift::audio_engine_set_wav_bit_depth(wav_bit_depth);
+
     if let Some(ref device) = saved_device {
         crate::debug!("Pre-initializing audio engine with saved device: {}", device);
     }
 
-    if let Err(e) = audio_monitor.init(saved_device) {
+    if let Err(e) = audio_monitor.init(saved_device.clone()) {
         crate::warn!(
             "Failed to pre-initialize audio engine: {} (will initialize lazily)",
             e
@@ -367,6 +555,16 @@ fn setup_audio_engine(
 
     // Initialize device change handler to restart audio engine when devices connect/disconnect
     device_handler::init_device_change_handler();
+
+    // Warn (and wait for a device) when none is present, e.g. a USB mic still enumerating
+    let device_status = Arc::new(audio::device_check::InputDeviceStatus::new());
+    app.manage(device_status.clone());
+    audio::device_check::check_input_devices_at_startup(
+        app.handle(),
+        device_status,
+        device_retry,
+        saved_device,
+    );
 }
 
 /// Set up voice command executor and registry.
@@ -384,7 +582,33 @@ fn setup_voice_commands(
     let dispatcher = executor_state.dispatcher.clone();
     app.manage(executor_state);
 
-    let command_matcher = Arc::new(voice_commands::matcher::CommandMatcher::new());
+    let store = app.store(crate::util::get_settings_file(app.handle())).ok();
+    let max_candidates = store
+        .as_ref()
+        .and_then(|store| store.get(voice_commands::matcher::MAX_AMBIGUOUS_CANDIDATES_KEY))
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize)
+        .unwrap_or(voice_commands::matcher::DEFAULT_MAX_AMBIGUOUS_CANDIDATES);
+    let flag = |key: &str, default: bool| {
+        store
+            .as_ref()
+            .and_then(|store| store.get(key))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(default)
+    };
+    let normalization = voice_commands::matcher::NormalizationOptions {
+        lowercase: flag(voice_commands::matcher::IGNORE_CASE_KEY, true),
+        strip_accents: flag(voice_commands::matcher::IGNORE_ACCENTS_KEY, false),
+        strip_punctuation: flag(voice_commands::matcher::IGNORE_PUNCTUATION_KEY, false),
+    };
+    crate::debug!("Command trigger normalization: {:?}", normalization);
+    let command_matcher = Arc::new(voice_commands::matcher::CommandMatcher::with_config(
+        voice_commands::matcher::MatcherConfig {
+            max_candidates,
+            normalization,
+            ..Default::default()
+        },
+    ));
     crate::debug!("Voice command infrastructure initialized");
 
     Ok((command_matcher, Some(dispatcher)))
@@ -392,24 +616,49 @@ fn setup_voice_commands(
 
 /// Load transcription model at startup if available.
 fn load_transcription_model(app: &App, shared_model: &Arc<parakeet::SharedTranscriptionModel>) {
-    if let Ok(true) = model::check_model_exists_for_type(model::download::ModelType::ParakeetTDT) {
-        if let Ok(model_dir) = model::download::get_model_dir(model::download::ModelType::ParakeetTDT)
-        {
-            crate::info!("Loading shared Parakeet TDT model from {:?}...", model_dir);
-            match shared_model.load(&model_dir) {
-                Ok(()) => {
-                    crate::info!(
-                        "Shared Parakeet TDT model loaded successfully (saves ~3GB by sharing)"
-                    );
-
-                    wake_handler::init_wake_handler(
-                        app.handle().clone(),
-                        (**shared_model).clone(),
-                        model_dir,
-                    );
-                }
-                Err(e) => crate::warn!("Failed to load Parakeet TDT model: {}", e),
+    // Prefer the directory chosen with set_model_path while it stays complete
+    let saved_dir = app
+        .store(crate::util::get_settings_file(app.handle()))
+        .ok()
+        .and_then(|store| store.get(model::MODEL_PATH_KEY))
+        .and_then(|v| v.as_str().map(std::path::PathBuf::from))
+        .filter(|dir| {
+            let complete = dir.is_dir()
+                && model::missing_model_files(dir, &model::ModelManifest::tdt()).is_empty();
+            if !complete {
+                crate::warn!(
+                    "Saved model directory {:?} is incomplete, using default",
+                    dir
+                );
             }
+            complete
+        });
+
+    let model_dir = match saved_dir {
+        Some(dir) => Some(dir),
+        None => match model::check_model_exists_for_type(model::download::ModelType::ParakeetTDT) {
+            Ok(true) => {
+                model::download::get_model_dir(model::download::ModelType::ParakeetTDT).ok()
+            }
+            _ => None,
+        },
+    };
+
+    if let Some(model_dir) = model_dir {
+        crate::info!("Loading shared Parakeet TDT model from {:?}...", model_dir);
+        match shared_model.load(&model_dir) {
+            Ok(()) => {
+                crate::info!(
+                    "Shared Parakeet TDT model loaded successfully (saves ~3GB by sharing)"
+                );
+
+                wake_handler::init_wake_handler(
+                    app.handle().clone(),
+                    (**shared_model).clone(),
+                    model_dir,
+                );
+            }
+            Err(e) => crate::warn!("Failed to load Parakeet TDT model: {}", e),
         }
     } else {
         crate::info!(
@@ -432,6 +681,7 @@ fn setup_transcription_service(
     let mut transcription_service = transcription::RecordingTranscriptionService::new(
         shared_model.clone(),
         transcription_service_emitter,
+        Arc::new(transcription::clipboard::TauriClipboardWriter::new(app.handle().clone())),
         recording_state.clone(),
         app.handle().clone(),
     );
@@ -499,6 +749,16 @@ fn setup_transcription_service(
     transcription_service = transcription_service.with_context_resolver(context_resolver);
     crate::debug!("Context resolver wired to TranscriptionService");
 
+    // Apply a saved transcription timeout
+    let transcription_timeout = transcription::timeout::transcription_timeout_from_setting(
+        app.store(crate::util::get_settings_file(app.handle()))
+            .ok()
+            .and_then(|store| store.get(transcription::timeout::TRANSCRIPTION_TIMEOUT_SECS_KEY))
+            .and_then(|v| v.as_u64()),
+        transcription_service.transcription_timeout(),
+    );
+    transcription_service = transcription_service.with_transcription_timeout(transcription_timeout);
+
     Ok(Arc::new(transcription_service))
 }
 
@@ -546,6 +806,7 @@ fn setup_hotkey_integration(
     .with_recordings_dir(recordings_dir.clone())
     .with_shortcut_backend(shared_backend)
     .with_transcription_callback(transcription_callback)
+    .with_transcription_timeout(transcription_service.transcription_timeout())
     .with_hotkey_emitter(hotkey_emitter)
     .with_silence_detection_enabled(false);
 
@@ -570,7 +831,10 @@ fn setup_hotkey_integration(
         let escape_callback: Arc<dyn Fn() + Send + Sync> = Arc::new(move || {
             crate::debug!("Double-tap Escape detected, cancelling recording");
             if let Ok(mut guard) = integration_for_escape.lock() {
-                guard.cancel_recording(&state_for_escape, "double-tap-escape");
+                // After recording has stopped, the same gesture cancels transcription
+                if !guard.cancel_recording(&state_for_escape, "double-tap-escape") {
+                    guard.cancel_transcription("double-tap-escape");
+                }
             } else {
                 crate::error!("Failed to acquire integration lock for cancel");
             }
@@ -582,6 +846,49 @@ fn setup_hotkey_integration(
         }
     }
 
+    // Start recordings queued behind a running transcription (toggle mode), and
+    // choose how a poisoned recording lock is handled
+    {
+        let store = app.store(crate::util::get_settings_file(app.handle())).ok();
+        let queue_depth = store
+            .as_ref()
+            .and_then(|store| store.get(recording::QUEUE_DEPTH_KEY))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as usize;
+        let poison_recovery = recording::LockPoisonRecovery::from_setting(
+            store
+                .as_ref()
+                .and_then(|store| store.get(recording::LOCK_POISON_RECOVERY_KEY))
+                .as_ref()
+                .and_then(|v| v.as_str()),
+        );
+        let integration_for_queue = integration.clone();
+        let state_for_queue = recording_state.clone();
+        let queued_start_handler: transcription::cancel::TranscriptionsFinishedHandler =
+            Arc::new(move || {
+                // Runs as a transcription task ends; start from another thread
+                let integration = integration_for_queue.clone();
+                let state = state_for_queue.clone();
+                std::thread::spawn(move || {
+                    if let Ok(mut guard) = integration.lock() {
+                        guard.start_queued_recording(&state);
+                    } else {
+                        crate::error!("Failed to acquire integration lock for queued recording");
+                    }
+                });
+            });
+        transcription::cancel::transcriptions().set_finished_handler(queued_start_handler);
+
+        if let Ok(mut manager) = recording_state.lock() {
+            manager.set_queue_depth(queue_depth);
+            manager.set_lock_poison_recovery(poison_recovery);
+            crate::debug!(
+                "Recording queue depth set to {}",
+                queue_depth.min(recording::MAX_QUEUED_STARTS)
+            );
+        }
+    }
+
     Ok(integration)
 }
 
@@ -667,4 +974,7 @@ pub fn on_window_destroyed(window: &tauri::Window) {
             }
         }
     }
+
+    // Stop the audio engine so a device switched to 16kHz gets its own rate back
+    crate::swift::audio_engine_stop();
 }
@@ -0,0 +1,59 @@
+// Input channel selection
+//
+// The Swift engine downmixes multi-channel input to mono by averaging, which
+// halves the level of USB interfaces that only carry signal on one channel.
+// `ChannelSelection` lets the user take a single channel instead, or let the
+// engine pick the louder one. The Swift tap implements the selection natively;
+// this module resolves the setting and its FFI code.
+
+use serde::{Deserialize, Serialize};
+
+/// Settings key for the input channel selection ("auto", "left", "right" or "mix")
+pub const CHANNEL_SELECTION_KEY: &str = "audio.channelSelection";
+
+/// How multi-channel input is reduced to the mono capture stream
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelSelection {
+    /// Use whichever channel carries more energy over the first second
+    Auto,
+    /// Use the first channel only
+    Left,
+    /// Use the second channel only
+    Right,
+    /// Average all channels (the engine's native downmix)
+    #[default]
+    Mix,
+}
+
+impl ChannelSelection {
+    /// Resolve the channel selection from its settings value
+    ///
+    /// Unset or unrecognised values fall back to mixing all channels.
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            Some("auto") => Self::Auto,
+            Some("left") => Self::Left,
+            Some("right") => Self::Right,
+            Some("mix") | None => Self::Mix,
+            Some(other) => {
+                crate::warn!("Ignoring unknown channel selection '{}', using mix", other);
+                Self::Mix
+            }
+        }
+    }
+
+    /// Code passed to the Swift engine (matches its `ChannelSelection` raw values)
+    pub fn ffi_code(self) -> i64 {
+        match self {
+            Self::Mix => 0,
+            Self::Left => 1,
+            Self::Right => 2,
+            Self::Auto => 3,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "channel_test.rs"]
+mod tests;
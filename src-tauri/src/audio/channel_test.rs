@@ -0,0 +1,11 @@
+use super::*;
+
+#[test]
+fn test_from_setting() {
+    assert_eq!(ChannelSelection::from_setting(None), ChannelSelection::Mix);
+    assert_eq!(ChannelSelection::from_setting(Some("auto")), ChannelSelection::Auto);
+    assert_eq!(ChannelSelection::from_setting(Some("left")), ChannelSelection::Left);
+    assert_eq!(ChannelSelection::from_setting(Some("right")), ChannelSelection::Right);
+    assert_eq!(ChannelSelection::from_setting(Some("stereo")), ChannelSelection::Mix);
+}
+
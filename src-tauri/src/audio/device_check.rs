@@ -0,0 +1,150 @@
+// Input device check at startup
+//
+// A laptop can finish booting before its USB microphone enumerates, leaving no
+// input device at launch. Instead of letting the first recording fail, startup
+// emits `no_input_device` and, unless retrying is turned off, polls device
+// discovery until a device appears. The audio engine is then started and
+// `input_device_available` clears the warning. The event fires before the
+// webview is listening, so the warning is also kept in `InputDeviceStatus`
+// for the frontend to query once it loads.
+
+use super::{list_input_devices, AudioInputDevice};
+use crate::emit_or_warn;
+use crate::events::{
+    current_timestamp, event_names, InputDeviceAvailablePayload, NoInputDevicePayload,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// Settings key for seconds between device discovery retries (0 disables retrying)
+pub const NO_DEVICE_RETRY_SECS_KEY: &str = "audio.noDeviceRetrySecs";
+
+/// Default seconds between device discovery retries
+pub const DEFAULT_NO_DEVICE_RETRY_SECS: u64 = 5;
+
+/// Resolve the retry interval from its settings value
+///
+/// Returns None when retrying is turned off with 0.
+pub fn retry_interval_from_setting(value: Option<u64>) -> Option<Duration> {
+    match value.unwrap_or(DEFAULT_NO_DEVICE_RETRY_SECS) {
+        0 => None,
+        secs => Some(Duration::from_secs(secs)),
+    }
+}
+
+/// Name of the device to report once devices appear: the default, else the first
+pub fn preferred_device_name(devices: &[AudioInputDevice]) -> Option<String> {
+    devices
+        .iter()
+        .find(|device| device.is_default)
+        .or_else(|| devices.first())
+        .map(|device| device.name.clone())
+}
+
+/// Startup input device warning, kept until a device appears
+#[derive(Debug, Default)]
+pub struct InputDeviceStatus {
+    missing: Mutex<Option<NoInputDevicePayload>>,
+}
+
+impl InputDeviceStatus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The pending `no_input_device` warning, None once a device is present
+    pub fn missing(&self) -> Option<NoInputDevicePayload> {
+        self.missing.lock().ok().and_then(|missing| missing.clone())
+    }
+
+    fn set_missing(&self, payload: Option<NoInputDevicePayload>) {
+        if let Ok(mut missing) = self.missing.lock() {
+            *missing = payload;
+        }
+    }
+}
+
+/// Check for an input device at startup, waiting for one if none is present
+///
+/// Emits `no_input_device` and records it in `status` when no device exists.
+/// With a retry interval, discovery is repeated until a device appears; the
+/// audio engine is then started with `saved_device`, the status is cleared
+/// and `input_device_available` is emitted.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn check_input_devices_at_startup(
+    app_handle: &AppHandle,
+    status: Arc<InputDeviceStatus>,
+    retry_interval: Option<Duration>,
+    saved_device: Option<String>,
+) {
+    if !list_input_devices().is_empty() {
+        return;
+    }
+
+    crate::warn!("No audio input device found at startup");
+    let payload = NoInputDevicePayload {
+        retry_interval_secs: retry_interval.map(|interval| interval.as_secs()),
+        timestamp: current_timestamp(),
+    };
+    status.set_missing(Some(payload.clone()));
+    emit_or_warn!(app_handle, event_names::NO_INPUT_DEVICE, payload);
+
+    let Some(interval) = retry_interval else {
+        crate::info!("Input device discovery retry disabled");
+        return;
+    };
+
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            let devices = tauri::async_runtime::spawn_blocking(list_input_devices)
+                .await
+                .unwrap_or_default();
+            let Some(device_name) = preferred_device_name(&devices) else {
+                crate::debug!("Still no audio input device, retrying in {:?}", interval);
+                continue;
+            };
+
+            crate::info!("Audio input device appeared: {}", device_name);
+            start_engine_for_new_device(saved_device).await;
+            status.set_missing(None);
+            emit_or_warn!(
+                app_handle,
+                event_names::INPUT_DEVICE_AVAILABLE,
+                InputDeviceAvailablePayload {
+                    device_name,
+                    timestamp: current_timestamp(),
+                }
+            );
+            break;
+        }
+    });
+}
+
+/// Start the audio engine that couldn't start without a device
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn start_engine_for_new_device(saved_device: Option<String>) {
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        if crate::swift::audio_engine_is_running() {
+            return crate::swift::AudioEngineResult::Ok;
+        }
+        crate::swift::audio_engine_start(saved_device.as_deref())
+    })
+    .await;
+
+    match result {
+        Ok(crate::swift::AudioEngineResult::Ok) => {
+            crate::info!("Audio engine running with the new input device");
+        }
+        Ok(crate::swift::AudioEngineResult::Failed(e)) => {
+            crate::warn!("Failed to start audio engine for new input device: {}", e);
+        }
+        Err(e) => crate::error!("Audio engine start task panicked: {}", e),
+    }
+}
+
+#[cfg(test)]
+#[path = "device_check_test.rs"]
+mod tests;
@@ -0,0 +1,51 @@
+use super::*;
+
+fn device(name: &str, is_default: bool) -> AudioInputDevice {
+    AudioInputDevice {
+        name: name.to_string(),
+        is_default,
+    }
+}
+
+#[test]
+fn test_retry_interval_defaults_and_disables() {
+    assert_eq!(
+        retry_interval_from_setting(None),
+        Some(Duration::from_secs(DEFAULT_NO_DEVICE_RETRY_SECS))
+    );
+    assert_eq!(
+        retry_interval_from_setting(Some(30)),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(retry_interval_from_setting(Some(0)), None);
+}
+
+#[test]
+fn test_preferred_device_name_prefers_default() {
+    let devices = [device("USB Mic", false), device("MacBook Microphone", true)];
+    assert_eq!(
+        preferred_device_name(&devices).as_deref(),
+        Some("MacBook Microphone")
+    );
+
+    let devices = [device("USB Mic", false)];
+    assert_eq!(preferred_device_name(&devices).as_deref(), Some("USB Mic"));
+
+    assert_eq!(preferred_device_name(&[]), None);
+}
+
+#[test]
+fn test_input_device_status_tracks_missing_device() {
+    let status = InputDeviceStatus::new();
+    assert_eq!(status.missing(), None);
+
+    let payload = NoInputDevicePayload {
+        retry_interval_secs: Some(5),
+        timestamp: "2026-01-01T00:00:00Z".to_string(),
+    };
+    status.set_missing(Some(payload.clone()));
+    assert_eq!(status.missing(), Some(payload));
+
+    status.set_missing(None);
+    assert_eq!(status.missing(), None);
+}
@@ -31,6 +31,8 @@ pub enum QualityWarningType {
     TooQuiet,
     /// Input signal is clipping (distortion)
     Clipping,
+    /// Another app appears to be using the microphone
+    MicrophoneBusy,
 }
 
 /// Severity level for quality warnings
@@ -51,6 +53,42 @@ pub struct QualityWarning {
     pub message: String,
 }
 
+impl QualityWarning {
+    /// Warning that another app appears to hold the input device
+    pub fn microphone_busy(device_name: Option<&str>) -> Self {
+        let device = device_name.unwrap_or("The default microphone");
+        Self {
+            warning_type: QualityWarningType::MicrophoneBusy,
+            severity: WarningSeverity::Warning,
+            message: format!(
+                "{} appears to be in use by another app. The recording may be silent.",
+                device
+            ),
+        }
+    }
+}
+
+/// Warn the frontend before recording if another app appears to hold the microphone
+///
+/// Best effort: a busy device is reported via `recording_quality_warning` but
+/// never blocks the recording.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn warn_if_microphone_busy(app_handle: &tauri::AppHandle, device_name: Option<&str>) {
+    use tauri::Emitter;
+
+    if !crate::swift::is_microphone_busy(device_name) {
+        return;
+    }
+
+    let warning = QualityWarning::microphone_busy(device_name);
+    crate::warn!("{}", warning.message);
+    crate::emit_or_warn!(
+        app_handle,
+        crate::events::event_names::RECORDING_QUALITY_WARNING,
+        &warning
+    );
+}
+
 /// Audio level metrics
 #[derive(Debug, Clone, Default)]
 pub struct LevelMetrics {
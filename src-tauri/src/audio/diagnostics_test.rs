@@ -109,3 +109,17 @@ fn test_diagnostics_default() {
     assert_eq!(diag.input_metrics().sample_count, 0);
     assert_eq!(diag.clipping_count(), 0);
 }
+
+#[test]
+fn test_microphone_busy_warning_names_device() {
+    let warning = QualityWarning::microphone_busy(Some("USB Mic"));
+    assert_eq!(warning.warning_type, QualityWarningType::MicrophoneBusy);
+    assert_eq!(warning.severity, WarningSeverity::Warning);
+    assert!(warning.message.starts_with("USB Mic "));
+
+    let default = QualityWarning::microphone_busy(None);
+    assert!(default.message.starts_with("The default microphone "));
+
+    let json = serde_json::to_value(&warning).unwrap();
+    assert_eq!(json["warning_type"], "microphone_busy");
+}
@@ -0,0 +1,23 @@
+// Recording length math shared by capture, storage and import paths
+//
+// Sample counts are carried as u64 so multi-hour recordings at high sample
+// rates never wrap a 32-bit count.
+
+/// Duration in seconds for a number of samples at the given rate
+///
+/// Returns 0.0 for a sample rate of 0 rather than dividing by zero.
+pub fn duration_secs(sample_count: u64, sample_rate: u32) -> f64 {
+    if sample_rate == 0 {
+        return 0.0;
+    }
+    sample_count as f64 / sample_rate as f64
+}
+
+/// Number of samples covering a duration in milliseconds at the given rate
+///
+/// Uses 128-bit intermediate math so the product can't overflow, and
+/// saturates at `u64::MAX`.
+pub fn samples_for_duration_ms(duration_ms: u64, sample_rate: u32) -> u64 {
+    let samples = duration_ms as u128 * sample_rate as u128 / 1000;
+    u64::try_from(samples).unwrap_or(u64::MAX)
+}
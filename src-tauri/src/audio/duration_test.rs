@@ -0,0 +1,34 @@
+use super::duration::*;
+
+const TWO_HOURS_MS: u64 = 2 * 60 * 60 * 1000;
+
+#[test]
+fn test_duration_secs_basic() {
+    assert!((duration_secs(16000, 16000) - 1.0).abs() < f64::EPSILON);
+    assert!((duration_secs(8000, 16000) - 0.5).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_duration_secs_zero_rate() {
+    assert_eq!(duration_secs(16000, 0), 0.0);
+}
+
+#[test]
+fn test_two_hour_recording_duration() {
+    let samples = samples_for_duration_ms(TWO_HOURS_MS, 48000);
+    assert_eq!(samples, 345_600_000);
+    assert!((duration_secs(samples, 48000) - 7200.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_sample_count_beyond_u32_range() {
+    // ~25 hours at 48kHz exceeds u32::MAX samples
+    let samples = samples_for_duration_ms(25 * 60 * 60 * 1000, 48000);
+    assert!(samples > u32::MAX as u64);
+    assert!((duration_secs(samples, 48000) - 90_000.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_samples_for_duration_saturates() {
+    assert_eq!(samples_for_duration_ms(u64::MAX, 48000), u64::MAX);
+}
@@ -0,0 +1,34 @@
+// Software input gain
+//
+// A fixed multiplier applied to raw input samples before they are resampled,
+// for microphones that are too quiet even after normalization. The Swift
+// engine applies the gain natively in its tap, clamping to [-1.0, 1.0]; this
+// module only resolves the setting.
+
+/// Settings key for the input gain multiplier
+pub const INPUT_GAIN_KEY: &str = "audio.inputGain";
+
+/// Gain used when the setting is unset (no change)
+pub const DEFAULT_INPUT_GAIN: f32 = 1.0;
+
+/// Highest accepted gain, so a bad setting can't turn noise into a square wave
+pub const MAX_INPUT_GAIN: f32 = 8.0;
+
+/// Resolve the input gain from its settings value
+///
+/// Falls back to `DEFAULT_INPUT_GAIN` when unset or not a finite number and is
+/// clamped to `0.0..=MAX_INPUT_GAIN`.
+pub fn input_gain_from_setting(value: Option<f64>) -> f32 {
+    match value {
+        Some(gain) if gain.is_finite() => (gain as f32).clamp(0.0, MAX_INPUT_GAIN),
+        Some(gain) => {
+            crate::warn!("Invalid {} value {}, using {}", INPUT_GAIN_KEY, gain, DEFAULT_INPUT_GAIN);
+            DEFAULT_INPUT_GAIN
+        }
+        None => DEFAULT_INPUT_GAIN,
+    }
+}
+
+#[cfg(test)]
+#[path = "gain_test.rs"]
+mod tests;
@@ -0,0 +1,11 @@
+use super::*;
+
+#[test]
+fn test_gain_defaults_and_clamps() {
+    assert_eq!(input_gain_from_setting(None), DEFAULT_INPUT_GAIN);
+    assert_eq!(input_gain_from_setting(Some(2.5)), 2.5);
+    assert_eq!(input_gain_from_setting(Some(100.0)), MAX_INPUT_GAIN);
+    assert_eq!(input_gain_from_setting(Some(-1.0)), 0.0);
+    assert_eq!(input_gain_from_setting(Some(f64::NAN)), DEFAULT_INPUT_GAIN);
+}
+
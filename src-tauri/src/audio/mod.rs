@@ -12,6 +12,9 @@ pub use swift_backend::SwiftBackend;
 mod device;
 pub use device::{list_input_devices, AudioInputDevice};
 
+// Startup check for a missing input device, retrying discovery until one appears
+pub mod device_check;
+
 mod error;
 pub use error::AudioDeviceError;
 
@@ -22,7 +25,25 @@ pub mod thread;
 pub use thread::AudioThreadHandle;
 
 pub mod wav;
-pub use wav::{encode_wav, parse_duration_from_file, SystemFileWriter};
+pub use wav::{
+    decode_wav_samples, encode_wav, parse_duration_from_file, parse_sample_count_from_file,
+    read_wav_samples, SystemFileWriter, WavBitDepth, WavEncodingError, WAV_BIT_DEPTH_KEY,
+};
+
+pub mod opus;
+pub use opus::encode_opus;
+
+pub mod duration;
+pub use duration::{duration_secs, samples_for_duration_ms};
+
+// Resampler diagnostics (the Swift backend converts natively)
+pub mod resample;
+
+// Input channel selection (applied natively by the Swift engine)
+pub mod channel;
+
+// Software input gain (applied natively by the Swift engine)
+pub mod gain;
 
 pub mod diagnostics;
 #[allow(unused_imports)]
@@ -34,6 +55,12 @@ mod mod_test;
 #[cfg(test)]
 mod wav_test;
 
+#[cfg(test)]
+mod opus_test;
+
+#[cfg(test)]
+mod duration_test;
+
 /// Thread-safe buffer for storing audio samples using lock-free ring buffer
 ///
 /// Uses a SPSC ring buffer for low-contention audio capture:
@@ -204,7 +231,10 @@ impl std::fmt::Display for AudioCaptureError {
 impl std::error::Error for AudioCaptureError {}
 
 /// Reason why audio capture was stopped automatically
-#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+///
+/// Serialized (and stored in Turso) by variant name; `as_str` and `FromStr`
+/// use the same names, so existing rows keep parsing as variants are added.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum StopReason {
     /// Buffer reached maximum capacity (~10 minutes)
     #[allow(dead_code)]
@@ -224,6 +254,40 @@ pub enum StopReason {
     /// No speech detected after wake word (false activation timeout)
     #[allow(dead_code)] // Used by silence detection in listening module
     NoSpeechTimeout,
+    /// Recording was recovered after the fact (e.g. an orphaned file) so the real reason is unknown
+    Recovered,
+}
+
+impl StopReason {
+    /// Stable name of the reason, as serialized and stored
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopReason::BufferFull => "BufferFull",
+            StopReason::LockError => "LockError",
+            StopReason::StreamError => "StreamError",
+            StopReason::ResampleOverflow => "ResampleOverflow",
+            StopReason::SilenceAfterSpeech => "SilenceAfterSpeech",
+            StopReason::NoSpeechTimeout => "NoSpeechTimeout",
+            StopReason::Recovered => "Recovered",
+        }
+    }
+}
+
+impl std::str::FromStr for StopReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "BufferFull" => Ok(StopReason::BufferFull),
+            "LockError" => Ok(StopReason::LockError),
+            "StreamError" => Ok(StopReason::StreamError),
+            "ResampleOverflow" => Ok(StopReason::ResampleOverflow),
+            "SilenceAfterSpeech" => Ok(StopReason::SilenceAfterSpeech),
+            "NoSpeechTimeout" => Ok(StopReason::NoSpeechTimeout),
+            "Recovered" => Ok(StopReason::Recovered),
+            _ => Err(format!("Unknown stop reason: {}", s)),
+        }
+    }
 }
 
 /// Trait for audio capture backends (allows mocking in tests)
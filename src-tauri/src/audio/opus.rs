@@ -0,0 +1,198 @@
+// Ogg/Opus encoding module for compact voice-note copies of recordings
+//
+// WAV remains the archival/transcription format; Opus files are small
+// companions intended for sharing. Voice notes are not encoded while
+// recording: the Swift engine captures straight to WAV, and a voice note is
+// encoded afterwards from that saved file when one is requested.
+
+use super::wav::{unique_file_path, FileWriter};
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+use std::path::Path;
+
+/// Opus frame duration in milliseconds (20ms is the recommended default for voice)
+const OPUS_FRAME_MS: u32 = 20;
+
+/// Target bitrate for voice notes in bits per second
+const OPUS_BITRATE_BPS: i32 = 24_000;
+
+/// Maximum size of a single encoded Opus packet (recommended by libopus)
+const MAX_PACKET_SIZE: usize = 4000;
+
+/// Ogg granule positions for Opus are always expressed at 48kHz
+const OPUS_GRANULE_RATE: u32 = 48_000;
+
+/// Ogg stream serial number (single logical stream per file)
+const OGG_STREAM_SERIAL: u32 = 1;
+
+/// Sample rates natively supported by the Opus encoder
+const SUPPORTED_SAMPLE_RATES: [u32; 5] = [8000, 12000, 16000, 24000, 48000];
+
+/// Errors that can occur during Opus encoding
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpusEncodingError {
+    /// I/O error (directory creation, file write)
+    IoError(String),
+    /// Error from the Opus encoder or Ogg writer
+    EncodingError(String),
+    /// Invalid input (empty samples, NaN/infinity values, unsupported sample rate)
+    InvalidInput(String),
+}
+
+impl std::fmt::Display for OpusEncodingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpusEncodingError::IoError(msg) => write!(f, "I/O error: {}", msg),
+            OpusEncodingError::EncodingError(msg) => write!(f, "Encoding error: {}", msg),
+            OpusEncodingError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for OpusEncodingError {}
+
+/// Convert an opus error to OpusEncodingError
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn opus_error(e: ::opus::Error) -> OpusEncodingError {
+    OpusEncodingError::EncodingError(e.to_string())
+}
+
+/// Convert an I/O error to OpusEncodingError
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn io_error(e: std::io::Error) -> OpusEncodingError {
+    OpusEncodingError::IoError(e.to_string())
+}
+
+/// Build the OpusHead identification header (RFC 7845 section 5.1)
+fn opus_head(pre_skip: u16, input_sample_rate: u32) -> Vec<u8> {
+    let mut head = Vec::with_capacity(19);
+    head.extend_from_slice(b"OpusHead");
+    head.push(1); // Version
+    head.push(1); // Channel count (mono)
+    head.extend_from_slice(&pre_skip.to_le_bytes());
+    head.extend_from_slice(&input_sample_rate.to_le_bytes());
+    head.extend_from_slice(&0i16.to_le_bytes()); // Output gain
+    head.push(0); // Channel mapping family
+    head
+}
+
+/// Build the OpusTags comment header (RFC 7845 section 5.2)
+fn opus_tags() -> Vec<u8> {
+    let vendor = b"heycat";
+    let mut tags = Vec::with_capacity(8 + 4 + vendor.len() + 4);
+    tags.extend_from_slice(b"OpusTags");
+    tags.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+    tags.extend_from_slice(vendor);
+    tags.extend_from_slice(&0u32.to_le_bytes()); // No user comments
+    tags
+}
+
+/// Encode audio samples to an Ogg/Opus file
+///
+/// Uses the writer's output directory and generated filename, with the
+/// extension replaced by `.ogg`.
+///
+/// # Arguments
+/// * `samples` - Mono audio samples as f32 values (expected range: -1.0 to 1.0)
+/// * `sample_rate` - Sample rate in Hz (must be 8000, 12000, 16000, 24000 or 48000)
+/// * `writer` - File writer for filesystem operations
+///
+/// # Returns
+/// * `Ok(String)` - Path to the created `.ogg` file
+/// * `Err(OpusEncodingError)` - If encoding fails
+pub fn encode_opus<W: FileWriter>(
+    samples: &[f32],
+    sample_rate: u32,
+    writer: &W,
+) -> Result<String, OpusEncodingError> {
+    // Validate input
+    if samples.is_empty() {
+        return Err(OpusEncodingError::InvalidInput(
+            "Cannot encode empty samples".to_string(),
+        ));
+    }
+
+    if samples.iter().any(|s| !s.is_finite()) {
+        return Err(OpusEncodingError::InvalidInput(
+            "Samples contain NaN or infinity values".to_string(),
+        ));
+    }
+
+    if !SUPPORTED_SAMPLE_RATES.contains(&sample_rate) {
+        return Err(OpusEncodingError::InvalidInput(format!(
+            "Unsupported sample rate for Opus: {} Hz",
+            sample_rate
+        )));
+    }
+
+    // Ensure output directory exists
+    let output_dir = writer.output_dir();
+    if !writer.path_exists(&output_dir) {
+        writer.create_dir_all(&output_dir).map_err(io_error)?;
+    }
+
+    let filename = Path::new(&writer.generate_filename()).with_extension("ogg");
+    let file_path = unique_file_path(writer, &output_dir, &filename.to_string_lossy())
+        .map_err(|e| OpusEncodingError::IoError(e.to_string()))?;
+    crate::info!("Saving voice note to: {}", file_path.display());
+
+    let mut encoder =
+        ::opus::Encoder::new(sample_rate, ::opus::Channels::Mono, ::opus::Application::Voip)
+            .map_err(opus_error)?;
+    encoder
+        .set_bitrate(::opus::Bitrate::Bits(OPUS_BITRATE_BPS))
+        .map_err(opus_error)?;
+
+    let granule_scale = (OPUS_GRANULE_RATE / sample_rate) as u64;
+    let pre_skip = encoder.get_lookahead().map_err(opus_error)? as u64 * granule_scale;
+
+    let file = std::fs::File::create(&file_path).map_err(io_error)?;
+    let mut packet_writer = PacketWriter::new(std::io::BufWriter::new(file));
+
+    // Header packets each occupy their own page
+    packet_writer
+        .write_packet(
+            opus_head(pre_skip as u16, sample_rate),
+            OGG_STREAM_SERIAL,
+            PacketWriteEndInfo::EndPage,
+            0,
+        )
+        .map_err(io_error)?;
+    packet_writer
+        .write_packet(opus_tags(), OGG_STREAM_SERIAL, PacketWriteEndInfo::EndPage, 0)
+        .map_err(io_error)?;
+
+    // Encode fixed-size frames, zero-padding the final frame
+    let frame_size = (sample_rate * OPUS_FRAME_MS / 1000) as usize;
+    let frame_count = samples.len().div_ceil(frame_size);
+    let mut frame = vec![0.0f32; frame_size];
+    let mut packet = vec![0u8; MAX_PACKET_SIZE];
+
+    for (i, chunk) in samples.chunks(frame_size).enumerate() {
+        frame[..chunk.len()].copy_from_slice(chunk);
+        frame[chunk.len()..].fill(0.0);
+
+        let len = encoder.encode_float(&frame, &mut packet).map_err(opus_error)?;
+
+        let is_last = i + 1 == frame_count;
+        let encoded_samples = if is_last {
+            samples.len() as u64
+        } else {
+            ((i + 1) * frame_size) as u64
+        };
+        let granule = pre_skip + encoded_samples * granule_scale;
+        let end_info = if is_last {
+            PacketWriteEndInfo::EndStream
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+
+        packet_writer
+            .write_packet(packet[..len].to_vec(), OGG_STREAM_SERIAL, end_info, granule)
+            .map_err(io_error)?;
+    }
+
+    let mut inner = packet_writer.into_inner();
+    std::io::Write::flush(&mut inner).map_err(io_error)?;
+
+    Ok(file_path.to_string_lossy().to_string())
+}
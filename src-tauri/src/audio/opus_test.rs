@@ -0,0 +1,93 @@
+#![cfg(test)]
+#![cfg_attr(coverage_nightly, coverage(off))]
+
+use super::opus::{encode_opus, OpusEncodingError};
+use super::wav::FileWriter;
+use std::path::{Path, PathBuf};
+
+struct TempFileWriter {
+    output_dir: PathBuf,
+}
+
+impl FileWriter for TempFileWriter {
+    fn output_dir(&self) -> PathBuf {
+        self.output_dir.clone()
+    }
+
+    fn generate_filename(&self) -> String {
+        "voice-note.wav".to_string()
+    }
+
+    fn create_dir_all(&self, path: &Path) -> Result<(), std::io::Error> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn path_exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+#[test]
+fn test_encode_opus_empty_samples() {
+    let writer = TempFileWriter {
+        output_dir: std::env::temp_dir().join("heycat-opus-test-empty"),
+    };
+
+    let result = encode_opus(&[], 16000, &writer);
+    assert!(matches!(result, Err(OpusEncodingError::InvalidInput(_))));
+}
+
+#[test]
+fn test_encode_opus_unsupported_sample_rate() {
+    let writer = TempFileWriter {
+        output_dir: std::env::temp_dir().join("heycat-opus-test-rate"),
+    };
+
+    let result = encode_opus(&[0.1; 441], 44100, &writer);
+    assert!(matches!(result, Err(OpusEncodingError::InvalidInput(_))));
+}
+
+#[test]
+fn test_encode_opus_writes_ogg_file() {
+    let temp_dir = std::env::temp_dir().join("heycat-opus-test-success");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    let writer = TempFileWriter {
+        output_dir: temp_dir.clone(),
+    };
+
+    // One second of a 440Hz tone at 16kHz (not a multiple of the frame size)
+    let samples: Vec<f32> = (0..16010)
+        .map(|i| (i as f32 * 440.0 * 2.0 * std::f32::consts::PI / 16000.0).sin() * 0.5)
+        .collect();
+    let path = encode_opus(&samples, 16000, &writer).unwrap();
+
+    assert!(path.ends_with("voice-note.ogg"));
+    let bytes = std::fs::read(&path).unwrap();
+    assert!(bytes.starts_with(b"OggS"));
+    assert!(bytes.windows(8).any(|w| w == b"OpusHead"));
+    // Opus at 24kbps should be far smaller than 16-bit PCM
+    assert!(bytes.len() < samples.len() * 2 / 4);
+
+    // Cleanup
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
+
+#[test]
+fn test_encode_opus_does_not_overwrite_existing_file() {
+    let temp_dir = std::env::temp_dir().join("heycat-opus-test-collision");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let existing = temp_dir.join("voice-note.ogg");
+    std::fs::write(&existing, b"earlier voice note").unwrap();
+    let writer = TempFileWriter {
+        output_dir: temp_dir.clone(),
+    };
+
+    let path = encode_opus(&[0.1; 1600], 16000, &writer).unwrap();
+
+    assert_eq!(Path::new(&path), temp_dir.join("voice-note-2.ogg"));
+    assert_eq!(std::fs::read(&existing).unwrap(), b"earlier voice note");
+
+    // Cleanup
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
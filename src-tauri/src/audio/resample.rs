@@ -0,0 +1,108 @@
+// Resampler diagnostics
+//
+// `ResamplerInfo` reports how the current device is converted to 16kHz and
+// the latency the converter adds, for debugging clipped word starts.
+//
+// Audio is resampled in chunks sized from the device's own I/O buffer, so a
+// device with small buffers isn't held back waiting for a fixed-size chunk.
+// The Swift engine sizes its tap the same way as `resample_chunk_size`.
+//
+// With `audio.preferNativeSampleRate` on, the engine switches a device that
+// supports 16kHz to that rate so no converter runs at all. Whether a recording
+// took that path is reported on its metadata.
+
+use super::TARGET_SAMPLE_RATE;
+use crate::audio_constants::{
+    MAX_RESAMPLE_CHUNK_SIZE, MIN_RESAMPLE_CHUNK_SIZE, RESAMPLE_CHUNK_SIZE,
+};
+use serde::Serialize;
+
+/// Settings key for switching 16kHz-capable devices to 16kHz to skip resampling
+pub const PREFER_NATIVE_SAMPLE_RATE_KEY: &str = "audio.preferNativeSampleRate";
+
+/// Resample chunk size for a device's reported I/O buffer size
+///
+/// Follows the device buffer within `MIN_RESAMPLE_CHUNK_SIZE` and
+/// `MAX_RESAMPLE_CHUNK_SIZE`; falls back to `RESAMPLE_CHUNK_SIZE` when the
+/// buffer size is unknown.
+pub fn resample_chunk_size(device_buffer_frames: Option<u32>) -> usize {
+    match device_buffer_frames {
+        Some(frames) if frames > 0 => {
+            (frames as usize).clamp(MIN_RESAMPLE_CHUNK_SIZE, MAX_RESAMPLE_CHUNK_SIZE)
+        }
+        _ => RESAMPLE_CHUNK_SIZE,
+    }
+}
+
+/// Resampler details for the current input device
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResamplerInfo {
+    /// Native sample rate of the input device (None if the engine isn't running)
+    pub source_rate: Option<u32>,
+    /// Rate audio is converted to for transcription
+    pub target_rate: u32,
+    /// I/O buffer size reported by the input device (None if unknown)
+    pub device_buffer_frames: Option<u32>,
+    /// Input frames converted per chunk
+    pub chunk_size: usize,
+    /// Whether audio is being resampled
+    pub resampling: bool,
+    /// Output samples held back by the resampler while it primes (None until measured)
+    pub latency_samples: Option<usize>,
+    /// `latency_samples` as milliseconds at the target rate
+    pub latency_ms: Option<f64>,
+}
+
+impl ResamplerInfo {
+    /// Build the info from the device rate and buffer size and the measured latency
+    pub fn new(
+        source_rate: Option<u32>,
+        device_buffer_frames: Option<u32>,
+        latency_samples: Option<usize>,
+    ) -> Self {
+        let resampling = source_rate.is_some_and(|rate| rate != TARGET_SAMPLE_RATE);
+        Self {
+            source_rate,
+            target_rate: TARGET_SAMPLE_RATE,
+            device_buffer_frames,
+            chunk_size: resample_chunk_size(device_buffer_frames),
+            resampling,
+            latency_samples,
+            latency_ms: latency_samples
+                .map(|samples| samples as f64 * 1000.0 / TARGET_SAMPLE_RATE as f64),
+        }
+    }
+}
+
+/// Whether capture from a `source_rate`, `source_channels` input skips conversion
+///
+/// Only 16kHz mono input is captured as is; more channels are still mixed down
+/// by the converter. None if the rate, or for a 16kHz input the channel count,
+/// is unknown.
+pub fn native_sample_rate_used(
+    source_rate: Option<u32>,
+    source_channels: Option<u16>,
+) -> Option<bool> {
+    match source_rate? {
+        TARGET_SAMPLE_RATE => source_channels.map(|channels| channels == 1),
+        _ => Some(false),
+    }
+}
+
+/// Warning for a recording that resampled although the native path was preferred
+///
+/// None when the native path was used, not preferred, or the rate is unknown.
+pub fn native_sample_rate_warning(prefer_native: bool, source_rate: Option<u32>) -> Option<String> {
+    match (prefer_native, source_rate) {
+        (true, Some(rate)) if rate != TARGET_SAMPLE_RATE => Some(format!(
+            "Native {}Hz capture was preferred but the input device runs at {}Hz, resampling instead",
+            TARGET_SAMPLE_RATE, rate
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "resample_test.rs"]
+mod tests;
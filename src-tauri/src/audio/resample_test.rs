@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn test_chunk_size_follows_device_buffer_within_bounds() {
+    assert_eq!(resample_chunk_size(Some(512)), 512);
+    assert_eq!(resample_chunk_size(Some(64)), MIN_RESAMPLE_CHUNK_SIZE);
+    assert_eq!(resample_chunk_size(Some(16384)), MAX_RESAMPLE_CHUNK_SIZE);
+}
+
+#[test]
+fn test_chunk_size_falls_back_when_buffer_unknown() {
+    assert_eq!(resample_chunk_size(None), RESAMPLE_CHUNK_SIZE);
+    assert_eq!(resample_chunk_size(Some(0)), RESAMPLE_CHUNK_SIZE);
+}
+
+#[test]
+fn test_resampler_info_for_48khz_device() {
+    let info = ResamplerInfo::new(Some(48000), Some(512), Some(160));
+    assert!(info.resampling);
+    assert_eq!(info.target_rate, TARGET_SAMPLE_RATE);
+    assert_eq!(info.device_buffer_frames, Some(512));
+    assert_eq!(info.chunk_size, 512);
+    assert_eq!(info.latency_ms, Some(10.0));
+}
+
+#[test]
+fn test_resampler_info_without_engine_or_conversion() {
+    let idle = ResamplerInfo::new(None, None, None);
+    assert!(!idle.resampling);
+    assert_eq!(idle.latency_ms, None);
+
+    let native = ResamplerInfo::new(Some(TARGET_SAMPLE_RATE), None, Some(0));
+    assert!(!native.resampling);
+    assert_eq!(native.latency_samples, Some(0));
+}
+
+#[test]
+fn test_native_sample_rate_used() {
+    assert_eq!(
+        native_sample_rate_used(Some(TARGET_SAMPLE_RATE), Some(1)),
+        Some(true)
+    );
+    assert_eq!(
+        native_sample_rate_used(Some(TARGET_SAMPLE_RATE), Some(2)),
+        Some(false)
+    );
+    assert_eq!(
+        native_sample_rate_used(Some(TARGET_SAMPLE_RATE), None),
+        None
+    );
+    assert_eq!(native_sample_rate_used(Some(48000), Some(1)), Some(false));
+    assert_eq!(native_sample_rate_used(None, Some(1)), None);
+}
+
+#[test]
+fn test_native_sample_rate_warning_only_when_preferred_and_missed() {
+    let warning = native_sample_rate_warning(true, Some(48000)).unwrap();
+    assert!(warning.contains("48000Hz"));
+
+    assert_eq!(native_sample_rate_warning(true, Some(TARGET_SAMPLE_RATE)), None);
+    assert_eq!(native_sample_rate_warning(false, Some(48000)), None);
+    assert_eq!(native_sample_rate_warning(true, None), None);
+}
@@ -10,6 +10,7 @@
 
 use super::{AudioBuffer, AudioCaptureBackend, AudioCaptureError, CaptureState, StopReason, TARGET_SAMPLE_RATE};
 use super::diagnostics::{QualityWarning, RecordingDiagnostics};
+use super::resample::{native_sample_rate_used, native_sample_rate_warning};
 use crate::swift::{self, AudioEngineResult};
 use std::sync::mpsc::Sender;
 use std::sync::Arc;
@@ -41,6 +42,8 @@ pub struct SwiftBackend {
     last_capture_file_path: Option<String>,
     /// Duration of the last recording in milliseconds
     last_duration_ms: u64,
+    /// Whether the current or last recording skipped conversion (None if unknown)
+    native_sample_rate: Option<bool>,
 }
 
 impl SwiftBackend {
@@ -53,6 +56,7 @@ impl SwiftBackend {
             last_warnings: Vec::new(),
             last_capture_file_path: None,
             last_duration_ms: 0,
+            native_sample_rate: None,
         }
     }
 
@@ -76,6 +80,11 @@ impl SwiftBackend {
     pub fn take_capture_file(&mut self) -> Option<(String, u64)> {
         self.last_capture_file_path.take().map(|path| (path, self.last_duration_ms))
     }
+
+    /// Take whether the last recording was captured at 16kHz without resampling
+    pub fn take_native_sample_rate(&mut self) -> Option<bool> {
+        self.native_sample_rate.take()
+    }
 }
 
 impl Default for SwiftBackend {
@@ -146,6 +155,22 @@ impl AudioCaptureBackend for SwiftBackend {
             AudioEngineResult::Ok => {
                 crate::info!("Audio capture started successfully via SharedAudioEngine");
                 self.state = CaptureState::Capturing;
+
+                let source_rate = swift::audio_engine_source_sample_rate();
+                let source_channels = swift::audio_engine_source_channel_count();
+                self.native_sample_rate = native_sample_rate_used(source_rate, source_channels);
+                crate::info!(
+                    "Capturing from {:?}Hz {:?}-channel input (native 16kHz path: {:?})",
+                    source_rate,
+                    source_channels,
+                    self.native_sample_rate
+                );
+                if let Some(warning) = native_sample_rate_warning(
+                    swift::audio_engine_prefers_native_sample_rate(),
+                    source_rate,
+                ) {
+                    crate::warn!("{}", warning);
+                }
                 // AVFoundation captures at 16kHz (configured in Swift)
                 Ok(TARGET_SAMPLE_RATE)
             }
@@ -6,13 +6,18 @@
 
 use super::{AudioBuffer, AudioCaptureBackend, AudioCaptureError, StopReason, SwiftBackend};
 use super::diagnostics::QualityWarning;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
 
 /// Response from a Start command
 pub type StartResponse = Result<u32, AudioCaptureError>;
 
+/// How long `stop` waits for the audio thread to finish tearing down capture
+pub const STOP_JOIN_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Result of stopping a recording (includes reason if auto-stopped)
 #[derive(Debug, Clone)]
 pub struct StopResult {
@@ -25,6 +30,25 @@ pub struct StopResult {
     /// Path to the captured WAV file with duration in ms
     /// Caller should move/rename this to the final location (instant, no I/O)
     pub capture_file: Option<(String, u64)>,
+    /// Whether the device delivered 16kHz mono without conversion (None if unknown)
+    pub native_sample_rate: Option<bool>,
+    /// The audio thread didn't finish stopping capture in time; the other
+    /// fields are empty and capture may still be shutting down
+    pub join_timed_out: bool,
+}
+
+impl StopResult {
+    /// Result of a stop the audio thread didn't finish in time
+    fn timed_out() -> Self {
+        Self {
+            reason: None,
+            warnings: Vec::new(),
+            raw_audio: None,
+            capture_file: None,
+            native_sample_rate: None,
+            join_timed_out: true,
+        }
+    }
 }
 
 /// Commands sent to the audio thread
@@ -51,20 +75,25 @@ pub enum AudioCommand {
 pub struct AudioThreadHandle {
     sender: Sender<AudioCommand>,
     thread: Option<JoinHandle<()>>,
+    /// Set while a Stop is queued or being processed by the audio thread
+    stop_pending: Arc<AtomicBool>,
 }
 
 impl AudioThreadHandle {
     /// Spawn a new audio capture thread
     pub fn spawn() -> Self {
         let (sender, receiver) = mpsc::channel();
+        let stop_pending = Arc::new(AtomicBool::new(false));
 
+        let thread_stop_pending = stop_pending.clone();
         let thread = thread::spawn(move || {
-            audio_thread_main(receiver);
+            audio_thread_main(receiver, thread_stop_pending);
         });
 
         Self {
             sender,
             thread: Some(thread),
+            stop_pending,
         }
     }
 
@@ -101,27 +130,45 @@ impl AudioThreadHandle {
 
     /// Stop audio capture and return the stop result
     ///
-    /// Uses a 5-second timeout to prevent blocking forever if the audio thread
-    /// panics or becomes unresponsive. Returns `StopTimeout` if the timeout expires.
+    /// Blocks until the audio thread has finished tearing down capture, for at
+    /// most `STOP_JOIN_TIMEOUT`, so a stop never overlaps the next start. If the
+    /// audio thread panics or becomes unresponsive the result has
+    /// `join_timed_out` set, and `is_stop_pending` stays true until the audio
+    /// thread catches up.
     #[must_use = "this returns a Result that should be handled"]
     pub fn stop(&self) -> Result<StopResult, AudioThreadError> {
+        self.stop_with_timeout(STOP_JOIN_TIMEOUT)
+    }
+
+    fn stop_with_timeout(&self, timeout: Duration) -> Result<StopResult, AudioThreadError> {
         let (response_tx, response_rx) = mpsc::channel();
-        self.sender
+        self.stop_pending.store(true, Ordering::SeqCst);
+        if self
+            .sender
             .send(AudioCommand::Stop(Some(response_tx)))
-            .map_err(|_| AudioThreadError::ThreadDisconnected)?;
+            .is_err()
+        {
+            self.stop_pending.store(false, Ordering::SeqCst);
+            return Err(AudioThreadError::ThreadDisconnected);
+        }
 
-        // Wait for response with 5-second timeout to prevent blocking forever
-        // if the audio thread panics or becomes unresponsive
         use std::sync::mpsc::RecvTimeoutError;
-        response_rx
-            .recv_timeout(Duration::from_secs(5))
-            .map_err(|e| match e {
-                RecvTimeoutError::Timeout => {
-                    crate::warn!("Audio thread stop timed out after 5 seconds");
-                    AudioThreadError::StopTimeout
-                }
-                RecvTimeoutError::Disconnected => AudioThreadError::ThreadDisconnected,
-            })
+        match response_rx.recv_timeout(timeout) {
+            Ok(result) => Ok(result),
+            Err(RecvTimeoutError::Timeout) => {
+                crate::warn!("Audio thread stop timed out after {:?}", timeout);
+                Ok(StopResult::timed_out())
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(AudioThreadError::ThreadDisconnected),
+        }
+    }
+
+    /// Whether an earlier stop is still being processed by the audio thread
+    ///
+    /// True after a stop timed out, until the audio thread finishes it. A new
+    /// recording must not start while this is set.
+    pub fn is_stop_pending(&self) -> bool {
+        self.stop_pending.load(Ordering::SeqCst)
     }
 
     /// Shutdown the audio thread gracefully (used in tests)
@@ -156,8 +203,6 @@ pub enum AudioThreadError {
     ThreadDisconnected,
     /// Audio capture failed
     CaptureError(AudioCaptureError),
-    /// Stop operation timed out waiting for audio thread response
-    StopTimeout,
 }
 
 impl std::fmt::Display for AudioThreadError {
@@ -165,9 +210,6 @@ impl std::fmt::Display for AudioThreadError {
         match self {
             AudioThreadError::ThreadDisconnected => write!(f, "Audio thread disconnected"),
             AudioThreadError::CaptureError(e) => write!(f, "Audio capture error: {}", e),
-            AudioThreadError::StopTimeout => {
-                write!(f, "Audio thread stop timed out after 5 seconds")
-            }
         }
     }
 }
@@ -186,7 +228,7 @@ impl std::error::Error for AudioThreadError {
 /// Creates SwiftBackend (AVFoundation) and processes commands.
 /// This runs on a dedicated thread for consistent audio handling.
 #[cfg_attr(coverage_nightly, coverage(off))]
-fn audio_thread_main(receiver: Receiver<AudioCommand>) {
+fn audio_thread_main(receiver: Receiver<AudioCommand>, stop_pending: Arc<AtomicBool>) {
     crate::info!("Audio thread started, creating SwiftBackend (AVFoundation)...");
     let mut backend = SwiftBackend::new();
     crate::debug!("SwiftBackend created, waiting for commands...");
@@ -274,11 +316,20 @@ fn audio_thread_main(receiver: Receiver<AudioCommand>) {
                 let warnings = backend.take_warnings();
                 let raw_audio = backend.take_raw_audio();
                 let capture_file = backend.take_capture_file();
+                let native_sample_rate = backend.take_native_sample_rate();
 
                 // Send stop result back
                 if let Some(tx) = response_tx {
-                    let _ = tx.send(StopResult { reason, warnings, raw_audio, capture_file });
+                    let _ = tx.send(StopResult {
+                        reason,
+                        warnings,
+                        raw_audio,
+                        capture_file,
+                        native_sample_rate,
+                        join_timed_out: false,
+                    });
                 }
+                stop_pending.store(false, Ordering::SeqCst);
             }
             AudioCommand::Shutdown => {
                 crate::debug!("Received SHUTDOWN command");
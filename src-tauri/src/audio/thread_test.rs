@@ -20,6 +20,41 @@ fn test_drop_shuts_down_thread() {
     // If we get here without hanging, the Drop impl worked correctly
 }
 
+#[test]
+fn test_stop_reports_join_timeout_and_stays_pending() {
+    // A handle whose audio thread never answers the Stop command
+    let (sender, _receiver) = mpsc::channel();
+    let handle = AudioThreadHandle {
+        sender,
+        thread: None,
+        stop_pending: Arc::new(AtomicBool::new(false)),
+    };
+
+    let result = handle
+        .stop_with_timeout(Duration::from_millis(20))
+        .expect("timeout should still produce a stop result");
+    assert!(result.join_timed_out);
+    assert!(result.capture_file.is_none());
+    assert!(handle.is_stop_pending());
+}
+
+#[test]
+fn test_stop_on_disconnected_thread_is_not_pending() {
+    let (sender, receiver) = mpsc::channel();
+    drop(receiver);
+    let handle = AudioThreadHandle {
+        sender,
+        thread: None,
+        stop_pending: Arc::new(AtomicBool::new(false)),
+    };
+
+    assert_eq!(
+        handle.stop().unwrap_err(),
+        AudioThreadError::ThreadDisconnected
+    );
+    assert!(!handle.is_stop_pending());
+}
+
 /// Test that start and stop commands work
 /// Excluded from coverage because hardware availability varies
 #[test]
@@ -37,8 +72,10 @@ fn test_start_stop_commands() {
         Err(e) => panic!("Unexpected error: {:?}", e),
     }
 
-    // Stop should succeed
-    assert!(handle.stop().is_ok());
+    // Stop should succeed and leave no stop pending
+    let stop_result = handle.stop().expect("stop should succeed");
+    assert!(!stop_result.join_timed_out);
+    assert!(!handle.is_stop_pending());
 
     // Shutdown
     assert!(handle.shutdown().is_ok());
@@ -9,8 +9,10 @@ pub enum WavEncodingError {
     IoError(String),
     /// Error during WAV encoding
     EncodingError(String),
-    /// Invalid input (empty samples, NaN/infinity values)
+    /// Invalid input (NaN/infinity values, bad templates)
     InvalidInput(String),
+    /// No samples to encode (zero-length recording)
+    EmptyInput,
 }
 
 impl std::fmt::Display for WavEncodingError {
@@ -19,6 +21,7 @@ impl std::fmt::Display for WavEncodingError {
             WavEncodingError::IoError(msg) => write!(f, "I/O error: {}", msg),
             WavEncodingError::EncodingError(msg) => write!(f, "Encoding error: {}", msg),
             WavEncodingError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+            WavEncodingError::EmptyInput => write!(f, "Cannot encode empty samples"),
         }
     }
 }
@@ -44,19 +47,204 @@ pub trait FileWriter {
 
     /// Check if a path exists
     fn path_exists(&self, path: &Path) -> bool;
+
+    /// Sample format to encode with (16-bit integer unless overridden)
+    fn bit_depth(&self) -> WavBitDepth {
+        WavBitDepth::Int16
+    }
+}
+
+/// Settings key for the WAV sample format (16 or 32, default 16)
+pub const WAV_BIT_DEPTH_KEY: &str = "audio.wavBitDepth";
+
+/// Sample format recordings are saved in
+///
+/// The audio engine writes captured recordings in this format, and
+/// `encode_wav` uses it for recordings saved from a sample buffer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WavBitDepth {
+    /// 16-bit signed integer PCM
+    #[default]
+    Int16,
+    /// 32-bit IEEE float
+    Float32,
+}
+
+impl WavBitDepth {
+    /// Resolve the bit depth from its settings value
+    ///
+    /// Unset or unsupported values fall back to 16-bit.
+    pub fn from_setting(value: Option<u64>) -> Self {
+        match value {
+            None | Some(16) => WavBitDepth::Int16,
+            Some(32) => WavBitDepth::Float32,
+            Some(other) => {
+                crate::warn!("Ignoring unsupported WAV bit depth {}, using 16-bit", other);
+                WavBitDepth::Int16
+            }
+        }
+    }
+
+    /// hound spec for mono audio at this depth
+    fn spec(self, sample_rate: u32) -> hound::WavSpec {
+        let (bits_per_sample, sample_format) = match self {
+            WavBitDepth::Int16 => (16, hound::SampleFormat::Int),
+            WavBitDepth::Float32 => (32, hound::SampleFormat::Float),
+        };
+        hound::WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample,
+            sample_format,
+        }
+    }
+}
+
+/// Default filename template for recordings (produces "recording-2025-12-01-143025.wav")
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "recording-{timestamp}";
+
+/// Placeholders supported in filename templates
+const FILENAME_PLACEHOLDERS: [&str; 3] = ["{timestamp}", "{app}", "{index}"];
+
+/// Value substituted for `{app}` when the recording app is unknown
+const UNKNOWN_APP_NAME: &str = "unknown";
+
+/// Validate a recording filename template
+///
+/// A valid template:
+/// - Is not empty
+/// - Contains no path separators or `..` (no path traversal)
+/// - Only uses the `{timestamp}`, `{app}` and `{index}` placeholders
+/// - Contains `{timestamp}` or `{index}` so generated names are unique
+pub fn validate_filename_template(template: &str) -> Result<(), WavEncodingError> {
+    let trimmed = template.trim();
+    if trimmed.is_empty() {
+        return Err(WavEncodingError::InvalidInput(
+            "Filename template cannot be empty".to_string(),
+        ));
+    }
+
+    if trimmed.contains('/') || trimmed.contains('\\') || trimmed.contains("..") {
+        return Err(WavEncodingError::InvalidInput(
+            "Filename template cannot contain path separators or '..'".to_string(),
+        ));
+    }
+
+    // Strip known placeholders - any remaining braces are unknown placeholders
+    let mut literal = trimmed.to_string();
+    for placeholder in FILENAME_PLACEHOLDERS {
+        literal = literal.replace(placeholder, "");
+    }
+    if literal.contains('{') || literal.contains('}') {
+        return Err(WavEncodingError::InvalidInput(format!(
+            "Filename template contains an unknown placeholder (supported: {})",
+            FILENAME_PLACEHOLDERS.join(", ")
+        )));
+    }
+
+    if !trimmed.contains("{timestamp}") && !trimmed.contains("{index}") {
+        return Err(WavEncodingError::InvalidInput(
+            "Filename template must include {timestamp} or {index}".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Make a string safe for use in a filename
+///
+/// Lowercases the input, replaces anything other than ASCII letters, digits,
+/// `-` and `_` with `-`, and collapses/trims repeated dashes.
+pub fn sanitize_filename_component(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_alphanumeric() || c == '_' {
+            result.push(c);
+        } else if !result.ends_with('-') {
+            result.push('-');
+        }
+    }
+    result.trim_matches('-').to_string()
+}
+
+/// Render a filename template into a `.wav` filename
+///
+/// Substituted values and the final name are sanitized for filesystem safety.
+/// Falls back to the default template's shape if the result would be empty.
+pub fn render_filename_template(
+    template: &str,
+    timestamp: &str,
+    app_name: Option<&str>,
+    index: u32,
+) -> String {
+    let app = app_name
+        .map(sanitize_filename_component)
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| UNKNOWN_APP_NAME.to_string());
+
+    let rendered = template
+        .trim()
+        .replace("{timestamp}", timestamp)
+        .replace("{app}", &app)
+        .replace("{index}", &index.to_string());
+
+    let name = sanitize_filename_component(&rendered);
+    if name.is_empty() {
+        format!("recording-{}.wav", timestamp)
+    } else {
+        format!("{}.wav", name)
+    }
 }
 
 /// Production file writer using system paths and real filesystem
 ///
 /// Supports worktree-specific recordings by accepting a pre-computed recordings directory.
+/// Filenames are generated from a template (see [`DEFAULT_FILENAME_TEMPLATE`]).
 pub struct SystemFileWriter {
     recordings_dir: PathBuf,
+    filename_template: String,
+    app_name: Option<String>,
+    bit_depth: WavBitDepth,
 }
 
 impl SystemFileWriter {
     /// Create a new SystemFileWriter with a specific recordings directory
     pub fn new(recordings_dir: PathBuf) -> Self {
-        Self { recordings_dir }
+        Self {
+            recordings_dir,
+            filename_template: DEFAULT_FILENAME_TEMPLATE.to_string(),
+            app_name: None,
+            bit_depth: WavBitDepth::default(),
+        }
+    }
+
+    /// Set a custom filename template (builder pattern)
+    ///
+    /// Invalid templates are logged and ignored, keeping the default template.
+    pub fn with_filename_template(mut self, template: &str) -> Self {
+        match validate_filename_template(template) {
+            Ok(()) => self.filename_template = template.trim().to_string(),
+            Err(e) => crate::warn!("Ignoring filename template '{}': {}", template, e),
+        }
+        self
+    }
+
+    /// Set the app name substituted for `{app}` (builder pattern)
+    pub fn with_app_name(mut self, app_name: Option<String>) -> Self {
+        self.app_name = app_name;
+        self
+    }
+
+    /// Set the sample format recordings are encoded with (builder pattern)
+    pub fn with_bit_depth(mut self, bit_depth: WavBitDepth) -> Self {
+        self.bit_depth = bit_depth;
+        self
+    }
+
+    /// Get the filename template in use
+    pub fn filename_template(&self) -> &str {
+        &self.filename_template
     }
 }
 
@@ -68,8 +256,23 @@ impl FileWriter for SystemFileWriter {
 
     #[cfg_attr(coverage_nightly, coverage(off))]
     fn generate_filename(&self) -> String {
-        let now = chrono::Utc::now();
-        format!("recording-{}.wav", now.format("%Y-%m-%d-%H%M%S"))
+        let timestamp = chrono::Utc::now().format("%Y-%m-%d-%H%M%S").to_string();
+        let app_name = self.app_name.as_deref();
+
+        if !self.filename_template.contains("{index}") {
+            return render_filename_template(&self.filename_template, &timestamp, app_name, 1);
+        }
+
+        // Use the lowest index that doesn't collide with an existing recording
+        let mut index = 1;
+        loop {
+            let filename =
+                render_filename_template(&self.filename_template, &timestamp, app_name, index);
+            if index == u32::MAX || !self.recordings_dir.join(&filename).exists() {
+                return filename;
+            }
+            index += 1;
+        }
     }
 
     #[cfg_attr(coverage_nightly, coverage(off))]
@@ -81,10 +284,66 @@ impl FileWriter for SystemFileWriter {
     fn path_exists(&self, path: &Path) -> bool {
         path.exists()
     }
+
+    fn bit_depth(&self) -> WavBitDepth {
+        self.bit_depth
+    }
+}
+
+/// Most numbered suffixes tried when a recording filename is taken
+const MAX_FILENAME_ATTEMPTS: u32 = 1000;
+
+/// Add a numbered suffix before the extension ("a.wav", 2 -> "a-2.wav")
+///
+/// Attempt 1 returns the filename unchanged.
+pub fn uniquify_filename(filename: &str, attempt: u32) -> String {
+    if attempt <= 1 {
+        return filename.to_string();
+    }
+    match filename.rsplit_once('.') {
+        Some((stem, extension)) => format!("{}-{}.{}", stem, attempt, extension),
+        None => format!("{}-{}", filename, attempt),
+    }
+}
+
+/// Pick a path in `dir` for `filename` that doesn't exist yet
+///
+/// Two recordings saved within the same second render the same timestamped
+/// name, so a taken name gets a numbered suffix instead of being overwritten.
+pub fn unique_file_path<W: FileWriter>(
+    writer: &W,
+    dir: &Path,
+    filename: &str,
+) -> Result<PathBuf, WavEncodingError> {
+    for attempt in 1..=MAX_FILENAME_ATTEMPTS {
+        let path = dir.join(uniquify_filename(filename, attempt));
+        if !writer.path_exists(&path) {
+            if attempt > 1 {
+                crate::warn!(
+                    "Recording file {} exists, saving as {}",
+                    filename,
+                    path.display()
+                );
+            }
+            return Ok(path);
+        }
+    }
+    Err(WavEncodingError::IoError(format!(
+        "No free filename for {} after {} attempts",
+        filename, MAX_FILENAME_ATTEMPTS
+    )))
 }
 
+/// Samples converted and written per chunk when encoding
+pub const WAV_WRITE_CHUNK_SAMPLES: usize = 16_384;
+
 /// Encode audio samples to a WAV file
 ///
+/// The sample format comes from `writer.bit_depth()`. Samples are clamped to
+/// -1.0..=1.0 for both formats. The slice is borrowed and written in chunks of
+/// `WAV_WRITE_CHUNK_SAMPLES`, so callers can pass a locked buffer directly
+/// instead of cloning it.
+///
 /// # Arguments
 /// * `samples` - Audio samples as f32 values (expected range: -1.0 to 1.0)
 /// * `sample_rate` - Sample rate in Hz (e.g., 44100)
@@ -98,14 +357,37 @@ pub fn encode_wav<W: FileWriter>(
     sample_rate: u32,
     writer: &W,
 ) -> Result<String, WavEncodingError> {
+    encode_wav_chunks(samples.chunks(WAV_WRITE_CHUNK_SAMPLES), sample_rate, writer)
+}
+
+/// Encode audio held in several borrowed slices to a single WAV file
+///
+/// The slices are written back to back without being joined, so audio kept in
+/// segments never needs a contiguous copy. All slices are validated before
+/// the file is created.
+///
+/// # Arguments
+/// * `chunks` - Audio slices in playback order
+/// * `sample_rate` - Sample rate in Hz
+/// * `writer` - File writer for filesystem operations
+pub fn encode_wav_chunks<'a, I, W>(
+    chunks: I,
+    sample_rate: u32,
+    writer: &W,
+) -> Result<String, WavEncodingError>
+where
+    I: IntoIterator<Item = &'a [f32]>,
+    I::IntoIter: Clone,
+    W: FileWriter,
+{
+    let chunks = chunks.into_iter();
+
     // Validate input
-    if samples.is_empty() {
-        return Err(WavEncodingError::InvalidInput(
-            "Cannot encode empty samples".to_string(),
-        ));
+    if chunks.clone().all(|chunk| chunk.is_empty()) {
+        return Err(WavEncodingError::EmptyInput);
     }
 
-    if samples.iter().any(|s| !s.is_finite()) {
+    if chunks.clone().flatten().any(|s| !s.is_finite()) {
         return Err(WavEncodingError::InvalidInput(
             "Samples contain NaN or infinity values".to_string(),
         ));
@@ -119,27 +401,19 @@ pub fn encode_wav<W: FileWriter>(
             .map_err(|e| WavEncodingError::IoError(e.to_string()))?;
     }
 
-    // Generate file path
+    // Generate file path, never overwriting an existing recording
     let filename = writer.generate_filename();
-    let file_path = output_dir.join(&filename);
+    let file_path = unique_file_path(writer, &output_dir, &filename)?;
     crate::info!("Saving recording to: {}", file_path.display());
 
     // Create WAV writer
-    let spec = hound::WavSpec {
-        channels: 1,
-        sample_rate,
-        bits_per_sample: 16,
-        sample_format: hound::SampleFormat::Int,
-    };
-
+    let bit_depth = writer.bit_depth();
     let mut wav_writer =
-        hound::WavWriter::create(&file_path, spec).map_err(hound_error)?;
+        hound::WavWriter::create(&file_path, bit_depth.spec(sample_rate)).map_err(hound_error)?;
 
-    // Convert and write samples
-    for &sample in samples {
-        let clamped = sample.clamp(-1.0, 1.0);
-        let sample_i16 = (clamped * i16::MAX as f32) as i16;
-        wav_writer.write_sample(sample_i16).map_err(hound_error)?;
+    // Convert and write samples chunk by chunk
+    for chunk in chunks {
+        write_samples(&mut wav_writer, chunk, bit_depth)?;
     }
 
     // Finalize
@@ -148,8 +422,38 @@ pub fn encode_wav<W: FileWriter>(
     Ok(file_path.to_string_lossy().to_string())
 }
 
+/// Convert and write samples in chunks of `WAV_WRITE_CHUNK_SAMPLES`
+fn write_samples<W: std::io::Write + std::io::Seek>(
+    wav_writer: &mut hound::WavWriter<W>,
+    samples: &[f32],
+    bit_depth: WavBitDepth,
+) -> Result<(), WavEncodingError> {
+    for chunk in samples.chunks(WAV_WRITE_CHUNK_SAMPLES) {
+        match bit_depth {
+            WavBitDepth::Int16 => {
+                let mut chunk_writer = wav_writer.get_i16_writer(chunk.len() as u32);
+                for &sample in chunk {
+                    let sample_i16 = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+                    chunk_writer.write_sample(sample_i16);
+                }
+                chunk_writer.flush().map_err(hound_error)?;
+            }
+            WavBitDepth::Float32 => {
+                for &sample in chunk {
+                    wav_writer
+                        .write_sample(sample.clamp(-1.0, 1.0))
+                        .map_err(hound_error)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Parse the duration of a WAV file from its header
 ///
+/// Works for any sample format, since the header's frame count is used.
+///
 /// # Arguments
 /// * `path` - Path to the WAV file
 ///
@@ -167,6 +471,66 @@ pub fn parse_duration_from_file(path: &Path) -> Result<f64, WavEncodingError> {
         ));
     }
 
-    let duration_secs = num_samples as f64 / spec.sample_rate as f64;
-    Ok(duration_secs)
+    Ok(super::duration::duration_secs(num_samples as u64, spec.sample_rate))
+}
+
+/// Parse the per-channel sample count of a WAV file from its header
+///
+/// The count is at the file's own sample rate, whatever rate it was captured at.
+pub fn parse_sample_count_from_file(path: &Path) -> Result<u64, WavEncodingError> {
+    let reader = hound::WavReader::open(path).map_err(hound_error)?;
+    Ok(reader.duration() as u64)
+}
+
+/// Read a WAV file into mono f32 samples
+///
+/// Integer samples are normalized to the -1.0 to 1.0 range. Multi-channel
+/// files are downmixed by averaging channels.
+///
+/// # Returns
+/// * `Ok((Vec<f32>, u32))` - Samples and the file's sample rate
+/// * `Err(WavEncodingError)` - If the file cannot be read or decoded
+pub fn read_wav_samples(path: &Path) -> Result<(Vec<f32>, u32), WavEncodingError> {
+    wav_reader_samples(hound::WavReader::open(path).map_err(hound_error)?)
+}
+
+/// Decode an in-memory WAV file into mono f32 samples
+///
+/// Same conversion as `read_wav_samples`, for audio that was never written to disk.
+pub fn decode_wav_samples(bytes: &[u8]) -> Result<(Vec<f32>, u32), WavEncodingError> {
+    wav_reader_samples(hound::WavReader::new(std::io::Cursor::new(bytes)).map_err(hound_error)?)
+}
+
+/// Read every sample from `reader`, normalized and downmixed to mono
+fn wav_reader_samples<R: std::io::Read>(
+    mut reader: hound::WavReader<R>,
+) -> Result<(Vec<f32>, u32), WavEncodingError> {
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(hound_error)?,
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample.max(1) - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / scale))
+                .collect::<Result<_, _>>()
+                .map_err(hound_error)?
+        }
+    };
+
+    let samples = if channels == 1 {
+        interleaved
+    } else {
+        interleaved
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect()
+    };
+
+    Ok((samples, spec.sample_rate))
 }
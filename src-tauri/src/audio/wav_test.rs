@@ -1,7 +1,11 @@
 #![cfg(test)]
 #![cfg_attr(coverage_nightly, coverage(off))]
 
-use super::wav::{encode_wav, parse_duration_from_file, FileWriter, SystemFileWriter, WavEncodingError};
+use super::wav::{
+    decode_wav_samples, encode_wav, encode_wav_chunks, parse_duration_from_file, read_wav_samples,
+    render_filename_template, sanitize_filename_component, validate_filename_template, FileWriter,
+    SystemFileWriter, WavBitDepth, WavEncodingError, WAV_WRITE_CHUNK_SAMPLES,
+};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
@@ -15,6 +19,7 @@ struct MockFileWriter {
     dir_exists: bool,
     should_fail_dir_creation: bool,
     created_dirs: Arc<Mutex<Vec<PathBuf>>>,
+    bit_depth: WavBitDepth,
 }
 
 impl MockFileWriter {
@@ -25,6 +30,7 @@ impl MockFileWriter {
             dir_exists: false,
             should_fail_dir_creation: false,
             created_dirs: Arc::new(Mutex::new(Vec::new())),
+            bit_depth: WavBitDepth::Int16,
         }
     }
 
@@ -47,6 +53,11 @@ impl MockFileWriter {
         self.filename = filename.to_string();
         self
     }
+
+    fn with_bit_depth(mut self, bit_depth: WavBitDepth) -> Self {
+        self.bit_depth = bit_depth;
+        self
+    }
 }
 
 impl FileWriter for MockFileWriter {
@@ -71,13 +82,17 @@ impl FileWriter for MockFileWriter {
     }
 
     fn path_exists(&self, path: &Path) -> bool {
-        if self.dir_exists {
+        if self.dir_exists && path == self.output_dir {
             true
         } else {
             // Check actual filesystem
             path.exists()
         }
     }
+
+    fn bit_depth(&self) -> WavBitDepth {
+        self.bit_depth
+    }
 }
 
 // =============================================================================
@@ -89,10 +104,8 @@ fn test_encode_wav_empty_samples() {
     let writer = MockFileWriter::new();
     let result = encode_wav(&[], 44100, &writer);
 
-    assert!(matches!(result, Err(WavEncodingError::InvalidInput(_))));
-    if let Err(WavEncodingError::InvalidInput(msg)) = result {
-        assert!(msg.contains("empty"));
-    }
+    assert_eq!(result, Err(WavEncodingError::EmptyInput));
+    assert!(writer.created_dirs.lock().unwrap().is_empty());
 }
 
 #[test]
@@ -309,6 +322,105 @@ fn test_system_file_writer_create_dir_all() {
     let _ = std::fs::remove_dir_all(&temp_dir);
 }
 
+#[test]
+fn test_system_file_writer_custom_template_with_app() {
+    let writer = SystemFileWriter::new(std::env::temp_dir().join("heycat-test-recordings"))
+        .with_filename_template("{app}-{timestamp}")
+        .with_app_name(Some("Slack".to_string()));
+    let filename = writer.generate_filename();
+
+    assert!(filename.starts_with("slack-"));
+    assert!(filename.ends_with(".wav"));
+}
+
+#[test]
+fn test_system_file_writer_invalid_template_keeps_default() {
+    let writer = SystemFileWriter::new(std::env::temp_dir().join("heycat-test-recordings"))
+        .with_filename_template("../{timestamp}");
+
+    assert_eq!(writer.filename_template(), "recording-{timestamp}");
+}
+
+#[test]
+fn test_system_file_writer_index_skips_existing_files() {
+    let temp_dir = std::env::temp_dir().join("heycat-wav-test-index");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    std::fs::write(temp_dir.join("take-1.wav"), b"").unwrap();
+
+    let writer = SystemFileWriter::new(temp_dir.clone()).with_filename_template("take-{index}");
+
+    assert_eq!(writer.generate_filename(), "take-2.wav");
+
+    // Cleanup
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
+
+#[test]
+fn test_encode_wav_does_not_overwrite_existing_file() {
+    let temp_dir = std::env::temp_dir().join("heycat-wav-test-collision");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let existing = temp_dir.join("test-recording.wav");
+    std::fs::write(&existing, b"earlier recording").unwrap();
+
+    // Same filename as the existing file, as for two recordings in one second
+    let writer = MockFileWriter::new().with_output_dir(temp_dir.clone());
+    let first = encode_wav(&[0.1; 100], 16000, &writer).unwrap();
+    let second = encode_wav(&[0.2; 100], 16000, &writer).unwrap();
+
+    assert_eq!(Path::new(&first), temp_dir.join("test-recording-2.wav"));
+    assert_eq!(Path::new(&second), temp_dir.join("test-recording-3.wav"));
+    assert_eq!(std::fs::read(&existing).unwrap(), b"earlier recording");
+
+    // Cleanup
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
+
+#[test]
+fn test_uniquify_filename() {
+    assert_eq!(uniquify_filename("recording.wav", 1), "recording.wav");
+    assert_eq!(uniquify_filename("recording.wav", 2), "recording-2.wav");
+    assert_eq!(uniquify_filename("recording", 3), "recording-3");
+}
+
+// =============================================================================
+// Filename Template Tests
+// =============================================================================
+
+#[test]
+fn test_validate_filename_template_accepts_supported_placeholders() {
+    assert!(validate_filename_template("recording-{timestamp}").is_ok());
+    assert!(validate_filename_template("{app}-{index}").is_ok());
+}
+
+#[test]
+fn test_validate_filename_template_rejects_invalid_templates() {
+    let invalid = ["", "   ", "a/{timestamp}", "a\\{timestamp}", "..{timestamp}", "{date}-{index}", "{app}"];
+    for template in invalid {
+        assert!(
+            matches!(validate_filename_template(template), Err(WavEncodingError::InvalidInput(_))),
+            "Template {:?} should be rejected",
+            template
+        );
+    }
+}
+
+#[test]
+fn test_render_filename_template_sanitizes_values() {
+    let name = render_filename_template("{app}-{timestamp}", "2025-01-01-120000", Some("Visual Studio Code!"), 1);
+    assert_eq!(name, "visual-studio-code-2025-01-01-120000.wav");
+
+    let unknown = render_filename_template("{app}-{index}", "ts", None, 3);
+    assert_eq!(unknown, "unknown-3.wav");
+}
+
+#[test]
+fn test_sanitize_filename_component() {
+    assert_eq!(sanitize_filename_component("My App/../x"), "my-app-x");
+    assert_eq!(sanitize_filename_component("***"), "");
+}
+
 // =============================================================================
 // WAV Format Verification Tests
 // =============================================================================
@@ -339,6 +451,122 @@ fn test_wav_file_has_correct_format() {
     let _ = std::fs::remove_dir_all(&temp_dir);
 }
 
+/// Encode with the given depth and read the result back
+fn roundtrip(
+    bit_depth: WavBitDepth,
+    dir_name: &str,
+    samples: &[f32],
+) -> (hound::WavSpec, Vec<f32>, f64) {
+    let temp_dir = std::env::temp_dir().join(dir_name);
+    let _ = std::fs::remove_dir_all(&temp_dir);
+
+    let writer = MockFileWriter::new()
+        .with_output_dir(temp_dir.clone())
+        .with_filename("roundtrip.wav")
+        .with_bit_depth(bit_depth);
+
+    let path = encode_wav(samples, 16000, &writer).unwrap();
+    let spec = hound::WavReader::open(&path).unwrap().spec();
+    let (read_back, _) = read_wav_samples(Path::new(&path)).unwrap();
+    let duration = parse_duration_from_file(Path::new(&path)).unwrap();
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    (spec, read_back, duration)
+}
+
+#[test]
+fn test_roundtrip_int16() {
+    let samples: Vec<f32> = (0..16000).map(|i| ((i % 100) as f32 / 50.0) - 1.0).collect();
+    let (spec, read_back, duration) =
+        roundtrip(WavBitDepth::Int16, "heycat-wav-test-rt16", &samples);
+
+    assert_eq!(spec.bits_per_sample, 16);
+    assert_eq!(spec.sample_format, hound::SampleFormat::Int);
+    assert_eq!(read_back.len(), samples.len());
+    for (a, b) in samples.iter().zip(&read_back) {
+        assert!((a - b).abs() < 1e-3, "{} vs {}", a, b);
+    }
+    assert!((duration - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_roundtrip_float32_is_exact() {
+    let samples: Vec<f32> = (0..8000).map(|i| ((i % 100) as f32 / 50.0) - 1.0).collect();
+    let (spec, read_back, duration) =
+        roundtrip(WavBitDepth::Float32, "heycat-wav-test-rt32", &samples);
+
+    assert_eq!(spec.bits_per_sample, 32);
+    assert_eq!(spec.sample_format, hound::SampleFormat::Float);
+    assert_eq!(read_back, samples);
+    assert!((duration - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_float32_clamps_out_of_range_samples() {
+    let (_, read_back, _) = roundtrip(
+        WavBitDepth::Float32,
+        "heycat-wav-test-rt32-clamp",
+        &[1.5, -2.0, 0.25],
+    );
+    assert_eq!(read_back, vec![1.0, -1.0, 0.25]);
+}
+
+#[test]
+fn test_roundtrip_large_buffer_spanning_many_chunks() {
+    // Not a multiple of the chunk size, so the last chunk is partial
+    let len = WAV_WRITE_CHUNK_SAMPLES * 40 + 123;
+    let samples: Vec<f32> = (0..len).map(|i| ((i % 1000) as f32 / 500.0) - 1.0).collect();
+    let (_, read_back, _) = roundtrip(WavBitDepth::Int16, "heycat-wav-test-large", &samples);
+
+    assert_eq!(read_back.len(), samples.len());
+    for (a, b) in samples.iter().zip(&read_back) {
+        assert!((a - b).abs() < 1e-3, "{} vs {}", a, b);
+    }
+}
+
+#[test]
+fn test_encode_wav_chunks_concatenates_slices() {
+    let temp_dir = std::env::temp_dir().join("heycat-wav-test-chunks");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    let writer = MockFileWriter::new()
+        .with_output_dir(temp_dir.clone())
+        .with_filename("chunks.wav")
+        .with_bit_depth(WavBitDepth::Float32);
+
+    let first = [0.25f32; 3];
+    let second: [f32; 0] = [];
+    let third = [-0.5f32, 0.75];
+    let path = encode_wav_chunks([&first[..], &second[..], &third[..]], 16000, &writer).unwrap();
+    let (read_back, _) = read_wav_samples(Path::new(&path)).unwrap();
+    assert_eq!(read_back, vec![0.25, 0.25, 0.25, -0.5, 0.75]);
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
+
+#[test]
+fn test_encode_wav_chunks_validates_every_slice() {
+    let writer = MockFileWriter::new();
+    let empty: [&[f32]; 2] = [&[], &[]];
+    assert_eq!(
+        encode_wav_chunks(empty, 16000, &writer),
+        Err(WavEncodingError::EmptyInput)
+    );
+
+    let with_nan: [&[f32]; 2] = [&[0.1], &[f32::NAN]];
+    assert!(matches!(
+        encode_wav_chunks(with_nan, 16000, &writer),
+        Err(WavEncodingError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn test_bit_depth_from_setting() {
+    assert_eq!(WavBitDepth::from_setting(None), WavBitDepth::Int16);
+    assert_eq!(WavBitDepth::from_setting(Some(16)), WavBitDepth::Int16);
+    assert_eq!(WavBitDepth::from_setting(Some(32)), WavBitDepth::Float32);
+    assert_eq!(WavBitDepth::from_setting(Some(24)), WavBitDepth::Int16);
+}
+
 #[test]
 fn test_wav_file_sample_count_matches() {
     let temp_dir = std::env::temp_dir().join("heycat-wav-test-count");
@@ -447,3 +675,30 @@ fn test_parse_duration_from_invalid_file() {
     // Cleanup
     let _ = std::fs::remove_dir_all(&temp_dir);
 }
+
+#[test]
+fn test_decode_wav_samples_downmixes_in_memory_wav() {
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate: 16000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut bytes = std::io::Cursor::new(Vec::new());
+    {
+        let mut writer = hound::WavWriter::new(&mut bytes, spec).unwrap();
+        for sample in [16384i16, 0, -16384, -16384] {
+            writer.write_sample(sample).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    let (samples, sample_rate) = decode_wav_samples(bytes.get_ref()).unwrap();
+    assert_eq!(sample_rate, 16000);
+    assert_eq!(samples, vec![0.25, -0.5]);
+}
+
+#[test]
+fn test_decode_wav_samples_rejects_garbage() {
+    assert!(decode_wav_samples(b"not a wav file").is_err());
+}
@@ -77,6 +77,14 @@ pub const PAUSE_TOLERANCE_MS: u32 = 1000;
 /// utterances while filtering random pops.
 pub const SILENCE_MIN_SPEECH_FRAMES: usize = 2;
 
+/// Minimum sustained speech frames before a recording counts as having speech.
+///
+/// Until this is reached the no-speech timeout stays active, so brief blips
+/// don't switch the detector to the shorter silence-after-speech timer.
+/// 8 chunks is ~256ms at 16kHz: shorter than a spoken word, longer than a
+/// cough or click. Values below `SILENCE_MIN_SPEECH_FRAMES` have no extra effect.
+pub const SILENCE_MIN_ONSET_SPEECH_FRAMES: usize = 8;
+
 // =============================================================================
 // PIPELINE CONFIGURATION
 // =============================================================================
@@ -99,10 +107,21 @@ pub const MIN_DETECTION_SAMPLES: usize = 1600;
 ///
 /// When the audio device doesn't support 16kHz natively, we resample
 /// in chunks of this size. 1024 samples provides a good balance between
-/// latency (~64ms at 16kHz) and processing efficiency.
-#[allow(dead_code)]
+/// latency (~64ms at 16kHz) and processing efficiency. Used as the tap
+/// buffer size of the Swift audio engine when the device doesn't report
+/// its own I/O buffer size.
 pub const RESAMPLE_CHUNK_SIZE: usize = 1024;
 
+/// Smallest resample chunk derived from a device's buffer size (samples).
+///
+/// Below this the per-chunk overhead of the converter dominates.
+pub const MIN_RESAMPLE_CHUNK_SIZE: usize = 256;
+
+/// Largest resample chunk derived from a device's buffer size (samples).
+///
+/// Above this a chunk adds more latency than it saves (~85ms at 48kHz).
+pub const MAX_RESAMPLE_CHUNK_SIZE: usize = 4096;
+
 // =============================================================================
 // BUFFER SIZE CONFIGURATION
 // =============================================================================
@@ -2,11 +2,20 @@
 //!
 //! Contains commands for listing devices and monitoring audio levels.
 
+use std::sync::{Arc, Mutex};
 use tauri::{AppHandle, Emitter, State};
 use tauri_plugin_store::StoreExt;
 
+use crate::audio::channel::{ChannelSelection, CHANNEL_SELECTION_KEY};
+use crate::audio::device_check::InputDeviceStatus;
+use crate::audio::gain::{input_gain_from_setting, INPUT_GAIN_KEY};
+use crate::audio::resample::ResamplerInfo;
 use crate::audio::AudioInputDevice;
-use crate::events::event_names;
+use crate::events::{event_names, NoInputDevicePayload};
+use crate::recording::{
+    validate_vad_threshold, RecordingDetectors, VadContext, VadThresholds,
+    SILENCE_VAD_THRESHOLD_KEY,
+};
 
 use super::common::get_settings_file;
 use super::AudioMonitorState;
@@ -20,6 +29,137 @@ pub fn list_audio_devices() -> Vec<AudioInputDevice> {
     crate::audio::list_input_devices()
 }
 
+/// Check whether another app appears to be using an input device
+///
+/// `device_name` None checks the system default input device. heycat's own
+/// capture is not counted. Best effort: false when the device can't be queried.
+#[tauri::command]
+pub fn is_microphone_busy(device_name: Option<String>) -> bool {
+    crate::swift::is_microphone_busy(device_name.as_deref())
+}
+
+/// Get the startup warning for a missing input device
+///
+/// Returns the `no_input_device` payload while no device has appeared, so a
+/// frontend that loads after the startup event can still show the warning.
+#[tauri::command]
+pub fn get_input_device_status(
+    status: State<'_, Arc<InputDeviceStatus>>,
+) -> Option<NoInputDevicePayload> {
+    status.missing()
+}
+
+/// Get resampler details for the current input device
+///
+/// Reports the device rate and buffer size, the 16kHz target, the chunk size
+/// derived from the buffer size, and the
+/// latency the resampler introduces while priming, which can clip the
+/// start of the first word when resampling is active. The latency is
+/// measured once audio has flowed through the engine.
+#[tauri::command]
+pub fn get_resampler_info() -> ResamplerInfo {
+    ResamplerInfo::new(
+        crate::swift::audio_engine_source_sample_rate(),
+        crate::swift::audio_engine_device_buffer_frames(),
+        crate::swift::audio_engine_resampler_latency_samples(),
+    )
+}
+
+/// Set the software input gain and apply it to the running engine
+///
+/// The gain is clamped to the accepted range before it is saved and applied.
+/// Returns the gain actually in effect.
+#[tauri::command]
+pub fn set_input_gain(app_handle: AppHandle, gain: f64) -> Result<f32, String> {
+    let gain = input_gain_from_setting(Some(gain));
+
+    let settings_file = get_settings_file(&app_handle);
+    let store = app_handle
+        .store(&settings_file)
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store.set(INPUT_GAIN_KEY, serde_json::json!(gain));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    crate::swift::audio_engine_set_input_gain(gain);
+    crate::info!("Input gain set to {}", gain);
+    Ok(gain)
+}
+
+/// Set which channel of multi-channel input is captured and apply it to the running engine
+///
+/// "auto" picks the louder channel over the first second of input; "mix"
+/// averages all channels. Mono devices are unaffected.
+#[tauri::command]
+pub fn set_channel_selection(
+    app_handle: AppHandle,
+    selection: ChannelSelection,
+) -> Result<(), String> {
+    let settings_file = get_settings_file(&app_handle);
+    let store = app_handle
+        .store(&settings_file)
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store.set(
+        CHANNEL_SELECTION_KEY,
+        serde_json::to_value(selection).unwrap_or_default(),
+    );
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    crate::swift::audio_engine_set_channel_selection(selection);
+    crate::info!("Channel selection set to {:?}", selection);
+    Ok(())
+}
+
+/// Get the speech thresholds of the voice activity detectors
+#[tauri::command]
+pub fn get_vad_thresholds(
+    detectors: State<'_, Arc<Mutex<RecordingDetectors>>>,
+) -> Result<VadThresholds, String> {
+    let detectors = detectors
+        .lock()
+        .map_err(|_| "Failed to access recording detectors.".to_string())?;
+    Ok(VadThresholds {
+        silence: detectors.vad_threshold(),
+    })
+}
+
+/// Set a voice activity detector's speech threshold and apply it live
+///
+/// `context` is "silence", currently the only detector. The threshold is saved and applied
+/// to the running detector, so a recording in progress uses it straight
+/// away. Returns the threshold now in effect.
+#[tauri::command]
+pub fn set_vad_threshold(
+    app_handle: AppHandle,
+    detectors: State<'_, Arc<Mutex<RecordingDetectors>>>,
+    context: VadContext,
+    value: f64,
+) -> Result<f32, String> {
+    let threshold = validate_vad_threshold(value)?;
+    let key = match context {
+        VadContext::Silence => SILENCE_VAD_THRESHOLD_KEY,
+    };
+
+    let settings_file = get_settings_file(&app_handle);
+    let store = app_handle
+        .store(&settings_file)
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store.set(key, serde_json::json!(threshold));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    detectors
+        .lock()
+        .map_err(|_| "Failed to access recording detectors.".to_string())?
+        .set_vad_threshold(threshold);
+    crate::info!("Silence VAD threshold set to {}", threshold);
+    Ok(threshold)
+}
+
 /// Start audio level monitoring for device testing
 ///
 /// Starts capturing audio from the specified device and emits "audio-level" events
@@ -6,12 +6,17 @@
 use tauri::{AppHandle, Emitter};
 
 use crate::emit_or_warn;
+use crate::last_error::{last_error, ErrorCategory};
+use crate::transcription::cancel::TRANSCRIPTION_CANCELLED_MESSAGE;
 use crate::events::{
     command_events, event_names, hotkey_events, CommandAmbiguousPayload, CommandEventEmitter,
     CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload, HotkeyEventEmitter,
-    RecordingCancelledPayload, RecordingErrorPayload, RecordingEventEmitter,
-    RecordingStartedPayload, RecordingStoppedPayload, TranscriptionCompletedPayload,
-    TranscriptionErrorPayload, TranscriptionEventEmitter, TranscriptionStartedPayload,
+    RecordingCancelledPayload, RecordingEmptyPayload, RecordingErrorPayload,
+    RecordingEventEmitter, RecordingStartedPayload, RecordingStateChangedPayload,
+    RecordingStateRecoveredPayload,
+    RecordingStoppedPayload,
+    SilenceCountdownPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
+    TranscriptionEventEmitter, TranscriptionStartedPayload,
 };
 
 /// Tauri AppHandle-based event emitter for production use.
@@ -43,8 +48,33 @@ impl RecordingEventEmitter for TauriEventEmitter {
     }
 
     fn emit_recording_error(&self, payload: RecordingErrorPayload) {
+        last_error().record(ErrorCategory::Recording, payload.message.clone());
         emit_or_warn!(self.app_handle, event_names::RECORDING_ERROR, payload);
     }
+
+    fn emit_recording_empty(&self, payload: RecordingEmptyPayload) {
+        emit_or_warn!(self.app_handle, event_names::RECORDING_EMPTY, payload);
+    }
+
+    fn emit_silence_countdown(&self, payload: SilenceCountdownPayload) {
+        emit_or_warn!(self.app_handle, event_names::SILENCE_COUNTDOWN, payload);
+    }
+
+    fn emit_recording_state_changed(&self, payload: RecordingStateChangedPayload) {
+        emit_or_warn!(
+            self.app_handle,
+            event_names::RECORDING_STATE_CHANGED,
+            payload
+        );
+    }
+
+    fn emit_recording_state_recovered(&self, payload: RecordingStateRecoveredPayload) {
+        emit_or_warn!(
+            self.app_handle,
+            event_names::RECORDING_STATE_RECOVERED,
+            payload
+        );
+    }
 }
 
 impl TranscriptionEventEmitter for TauriEventEmitter {
@@ -61,6 +91,10 @@ impl TranscriptionEventEmitter for TauriEventEmitter {
     }
 
     fn emit_transcription_error(&self, payload: TranscriptionErrorPayload) {
+        // A cancellation is the user's choice, not an error to keep around
+        if payload.error != TRANSCRIPTION_CANCELLED_MESSAGE {
+            last_error().record(ErrorCategory::Transcription, payload.error.clone());
+        }
         emit_or_warn!(self.app_handle, event_names::TRANSCRIPTION_ERROR, payload);
     }
 }
@@ -75,6 +109,7 @@ impl CommandEventEmitter for TauriEventEmitter {
     }
 
     fn emit_command_failed(&self, payload: CommandFailedPayload) {
+        last_error().record_command_failure(&payload.trigger, &payload.error_message);
         emit_or_warn!(self.app_handle, command_events::COMMAND_FAILED, payload);
     }
 
@@ -0,0 +1,88 @@
+// Configuration export/import Tauri commands
+// Backs up and restores voice commands, dictionary entries and window contexts
+// as a single JSON document
+//
+// This file contains Tauri-specific wrappers and is excluded from coverage.
+#![cfg_attr(coverage_nightly, coverage(off))]
+
+use crate::commands::dictionary::refresh_dictionary_expander;
+use crate::commands::TranscriptionServiceState;
+use crate::turso::{events as turso_events, ConfigExport, ConfigImportSummary, TursoClient};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+/// Type alias for Turso client state
+pub type TursoClientState = Arc<TursoClient>;
+
+/// Export all voice commands, dictionary entries and window contexts to a JSON file
+///
+/// # Arguments
+/// * `out_path` - Where to write the document (overwritten if it exists)
+#[tauri::command]
+pub async fn export_config(
+    turso_client: State<'_, TursoClientState>,
+    out_path: String,
+) -> Result<(), String> {
+    let config = turso_client
+        .export_config()
+        .await
+        .map_err(|e| format!("Failed to export config: {}", e))?;
+    let json = serde_json::to_vec_pretty(&config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    tokio::fs::write(&out_path, json)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", out_path, e))?;
+
+    crate::info!(
+        "Exported {} commands, {} dictionary entries and {} window contexts to {}",
+        config.commands.len(),
+        config.dictionary_entries.len(),
+        config.window_contexts.len(),
+        out_path
+    );
+    Ok(())
+}
+
+/// Import a configuration document written by `export_config`
+///
+/// IDs are kept, so window contexts keep pointing at the same commands and
+/// dictionary entries. References to items that exist neither in the document
+/// nor locally are dropped and listed in the returned summary.
+///
+/// # Arguments
+/// * `path` - Path to the JSON document
+/// * `replace` - Delete the current configuration first instead of merging into it
+#[tauri::command]
+pub async fn import_config(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    transcription_service: State<'_, TranscriptionServiceState>,
+    path: String,
+    replace: bool,
+) -> Result<ConfigImportSummary, String> {
+    let json = tokio::fs::read(&path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let config: ConfigExport =
+        serde_json::from_slice(&json).map_err(|e| format!("Invalid config file: {}", e))?;
+
+    let summary = turso_client
+        .import_config(config, replace)
+        .await
+        .map_err(|e| format!("Failed to import config: {}", e))?;
+
+    refresh_dictionary_expander(&turso_client, &transcription_service).await;
+    turso_events::emit_voice_commands_updated(&app_handle, "import", "");
+    turso_events::emit_dictionary_updated(&app_handle, "import", "");
+    turso_events::emit_window_contexts_updated(&app_handle, "import", "");
+
+    crate::info!(
+        "Imported {} commands, {} dictionary entries and {} window contexts from {} ({} dangling references dropped)",
+        summary.commands,
+        summary.dictionary_entries,
+        summary.window_contexts,
+        path,
+        summary.dangling_references.len()
+    );
+    Ok(summary)
+}
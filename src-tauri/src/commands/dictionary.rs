@@ -5,7 +5,12 @@
 #![cfg_attr(coverage_nightly, coverage(off))]
 
 use crate::commands::TranscriptionServiceState;
-use crate::dictionary::{DictionaryEntry, DictionaryError};
+use crate::dictionary::suggestions::{
+    suggest_entries, DictionarySuggestion, MIN_SUGGESTION_OCCURRENCES,
+};
+use crate::dictionary::{
+    DictionaryEntry, DictionaryError, DictionaryExpander, DictionaryPreview,
+};
 use crate::turso::{events as turso_events, TursoClient};
 use std::sync::Arc;
 use tauri::{AppHandle, State};
@@ -24,7 +29,7 @@ fn to_user_error(error: DictionaryError) -> String {
 }
 
 /// Refresh the dictionary expander in the transcription service with current entries from Turso
-async fn refresh_dictionary_expander(
+pub(crate) async fn refresh_dictionary_expander(
     client: &TursoClient,
     transcription_service: &TranscriptionServiceState,
 ) {
@@ -204,6 +209,49 @@ pub async fn delete_dictionary_entry(
     Ok(())
 }
 
+/// Suggest dictionary entries from repeated transcription corrections
+///
+/// Phrases the user has corrected the same way at least
+/// `MIN_SUGGESTION_OCCURRENCES` times are returned, skipping triggers that
+/// already have an entry.
+#[tauri::command]
+pub async fn get_dictionary_suggestions(
+    turso_client: State<'_, TursoClientState>,
+) -> Result<Vec<DictionarySuggestion>, String> {
+    let corrections = turso_client
+        .list_transcription_corrections()
+        .await
+        .map_err(|e| format!("Failed to load corrections: {}", e))?;
+    let existing = turso_client
+        .list_dictionary_entries()
+        .await
+        .map_err(to_user_error)?;
+
+    Ok(suggest_entries(&corrections, &existing, MIN_SUGGESTION_OCCURRENCES))
+}
+
+/// Preview the dictionary's effect on sample text
+///
+/// Runs the same expansion pass used on transcriptions over arbitrary text
+/// and reports which entries fired and where, so the settings UI can show
+/// before/after without recording anything.
+///
+/// # Arguments
+/// * `text` - Sample text to expand
+#[tauri::command]
+pub async fn apply_dictionary_preview(
+    turso_client: State<'_, TursoClientState>,
+    text: String,
+) -> Result<DictionaryPreview, String> {
+    let entries = turso_client
+        .list_dictionary_entries()
+        .await
+        .map_err(to_user_error)?;
+
+    Ok(DictionaryExpander::new(&entries).preview(&text))
+}
+
 #[cfg(test)]
 #[path = "dictionary_test.rs"]
 mod tests;
+
@@ -5,8 +5,10 @@
 use tauri::{AppHandle, Emitter, State};
 use tauri_plugin_store::StoreExt;
 
-use crate::events::{event_names, RecordingErrorPayload};
-use crate::hotkey::RecordingMode;
+use crate::events::{event_names, RecordingErrorPayload, INTERNAL_ERROR_MESSAGE};
+use crate::hotkey::integration::CANCEL_SHORTCUT_KEY;
+use crate::hotkey::{RecordingMode, ESCAPE_SHORTCUT};
+use crate::last_error::{last_error, ErrorCategory};
 
 use super::common::get_settings_file;
 use super::{HotkeyIntegrationState, HotkeyServiceState, KeyboardCaptureState, ProductionState};
@@ -29,7 +31,9 @@ pub fn suspend_recording_shortcut(
 
     if let Some(shortcut) = shortcut {
         crate::info!("Suspending recording shortcut: {}", shortcut);
-        service.backend.unregister(&shortcut).map_err(|e| e.to_string())
+        service.backend.unregister(&shortcut).map_err(|e| e.to_string())?;
+        service.set_recording_registered(false);
+        Ok(())
     } else {
         crate::info!("No recording shortcut to suspend");
         Ok(())
@@ -72,17 +76,20 @@ pub fn resume_recording_shortcut(
                     }
                     Err(e) => {
                         crate::error!("Failed to acquire integration lock: {}", e);
+                        last_error().record(ErrorCategory::Recording, INTERNAL_ERROR_MESSAGE);
                         let _ = app_handle_clone.emit(
                             event_names::RECORDING_ERROR,
                             RecordingErrorPayload {
-                                message: "Internal error: please restart the application".to_string(),
+                                message: INTERNAL_ERROR_MESSAGE.to_string(),
                             },
                         );
                     }
                 }
             }),
         )
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    service.set_recording_registered(true);
+    Ok(())
 }
 
 /// Update the global recording shortcut
@@ -110,6 +117,7 @@ pub fn update_recording_shortcut(
         if let Err(e) = service.backend.unregister(current) {
             crate::warn!("Failed to unregister old shortcut '{}': {}", current, e);
         }
+        service.set_recording_registered(false);
     }
 
     let integration_clone = integration.inner().clone();
@@ -129,10 +137,11 @@ pub fn update_recording_shortcut(
                     }
                     Err(e) => {
                         crate::error!("Failed to acquire integration lock: {}", e);
+                        last_error().record(ErrorCategory::Recording, INTERNAL_ERROR_MESSAGE);
                         let _ = app_handle_clone.emit(
                             event_names::RECORDING_ERROR,
                             RecordingErrorPayload {
-                                message: "Internal error: please restart the application".to_string(),
+                                message: INTERNAL_ERROR_MESSAGE.to_string(),
                             },
                         );
                     }
@@ -140,6 +149,7 @@ pub fn update_recording_shortcut(
             }),
         )
         .map_err(|e| format!("Failed to register new shortcut: {}", e))?;
+    service.set_recording_registered(true);
 
     // Save to settings
     if let Ok(store) = app_handle.store(&settings_file) {
@@ -224,6 +234,70 @@ pub fn set_recording_mode(
     Ok(())
 }
 
+/// Get the cancel shortcut from settings (defaults to Escape)
+#[tauri::command]
+pub fn get_cancel_shortcut(app_handle: AppHandle) -> String {
+    let settings_file = get_settings_file(&app_handle);
+    app_handle
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get(CANCEL_SHORTCUT_KEY))
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or_else(|| ESCAPE_SHORTCUT.to_string())
+}
+
+/// Set the shortcut that cancels recording when double-tapped
+///
+/// Rejects if recording is currently active, if the shortcut is empty, or if
+/// it is the same as the recording shortcut.
+#[tauri::command]
+pub fn set_cancel_shortcut(
+    app_handle: AppHandle,
+    state: State<'_, ProductionState>,
+    integration: State<'_, HotkeyIntegrationState>,
+    shortcut: String,
+) -> Result<(), String> {
+    let shortcut = shortcut.trim().to_string();
+    if shortcut.is_empty() {
+        return Err("Cancel shortcut cannot be empty.".to_string());
+    }
+    if shortcut.eq_ignore_ascii_case(&get_recording_shortcut(app_handle.clone())) {
+        return Err("Cancel shortcut must differ from the recording shortcut.".to_string());
+    }
+
+    // Check if recording is active
+    let manager = state.lock().map_err(|_| {
+        "Unable to access recording state. Please try again or restart the application."
+    })?;
+    if manager.get_state() != crate::recording::RecordingState::Idle {
+        return Err("Cannot change the cancel shortcut while recording is active.".to_string());
+    }
+    drop(manager);
+
+    // Update HotkeyIntegration in memory
+    let mut integration_guard = integration.lock().map_err(|_| {
+        "Unable to access hotkey integration. Please try again or restart the application."
+    })?;
+    integration_guard.set_cancel_shortcut(shortcut.clone());
+    drop(integration_guard);
+
+    // Persist to settings
+    let settings_file = get_settings_file(&app_handle);
+    if let Ok(store) = app_handle.store(&settings_file) {
+        store.set(CANCEL_SHORTCUT_KEY, serde_json::json!(shortcut));
+        if let Err(e) = store.save() {
+            crate::warn!("Failed to persist settings: {}", e);
+            return Err(format!("Failed to save settings: {}", e));
+        }
+    } else {
+        return Err("Failed to access settings store.".to_string());
+    }
+
+    crate::info!("Cancel shortcut updated to: {}", shortcut);
+    Ok(())
+}
+
 /// Start capturing keyboard events for shortcut recording
 ///
 /// Uses CGEventTap to capture all keyboard events including fn key and media keys.
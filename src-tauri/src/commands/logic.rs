@@ -1,11 +1,19 @@
 // Command implementation logic - testable functions separate from Tauri wrappers
 
-use crate::audio::{parse_duration_from_file, AudioThreadHandle, QualityWarning, TARGET_SAMPLE_RATE};
+use crate::audio::wav::{unique_file_path, FileWriter};
+use crate::audio::{
+    duration_secs, encode_opus, parse_duration_from_file, read_wav_samples,
+    samples_for_duration_ms, AudioThreadHandle, QualityWarning, SystemFileWriter,
+    TARGET_SAMPLE_RATE,
+};
 
 /// Error identifier for microphone access failures.
 /// Used to detect microphone-related errors without fragile string matching.
 pub const MICROPHONE_ERROR_MARKER: &str = "[MICROPHONE_ACCESS_ERROR]";
-use crate::recording::{AudioData, RecordingManager, RecordingMetadata, RecordingState};
+use crate::recording::{
+    lock_recording_state, AudioData, RecordingManager, RecordingMetadata, RecordingState,
+    TriggerSource,
+};
 
 /// Extended result from stop_recording_impl that includes diagnostics
 pub struct StopRecordingResult {
@@ -49,6 +57,9 @@ pub struct RecordingInfo {
     /// Window title of the active window when recording started
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active_window_title: Option<String>,
+    /// What started the recording (hotkey, button, ...), if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_source: Option<TriggerSource>,
 }
 
 /// Information about the current recording state for frontend consumption
@@ -58,6 +69,18 @@ pub struct RecordingStateInfo {
     pub state: RecordingState,
 }
 
+/// Outcome of deleting one recording in a batch
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingDeleteResult {
+    /// Path of the recording
+    pub file_path: String,
+    /// Whether the recording file was deleted
+    pub success: bool,
+    /// Why the delete failed, if it did
+    pub error: Option<String>,
+}
+
 /// Implementation of start_recording
 ///
 /// # Arguments
@@ -65,19 +88,22 @@ pub struct RecordingStateInfo {
 /// * `audio_thread` - Optional audio thread handle for starting capture
 /// * `model_available` - Whether the transcription model is available
 /// * `device_name` - Optional device name to use; falls back to default if not found
+/// * `trigger_source` - What started the recording, stored with its metadata
 ///
 /// # Errors
 /// Returns an error string if:
 /// - Transcription model not available
 /// - Already recording
+/// - A previous stop is still being processed by the audio thread
 /// - State transition fails
 /// - Audio capture fails to start
-/// - State lock is poisoned
+/// - State lock is poisoned and lock recovery is disabled
 pub fn start_recording_impl(
     state: &Mutex<RecordingManager>,
     audio_thread: Option<&AudioThreadHandle>,
     model_available: bool,
     device_name: Option<String>,
+    trigger_source: TriggerSource,
 ) -> Result<(), String> {
     crate::debug!(
         "start_recording_impl called, model_available={}, device={:?}",
@@ -89,7 +115,7 @@ pub fn start_recording_impl(
         crate::debug!("Recording rejected: model not available");
         return Err("Please download the transcription model first.".to_string());
     }
-    let mut manager = state.lock().map_err(|_| {
+    let mut manager = lock_recording_state(state).ok_or_else(|| {
         crate::error!("Failed to acquire recording state lock in start_recording_impl");
         "Unable to access recording state. Please try again or restart the application."
     })?;
@@ -104,6 +130,15 @@ pub fn start_recording_impl(
         );
     }
 
+    // A stop that timed out may still be tearing down the previous capture
+    if audio_thread.is_some_and(|thread| thread.is_stop_pending()) {
+        crate::warn!("Recording rejected: previous capture is still stopping");
+        return Err(
+            "Cannot start recording: the previous recording is still stopping. Please try again in a moment."
+                .to_string(),
+        );
+    }
+
     // Start recording with default sample rate
     let buffer = manager
         .start_recording(TARGET_SAMPLE_RATE)
@@ -111,6 +146,7 @@ pub fn start_recording_impl(
             crate::error!("Failed to start recording: {:?}", e);
             "Failed to initialize recording."
         })?;
+    manager.set_trigger_source(trigger_source);
     crate::debug!("Recording buffer initialized");
 
     // Start audio capture if audio thread is available
@@ -139,47 +175,48 @@ pub fn start_recording_impl(
     Ok(())
 }
 
-/// Implementation of stop_recording
+/// Move a finished capture file into the recordings directory
 ///
-/// # Arguments
-/// * `state` - The recording manager state
-/// * `audio_thread` - Optional audio thread handle for stopping capture
-/// * `return_to_listening` - If true, return to Listening state instead of Idle
-/// * `recordings_dir` - Directory for saving recordings (supports worktree isolation)
+/// The final name comes from the writer and gets a numbered suffix if taken,
+/// so two recordings stopped within the same second don't overwrite each other.
+/// The rename is instant because the temp file is on the same filesystem.
 ///
 /// # Returns
-/// Recording metadata including duration, file path, and sample count
-///
-/// # Errors
-/// Returns an error string if:
-/// - Not currently recording
-/// - State transition fails
-/// - WAV encoding fails
-/// - State lock is poisoned
-pub fn stop_recording_impl(
-    state: &Mutex<RecordingManager>,
-    audio_thread: Option<&AudioThreadHandle>,
-    return_to_listening: bool,
-    recordings_dir: PathBuf,
-) -> Result<RecordingMetadata, String> {
-    // Call the extended implementation and discard diagnostics
-    stop_recording_impl_extended(state, audio_thread, return_to_listening, recordings_dir)
-        .map(|result| result.metadata)
+/// Path of the moved recording
+pub fn move_capture_file<W: FileWriter>(writer: &W, temp_path: &str) -> Result<String, String> {
+    let final_path = unique_file_path(writer, &writer.output_dir(), &writer.generate_filename())
+        .map_err(|e| {
+            crate::error!("Failed to pick a recording filename: {}", e);
+            let _ = std::fs::remove_file(temp_path);
+            "Failed to save the recording: no free filename in the recordings directory."
+        })?;
+    let final_path_str = final_path.to_string_lossy().to_string();
+
+    crate::debug!("Moving capture file: {} -> {}", temp_path, final_path_str);
+    std::fs::rename(temp_path, &final_path).map_err(|e| {
+        crate::error!("Failed to move capture file: {}", e);
+        // Try to clean up temp file on error
+        let _ = std::fs::remove_file(temp_path);
+        "Failed to save the recording. Please check disk space and try again."
+    })?;
+    crate::debug!("Capture file moved successfully");
+
+    Ok(final_path_str)
 }
 
-/// Extended implementation of stop_recording that returns diagnostics
+/// Implementation of stop_recording using a specific file writer
 ///
-/// This is the full implementation that returns quality warnings and raw audio
-/// in addition to recording metadata. Used by the command layer to emit events.
-pub fn stop_recording_impl_extended(
+/// The writer determines the recordings directory and the final filename
+/// (see `SystemFileWriter::with_filename_template`).
+pub fn stop_recording_impl_with_writer(
     state: &Mutex<RecordingManager>,
     audio_thread: Option<&AudioThreadHandle>,
     return_to_listening: bool,
-    recordings_dir: PathBuf,
+    writer: &SystemFileWriter,
 ) -> Result<StopRecordingResult, String> {
     crate::debug!("stop_recording_impl called");
 
-    let mut manager = state.lock().map_err(|_| {
+    let mut manager = lock_recording_state(state).ok_or_else(|| {
         crate::error!("Failed to acquire recording state lock in stop_recording_impl");
         "Unable to access recording state. Please try again or restart the application."
     })?;
@@ -196,6 +233,10 @@ pub fn stop_recording_impl_extended(
     let stop_result = if let Some(audio_thread) = audio_thread {
         crate::debug!("Stopping audio thread");
         match audio_thread.stop() {
+            Ok(result) if result.join_timed_out => {
+                crate::error!("Audio thread did not finish stopping capture in time");
+                None
+            }
             Ok(result) => Some(result),
             Err(e) => {
                 crate::error!("Audio thread stop failed: {:?}", e);
@@ -209,18 +250,23 @@ pub fn stop_recording_impl_extended(
     };
 
     // Extract capture file, stop reason, warnings, and raw audio from result
-    let (capture_file, stop_reason, warnings, raw_audio) = match &stop_result {
+    // Moved out rather than cloned: raw audio holds the whole recording at the device rate
+    let (capture_file, stop_reason, warnings, raw_audio, native_sample_rate) = match stop_result {
         Some(result) => (
-            result.capture_file.clone(),
-            result.reason.clone(),
-            result.warnings.clone(),
-            result.raw_audio.clone(),
+            result.capture_file,
+            result.reason,
+            result.warnings,
+            result.raw_audio,
+            result.native_sample_rate,
         ),
-        None => (None, None, Vec::new(), None),
+        None => (None, None, Vec::new(), None, None),
     };
 
     let _ = return_to_listening; // Suppress unused warning (kept for API compatibility)
 
+    // Read before the recording is released on the way to Idle
+    let trigger_source = manager.trigger_source();
+
     // Transition to Processing (required state machine step)
     manager
         .transition_to(RecordingState::Processing)
@@ -246,6 +292,7 @@ pub fn stop_recording_impl_extended(
         crate::debug!("State lock released");
 
         // Ensure output directory exists
+        let recordings_dir = writer.output_dir();
         if !recordings_dir.exists() {
             std::fs::create_dir_all(&recordings_dir).map_err(|e| {
                 crate::error!("Failed to create recordings dir: {}", e);
@@ -253,25 +300,12 @@ pub fn stop_recording_impl_extended(
             })?;
         }
 
-        // Generate final filename
-        let now = chrono::Utc::now();
-        let filename = format!("recording-{}.wav", now.format("%Y-%m-%d-%H%M%S"));
-        let final_path = recordings_dir.join(&filename);
-        let final_path_str = final_path.to_string_lossy().to_string();
-
-        // Rename temp file to final location (instant - same filesystem)
-        crate::debug!("Moving capture file: {} -> {}", temp_path, final_path_str);
-        std::fs::rename(&temp_path, &final_path).map_err(|e| {
-            crate::error!("Failed to move capture file: {}", e);
-            // Try to clean up temp file on error
-            let _ = std::fs::remove_file(&temp_path);
-            "Failed to save the recording. Please check disk space and try again."
-        })?;
-        crate::debug!("Capture file moved successfully");
+        let final_path_str = move_capture_file(writer, &temp_path)?;
 
         // Calculate sample count from duration (16kHz)
-        let sample_count = ((duration_ms as f64 / 1000.0) * TARGET_SAMPLE_RATE as f64) as usize;
-        let duration_secs = duration_ms as f64 / 1000.0;
+        let samples = samples_for_duration_ms(duration_ms, TARGET_SAMPLE_RATE);
+        let sample_count = usize::try_from(samples).unwrap_or(usize::MAX);
+        let duration_secs = duration_secs(samples, TARGET_SAMPLE_RATE);
 
         (final_path_str, duration_secs, sample_count)
     } else {
@@ -287,8 +321,8 @@ pub fn stop_recording_impl_extended(
         (String::new(), 0.0, 0)
     };
 
-    crate::info!("Recording stopped: {} samples, {:.2}s, stop_reason={:?}, warnings={}",
-          sample_count, duration_secs, stop_reason, warnings.len());
+    crate::info!("Recording stopped: {} samples, {:.2}s, stop_reason={:?}, warnings={}, native_sample_rate={:?}",
+          sample_count, duration_secs, stop_reason, warnings.len(), native_sample_rate);
 
     Ok(StopRecordingResult {
         metadata: RecordingMetadata {
@@ -296,6 +330,8 @@ pub fn stop_recording_impl_extended(
             file_path,
             sample_count,
             stop_reason,
+            native_sample_rate,
+            trigger_source,
         },
         warnings,
         raw_audio,
@@ -308,11 +344,11 @@ pub fn stop_recording_impl_extended(
 /// Current state information for the frontend
 ///
 /// # Errors
-/// Returns an error string if the state lock is poisoned
+/// Returns an error string if the state lock is poisoned and lock recovery is disabled
 pub fn get_recording_state_impl(
     state: &Mutex<RecordingManager>,
 ) -> Result<RecordingStateInfo, String> {
-    let manager = state.lock().map_err(|_| {
+    let manager = lock_recording_state(state).ok_or_else(|| {
         "Unable to access recording state. Please try again or restart the application."
     })?;
     Ok(RecordingStateInfo {
@@ -320,6 +356,20 @@ pub fn get_recording_state_impl(
     })
 }
 
+/// Implementation of get_recording_elapsed
+///
+/// # Returns
+/// Seconds since the current recording started, or None when not recording
+///
+/// # Errors
+/// Returns an error string if the state lock is poisoned and lock recovery is disabled
+pub fn get_recording_elapsed_impl(state: &Mutex<RecordingManager>) -> Result<Option<f64>, String> {
+    let manager = lock_recording_state(state).ok_or_else(|| {
+        "Unable to access recording state. Please try again or restart the application."
+    })?;
+    Ok(manager.get_elapsed().map(|elapsed| elapsed.as_secs_f64()))
+}
+
 /// Implementation of get_last_recording_buffer
 ///
 /// # Returns
@@ -328,11 +378,11 @@ pub fn get_recording_state_impl(
 /// # Errors
 /// Returns an error string if:
 /// - No previous recording exists
-/// - State lock is poisoned
+/// - State lock is poisoned and lock recovery is disabled
 pub fn get_last_recording_buffer_impl(
     state: &Mutex<RecordingManager>,
 ) -> Result<AudioData, String> {
-    let manager = state.lock().map_err(|_| {
+    let manager = lock_recording_state(state).ok_or_else(|| {
         "Unable to access recording state. Please try again or restart the application."
     })?;
     manager.get_last_recording_buffer().map_err(|_| {
@@ -345,9 +395,9 @@ pub fn get_last_recording_buffer_impl(
 /// Clears the retained recording buffer to free memory
 ///
 /// # Errors
-/// Returns an error string if the state lock is poisoned
+/// Returns an error string if the state lock is poisoned and lock recovery is disabled
 pub fn clear_last_recording_buffer_impl(state: &Mutex<RecordingManager>) -> Result<(), String> {
-    let mut manager = state.lock().map_err(|_| {
+    let mut manager = lock_recording_state(state).ok_or_else(|| {
         "Unable to access recording state. Please try again or restart the application."
     })?;
     manager.clear_last_recording();
@@ -378,6 +428,9 @@ pub struct PaginatedRecordingsResponse {
     pub total_count: usize,
     /// Whether there are more recordings after this page
     pub has_more: bool,
+    /// Whether new recordings and transcriptions are stored (see
+    /// `recordings.persistTranscriptions`); history stays empty when false
+    pub persistence_enabled: bool,
 }
 
 use std::collections::HashMap;
@@ -393,6 +446,8 @@ pub struct RecordingContextData {
     pub active_window_bundle_id: Option<String>,
     /// Window title of the active window when recording started
     pub active_window_title: Option<String>,
+    /// What started the recording
+    pub trigger_source: Option<TriggerSource>,
 }
 
 /// Implementation of list_recordings with pagination
@@ -425,6 +480,7 @@ pub fn list_recordings_impl(
             recordings: Vec::new(),
             total_count: 0,
             has_more: false,
+            persistence_enabled: true,
         });
     }
 
@@ -521,6 +577,7 @@ pub fn list_recordings_impl(
             active_window_app_name: context.and_then(|c| c.active_window_app_name.clone()),
             active_window_bundle_id: context.and_then(|c| c.active_window_bundle_id.clone()),
             active_window_title: context.and_then(|c| c.active_window_title.clone()),
+            trigger_source: context.and_then(|c| c.trigger_source),
         });
     }
 
@@ -544,6 +601,7 @@ pub fn list_recordings_impl(
         recordings: paginated_recordings,
         total_count,
         has_more,
+        persistence_enabled: true,
     })
 }
 
@@ -563,6 +621,116 @@ pub fn list_recordings_impl(
 /// - File is not in the recordings directory (security check)
 /// - Deletion fails
 pub fn delete_recording_impl(file_path: &str) -> Result<(), String> {
+    let path = resolve_recording_path(file_path, "delete")?;
+
+    // Delete the file
+    std::fs::remove_file(&path).map_err(|e| {
+        crate::error!("Failed to delete recording {}: {}", file_path, e);
+        format!("Failed to delete recording: {}", e)
+    })?;
+
+    // Remove the voice-note companion, if one was created
+    let voice_note = path.with_extension("ogg");
+    if voice_note.exists() {
+        if let Err(e) = std::fs::remove_file(&voice_note) {
+            crate::warn!("Failed to delete voice note {}: {}", voice_note.display(), e);
+        }
+    }
+
+    crate::info!("Deleted recording: {}", file_path);
+    Ok(())
+}
+
+/// Maximum length of a user-supplied recording name (without extension)
+const MAX_RECORDING_NAME_LEN: usize = 200;
+
+/// Validate a user-supplied recording name and return the `.wav` filename
+///
+/// Names may contain letters, digits, spaces, `-`, `_`, `.`, `(` and `)`.
+/// A trailing `.wav` is accepted and not duplicated. Path separators,
+/// leading dots and `..` are rejected.
+pub fn validate_recording_name(new_name: &str) -> Result<String, String> {
+    let trimmed = new_name.trim();
+    let stem = if trimmed.to_lowercase().ends_with(".wav") {
+        trimmed[..trimmed.len() - 4].trim_end()
+    } else {
+        trimmed
+    };
+
+    if stem.is_empty() {
+        return Err("Recording name cannot be empty".to_string());
+    }
+    if stem.chars().count() > MAX_RECORDING_NAME_LEN {
+        return Err(format!(
+            "Recording name cannot exceed {} characters",
+            MAX_RECORDING_NAME_LEN
+        ));
+    }
+    if stem.starts_with('.') || stem.contains("..") {
+        return Err("Recording name cannot start with '.' or contain '..'".to_string());
+    }
+    if let Some(c) = stem
+        .chars()
+        .find(|c| !(c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.' | '(' | ')')))
+    {
+        return Err(format!("Recording name contains an invalid character: '{}'", c));
+    }
+
+    Ok(format!("{}.wav", stem))
+}
+
+/// Rename a recording file on disk
+///
+/// Moves the voice-note companion (if any) along with the WAV.
+///
+/// # Returns
+/// The new file path on success
+///
+/// # Errors
+/// Returns an error string if:
+/// - The file is missing, outside the recordings directory, or not a `.wav`
+/// - The new name is not filesystem-safe
+/// - A file with the new name already exists
+/// - The rename fails
+pub fn rename_recording_impl(file_path: &str, new_name: &str) -> Result<String, String> {
+    let path = resolve_recording_path(file_path, "rename")?;
+    let filename = validate_recording_name(new_name)?;
+
+    let new_path = path
+        .parent()
+        .map(|dir| dir.join(&filename))
+        .ok_or_else(|| "Failed to resolve recording directory".to_string())?;
+
+    if new_path == path {
+        return Ok(file_path.to_string());
+    }
+    if new_path.exists() {
+        return Err(format!("A recording named '{}' already exists", filename));
+    }
+
+    std::fs::rename(&path, &new_path).map_err(|e| {
+        crate::error!("Failed to rename recording {}: {}", file_path, e);
+        format!("Failed to rename recording: {}", e)
+    })?;
+
+    // Keep the voice-note companion next to its recording
+    let voice_note = path.with_extension("ogg");
+    if voice_note.exists() {
+        if let Err(e) = std::fs::rename(&voice_note, new_path.with_extension("ogg")) {
+            crate::warn!("Failed to rename voice note {}: {}", voice_note.display(), e);
+        }
+    }
+
+    let new_path_str = new_path.to_string_lossy().to_string();
+    crate::info!("Renamed recording: {} -> {}", file_path, new_path_str);
+    Ok(new_path_str)
+}
+
+/// Resolve and validate a recording path supplied by the frontend
+///
+/// Ensures the file exists, is a `.wav` file, and lives inside the recordings
+/// directory. `action` is used in error messages (e.g. "delete").
+pub fn resolve_recording_path(file_path: &str, action: &str) -> Result<PathBuf, String> {
     let path = std::path::Path::new(file_path);
 
     // Check if file exists
@@ -581,25 +749,73 @@ pub fn delete_recording_impl(file_path: &str) -> Result<(), String> {
 
     if !canonical_path.starts_with(&canonical_recordings) {
         crate::error!(
-            "Security: Attempted to delete file outside recordings directory: {}",
+            "Security: Attempted to {} file outside recordings directory: {}",
+            action,
             file_path
         );
-        return Err("Cannot delete files outside the recordings directory".to_string());
+        return Err(format!(
+            "Cannot {} files outside the recordings directory",
+            action
+        ));
     }
 
     // Check it's a .wav file
     if path.extension().and_then(|s| s.to_str()) != Some("wav") {
-        return Err("Can only delete .wav recording files".to_string());
+        return Err(format!("Can only {} .wav recording files", action));
     }
 
-    // Delete the file
-    std::fs::remove_file(path).map_err(|e| {
-        crate::error!("Failed to delete recording {}: {}", file_path, e);
-        format!("Failed to delete recording: {}", e)
-    })?;
+    Ok(path.to_path_buf())
+}
 
-    crate::info!("Deleted recording: {}", file_path);
-    Ok(())
+/// File writer that targets a fixed path (used for voice-note companions)
+struct CompanionFileWriter {
+    path: PathBuf,
+}
+
+impl FileWriter for CompanionFileWriter {
+    fn output_dir(&self) -> PathBuf {
+        self.path
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_default()
+    }
+
+    fn generate_filename(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    fn create_dir_all(&self, path: &std::path::Path) -> Result<(), std::io::Error> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn path_exists(&self, path: &std::path::Path) -> bool {
+        path.exists()
+    }
+}
+
+/// Implementation of save_voice_note
+///
+/// Reads the saved WAV back and encodes a compact Ogg/Opus copy of it next to
+/// the original. The WAV file is kept untouched so transcription still uses
+/// full-fidelity audio.
+///
+/// # Returns
+/// Path to the created `.ogg` file
+pub fn save_voice_note_impl(file_path: &str) -> Result<String, String> {
+    let path = resolve_recording_path(file_path, "encode")?;
+
+    let (samples, sample_rate) = read_wav_samples(&path)
+        .map_err(|e| format!("Failed to read recording: {}", e))?;
+
+    let writer = CompanionFileWriter { path };
+    let voice_note_path = encode_opus(&samples, sample_rate, &writer)
+        .map_err(|e| format!("Failed to encode voice note: {}", e))?;
+
+    crate::info!("Saved voice note: {}", voice_note_path);
+    Ok(voice_note_path)
 }
 
 /// Implementation of transcribe_file
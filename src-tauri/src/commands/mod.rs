@@ -11,6 +11,8 @@
 //! - `hotkey`: Hotkey management commands
 //! - `dictionary`: Dictionary management commands
 //! - `window_context`: Window context commands
+//! - `settings`: Settings reload
+//! - `setup`: First-run setup status
 //! - `common`: Shared utilities (TauriEventEmitter)
 //! - `logic`: Core command logic (testable)
 
@@ -18,10 +20,13 @@
 
 pub mod audio;
 pub mod common;
+pub mod config;
 pub mod dictionary;
 pub mod hotkey;
 pub mod logic;
 pub mod recording;
+pub mod settings;
+pub mod setup;
 pub mod transcription;
 pub mod window;
 pub mod window_context;
@@ -46,6 +51,21 @@ pub fn get_settings_file_name(
     worktree_state.settings_file_name()
 }
 
+/// Get the fallback data directory if the OS data directory is unavailable
+///
+/// Returns `None` in the normal case. When a path is returned, the frontend
+/// warns the user once that data is stored in a fallback location.
+#[tauri::command]
+pub fn get_data_dir_fallback() -> Option<String> {
+    crate::paths::data_dir_fallback_in_use().map(|path| path.to_string_lossy().to_string())
+}
+
+/// List the stable event names emitted by the backend
+#[tauri::command]
+pub fn list_event_names() -> Vec<crate::events::EventNameInfo> {
+    crate::events::all_event_names()
+}
+
 #[cfg(test)]
 #[path = "mod_test.rs"]
 mod tests;
@@ -2,12 +2,13 @@
 #![cfg_attr(coverage_nightly, coverage(off))]
 
 use super::logic::{
-    clear_last_recording_buffer_impl, get_last_recording_buffer_impl, get_recording_state_impl,
-    list_recordings_impl, start_recording_impl, stop_recording_impl, PaginatedRecordingsResponse,
-    RecordingInfo, RecordingStateInfo,
+    clear_last_recording_buffer_impl, get_last_recording_buffer_impl, get_recording_elapsed_impl,
+    get_recording_state_impl, list_recordings_impl, move_capture_file, rename_recording_impl,
+    save_voice_note_impl, start_recording_impl, stop_recording_impl_with_writer,
+    validate_recording_name, PaginatedRecordingsResponse, RecordingInfo, RecordingStateInfo,
 };
-use crate::audio::TARGET_SAMPLE_RATE;
-use crate::recording::{RecordingManager, RecordingState};
+use crate::audio::{SystemFileWriter, TARGET_SAMPLE_RATE};
+use crate::recording::{RecordingManager, RecordingMetadata, RecordingState, TriggerSource};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
@@ -25,6 +26,12 @@ fn test_recordings_dir() -> PathBuf {
     std::env::temp_dir().join("heycat-test-recordings")
 }
 
+/// Stop recording into the test recordings directory, returning only the metadata
+fn stop_recording(state: &Mutex<RecordingManager>) -> Result<RecordingMetadata, String> {
+    let writer = SystemFileWriter::new(test_recordings_dir());
+    stop_recording_impl_with_writer(state, None, false, &writer).map(|result| result.metadata)
+}
+
 // =============================================================================
 // get_recording_state_impl Tests
 // =============================================================================
@@ -42,13 +49,23 @@ fn test_get_recording_state_returns_idle_initially() {
 #[test]
 fn test_get_recording_state_returns_recording_after_start() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     let result = get_recording_state_impl(&state);
     assert!(result.is_ok());
     assert_eq!(result.unwrap().state, RecordingState::Recording);
 }
 
+#[test]
+fn test_get_recording_elapsed_only_while_recording() {
+    let state = create_test_state();
+    assert_eq!(get_recording_elapsed_impl(&state).unwrap(), None);
+
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
+    let elapsed = get_recording_elapsed_impl(&state).unwrap();
+    assert!(elapsed.is_some_and(|secs| secs >= 0.0));
+}
+
 #[test]
 fn test_recording_state_info_serializes() {
     let state_info = RecordingStateInfo {
@@ -65,7 +82,7 @@ fn test_recording_state_info_serializes() {
 #[test]
 fn test_start_recording_returns_ok_from_idle() {
     let state = create_test_state();
-    let result = start_recording_impl(&state, None, true, None);
+    let result = start_recording_impl(&state, None, true, None, TriggerSource::Button);
 
     assert!(result.is_ok());
 }
@@ -73,7 +90,7 @@ fn test_start_recording_returns_ok_from_idle() {
 #[test]
 fn test_start_recording_transitions_to_recording() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     let manager = state.lock().unwrap();
     assert_eq!(manager.get_state(), RecordingState::Recording);
@@ -82,9 +99,9 @@ fn test_start_recording_transitions_to_recording() {
 #[test]
 fn test_start_recording_returns_error_when_already_recording() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
-    let result = start_recording_impl(&state, None, true, None);
+    let result = start_recording_impl(&state, None, true, None, TriggerSource::Button);
 
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("already recording"));
@@ -93,7 +110,7 @@ fn test_start_recording_returns_error_when_already_recording() {
 #[test]
 fn test_start_recording_creates_audio_buffer() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     let manager = state.lock().unwrap();
     let buffer_result = manager.get_audio_buffer();
@@ -101,13 +118,13 @@ fn test_start_recording_creates_audio_buffer() {
 }
 
 // =============================================================================
-// stop_recording_impl Tests
+// stop_recording_impl_with_writer Tests
 // =============================================================================
 
 #[test]
 fn test_stop_recording_returns_error_when_not_recording() {
     let state = create_test_state();
-    let result = stop_recording_impl(&state, None, false, test_recordings_dir());
+    let result = stop_recording(&state);
 
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("No recording in progress"));
@@ -116,8 +133,8 @@ fn test_stop_recording_returns_error_when_not_recording() {
 #[test]
 fn test_stop_recording_transitions_to_idle() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
+    stop_recording(&state).unwrap();
 
     let manager = state.lock().unwrap();
     assert_eq!(manager.get_state(), RecordingState::Idle);
@@ -126,9 +143,9 @@ fn test_stop_recording_transitions_to_idle() {
 #[test]
 fn test_stop_recording_returns_metadata_with_zero_samples() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
-    let result = stop_recording_impl(&state, None, false, test_recordings_dir());
+    let result = stop_recording(&state);
 
     assert!(result.is_ok());
     let metadata = result.unwrap();
@@ -137,6 +154,16 @@ fn test_stop_recording_returns_metadata_with_zero_samples() {
     assert!(metadata.file_path.is_empty()); // No file when no samples
 }
 
+#[test]
+fn test_stop_recording_metadata_keeps_trigger_source() {
+    let state = create_test_state();
+    start_recording_impl(&state, None, true, None, TriggerSource::Hotkey).unwrap();
+
+    let metadata = stop_recording(&state).unwrap();
+
+    assert_eq!(metadata.trigger_source, Some(TriggerSource::Hotkey));
+}
+
 // Note: Tests that pushed samples directly to the buffer were removed.
 // The new architecture gets audio data directly from Swift capture files,
 // not from the Rust buffer. Use integration tests with real audio capture
@@ -151,14 +178,14 @@ fn test_full_start_stop_cycle() {
     let state = create_test_state();
 
     // Start
-    assert!(start_recording_impl(&state, None, true, None).is_ok());
+    assert!(start_recording_impl(&state, None, true, None, TriggerSource::Button).is_ok());
     assert_eq!(
         get_recording_state_impl(&state).unwrap().state,
         RecordingState::Recording
     );
 
     // Stop
-    assert!(stop_recording_impl(&state, None, false, test_recordings_dir()).is_ok());
+    assert!(stop_recording(&state).is_ok());
     assert_eq!(
         get_recording_state_impl(&state).unwrap().state,
         RecordingState::Idle
@@ -170,8 +197,8 @@ fn test_multiple_start_stop_cycles() {
     let state = create_test_state();
 
     for _ in 0..3 {
-        assert!(start_recording_impl(&state, None, true, None).is_ok());
-        assert!(stop_recording_impl(&state, None, false, test_recordings_dir()).is_ok());
+        assert!(start_recording_impl(&state, None, true, None, TriggerSource::Button).is_ok());
+        assert!(stop_recording(&state).is_ok());
     }
 
     assert_eq!(
@@ -196,7 +223,7 @@ fn test_get_last_recording_buffer_returns_error_when_no_recording() {
 #[test]
 fn test_get_last_recording_buffer_available_after_stop() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     // Add samples to the buffer
     {
@@ -206,7 +233,7 @@ fn test_get_last_recording_buffer_available_after_stop() {
         guard.extend_from_slice(&[0.5f32, -0.5f32, 0.25f32]);
     }
 
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    stop_recording(&state).unwrap();
 
     let result = get_last_recording_buffer_impl(&state);
     assert!(result.is_ok());
@@ -222,7 +249,7 @@ fn test_get_last_recording_buffer_available_after_stop() {
 #[test]
 fn test_get_last_recording_buffer_correct_duration() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     // Add 1 second of samples at 16kHz
     {
@@ -232,7 +259,7 @@ fn test_get_last_recording_buffer_correct_duration() {
         guard.extend_from_slice(&vec![0.5f32; 16000]);
     }
 
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    stop_recording(&state).unwrap();
 
     let audio_data = get_last_recording_buffer_impl(&state).unwrap();
     assert!((audio_data.duration_secs - 1.0).abs() < 0.001);
@@ -241,7 +268,7 @@ fn test_get_last_recording_buffer_correct_duration() {
 #[test]
 fn test_get_last_recording_buffer_persists_in_idle() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     {
         let manager = state.lock().unwrap();
@@ -250,7 +277,7 @@ fn test_get_last_recording_buffer_persists_in_idle() {
         guard.push(0.5);
     }
 
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    stop_recording(&state).unwrap();
 
     // Confirm state is Idle
     let state_info = get_recording_state_impl(&state).unwrap();
@@ -266,24 +293,24 @@ fn test_get_last_recording_buffer_updates_on_new_recording() {
     let state = create_test_state();
 
     // First recording
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
     {
         let manager = state.lock().unwrap();
         let buffer = manager.get_audio_buffer().unwrap();
         let mut guard = buffer.lock().unwrap();
         guard.push(0.1);
     }
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    stop_recording(&state).unwrap();
 
     // Second recording with different data
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
     {
         let manager = state.lock().unwrap();
         let buffer = manager.get_audio_buffer().unwrap();
         let mut guard = buffer.lock().unwrap();
         guard.extend_from_slice(&[0.9, 0.8, 0.7]);
     }
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    stop_recording(&state).unwrap();
 
     // Should have the second recording's data
     let audio_data = get_last_recording_buffer_impl(&state).unwrap();
@@ -305,7 +332,7 @@ fn test_clear_last_recording_buffer_succeeds_when_empty() {
 #[test]
 fn test_clear_last_recording_buffer_clears_data() {
     let state = create_test_state();
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     {
         let manager = state.lock().unwrap();
@@ -314,7 +341,7 @@ fn test_clear_last_recording_buffer_clears_data() {
         guard.push(0.5);
     }
 
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    stop_recording(&state).unwrap();
 
     // Buffer should be available
     assert!(get_last_recording_buffer_impl(&state).is_ok());
@@ -332,15 +359,15 @@ fn test_clear_last_recording_buffer_allows_new_recording() {
     let state = create_test_state();
 
     // Record and stop
-    start_recording_impl(&state, None, true, None).unwrap();
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
+    stop_recording(&state).unwrap();
 
     // Clear
     clear_last_recording_buffer_impl(&state).unwrap();
 
     // Should be able to record again
-    assert!(start_recording_impl(&state, None, true, None).is_ok());
-    assert!(stop_recording_impl(&state, None, false, test_recordings_dir()).is_ok());
+    assert!(start_recording_impl(&state, None, true, None, TriggerSource::Button).is_ok());
+    assert!(stop_recording(&state).is_ok());
 }
 
 // =============================================================================
@@ -411,6 +438,7 @@ fn test_recording_info_struct_serializes() {
         active_window_app_name: None,
         active_window_bundle_id: None,
         active_window_title: None,
+        trigger_source: None,
     };
     let json = serde_json::to_string(&info);
     assert!(json.is_ok());
@@ -433,6 +461,7 @@ fn test_recording_info_with_transcription_serializes() {
         active_window_app_name: None,
         active_window_bundle_id: None,
         active_window_title: None,
+        trigger_source: None,
     };
     let json = serde_json::to_string(&info);
     assert!(json.is_ok());
@@ -462,6 +491,7 @@ fn test_recording_info_with_error_serializes() {
         active_window_app_name: None,
         active_window_bundle_id: None,
         active_window_title: None,
+        trigger_source: None,
     };
     let json = serde_json::to_string(&info);
     assert!(json.is_ok());
@@ -483,6 +513,7 @@ fn test_recording_info_without_error_omits_field() {
         active_window_app_name: None,
         active_window_bundle_id: None,
         active_window_title: None,
+        trigger_source: None,
     };
     let json = serde_json::to_string(&info).unwrap();
     // Error field should be omitted when None due to skip_serializing_if
@@ -491,6 +522,7 @@ fn test_recording_info_without_error_omits_field() {
     assert!(!json.contains("transcription"));
     // Window context fields should also be omitted when None
     assert!(!json.contains("active_window"));
+    assert!(!json.contains("trigger_source"));
 }
 
 #[test]
@@ -506,6 +538,7 @@ fn test_recording_info_with_window_context_serializes() {
         active_window_app_name: Some("Visual Studio Code".to_string()),
         active_window_bundle_id: Some("com.microsoft.VSCode".to_string()),
         active_window_title: Some("main.rs — heycat".to_string()),
+        trigger_source: Some(TriggerSource::Hotkey),
     };
     let json = serde_json::to_string(&info);
     assert!(json.is_ok());
@@ -513,6 +546,7 @@ fn test_recording_info_with_window_context_serializes() {
     assert!(json_str.contains("Visual Studio Code"));
     assert!(json_str.contains("com.microsoft.VSCode"));
     assert!(json_str.contains("main.rs"));
+    assert!(json_str.contains(r#""trigger_source":"Hotkey""#));
 }
 
 // =============================================================================
@@ -522,7 +556,7 @@ fn test_recording_info_with_window_context_serializes() {
 #[test]
 fn test_start_recording_returns_error_when_model_not_available() {
     let state = create_test_state();
-    let result = start_recording_impl(&state, None, false, None);
+    let result = start_recording_impl(&state, None, false, None, TriggerSource::Button);
 
     assert!(result.is_err());
     let error_msg = result.unwrap_err();
@@ -536,7 +570,7 @@ fn test_start_recording_returns_error_when_model_not_available() {
 #[test]
 fn test_start_recording_succeeds_when_model_is_available() {
     let state = create_test_state();
-    let result = start_recording_impl(&state, None, true, None);
+    let result = start_recording_impl(&state, None, true, None, TriggerSource::Button);
 
     assert!(result.is_ok());
 }
@@ -544,7 +578,7 @@ fn test_start_recording_succeeds_when_model_is_available() {
 #[test]
 fn test_start_recording_model_error_message_is_user_friendly() {
     let state = create_test_state();
-    let result = start_recording_impl(&state, None, false, None);
+    let result = start_recording_impl(&state, None, false, None, TriggerSource::Button);
 
     let error_msg = result.unwrap_err();
     // Verify the exact user-friendly message
@@ -566,7 +600,7 @@ fn test_start_recording_model_check_comes_before_state_check() {
     );
 
     // Model not available should fail before state is checked
-    let result = start_recording_impl(&state, None, false, None);
+    let result = start_recording_impl(&state, None, false, None, TriggerSource::Button);
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("download the transcription model"));
 
@@ -590,6 +624,7 @@ fn test_start_recording_with_device_name_succeeds() {
         None,  // No audio thread
         true,  // Model available
         Some("Test Microphone".to_string()),
+        TriggerSource::Button,
     );
 
     assert!(result.is_ok());
@@ -603,7 +638,7 @@ fn test_start_recording_with_device_name_succeeds() {
 fn test_start_recording_with_none_device_uses_default() {
     let state = create_test_state();
     // Pass None for device - should use default
-    let result = start_recording_impl(&state, None, true, None);
+    let result = start_recording_impl(&state, None, true, None, TriggerSource::Button);
 
     assert!(result.is_ok());
 }
@@ -613,15 +648,29 @@ fn test_start_recording_device_param_does_not_affect_state() {
     let state = create_test_state();
 
     // Start with a device name
-    start_recording_impl(&state, None, true, Some("Device1".to_string())).unwrap();
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    start_recording_impl(
+        &state,
+        None,
+        true,
+        Some("Device1".to_string()),
+        TriggerSource::Button,
+    )
+    .unwrap();
+    stop_recording(&state).unwrap();
 
     // Start with different device name
-    start_recording_impl(&state, None, true, Some("Device2".to_string())).unwrap();
-    stop_recording_impl(&state, None, false, test_recordings_dir()).unwrap();
+    start_recording_impl(
+        &state,
+        None,
+        true,
+        Some("Device2".to_string()),
+        TriggerSource::Button,
+    )
+    .unwrap();
+    stop_recording(&state).unwrap();
 
     // Start with no device name
-    start_recording_impl(&state, None, true, None).unwrap();
+    start_recording_impl(&state, None, true, None, TriggerSource::Button).unwrap();
 
     // Final state should be Recording
     assert_eq!(
@@ -630,3 +679,77 @@ fn test_start_recording_device_param_does_not_affect_state() {
     );
 }
 
+// =============================================================================
+// move_capture_file Tests
+// =============================================================================
+
+#[test]
+fn test_move_capture_file_does_not_overwrite_existing_recording() {
+    let temp_dir = std::env::temp_dir().join("heycat-test-move-capture");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    std::fs::write(temp_dir.join("take.wav"), b"earlier recording").unwrap();
+    let capture = temp_dir.join("capture.tmp");
+    std::fs::write(&capture, b"new recording").unwrap();
+
+    // Fixed template renders the same name, as for two stops in one second
+    let writer = SystemFileWriter::new(temp_dir.clone()).with_filename_template("take");
+    let moved = move_capture_file(&writer, capture.to_str().unwrap()).unwrap();
+
+    assert_eq!(PathBuf::from(&moved), temp_dir.join("take-2.wav"));
+    assert_eq!(std::fs::read(&moved).unwrap(), b"new recording");
+    assert_eq!(std::fs::read(temp_dir.join("take.wav")).unwrap(), b"earlier recording");
+    assert!(!capture.exists());
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+}
+
+// =============================================================================
+// save_voice_note_impl Tests
+// =============================================================================
+
+#[test]
+fn test_save_voice_note_missing_file_returns_error() {
+    let result = save_voice_note_impl("/nonexistent/heycat/recording.wav");
+    assert!(result.unwrap_err().contains("not found"));
+}
+
+#[test]
+fn test_save_voice_note_rejects_file_outside_recordings_dir() {
+    let temp_file = std::env::temp_dir().join("heycat-voice-note-outside.wav");
+    std::fs::write(&temp_file, b"not really a wav").unwrap();
+
+    let result = save_voice_note_impl(temp_file.to_str().unwrap());
+    assert!(result.unwrap_err().contains("outside the recordings directory"));
+
+    let _ = std::fs::remove_file(&temp_file);
+}
+
+#[test]
+fn test_validate_recording_name_appends_extension() {
+    assert_eq!(validate_recording_name("standup notes").unwrap(), "standup notes.wav");
+    assert_eq!(validate_recording_name(" idea (v2).WAV ").unwrap(), "idea (v2).wav");
+}
+
+#[test]
+fn test_validate_recording_name_rejects_unsafe_names() {
+    for name in ["", "   ", ".wav", "../escape", "a/b", "a\\b", ".hidden", "what?", "a:b"] {
+        assert!(
+            validate_recording_name(name).is_err(),
+            "expected '{}' to be rejected",
+            name
+        );
+    }
+    assert!(validate_recording_name(&"x".repeat(201)).is_err());
+}
+
+#[test]
+fn test_rename_recording_rejects_file_outside_recordings_dir() {
+    let temp_file = std::env::temp_dir().join("heycat-rename-outside.wav");
+    std::fs::write(&temp_file, b"not really a wav").unwrap();
+
+    let result = rename_recording_impl(temp_file.to_str().unwrap(), "renamed");
+    assert!(result.unwrap_err().contains("outside the recordings directory"));
+
+    let _ = std::fs::remove_file(&temp_file);
+}
@@ -2,19 +2,29 @@
 //!
 //! Contains commands for starting, stopping, and managing recordings.
 
+use std::path::Path;
 use tauri::{AppHandle, Emitter, Manager, State};
 
+use crate::audio::diagnostics::warn_if_microphone_busy;
+use crate::audio::wav::FileWriter;
 use crate::audio::{encode_wav, AudioDeviceError, SystemFileWriter, StopReason};
 use crate::emit_or_warn;
-use crate::events::{event_names, RecordingStartedPayload, RecordingStoppedPayload};
-use crate::recording::{AudioData, RecordingMetadata};
-use crate::turso::events as turso_events;
+use crate::events::{
+    current_timestamp, event_names, RecordingEmptyPayload, RecordingStartedPayload,
+    RecordingStoppedPayload,
+};
+use crate::last_error::{last_error, ErrorCategory};
+use crate::recording::{AudioData, RecordingMetadata, TriggerSource};
+use crate::sound::{play_cue, play_start_cue, SoundCue};
+use crate::storage::{write_recording_bundle, RecordingBundleSidecar};
+use crate::turso::{events as turso_events, RecordingStoreError};
 
 use super::logic::{
     clear_last_recording_buffer_impl, delete_recording_impl, get_last_recording_buffer_impl,
-    get_recording_state_impl, list_recordings_impl, start_recording_impl,
-    stop_recording_impl_extended, PaginatedRecordingsResponse, RecordingContextData,
-    RecordingStateInfo, MICROPHONE_ERROR_MARKER,
+    get_recording_elapsed_impl, get_recording_state_impl, list_recordings_impl,
+    rename_recording_impl, resolve_recording_path, save_voice_note_impl, start_recording_impl,
+    stop_recording_impl_with_writer, PaginatedRecordingsResponse, RecordingContextData,
+    RecordingDeleteResult, RecordingStateInfo, MICROPHONE_ERROR_MARKER,
 };
 use super::{AudioMonitorState, AudioThreadState, ProductionState, TranscriptionServiceState, TursoClientState};
 
@@ -34,6 +44,7 @@ pub fn start_recording(
     let devices = crate::audio::list_input_devices();
     if devices.is_empty() {
         let error = AudioDeviceError::NoDevicesAvailable;
+        last_error().record(ErrorCategory::AudioDevice, error.to_string());
         emit_or_warn!(app_handle, event_names::AUDIO_DEVICE_ERROR, error.clone());
         return Err(error.to_string());
     }
@@ -44,6 +55,7 @@ pub fn start_recording(
             let error = AudioDeviceError::DeviceNotFound {
                 device_name: name.clone(),
             };
+            last_error().record(ErrorCategory::AudioDevice, error.to_string());
             emit_or_warn!(app_handle, event_names::AUDIO_DEVICE_ERROR, error);
         }
     }
@@ -58,11 +70,18 @@ pub fn start_recording(
             }
         };
 
+    // Play the start cue; input is muted while it plays so it isn't recorded
+    if model_available {
+        warn_if_microphone_busy(&app_handle, device_name.as_deref());
+        play_start_cue(&app_handle);
+    }
+
     let result = start_recording_impl(
         state.as_ref(),
         Some(audio_thread.as_ref()),
         model_available,
         device_name,
+        TriggerSource::Button,
     );
 
     match &result {
@@ -81,6 +100,7 @@ pub fn start_recording(
                 let error = AudioDeviceError::CaptureError {
                     message: err_msg.clone(),
                 };
+                last_error().record(ErrorCategory::AudioDevice, error.to_string());
                 emit_or_warn!(app_handle, event_names::AUDIO_DEVICE_ERROR, error);
             }
         }
@@ -108,18 +128,24 @@ pub async fn stop_recording(
     let recordings_dir = crate::paths::get_recordings_dir(worktree_context.as_ref())
         .unwrap_or_else(|_| std::path::PathBuf::from(".").join("heycat").join("recordings"));
 
-    let result = stop_recording_impl_extended(
+    let writer = crate::storage::recording_file_writer(Some(&app_handle), recordings_dir.clone());
+    let result = stop_recording_impl_with_writer(
         state.as_ref(),
         Some(audio_thread.as_ref()),
         false,
-        recordings_dir.clone(),
+        &writer,
     );
 
     if let Ok(ref stop_result) = result {
+        play_cue(&app_handle, SoundCue::RecordingStop);
         let metadata = &stop_result.metadata;
 
         // Check if recording was stopped due to a device error
         if let Some(StopReason::StreamError) = metadata.stop_reason {
+            last_error().record(
+                ErrorCategory::AudioDevice,
+                AudioDeviceError::DeviceDisconnected.to_string(),
+            );
             emit_or_warn!(
                 app_handle,
                 event_names::AUDIO_DEVICE_ERROR,
@@ -135,7 +161,8 @@ pub async fn stop_recording(
 
         // Save raw audio file if debug mode was enabled
         if let Some((raw_samples, device_sample_rate)) = &stop_result.raw_audio {
-            let raw_writer = SystemFileWriter::new(recordings_dir);
+            let raw_writer =
+                SystemFileWriter::new(recordings_dir).with_bit_depth(writer.bit_depth());
             match encode_wav(raw_samples, *device_sample_rate, &raw_writer) {
                 Ok(raw_path) => {
                     let raw_path_with_suffix = raw_path.replace(".wav", "-raw.wav");
@@ -151,8 +178,21 @@ pub async fn stop_recording(
             }
         }
 
+        // Zero-length recordings have no file: notify and skip persistence
+        if metadata.file_path.is_empty() {
+            crate::warn!("Recording produced no audio - storage and transcription skipped (button flow)");
+            emit_or_warn!(
+                app_handle,
+                event_names::RECORDING_EMPTY,
+                RecordingEmptyPayload {
+                    source: "button".to_string(),
+                    timestamp: current_timestamp(),
+                }
+            );
+        }
+
         // Store recording metadata in Turso
-        if !metadata.file_path.is_empty() {
+        if !metadata.file_path.is_empty() && crate::storage::persist_transcriptions(&app_handle) {
             let window_context = crate::storage::WindowContext::capture();
             if let Err(e) = crate::storage::RecordingStorage::store(
                 turso_client.as_ref(),
@@ -191,6 +231,14 @@ pub fn get_recording_state(state: State<'_, ProductionState>) -> Result<Recordin
     get_recording_state_impl(state.as_ref())
 }
 
+/// Get the seconds elapsed in the current recording (None when not recording)
+///
+/// Measured from the backend's recording start, so the UI timer can't drift.
+#[tauri::command]
+pub fn get_recording_elapsed(state: State<'_, ProductionState>) -> Result<Option<f64>, String> {
+    get_recording_elapsed_impl(state.as_ref())
+}
+
 /// Get the audio data from the last completed recording for transcription
 #[tauri::command]
 pub fn get_last_recording_buffer(state: State<'_, ProductionState>) -> Result<AudioData, String> {
@@ -239,6 +287,7 @@ pub async fn list_recordings(
                     active_window_app_name: recording.active_window_app_name.clone(),
                     active_window_bundle_id: recording.active_window_bundle_id.clone(),
                     active_window_title: recording.active_window_title.clone(),
+                    trigger_source: recording.trigger_source,
                 },
             );
         }
@@ -259,7 +308,9 @@ pub async fn list_recordings(
         }
     }
 
-    list_recordings_impl(recordings_dir, limit, offset, recording_context)
+    let mut response = list_recordings_impl(recordings_dir, limit, offset, recording_context)?;
+    response.persistence_enabled = crate::storage::persist_transcriptions(&app_handle);
+    Ok(response)
 }
 
 /// Delete a recording file
@@ -280,3 +331,158 @@ pub async fn delete_recording(
 
     delete_recording_impl(&file_path)
 }
+
+/// Delete several recording files
+///
+/// Each path is handled like `delete_recording`; a failure is reported in
+/// its result and the remaining paths are still processed. A single
+/// `recordings_deleted` event lists the paths that were removed, followed by
+/// one `recordings_updated` so recording lists refresh.
+#[tauri::command]
+pub async fn delete_recordings(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    file_paths: Vec<String>,
+) -> Result<Vec<RecordingDeleteResult>, String> {
+    let mut results = Vec::with_capacity(file_paths.len());
+    let mut deleted = Vec::new();
+
+    for file_path in file_paths {
+        if let Err(e) = turso_client.delete_recording_by_path(&file_path).await {
+            crate::debug!("Turso recording delete (may not exist): {}", e);
+        }
+
+        match delete_recording_impl(&file_path) {
+            Ok(()) => {
+                deleted.push(file_path.clone());
+                results.push(RecordingDeleteResult {
+                    file_path,
+                    success: true,
+                    error: None,
+                });
+            }
+            Err(e) => results.push(RecordingDeleteResult {
+                file_path,
+                success: false,
+                error: Some(e),
+            }),
+        }
+    }
+
+    crate::info!(
+        "Batch delete removed {} of {} recordings",
+        deleted.len(),
+        results.len()
+    );
+    if !deleted.is_empty() {
+        turso_events::emit_recordings_deleted(&app_handle, deleted);
+        // Lists refresh on recordings_updated, as for a single delete
+        turso_events::emit_recordings_updated(&app_handle, "delete", None);
+    }
+
+    Ok(results)
+}
+
+/// Export a recording as a zip bundle for sharing
+///
+/// The bundle holds the WAV file and a JSON sidecar with the latest
+/// transcription, recording metadata, app context and stop reason. A
+/// recording that was never transcribed gets an empty `text` and
+/// `hasTranscription: false`. Only recordings inside the recordings
+/// directory can be exported.
+#[tauri::command]
+pub async fn export_recording_bundle(
+    turso_client: State<'_, TursoClientState>,
+    file_path: String,
+    out_path: String,
+) -> Result<(), String> {
+    let path = resolve_recording_path(&file_path, "export")?;
+    let recording = turso_client
+        .get_recording_by_path(&file_path)
+        .await
+        .map_err(|e| e.to_string())?;
+    let transcription = match &recording {
+        Some(recording) => turso_client
+            .get_transcriptions_by_recording(&recording.id)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .next(),
+        None => None,
+    };
+
+    let sidecar = RecordingBundleSidecar::new(&path, recording.as_ref(), transcription.as_ref());
+    write_recording_bundle(&path, &sidecar, Path::new(&out_path))?;
+
+    crate::info!("Exported recording bundle: {} -> {}", file_path, out_path);
+    Ok(())
+}
+
+/// Rename a recording file
+///
+/// Renames the WAV on disk and updates its path in Turso. Transcriptions
+/// reference the recording by ID and are unaffected. If the database update
+/// fails, the file rename is rolled back.
+#[tauri::command]
+pub async fn rename_recording(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    file_path: String,
+    new_name: String,
+) -> Result<String, String> {
+    let new_path = rename_recording_impl(&file_path, &new_name)?;
+    if new_path == file_path {
+        return Ok(new_path);
+    }
+
+    match turso_client.update_recording_path(&file_path, &new_path).await {
+        Ok(()) => {
+            turso_events::emit_recordings_updated(&app_handle, "update", Some(&new_path));
+        }
+        Err(RecordingStoreError::NotFound(_)) => {
+            crate::debug!("Renamed recording has no Turso row: {}", file_path);
+        }
+        Err(e) => {
+            crate::error!("Failed to update recording path in Turso: {}", e);
+            if let Err(rollback) = std::fs::rename(&new_path, &file_path) {
+                crate::error!("Failed to roll back recording rename: {}", rollback);
+            }
+            return Err(format!("Failed to rename recording: {}", e));
+        }
+    }
+
+    Ok(new_path)
+}
+
+/// Set (or clear) the stop reason of a recording
+///
+/// For annotating recordings whose reason wasn't captured, such as imported
+/// or reconciled ones. `reason` uses the serialized `StopReason` names.
+#[tauri::command]
+pub async fn set_recording_stop_reason(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    file_path: String,
+    reason: Option<StopReason>,
+) -> Result<(), String> {
+    turso_client
+        .update_recording_stop_reason(&file_path, reason)
+        .await
+        .map_err(|e| format!("Failed to set stop reason: {}", e))?;
+
+    turso_events::emit_recordings_updated(&app_handle, "update", Some(&file_path));
+    Ok(())
+}
+
+/// Save a compact Ogg/Opus voice note for a recording
+///
+/// Encoded on request from the saved WAV rather than at record time, so it
+/// costs an extra read of the recording. The original WAV is kept for
+/// transcription; the `.ogg` companion is intended for sharing and is removed
+/// along with the recording.
+#[tauri::command]
+pub async fn save_voice_note(file_path: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || save_voice_note_impl(&file_path))
+        .await
+        .map_err(|e| format!("Voice note task failed: {}", e))?
+}
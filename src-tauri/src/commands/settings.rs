@@ -0,0 +1,166 @@
+//! Settings reload command for Tauri IPC.
+//!
+//! Settings are read from the store on demand, but a few values are cached by
+//! running components at startup. Reloading re-reads the settings file from
+//! disk (e.g. after it was edited by hand) and pushes those values back out.
+
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, State};
+use tauri_plugin_store::StoreExt;
+
+use crate::audio::channel::{ChannelSelection, CHANNEL_SELECTION_KEY};
+use crate::audio::gain::{input_gain_from_setting, INPUT_GAIN_KEY};
+use crate::audio::resample::PREFER_NATIVE_SAMPLE_RATE_KEY;
+use crate::audio::{WavBitDepth, WAV_BIT_DEPTH_KEY};
+use crate::events::settings_events::{self, SettingsChangedPayload};
+use crate::hotkey::integration::{CANCEL_GRACE_MS_KEY, TOGGLE_DEBOUNCE_MS_KEY};
+use crate::hotkey::RecordingMode;
+use crate::recording::{
+    silence_vad_threshold_from_setting, RecordingDetectors, RecordingState,
+    SILENCE_VAD_THRESHOLD_KEY,
+};
+
+use super::common::get_settings_file;
+use super::{HotkeyIntegrationState, ProductionState};
+
+/// Settings values that running components hold on to
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeSettings {
+    /// Push-to-talk or toggle recording
+    pub recording_mode: RecordingMode,
+    /// Toggle debounce in milliseconds (None keeps the current value)
+    pub toggle_debounce_ms: Option<u64>,
+    /// Cancel grace period in milliseconds (None keeps the current value)
+    pub cancel_grace_ms: Option<u64>,
+    /// Software input gain
+    pub input_gain: f32,
+    /// Channel captured from multi-channel input
+    pub channel_selection: ChannelSelection,
+    /// Whether to capture at the device's native sample rate
+    pub prefer_native_sample_rate: bool,
+    /// Sample format recordings are saved in
+    pub wav_bit_depth: WavBitDepth,
+    /// Speech threshold of the silence detector
+    pub silence_vad_threshold: f32,
+    /// Selected input device (None uses the system default)
+    pub selected_device: Option<String>,
+}
+
+impl RuntimeSettings {
+    /// Read the runtime settings through `get`, falling back to defaults
+    pub fn from_values(get: impl Fn(&str) -> Option<serde_json::Value>) -> Self {
+        Self {
+            recording_mode: get("shortcuts.recordingMode")
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or_default(),
+            toggle_debounce_ms: get(TOGGLE_DEBOUNCE_MS_KEY).and_then(|v| v.as_u64()),
+            cancel_grace_ms: get(CANCEL_GRACE_MS_KEY).and_then(|v| v.as_u64()),
+            input_gain: input_gain_from_setting(get(INPUT_GAIN_KEY).and_then(|v| v.as_f64())),
+            channel_selection: ChannelSelection::from_setting(
+                get(CHANNEL_SELECTION_KEY).as_ref().and_then(|v| v.as_str()),
+            ),
+            prefer_native_sample_rate: get(PREFER_NATIVE_SAMPLE_RATE_KEY)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            wav_bit_depth: WavBitDepth::from_setting(
+                get(WAV_BIT_DEPTH_KEY).and_then(|v| v.as_u64()),
+            ),
+            silence_vad_threshold: silence_vad_threshold_from_setting(
+                get(SILENCE_VAD_THRESHOLD_KEY).and_then(|v| v.as_f64()),
+            ),
+            selected_device: get("audio.selectedDevice")
+                .and_then(|v| v.as_str().map(|s| s.to_string())),
+        }
+    }
+}
+
+/// Re-read the settings file from disk and apply it to running components
+///
+/// Audio engine gain, channel, sample rate and WAV format preferences, the silence VAD
+/// threshold and the hotkey timings are applied straight away. While a
+/// recording is active, a recording mode change is deferred until the next
+/// hotkey press after the recording ends, and the running engine keeps its
+/// device (the next capture opens the saved one). Emits `settings_changed`
+/// once the values are applied.
+#[tauri::command]
+pub fn reload_settings(
+    app_handle: AppHandle,
+    recording_state: State<'_, ProductionState>,
+    integration: State<'_, HotkeyIntegrationState>,
+    detectors: State<'_, Arc<Mutex<RecordingDetectors>>>,
+) -> Result<(), String> {
+    let settings_file = get_settings_file(&app_handle);
+    let store = app_handle
+        .store(&settings_file)
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store
+        .reload()
+        .map_err(|e| format!("Failed to reload settings: {}", e))?;
+    let settings = RuntimeSettings::from_values(|key| store.get(key));
+
+    crate::swift::audio_engine_set_input_gain(settings.input_gain);
+    crate::swift::audio_engine_set_channel_selection(settings.channel_selection);
+    crate::swift::audio_engine_set_prefer_native_sample_rate(settings.prefer_native_sample_rate);
+    crate::swift::audio_engine_set_wav_bit_depth(settings.wav_bit_depth);
+
+    {
+        let mut detectors = detectors
+            .lock()
+            .map_err(|_| "Failed to access recording detectors.".to_string())?;
+        detectors.set_vad_threshold(settings.silence_vad_threshold);
+        detectors.set_wav_bit_depth(settings.wav_bit_depth);
+    }
+
+    let idle = recording_state
+        .lock()
+        .map(|manager| manager.get_state() == RecordingState::Idle)
+        .map_err(|_| {
+            "Unable to access recording state. Please try again or restart the application."
+        })?;
+
+    {
+        let mut integration = integration.lock().map_err(|_| {
+            "Unable to access hotkey integration. Please try again or restart the application."
+        })?;
+        if idle {
+            integration.set_recording_mode(settings.recording_mode);
+        } else {
+            integration.defer_recording_mode(settings.recording_mode);
+        }
+        if let Some(ms) = settings.toggle_debounce_ms {
+            integration.set_debounce_duration(std::time::Duration::from_millis(ms));
+        }
+        if let Some(ms) = settings.cancel_grace_ms {
+            integration.set_cancel_grace(std::time::Duration::from_millis(ms));
+        }
+    }
+
+    // Recordings pick up the saved device when capture starts, so only switch
+    // a running engine that isn't capturing
+    if idle && settings.selected_device.is_some() && crate::swift::audio_engine_is_running() {
+        crate::device_handler::mark_user_device_change();
+        if let crate::swift::AudioEngineResult::Failed(error) =
+            crate::swift::audio_engine_set_device(settings.selected_device.as_deref())
+        {
+            crate::warn!("Failed to switch to reloaded input device: {}", error);
+        }
+    }
+
+    crate::info!(
+        "Settings reloaded from {} (recording active: {})",
+        settings_file,
+        !idle
+    );
+    crate::emit_or_warn!(
+        app_handle,
+        settings_events::SETTINGS_CHANGED,
+        SettingsChangedPayload {
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "settings_test.rs"]
+mod tests;
@@ -0,0 +1,58 @@
+use super::*;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn settings_from(values: &[(&str, serde_json::Value)]) -> RuntimeSettings {
+    let values: HashMap<String, serde_json::Value> = values
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect();
+    RuntimeSettings::from_values(|key| values.get(key).cloned())
+}
+
+#[test]
+fn test_runtime_settings_default_when_unset() {
+    let settings = settings_from(&[]);
+    assert_eq!(settings.recording_mode, RecordingMode::default());
+    assert_eq!(settings.toggle_debounce_ms, None);
+    assert_eq!(settings.cancel_grace_ms, None);
+    assert_eq!(settings.input_gain, input_gain_from_setting(None));
+    assert_eq!(settings.channel_selection, ChannelSelection::default());
+    assert!(!settings.prefer_native_sample_rate);
+    assert_eq!(settings.wav_bit_depth, WavBitDepth::Int16);
+    assert_eq!(
+        settings.silence_vad_threshold,
+        silence_vad_threshold_from_setting(None)
+    );
+    assert_eq!(settings.selected_device, None);
+}
+
+#[test]
+fn test_runtime_settings_read_saved_values() {
+    let settings = settings_from(&[
+        ("shortcuts.recordingMode", json!("push-to-talk")),
+        ("hotkey.toggleDebounceMs", json!(300)),
+        (PREFER_NATIVE_SAMPLE_RATE_KEY, json!(true)),
+        (WAV_BIT_DEPTH_KEY, json!(32)),
+        (SILENCE_VAD_THRESHOLD_KEY, json!(0.6)),
+        ("audio.selectedDevice", json!("USB Mic")),
+    ]);
+    assert_eq!(settings.recording_mode, RecordingMode::PushToTalk);
+    assert_eq!(settings.toggle_debounce_ms, Some(300));
+    assert!(settings.prefer_native_sample_rate);
+    assert_eq!(settings.wav_bit_depth, WavBitDepth::Float32);
+    assert_eq!(settings.silence_vad_threshold, 0.6);
+    assert_eq!(settings.selected_device.as_deref(), Some("USB Mic"));
+}
+
+#[test]
+fn test_runtime_settings_ignore_malformed_values() {
+    let settings = settings_from(&[
+        ("shortcuts.recordingMode", json!("sideways")),
+        ("hotkey.cancelGraceMs", json!("soon")),
+        ("audio.selectedDevice", json!(42)),
+    ]);
+    assert_eq!(settings.recording_mode, RecordingMode::default());
+    assert_eq!(settings.cancel_grace_ms, None);
+    assert_eq!(settings.selected_device, None);
+}
@@ -0,0 +1,53 @@
+//! First-run setup status command.
+//!
+//! Reports which prerequisites are in place so onboarding can drive a
+//! checklist from a single call.
+
+use std::sync::Arc;
+use tauri::{AppHandle, Manager, State};
+
+use crate::parakeet::SharedTranscriptionModel;
+
+use super::HotkeyServiceState;
+
+/// Setup checklist for onboarding
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetupStatus {
+    /// The transcription model is loaded and ready
+    pub model_loaded: bool,
+    /// Microphone access has been granted
+    pub mic_permission_granted: bool,
+    /// Accessibility access has been granted (needed to simulate paste and capture hotkeys)
+    pub accessibility_permission_granted: bool,
+    /// The recording shortcut is registered
+    pub hotkey_registered: bool,
+    /// Recordings can be written to the recordings directory
+    pub recordings_dir_writable: bool,
+}
+
+/// Get the first-run setup status
+///
+/// Permission checks never prompt the user.
+#[tauri::command]
+pub fn get_setup_status(
+    app_handle: AppHandle,
+    shared_model: State<'_, Arc<SharedTranscriptionModel>>,
+    hotkey_service: State<'_, HotkeyServiceState>,
+) -> SetupStatus {
+    let worktree_context = app_handle
+        .try_state::<crate::worktree::WorktreeState>()
+        .and_then(|s| s.context.clone());
+    let recordings_dir_writable = crate::paths::get_recordings_dir(worktree_context.as_ref())
+        .map(|dir| crate::paths::is_dir_writable(&dir))
+        .unwrap_or(false);
+
+    SetupStatus {
+        model_loaded: shared_model.is_loaded(),
+        mic_permission_granted: crate::swift::microphone_permission_granted(),
+        accessibility_permission_granted:
+            crate::keyboard_capture::permissions::check_accessibility_permission(),
+        hotkey_registered: hotkey_service.is_recording_registered(),
+        recordings_dir_writable,
+    }
+}
@@ -6,17 +6,37 @@ use std::sync::Arc;
 use tauri::{AppHandle, State};
 use tauri::Emitter;
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_store::StoreExt;
 
+use crate::audio::decode_wav_samples;
 use crate::emit_or_warn;
 use crate::events::{
-    event_names, TranscriptionCompletedPayload, TranscriptionErrorPayload,
-    TranscriptionStartedPayload,
+    event_names, RetranscribeCompletedPayload, RetranscribeProgressPayload,
+    TranscriptionBenchmarkProgressPayload, TranscriptionCompletedPayload,
+    TranscriptionErrorPayload, TranscriptionStartedPayload,
 };
-use crate::parakeet::SharedTranscriptionModel;
+use crate::hotkey::integration::CANCEL_GRACE_MS_KEY;
+use crate::last_error::{last_error, ErrorCategory};
+use crate::parakeet::{SharedTranscriptionModel, TranscriptionService};
+use crate::transcription::benchmark::{
+    summarize_benchmark, TranscriptionBenchmark, BENCHMARK_RUNS, BENCHMARK_SAMPLE,
+    BENCHMARK_SAMPLE_SECS,
+};
+use crate::transcription::fallback::{
+    override_model_version, run_timed_transcription, FallbackModel, PRIMARY_MODEL_VERSION,
+};
+use crate::transcription::language::{guess_language, guess_transcription_language};
+use crate::transcription::match_log::{self, MatchLogSettings};
+use crate::transcription::perf::TranscriptionPerf;
+use crate::transcription::timeout::{
+    validate_transcription_timeout, TRANSCRIPTION_TIMEOUT_SECS_KEY,
+};
+use crate::transcription::verbose_logging;
 use crate::turso::events as turso_events;
 
+use super::common::get_settings_file;
 use super::logic::transcribe_file_impl;
-use super::TursoClientState;
+use super::{HotkeyIntegrationState, TranscriptionServiceState, TursoClientState};
 
 /// Transcription record for frontend consumption
 #[derive(Debug, Clone, serde::Serialize)]
@@ -28,6 +48,8 @@ pub struct TranscriptionInfo {
     pub model_version: String,
     pub duration_ms: u64,
     pub created_at: String,
+    /// User correction of the text, if any
+    pub edited_text: Option<String>,
 }
 
 /// Transcribe an audio file and copy result to clipboard
@@ -40,6 +62,16 @@ pub async fn transcribe_file(
     shared_model: State<'_, Arc<SharedTranscriptionModel>>,
     file_path: String,
 ) -> Result<String, String> {
+    // Audio is deleted after transcription when recordings.retainAudio is off
+    if !std::path::Path::new(&file_path).exists() {
+        if let Ok(Some(_)) = turso_client.get_recording_by_path(&file_path).await {
+            return Err(
+                "The audio for this recording was deleted after transcription and can't be re-transcribed."
+                    .to_string(),
+            );
+        }
+    }
+
     // Emit transcription started event
     let start_time = std::time::Instant::now();
     emit_or_warn!(
@@ -69,7 +101,11 @@ pub async fn transcribe_file(
             }
 
             // Store transcription in Turso
-            if let Ok(Some(recording)) = turso_client.get_recording_by_path(&file_path).await {
+            if !crate::storage::persist_transcriptions(&app_handle) {
+                crate::debug!("Transcription history disabled, transcription not stored");
+            } else if let Ok(Some(recording)) =
+                turso_client.get_recording_by_path(&file_path).await
+            {
                 let recording_id = recording.id.clone();
                 let transcription_id = uuid::Uuid::new_v4().to_string();
                 if let Err(e) = turso_client
@@ -77,8 +113,8 @@ pub async fn transcribe_file(
                         transcription_id.clone(),
                         recording_id.clone(),
                         text.clone(),
-                        None,
-                        "parakeet-tdt".to_string(),
+                        guess_language(&text).map(String::from),
+                        PRIMARY_MODEL_VERSION.to_string(),
                         duration_ms,
                     )
                     .await
@@ -104,12 +140,14 @@ pub async fn transcribe_file(
                 TranscriptionCompletedPayload {
                     text: text.clone(),
                     duration_ms,
+                    language: guess_transcription_language(&text),
                 }
             );
 
             Ok(text)
         }
         Err(e) => {
+            last_error().record(ErrorCategory::Transcription, e.clone());
             emit_or_warn!(
                 app_handle,
                 event_names::TRANSCRIPTION_ERROR,
@@ -121,6 +159,242 @@ pub async fn transcribe_file(
     }
 }
 
+/// Result of a batch re-transcription
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RetranscribeSummary {
+    /// Model version recorded on the new transcriptions
+    pub model_version: String,
+    /// Recordings that produced a new transcription
+    pub succeeded: usize,
+    /// Recordings that failed to transcribe or store
+    pub failed: usize,
+}
+
+/// Re-transcribe every recording whose audio is still on disk
+///
+/// Each recording is transcribed with the loaded model, or with the model in
+/// `model_path` when given, and the result is stored as a new transcription
+/// row so it can be compared against earlier ones. Transcriptions share the
+/// service's concurrency semaphore, so a batch waits for live dictation
+/// rather than competing with it. Emits `retranscribe_progress` after each
+/// recording and `retranscribe_completed` at the end.
+#[tauri::command]
+pub async fn retranscribe_all(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    shared_model: State<'_, Arc<SharedTranscriptionModel>>,
+    transcription_service: State<'_, TranscriptionServiceState>,
+    model_path: Option<String>,
+) -> Result<RetranscribeSummary, String> {
+    let model_dir = model_path
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .map(std::path::PathBuf::from);
+    if let Some(dir) = &model_dir {
+        if !dir.is_dir() {
+            return Err(format!("Model directory not found: {}", dir.display()));
+        }
+    }
+    let model_version = model_dir
+        .as_deref()
+        .map(override_model_version)
+        .unwrap_or_else(|| PRIMARY_MODEL_VERSION.to_string());
+    // An override model is loaded once for the batch and dropped afterwards
+    let override_model = model_dir.map(|dir| (Arc::new(FallbackModel::new()), dir));
+
+    // Recordings whose audio was discarded after transcription are skipped
+    let recordings: Vec<_> = turso_client
+        .list_recordings()
+        .await
+        .map_err(|e| format!("Failed to list recordings: {}", e))?
+        .into_iter()
+        .filter(|r| std::path::Path::new(&r.file_path).exists())
+        .collect();
+    let total = recordings.len();
+    crate::info!(
+        "Re-transcribing {} recordings with {}",
+        total,
+        model_version
+    );
+
+    let semaphore = transcription_service.transcription_semaphore();
+    let timeout = transcription_service.transcription_timeout();
+    let mut succeeded = 0;
+    let mut failed = 0;
+
+    for (index, recording) in recordings.into_iter().enumerate() {
+        let _permit = semaphore
+            .acquire()
+            .await
+            .map_err(|e| format!("Transcription semaphore closed: {}", e))?;
+
+        let start_time = std::time::Instant::now();
+        let path = recording.file_path.clone();
+        let result = match &override_model {
+            Some((model, dir)) => {
+                let (model, dir) = (model.clone(), dir.clone());
+                run_timed_transcription(timeout, move || model.transcribe(&dir, &path)).await
+            }
+            None => {
+                let model = shared_model.inner().clone();
+                let result = run_timed_transcription(timeout, move || {
+                    model.transcribe(&path).map_err(|e| e.to_string())
+                })
+                .await;
+                if let Err(e) = shared_model.reset_to_idle() {
+                    crate::warn!("Failed to reset transcription state: {}", e);
+                }
+                result
+            }
+        };
+
+        let error = match result {
+            Ok(text) => {
+                let transcription_id = uuid::Uuid::new_v4().to_string();
+                match turso_client
+                    .add_transcription(
+                        transcription_id.clone(),
+                        recording.id.clone(),
+                        text.clone(),
+                        guess_language(&text).map(String::from),
+                        model_version.clone(),
+                        start_time.elapsed().as_millis() as u64,
+                    )
+                    .await
+                {
+                    Ok(_) => {
+                        turso_events::emit_transcriptions_updated(
+                            &app_handle,
+                            "add",
+                            Some(&transcription_id),
+                            Some(&recording.id),
+                        );
+                        None
+                    }
+                    Err(e) => Some(format!("Failed to store transcription: {}", e)),
+                }
+            }
+            Err(e) => Some(e),
+        };
+
+        if let Some(e) = &error {
+            crate::warn!("Re-transcription failed for {}: {}", recording.file_path, e);
+            failed += 1;
+        } else {
+            succeeded += 1;
+        }
+        emit_or_warn!(
+            app_handle,
+            event_names::RETRANSCRIBE_PROGRESS,
+            RetranscribeProgressPayload {
+                processed: index + 1,
+                total,
+                file_path: recording.file_path,
+                error,
+            }
+        );
+    }
+
+    crate::info!(
+        "Re-transcription finished: {} succeeded, {} failed",
+        succeeded,
+        failed
+    );
+    emit_or_warn!(
+        app_handle,
+        event_names::RETRANSCRIBE_COMPLETED,
+        RetranscribeCompletedPayload {
+            model_version: model_version.clone(),
+            succeeded,
+            failed,
+        }
+    );
+
+    Ok(RetranscribeSummary {
+        model_version,
+        succeeded,
+        failed,
+    })
+}
+
+/// Benchmark transcription speed on this machine
+///
+/// Transcribes a bundled speech sample `BENCHMARK_RUNS` times with the loaded
+/// model and returns the aggregate timing, including the real-time factor
+/// (audio seconds per processing second). Runs on the blocking thread pool and
+/// shares the transcription semaphore, so it waits for live dictation. Emits
+/// `transcription_benchmark_progress` after each run.
+#[tauri::command]
+pub async fn run_transcription_benchmark(
+    app_handle: AppHandle,
+    shared_model: State<'_, Arc<SharedTranscriptionModel>>,
+    transcription_service: State<'_, TranscriptionServiceState>,
+) -> Result<TranscriptionBenchmark, String> {
+    if !shared_model.is_loaded() {
+        return Err("Please download the Batch transcription model first.".to_string());
+    }
+
+    let (samples, sample_rate) = decode_wav_samples(BENCHMARK_SAMPLE)
+        .map_err(|e| format!("Failed to decode benchmark sample: {}", e))?;
+    let samples: Arc<[f32]> = samples.into();
+
+    let semaphore = transcription_service.transcription_semaphore();
+    let timeout = transcription_service.transcription_timeout();
+    let mut durations = Vec::with_capacity(BENCHMARK_RUNS);
+    let mut error = None;
+
+    for run in 1..=BENCHMARK_RUNS {
+        let _permit = match semaphore.acquire().await {
+            Ok(permit) => permit,
+            Err(e) => {
+                error = Some(format!("Transcription semaphore closed: {}", e));
+                break;
+            }
+        };
+
+        let model = shared_model.inner().clone();
+        let samples = samples.clone();
+        let start_time = std::time::Instant::now();
+        let result = run_timed_transcription(timeout, move || {
+            model
+                .transcribe_samples(&samples, sample_rate)
+                .map_err(|e| e.to_string())
+        })
+        .await;
+        let elapsed = start_time.elapsed();
+        if let Err(e) = shared_model.reset_to_idle() {
+            crate::warn!("Failed to reset transcription state: {}", e);
+        }
+        if let Err(e) = result {
+            error = Some(e);
+            break;
+        }
+
+        durations.push(elapsed);
+        emit_or_warn!(
+            app_handle,
+            event_names::TRANSCRIPTION_BENCHMARK_PROGRESS,
+            TranscriptionBenchmarkProgressPayload {
+                run,
+                total_runs: BENCHMARK_RUNS,
+                duration_ms: elapsed.as_millis() as u64,
+            }
+        );
+    }
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    let benchmark = summarize_benchmark(BENCHMARK_SAMPLE_SECS, &durations);
+    crate::info!(
+        "Transcription benchmark: {:.2}x real time over {} runs",
+        benchmark.real_time_factor,
+        benchmark.runs
+    );
+    Ok(benchmark)
+}
+
 /// List all transcriptions from Turso
 #[tauri::command]
 pub async fn list_transcriptions(
@@ -140,12 +414,136 @@ pub async fn list_transcriptions(
                     model_version: t.model_version,
                     duration_ms: t.duration_ms,
                     created_at: t.created_at,
+                    edited_text: t.edited_text,
                 })
                 .collect()
         })
         .map_err(|e| format!("Failed to list transcriptions: {}", e))
 }
 
+/// Get aggregate usage stats (word count, audio time, typing time saved)
+#[tauri::command]
+pub async fn get_usage_summary(
+    turso_client: State<'_, TursoClientState>,
+) -> Result<crate::turso::UsageSummary, String> {
+    turso_client
+        .get_usage_summary()
+        .await
+        .map_err(|e| format!("Failed to get usage summary: {}", e))
+}
+
+/// Get latency stats over the recent transcriptions
+///
+/// Returns min/avg/max/p95 transcription time and characters per second,
+/// to spot a degrading model or compare model sizes.
+#[tauri::command]
+pub fn get_transcription_perf(
+    transcription_service: State<'_, TranscriptionServiceState>,
+) -> TranscriptionPerf {
+    transcription_service.transcription_perf()
+}
+
+/// Get whether transcribed text is written to the log
+#[tauri::command]
+pub fn get_verbose_transcription_logging() -> bool {
+    verbose_logging::verbose_transcription_logging()
+}
+
+/// Turn verbose transcription logging on or off
+///
+/// Runtime-only: logging goes back to hiding transcribed text on restart.
+#[tauri::command]
+pub fn set_verbose_transcription_logging(enabled: bool) {
+    crate::info!(
+        "Verbose transcription logging {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    verbose_logging::set_verbose_transcription_logging(enabled);
+}
+
+/// Get whether match outcomes are logged and the file they go to
+#[tauri::command]
+pub fn get_match_log_settings(app_handle: AppHandle) -> MatchLogSettings {
+    match_log::match_log_settings(&app_handle)
+}
+
+/// Turn the match log on or off and optionally change its file
+///
+/// An empty `path` resets the file to the default in the data directory;
+/// `None` leaves it unchanged. Returns the settings now in effect.
+#[tauri::command]
+pub fn set_match_log_settings(
+    app_handle: AppHandle,
+    enabled: bool,
+    path: Option<String>,
+) -> Result<MatchLogSettings, String> {
+    let store = app_handle
+        .store(get_settings_file(&app_handle))
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store.set(match_log::MATCH_LOG_ENABLED_KEY, serde_json::json!(enabled));
+    if let Some(path) = path {
+        store.set(match_log::MATCH_LOG_PATH_KEY, serde_json::json!(path.trim()));
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    let settings = match_log::match_log_settings(&app_handle);
+    crate::info!(
+        "Match log enabled={}, path={:?}",
+        settings.enabled,
+        settings.path
+    );
+    Ok(settings)
+}
+
+/// Get how long a transcription may run before it is cancelled, in seconds
+#[tauri::command]
+pub fn get_transcription_timeout(
+    transcription_service: State<'_, TranscriptionServiceState>,
+) -> u64 {
+    transcription_service.transcription_timeout().as_secs()
+}
+
+/// Set how long a transcription may run before it is cancelled
+///
+/// Accepts 1 to `MAX_TRANSCRIPTION_TIMEOUT_SECS` seconds. The timeout is saved
+/// to settings and applies to transcriptions started from now on. When no
+/// cancel grace period is configured, it is kept at twice the timeout so a
+/// long transcription can still be cancelled. Returns the timeout in seconds.
+#[tauri::command]
+pub fn set_transcription_timeout(
+    app_handle: AppHandle,
+    transcription_service: State<'_, TranscriptionServiceState>,
+    integration: State<'_, HotkeyIntegrationState>,
+    secs: u64,
+) -> Result<u64, String> {
+    let timeout = validate_transcription_timeout(secs)?;
+
+    let store = app_handle
+        .store(get_settings_file(&app_handle))
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store.set(TRANSCRIPTION_TIMEOUT_SECS_KEY, serde_json::json!(secs));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+    let cancel_grace_configured = store.get(CANCEL_GRACE_MS_KEY).is_some();
+
+    transcription_service.set_transcription_timeout(timeout);
+    {
+        let mut integration = integration.lock().map_err(|_| {
+            "Unable to access hotkey integration. Please try again or restart the application."
+        })?;
+        integration.set_transcription_timeout(timeout);
+        if !cancel_grace_configured {
+            integration.set_cancel_grace(timeout * 2);
+        }
+    }
+
+    crate::info!("Transcription timeout set to {} seconds", secs);
+    Ok(secs)
+}
+
 /// Get transcriptions for a specific recording
 #[tauri::command]
 pub async fn get_transcriptions_by_recording(
@@ -166,8 +564,57 @@ pub async fn get_transcriptions_by_recording(
                     model_version: t.model_version,
                     duration_ms: t.duration_ms,
                     created_at: t.created_at,
+                    edited_text: t.edited_text,
                 })
                 .collect()
         })
         .map_err(|e| format!("Failed to get transcriptions: {}", e))
 }
+
+/// Paste a stored transcription through the clipboard flow
+///
+/// Fetches the transcription text from Turso and copies/pastes it (applying
+/// dictionary expansion and auto-enter) without re-transcribing the audio.
+///
+/// # Returns
+/// The text that was pasted, or an error if copying or pasting it failed
+#[tauri::command]
+pub async fn paste_transcription(
+    turso_client: State<'_, TursoClientState>,
+    transcription_service: State<'_, TranscriptionServiceState>,
+    transcription_id: String,
+) -> Result<String, String> {
+    let transcription = turso_client
+        .get_transcription_by_id(&transcription_id)
+        .await
+        .map_err(|e| format!("Failed to load transcription: {}", e))?
+        .ok_or_else(|| format!("Transcription with ID '{}' not found", transcription_id))?;
+
+    crate::info!("Re-pasting transcription {}", transcription.id);
+    let text = transcription
+        .edited_text
+        .as_deref()
+        .unwrap_or(&transcription.text);
+    transcription_service.paste_text(text).await
+}
+
+/// Store a user correction for a transcription
+///
+/// The original text is kept alongside the correction. An empty `text`
+/// clears the correction.
+#[tauri::command]
+pub async fn update_transcription_text(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    transcription_id: String,
+    text: String,
+) -> Result<(), String> {
+    turso_client
+        .update_transcription_text(&transcription_id, &text)
+        .await
+        .map_err(|e| format!("Failed to update transcription: {}", e))?;
+
+    turso_events::emit_transcriptions_updated(&app_handle, "update", Some(&transcription_id), None);
+    crate::info!("Updated transcription text: {}", transcription_id);
+    Ok(())
+}
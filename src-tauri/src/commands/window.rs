@@ -1,13 +1,63 @@
 //! Window management commands.
 
+use crate::util::get_settings_file;
+use std::time::Duration;
 use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// Label of the splash window in `tauri.conf.json`
+pub const SPLASH_WINDOW_LABEL: &str = "splash";
+
+/// Settings key: start without the splash window (bool, default false)
+pub const SKIP_SPLASH_KEY: &str = "app.skipSplash";
+
+/// Environment variable that skips the splash window when set (e.g. for dev runs)
+pub const SKIP_SPLASH_ENV: &str = "HEYCAT_NO_SPLASH";
+
+/// Settings key for how many times closing the splash window is attempted
+pub const SPLASH_CLOSE_ATTEMPTS_KEY: &str = "app.splashCloseAttempts";
+
+/// Settings key for the delay before the first splash close retry in milliseconds
+pub const SPLASH_CLOSE_RETRY_DELAY_MS_KEY: &str = "app.splashCloseRetryDelayMs";
+
+/// Default number of splash close attempts
+pub const DEFAULT_SPLASH_CLOSE_ATTEMPTS: u32 = 5;
+
+/// Default delay before the first splash close retry
+pub const DEFAULT_SPLASH_CLOSE_RETRY_DELAY_MS: u64 = 50;
+
+/// Upper bound on the configurable attempt count
+pub const MAX_SPLASH_CLOSE_ATTEMPTS: u32 = 20;
+
+/// Upper bound on any single retry delay, so backoff can't stall the command
+pub const MAX_SPLASH_CLOSE_RETRY_DELAY_MS: u64 = 2_000;
+
+/// Resolve the splash close attempt count from its settings value (at least 1)
+pub fn splash_close_attempts_from_setting(value: Option<u64>) -> u32 {
+    value
+        .map(|v| v.clamp(1, MAX_SPLASH_CLOSE_ATTEMPTS as u64) as u32)
+        .unwrap_or(DEFAULT_SPLASH_CLOSE_ATTEMPTS)
+}
+
+/// Delay before retrying after the given failed attempt (1-based)
+///
+/// Doubles after each failure, capped at `MAX_SPLASH_CLOSE_RETRY_DELAY_MS`.
+pub fn splash_close_retry_delay_ms(base_delay_ms: u64, failed_attempt: u32) -> u64 {
+    let factor = 1u64 << failed_attempt.saturating_sub(1).min(16);
+    base_delay_ms
+        .saturating_mul(factor)
+        .min(MAX_SPLASH_CLOSE_RETRY_DELAY_MS)
+}
 
 /// Show the main window, close the splash window, and give main focus
 ///
 /// Called by the frontend when the app is ready to be displayed (e.g., after
 /// initialization completes). This enables a seamless splash-to-app transition.
 ///
-/// Includes error recovery with retry logic for splash window operations.
+/// Closing the splash is retried with exponential backoff (attempts and the
+/// first delay are configurable); if every attempt fails the splash is hidden.
+/// When the splash is disabled there is no splash window and only the main
+/// window is shown.
 #[tauri::command]
 pub fn show_main_window(app_handle: AppHandle) -> Result<(), String> {
     // Show the main window first (before closing splash) for smoother UX
@@ -25,38 +75,60 @@ pub fn show_main_window(app_handle: AppHandle) -> Result<(), String> {
     crate::info!("Main window shown and focused");
 
     // Close the splash window with retry logic
-    if let Some(splash) = app_handle.get_webview_window("splash") {
-        let mut attempts = 0;
-        const MAX_ATTEMPTS: u32 = 3;
-        const RETRY_DELAY_MS: u64 = 50;
-
-        loop {
-            attempts += 1;
-            match splash.close() {
-                Ok(()) => {
-                    crate::debug!("Splash window closed");
-                    break;
-                }
-                Err(e) => {
-                    if attempts >= MAX_ATTEMPTS {
-                        // Log warning but don't fail - main window is already visible
-                        crate::warn!(
-                            "Failed to close splash window after {} attempts: {}",
-                            attempts,
-                            e
-                        );
-                        break;
-                    }
-                    crate::debug!(
-                        "Splash close attempt {} failed, retrying: {}",
+    let Some(splash) = app_handle.get_webview_window(SPLASH_WINDOW_LABEL) else {
+        crate::debug!("No splash window to close");
+        return Ok(());
+    };
+
+    let store = app_handle.store(get_settings_file(&app_handle)).ok();
+    let setting = |key: &str| {
+        store
+            .as_ref()
+            .and_then(|s| s.get(key))
+            .and_then(|v| v.as_u64())
+    };
+    let max_attempts = splash_close_attempts_from_setting(setting(SPLASH_CLOSE_ATTEMPTS_KEY));
+    let base_delay_ms =
+        setting(SPLASH_CLOSE_RETRY_DELAY_MS_KEY).unwrap_or(DEFAULT_SPLASH_CLOSE_RETRY_DELAY_MS);
+
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+        match splash.close() {
+            Ok(()) => {
+                crate::debug!("Splash window closed");
+                break;
+            }
+            Err(e) => {
+                if attempts >= max_attempts {
+                    // Don't fail - main window is already visible. Hide the
+                    // splash so it can't stay on top of the main window.
+                    crate::warn!(
+                        "Failed to close splash window after {} attempts, hiding it: {}",
                         attempts,
                         e
                     );
-                    std::thread::sleep(std::time::Duration::from_millis(RETRY_DELAY_MS));
+                    if let Err(e) = splash.hide() {
+                        crate::warn!("Failed to hide splash window: {}", e);
+                    }
+                    break;
                 }
+                let delay_ms = splash_close_retry_delay_ms(base_delay_ms, attempts);
+                crate::debug!(
+                    "Splash close attempt {} failed, retrying in {}ms: {}",
+                    attempts,
+                    delay_ms,
+                    e
+                );
+                std::thread::sleep(Duration::from_millis(delay_ms));
             }
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+#[path = "window_test.rs"]
+mod tests;
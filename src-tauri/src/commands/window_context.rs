@@ -4,10 +4,11 @@
 // This file contains Tauri-specific wrappers and is excluded from coverage.
 #![cfg_attr(coverage_nightly, coverage(off))]
 
+use crate::transcription::transforms::TextTransforms;
 use crate::turso::{events as turso_events, TursoClient};
 use crate::window_context::{
-    get_active_window, get_running_applications, ActiveWindowInfo, OverrideMode,
-    RunningApplication, WindowContext, WindowContextStoreError, WindowMatcher,
+    get_running_applications, ActiveWindowInfo, DetectedWindow, OverrideMode, RunningApplication,
+    WindowContext, WindowContextStoreError, WindowMatcher,
 };
 use std::sync::Arc;
 use tauri::{AppHandle, State};
@@ -35,7 +36,20 @@ fn to_user_error(error: WindowContextStoreError) -> String {
 /// Useful for testing window detection and context matching.
 #[tauri::command]
 pub fn get_active_window_info() -> Result<ActiveWindowInfo, String> {
-    get_active_window()
+    crate::window_context::get_active_window()
+}
+
+/// Get what active-window detection sees right now
+///
+/// Returns the app name, bundle ID and window title exactly as window
+/// matchers see them, so users can check detection works on their system and
+/// copy the strings into a matcher. Never fails: fields the OS can't provide
+/// are null, and `error` explains a detection that failed outright.
+#[tauri::command]
+pub fn get_active_window() -> DetectedWindow {
+    let detected = DetectedWindow::from_detection(crate::window_context::get_active_window());
+    crate::debug!("Active window detection: {:?}", detected);
+    detected
 }
 
 /// List all running user-visible applications
@@ -79,6 +93,8 @@ pub async fn add_window_context(
     dictionary_entry_ids: Option<Vec<String>>,
     enabled: Option<bool>,
     priority: Option<i32>,
+    text_transforms: Option<TextTransforms>,
+    dictation_disabled: Option<bool>,
 ) -> Result<WindowContext, String> {
     // Validate: name cannot be empty
     if name.trim().is_empty() {
@@ -90,6 +106,10 @@ pub async fn add_window_context(
         return Err("App name cannot be empty".to_string());
     }
 
+    if let Some(transforms) = &text_transforms {
+        transforms.validate()?;
+    }
+
     let matcher = WindowMatcher {
         app_name,
         title_pattern,
@@ -120,6 +140,8 @@ pub async fn add_window_context(
             dictionary_entry_ids_val,
             enabled_val,
             priority_val,
+            text_transforms,
+            dictation_disabled.unwrap_or(false),
         )
         .await
         .map_err(to_user_error)?;
@@ -150,6 +172,8 @@ pub async fn update_window_context(
     dictionary_entry_ids: Option<Vec<String>>,
     enabled: Option<bool>,
     priority: Option<i32>,
+    text_transforms: Option<TextTransforms>,
+    dictation_disabled: Option<bool>,
 ) -> Result<(), String> {
     // Validate: name cannot be empty
     if name.trim().is_empty() {
@@ -163,6 +187,10 @@ pub async fn update_window_context(
 
     let uuid = Uuid::parse_str(&id).map_err(|_| format!("Invalid UUID: {}", id))?;
 
+    if let Some(transforms) = &text_transforms {
+        transforms.validate()?;
+    }
+
     let matcher = WindowMatcher {
         app_name,
         title_pattern,
@@ -188,6 +216,8 @@ pub async fn update_window_context(
         dictionary_entry_ids: dictionary_entry_ids.unwrap_or_default(),
         enabled: enabled.unwrap_or(true),
         priority: priority.unwrap_or(0),
+        text_transforms,
+        dictation_disabled: dictation_disabled.unwrap_or(false),
     };
 
     // Update context in Turso
@@ -0,0 +1,30 @@
+use super::*;
+
+#[test]
+fn test_splash_close_attempts_default_and_clamp() {
+    assert_eq!(
+        splash_close_attempts_from_setting(None),
+        DEFAULT_SPLASH_CLOSE_ATTEMPTS
+    );
+    assert_eq!(splash_close_attempts_from_setting(Some(0)), 1);
+    assert_eq!(splash_close_attempts_from_setting(Some(8)), 8);
+    assert_eq!(
+        splash_close_attempts_from_setting(Some(1_000)),
+        MAX_SPLASH_CLOSE_ATTEMPTS
+    );
+}
+
+#[test]
+fn test_splash_close_retry_delay_doubles_and_caps() {
+    assert_eq!(splash_close_retry_delay_ms(50, 1), 50);
+    assert_eq!(splash_close_retry_delay_ms(50, 2), 100);
+    assert_eq!(splash_close_retry_delay_ms(50, 4), 400);
+    assert_eq!(
+        splash_close_retry_delay_ms(50, 30),
+        MAX_SPLASH_CLOSE_RETRY_DELAY_MS
+    );
+    assert_eq!(
+        splash_close_retry_delay_ms(u64::MAX, 3),
+        MAX_SPLASH_CLOSE_RETRY_DELAY_MS
+    );
+}
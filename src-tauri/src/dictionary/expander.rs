@@ -2,6 +2,7 @@
 // Uses case-insensitive, whole-word matching with regex
 
 use regex::Regex;
+use serde::Serialize;
 
 use super::DictionaryEntry;
 
@@ -14,6 +15,36 @@ pub struct ExpansionResult {
     pub should_press_enter: bool,
 }
 
+/// A dictionary entry that fired during expansion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionaryMatch {
+    /// ID of the entry that fired
+    pub entry_id: String,
+    /// The entry's trigger
+    pub trigger: String,
+    /// Text that was replaced
+    pub matched_text: String,
+    /// Character offset where the match starts
+    pub start: usize,
+    /// Character offset where the match ends (exclusive)
+    pub end: usize,
+}
+
+/// Before/after view of running the dictionary over some text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionaryPreview {
+    /// The input text
+    pub original_text: String,
+    /// The text after all expansions
+    pub expanded_text: String,
+    /// True if any matched entry had auto_enter enabled
+    pub should_press_enter: bool,
+    /// Entries that fired, in the order they were applied
+    pub matches: Vec<DictionaryMatch>,
+}
+
 /// Compiled pattern for a single dictionary entry (partial matching)
 struct CompiledPattern {
     regex: Regex,
@@ -51,8 +82,14 @@ impl DictionaryExpander {
                     entry: entry.clone(),
                 });
             } else {
-                // Compile regex pattern for partial matching
-                let pattern = format!(r"(?i)\b{}\b", regex::escape(&entry.trigger));
+                // Compile regex pattern for partial matching. With disable_suffix the
+                // pattern also takes any trailing punctuation, so it is removed too
+                let trigger = regex::escape(&entry.trigger);
+                let pattern = if entry.disable_suffix {
+                    format!(r"(?i)\b{}\b([.!?,;:]*)", trigger)
+                } else {
+                    format!(r"(?i)\b{}\b", trigger)
+                };
                 match Regex::new(&pattern) {
                     Ok(regex) => {
                         partial_patterns.push(CompiledPattern {
@@ -84,7 +121,29 @@ impl DictionaryExpander {
     /// (case-insensitive) a complete-match trigger, return immediately with expansion.
     /// Otherwise, fall through to partial matching.
     pub fn expand(&self, text: &str) -> ExpansionResult {
+        self.expand_with_matches(text).0
+    }
+
+    /// Run the expansion pass over text and report which entries fired and where
+    ///
+    /// Used by the settings UI to show the dictionary's effect without recording.
+    pub fn preview(&self, text: &str) -> DictionaryPreview {
+        let (result, matches) = self.expand_with_matches(text);
+        DictionaryPreview {
+            original_text: text.to_string(),
+            expanded_text: result.expanded_text,
+            should_press_enter: result.should_press_enter,
+            matches,
+        }
+    }
+
+    /// Apply all expansions, collecting every match as it is replaced
+    ///
+    /// Offsets are into the text the entry was applied to: the input itself,
+    /// unless an earlier entry already changed it.
+    fn expand_with_matches(&self, text: &str) -> (ExpansionResult, Vec<DictionaryMatch>) {
         let trimmed = text.trim();
+        let mut matches = Vec::new();
 
         // Check complete-match entries FIRST
         for complete_entry in &self.complete_match_entries {
@@ -109,10 +168,22 @@ impl DictionaryExpander {
                     }
                 };
 
-                return ExpansionResult {
-                    expanded_text: replacement,
-                    should_press_enter: complete_entry.entry.auto_enter,
-                };
+                let start = text[..text.len() - text.trim_start().len()].chars().count();
+                matches.push(DictionaryMatch {
+                    entry_id: complete_entry.entry.id.clone(),
+                    trigger: complete_entry.entry.trigger.clone(),
+                    matched_text: trimmed.to_string(),
+                    start,
+                    end: start + trimmed.chars().count(),
+                });
+
+                return (
+                    ExpansionResult {
+                        expanded_text: replacement,
+                        should_press_enter: complete_entry.entry.auto_enter,
+                    },
+                    matches,
+                );
             }
         }
 
@@ -121,54 +192,54 @@ impl DictionaryExpander {
         let mut should_press_enter = false;
 
         for pattern in &self.partial_patterns {
-            if pattern.regex.is_match(&result) {
-                // Build replacement based on suffix and disable_suffix settings
-                let replacement = if pattern.entry.disable_suffix {
-                    // When disable_suffix is true, use expansion only (no trailing punctuation)
-                    pattern.entry.expansion.clone()
-                } else {
-                    // Normal behavior: append suffix if present
-                    match &pattern.entry.suffix {
-                        Some(suffix) => format!("{}{}", pattern.entry.expansion, suffix),
-                        None => pattern.entry.expansion.clone(),
-                    }
-                };
-
-                // When disable_suffix is true, we also need to strip any trailing punctuation
-                // that may follow the trigger in the original text
-                if pattern.entry.disable_suffix {
-                    // Use a capturing regex to also match and remove trailing punctuation
-                    let pattern_with_punct =
-                        format!(r"(?i)\b{}\b([.!?,;:]*)", regex::escape(&pattern.entry.trigger));
-                    if let Ok(punct_regex) = regex::Regex::new(&pattern_with_punct) {
-                        result = punct_regex
-                            .replace_all(&result, replacement.as_str())
-                            .to_string();
-                    } else {
-                        // Fallback to standard replacement if regex fails
-                        result = pattern
-                            .regex
-                            .replace_all(&result, replacement.as_str())
-                            .to_string();
-                    }
-                } else {
-                    result = pattern
-                        .regex
-                        .replace_all(&result, replacement.as_str())
-                        .to_string();
+            // Build replacement based on suffix and disable_suffix settings
+            let replacement = if pattern.entry.disable_suffix {
+                // When disable_suffix is true, use expansion only (no trailing punctuation)
+                pattern.entry.expansion.clone()
+            } else {
+                // Normal behavior: append suffix if present
+                match &pattern.entry.suffix {
+                    Some(suffix) => format!("{}{}", pattern.entry.expansion, suffix),
+                    None => pattern.entry.expansion.clone(),
                 }
+            };
 
-                // Track auto_enter
-                if pattern.entry.auto_enter {
-                    should_press_enter = true;
-                }
+            // Single pass: record each match while replacing it
+            let mut fired = false;
+            let expanded = pattern
+                .regex
+                .replace_all(&result, |caps: &regex::Captures| {
+                    let m = caps.get(0).expect("group 0 is always present");
+                    let start = result[..m.start()].chars().count();
+                    matches.push(DictionaryMatch {
+                        entry_id: pattern.entry.id.clone(),
+                        trigger: pattern.entry.trigger.clone(),
+                        matched_text: m.as_str().to_string(),
+                        start,
+                        end: start + m.as_str().chars().count(),
+                    });
+                    fired = true;
+
+                    let mut dst = String::new();
+                    caps.expand(&replacement, &mut dst);
+                    dst
+                })
+                .into_owned();
+            result = expanded;
+
+            // Track auto_enter
+            if fired && pattern.entry.auto_enter {
+                should_press_enter = true;
             }
         }
 
-        ExpansionResult {
-            expanded_text: result,
-            should_press_enter,
-        }
+        (
+            ExpansionResult {
+                expanded_text: result,
+                should_press_enter,
+            },
+            matches,
+        )
     }
 }
 
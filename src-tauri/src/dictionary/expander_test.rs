@@ -532,3 +532,37 @@ fn test_complete_match_only_without_disable_suffix_keeps_punctuation() {
     // Without punctuation, it matches
     assert_eq!(expander.expand("yes").expanded_text, "affirmative");
 }
+
+#[test]
+fn test_preview_reports_each_match_with_offsets() {
+    let entries = vec![make_entry("brb", "be right back"), make_entry("api", "API")];
+    let expander = DictionaryExpander::new(&entries);
+
+    let preview = expander.preview("brb, the api is brb");
+
+    assert_eq!(preview.original_text, "brb, the api is brb");
+    assert_eq!(preview.expanded_text, "be right back, the API is be right back");
+    let fired: Vec<_> = preview
+        .matches
+        .iter()
+        .map(|m| (m.entry_id.as_str(), m.start, m.end))
+        .collect();
+    assert_eq!(fired, vec![("test-brb", 0, 3), ("test-brb", 16, 19), ("test-api", 19, 22)]);
+}
+
+#[test]
+fn test_preview_complete_match_and_no_match() {
+    let mut entry = make_entry("clear", "/clear");
+    entry.complete_match_only = true;
+    let expander = DictionaryExpander::new(&[entry]);
+
+    let preview = expander.preview("  Clear ");
+    assert_eq!(preview.expanded_text, "/clear");
+    assert_eq!(preview.matches.len(), 1);
+    assert_eq!(preview.matches[0].matched_text, "Clear");
+    assert_eq!((preview.matches[0].start, preview.matches[0].end), (2, 7));
+
+    let preview = expander.preview("please clear it");
+    assert_eq!(preview.expanded_text, "please clear it");
+    assert!(preview.matches.is_empty());
+}
@@ -5,7 +5,8 @@
 
 mod expander;
 mod store;
+pub mod suggestions;
 
 #[allow(unused_imports)]
-pub use expander::{DictionaryExpander, ExpansionResult};
+pub use expander::{DictionaryExpander, DictionaryPreview, ExpansionResult};
 pub use store::{DictionaryEntry, DictionaryError};
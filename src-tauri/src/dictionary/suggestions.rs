@@ -0,0 +1,126 @@
+// Dictionary suggestions derived from repeated transcription corrections
+//
+// When the user keeps correcting the same phrase the same way, that phrase is
+// a good candidate for a dictionary entry (trigger -> expansion).
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+use super::DictionaryEntry;
+
+/// Minimum number of identical corrections before a phrase is suggested
+pub const MIN_SUGGESTION_OCCURRENCES: usize = 2;
+
+/// Maximum number of words in a corrected span; longer rewrites aren't phrase fixes
+const MAX_SPAN_WORDS: usize = 4;
+
+/// A dictionary entry suggested from repeated corrections
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DictionarySuggestion {
+    /// Phrase as it was transcribed
+    pub trigger: String,
+    /// Phrase as the user corrected it
+    pub expansion: String,
+    /// Number of corrections that made this replacement
+    pub occurrences: usize,
+}
+
+/// Normalize a word for comparison (case and surrounding punctuation ignored)
+fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| c.is_ascii_punctuation())
+        .to_lowercase()
+}
+
+/// Extract the replaced span between an original and a corrected text
+///
+/// Trims the words shared at the start and end of both texts and returns the
+/// differing (original, corrected) span. Returns `None` when the texts are the
+/// same, when the correction is a pure insertion or deletion, or when the span
+/// is too long to be a phrase correction.
+pub fn correction_span(original: &str, edited: &str) -> Option<(String, String)> {
+    let original_words: Vec<&str> = original.split_whitespace().collect();
+    let edited_words: Vec<&str> = edited.split_whitespace().collect();
+
+    let same = |a: &str, b: &str| normalize_word(a) == normalize_word(b);
+
+    let prefix = original_words
+        .iter()
+        .zip(edited_words.iter())
+        .take_while(|(a, b)| same(a, b))
+        .count();
+    let max_suffix = original_words.len().min(edited_words.len()) - prefix;
+    let suffix = original_words[prefix..]
+        .iter()
+        .rev()
+        .zip(edited_words[prefix..].iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| same(a, b))
+        .count();
+
+    let from = &original_words[prefix..original_words.len() - suffix];
+    let to = &edited_words[prefix..edited_words.len() - suffix];
+    if from.is_empty() || to.is_empty() || from.len() > MAX_SPAN_WORDS || to.len() > MAX_SPAN_WORDS
+    {
+        return None;
+    }
+
+    let trigger = from
+        .iter()
+        .map(|w| normalize_word(w))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let expansion = to
+        .iter()
+        .map(|w| w.trim_matches(|c: char| c.is_ascii_punctuation()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if trigger.is_empty() || expansion.is_empty() {
+        return None;
+    }
+
+    Some((trigger, expansion))
+}
+
+/// Suggest dictionary entries from (original, edited) correction pairs
+///
+/// Replacements seen at least `min_occurrences` times are suggested, most
+/// frequent first. Triggers already in the dictionary are skipped.
+pub fn suggest_entries(
+    corrections: &[(String, String)],
+    existing: &[DictionaryEntry],
+    min_occurrences: usize,
+) -> Vec<DictionarySuggestion> {
+    let mut counts: HashMap<(String, String), usize> = HashMap::new();
+    for (original, edited) in corrections {
+        if let Some(span) = correction_span(original, edited) {
+            *counts.entry(span).or_insert(0) += 1;
+        }
+    }
+
+    let mut suggestions: Vec<DictionarySuggestion> = counts
+        .into_iter()
+        .filter(|(_, occurrences)| *occurrences >= min_occurrences)
+        .filter(|((trigger, _), _)| {
+            !existing
+                .iter()
+                .any(|entry| entry.trigger.eq_ignore_ascii_case(trigger))
+        })
+        .map(|((trigger, expansion), occurrences)| DictionarySuggestion {
+            trigger,
+            expansion,
+            occurrences,
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.occurrences
+            .cmp(&a.occurrences)
+            .then_with(|| a.trigger.cmp(&b.trigger))
+    });
+    suggestions
+}
+
+#[cfg(test)]
+#[path = "suggestions_test.rs"]
+mod tests;
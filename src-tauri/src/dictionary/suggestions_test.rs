@@ -0,0 +1,72 @@
+use super::*;
+
+fn pair(original: &str, edited: &str) -> (String, String) {
+    (original.to_string(), edited.to_string())
+}
+
+fn entry(trigger: &str) -> DictionaryEntry {
+    DictionaryEntry {
+        id: "1".to_string(),
+        trigger: trigger.to_string(),
+        expansion: "anything".to_string(),
+        suffix: None,
+        auto_enter: false,
+        disable_suffix: false,
+        complete_match_only: false,
+    }
+}
+
+#[test]
+fn test_correction_span_extracts_replaced_words() {
+    assert_eq!(
+        correction_span("send it to hey cat now", "send it to heycat now"),
+        Some(("hey cat".to_string(), "heycat".to_string()))
+    );
+}
+
+#[test]
+fn test_correction_span_ignores_case_and_punctuation_changes() {
+    assert_eq!(correction_span("hello world.", "Hello world"), None);
+}
+
+#[test]
+fn test_correction_span_rejects_insertions_and_long_rewrites() {
+    assert_eq!(correction_span("open slack", "please open slack"), None);
+    assert_eq!(
+        correction_span(
+            "one two three four five six",
+            "alpha beta gamma delta epsilon zeta"
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_suggest_entries_requires_repeated_corrections() {
+    let corrections = vec![
+        pair("ping cooper about it", "ping Kuper about it"),
+        pair("tell cooper thanks", "tell Kuper thanks"),
+        pair("open get hub", "open GitHub"),
+    ];
+
+    let suggestions = suggest_entries(&corrections, &[], MIN_SUGGESTION_OCCURRENCES);
+    assert_eq!(
+        suggestions,
+        vec![DictionarySuggestion {
+            trigger: "cooper".to_string(),
+            expansion: "Kuper".to_string(),
+            occurrences: 2,
+        }]
+    );
+}
+
+#[test]
+fn test_suggest_entries_skips_existing_triggers() {
+    let corrections = vec![
+        pair("ping cooper", "ping Kuper"),
+        pair("call cooper", "call Kuper"),
+    ];
+
+    let suggestions = suggest_entries(&corrections, &[entry("Cooper")], 2);
+    assert!(suggestions.is_empty());
+}
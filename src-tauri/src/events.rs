@@ -3,7 +3,8 @@
 
 use serde::Serialize;
 
-use crate::recording::RecordingMetadata;
+use crate::recording::{RecordingMetadata, RecordingState};
+use crate::voice_commands::disambiguation::AmbiguityTimeoutAction;
 
 /// Event names as constants for consistency
 pub mod event_names {
@@ -11,13 +12,27 @@ pub mod event_names {
     pub const RECORDING_STOPPED: &str = "recording_stopped";
     pub const RECORDING_CANCELLED: &str = "recording_cancelled";
     pub const RECORDING_ERROR: &str = "recording_error";
+    pub const RECORDING_EMPTY: &str = "recording_empty";
+    pub const RECORDING_STATE_CHANGED: &str = "recording_state_changed";
+    pub const RECORDING_STATE_RECOVERED: &str = "recording_state_recovered";
+    pub const SILENCE_COUNTDOWN: &str = "silence_countdown";
     pub const AUDIO_DEVICE_ERROR: &str = "audio_device_error";
     pub const AUDIO_LEVEL: &str = "audio-level";
     pub const RECORDING_QUALITY_WARNING: &str = "recording_quality_warning";
     pub const TRANSCRIPTION_STARTED: &str = "transcription_started";
     pub const TRANSCRIPTION_COMPLETED: &str = "transcription_completed";
     pub const TRANSCRIPTION_ERROR: &str = "transcription_error";
+    pub const TRANSCRIPTION_SKIPPED: &str = "transcription_skipped";
     pub const SHORTCUT_KEY_CAPTURED: &str = "shortcut_key_captured";
+    pub const RETRANSCRIBE_PROGRESS: &str = "retranscribe_progress";
+    pub const RETRANSCRIBE_COMPLETED: &str = "retranscribe_completed";
+    pub const TRANSCRIPTION_BENCHMARK_PROGRESS: &str = "transcription_benchmark_progress";
+    pub const ACCESSIBILITY_PERMISSION_REQUIRED: &str = "accessibility_permission_required";
+    pub const PASTE_PAUSED: &str = "paste_paused";
+    pub const TRANSCRIPTION_OUTPUT_FAILED: &str = "transcription_output_failed";
+    pub const TRANSCRIPTION_HALLUCINATION_SUSPECTED: &str = "transcription_hallucination_suspected";
+    pub const NO_INPUT_DEVICE: &str = "no_input_device";
+    pub const INPUT_DEVICE_AVAILABLE: &str = "input_device_available";
 }
 
 /// Command-related event names
@@ -26,6 +41,9 @@ pub mod command_events {
     pub const COMMAND_EXECUTED: &str = "command_executed";
     pub const COMMAND_FAILED: &str = "command_failed";
     pub const COMMAND_AMBIGUOUS: &str = "command_ambiguous";
+    pub const COMMAND_AUTO_RESOLVED: &str = "command_auto_resolved";
+    pub const COMMAND_CONFIRMATION_REQUIRED: &str = "command_confirmation_required";
+    pub const COMMAND_CONFIRMATION_CANCELLED: &str = "command_confirmation_cancelled";
 }
 
 /// Hotkey-related event names
@@ -101,6 +119,9 @@ pub mod window_context_events {
 pub mod model_events {
     pub const MODEL_DOWNLOAD_COMPLETED: &str = "model_download_completed";
     pub const MODEL_FILE_DOWNLOAD_PROGRESS: &str = "model_file_download_progress";
+    pub const MODEL_RELOADING: &str = "model_reloading";
+    pub const MODEL_RELOADED: &str = "model_reloaded";
+    pub const MODEL_RELOAD_FAILED: &str = "model_reload_failed";
 
     /// Payload for model_download_completed event
     #[derive(Debug, Clone, serde::Serialize, PartialEq)]
@@ -133,6 +154,116 @@ pub mod model_events {
     }
 }
 
+/// Settings-related event names
+pub mod settings_events {
+    pub const SETTINGS_CHANGED: &str = "settings_changed";
+
+    /// Payload for settings_changed event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SettingsChangedPayload {
+        /// ISO 8601 timestamp when the settings were reloaded
+        pub timestamp: String,
+    }
+}
+
+/// A stable event name along with the module category it belongs to
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct EventNameInfo {
+    /// Constant module the name is defined in (e.g., "recording", "command")
+    pub category: &'static str,
+    /// Event name as emitted to the frontend
+    pub name: &'static str,
+}
+
+/// List every event name the backend emits, grouped by category
+///
+/// Used by tooling and tests to verify the frontend listens to the right names.
+pub fn all_event_names() -> Vec<EventNameInfo> {
+    use crate::turso::events::event_names as turso_events;
+
+    let groups: [(&'static str, &[&'static str]); 8] = [
+        (
+            "recording",
+            &[
+                event_names::RECORDING_STARTED,
+                event_names::RECORDING_STOPPED,
+                event_names::RECORDING_CANCELLED,
+                event_names::RECORDING_ERROR,
+                event_names::RECORDING_EMPTY,
+                event_names::RECORDING_STATE_CHANGED,
+                event_names::RECORDING_STATE_RECOVERED,
+                event_names::SILENCE_COUNTDOWN,
+                event_names::AUDIO_DEVICE_ERROR,
+                event_names::AUDIO_LEVEL,
+                event_names::RECORDING_QUALITY_WARNING,
+                event_names::TRANSCRIPTION_STARTED,
+                event_names::TRANSCRIPTION_COMPLETED,
+                event_names::TRANSCRIPTION_ERROR,
+                event_names::TRANSCRIPTION_SKIPPED,
+                event_names::SHORTCUT_KEY_CAPTURED,
+                event_names::RETRANSCRIBE_PROGRESS,
+                event_names::RETRANSCRIBE_COMPLETED,
+                event_names::TRANSCRIPTION_BENCHMARK_PROGRESS,
+                event_names::ACCESSIBILITY_PERMISSION_REQUIRED,
+                event_names::PASTE_PAUSED,
+                event_names::TRANSCRIPTION_OUTPUT_FAILED,
+                event_names::TRANSCRIPTION_HALLUCINATION_SUSPECTED,
+                event_names::NO_INPUT_DEVICE,
+                event_names::INPUT_DEVICE_AVAILABLE,
+            ],
+        ),
+        (
+            "command",
+            &[
+                command_events::COMMAND_MATCHED,
+                command_events::COMMAND_EXECUTED,
+                command_events::COMMAND_FAILED,
+                command_events::COMMAND_AMBIGUOUS,
+                command_events::COMMAND_AUTO_RESOLVED,
+                command_events::COMMAND_CONFIRMATION_REQUIRED,
+                command_events::COMMAND_CONFIRMATION_CANCELLED,
+            ],
+        ),
+        ("hotkey", &[hotkey_events::KEY_BLOCKING_UNAVAILABLE]),
+        ("dictionary", &[dictionary_events::DICTIONARY_UPDATED]),
+        (
+            "window_context",
+            &[
+                window_context_events::WINDOW_CONTEXTS_UPDATED,
+                window_context_events::ACTIVE_WINDOW_CHANGED,
+            ],
+        ),
+        (
+            "model",
+            &[
+                model_events::MODEL_DOWNLOAD_COMPLETED,
+                model_events::MODEL_FILE_DOWNLOAD_PROGRESS,
+                model_events::MODEL_RELOADING,
+                model_events::MODEL_RELOADED,
+                model_events::MODEL_RELOAD_FAILED,
+            ],
+        ),
+        ("settings", &[settings_events::SETTINGS_CHANGED]),
+        (
+            "database",
+            &[
+                turso_events::VOICE_COMMANDS_UPDATED,
+                turso_events::RECORDINGS_UPDATED,
+                turso_events::RECORDINGS_DELETED,
+                turso_events::TRANSCRIPTIONS_UPDATED,
+            ],
+        ),
+    ];
+
+    groups
+        .iter()
+        .flat_map(|&(category, names)| {
+            names.iter().map(move |&name| EventNameInfo { category, name })
+        })
+        .collect()
+}
+
 /// Payload for recording_started event
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct RecordingStartedPayload {
@@ -147,6 +278,9 @@ pub struct RecordingStoppedPayload {
     pub metadata: RecordingMetadata,
 }
 
+/// Error message shown when an internal lock is poisoned and the app needs a restart
+pub const INTERNAL_ERROR_MESSAGE: &str = "Internal error: please restart the application";
+
 /// Payload for recording_error event
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct RecordingErrorPayload {
@@ -164,6 +298,62 @@ pub struct RecordingCancelledPayload {
     pub timestamp: String,
 }
 
+/// Payload for recording_empty event
+///
+/// Emitted when a recording stops without any captured audio, so no file
+/// is persisted or transcribed.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingEmptyPayload {
+    /// Source of the recording (e.g., "hotkey", "PTT", "button")
+    pub source: String,
+    /// ISO 8601 timestamp when the recording stopped
+    pub timestamp: String,
+}
+
+/// Payload for silence_countdown event
+///
+/// Emitted while silence after speech is counting toward auto-stop, so the
+/// UI can show "stopping in 1s..." and the user can keep talking.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SilenceCountdownPayload {
+    /// How long the current silence has lasted in milliseconds
+    pub elapsed_ms: u64,
+    /// Silence duration that triggers auto-stop in milliseconds
+    pub threshold_ms: u64,
+    /// Milliseconds left before auto-stop
+    pub remaining_ms: u64,
+}
+
+/// Payload for recording_state_changed event
+///
+/// Emitted by `RecordingManager` on every state change, so the UI can follow
+/// Idle → Recording → Processing → Idle without polling.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RecordingStateChangedPayload {
+    /// State before the change
+    pub from: RecordingState,
+    /// State after the change
+    pub to: RecordingState,
+    /// ISO 8601 timestamp of the change
+    pub timestamp: String,
+}
+
+/// Payload for recording_state_recovered event
+///
+/// Emitted when a poisoned lock was found and the recording state was reset to
+/// Idle, discarding any in-progress or retained audio.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RecordingStateRecoveredPayload {
+    /// Which lock was poisoned ("recording_state" or "audio_buffer")
+    pub lock: String,
+    /// State before the reset
+    pub from: RecordingState,
+    /// ISO 8601 timestamp of the recovery
+    pub timestamp: String,
+}
+
 /// Payload for transcription_started event
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct TranscriptionStartedPayload {
@@ -178,6 +368,8 @@ pub struct TranscriptionCompletedPayload {
     pub text: String,
     /// Duration of transcription in milliseconds
     pub duration_ms: u64,
+    /// Guessed language of the text (ISO 639-1), if it could be determined
+    pub language: Option<String>,
 }
 
 /// Payload for transcription_error event
@@ -187,6 +379,132 @@ pub struct TranscriptionErrorPayload {
     pub error: String,
 }
 
+/// Payload for retranscribe_progress event (one per recording in a batch)
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RetranscribeProgressPayload {
+    /// Number of recordings processed so far, including this one
+    pub processed: usize,
+    /// Total number of recordings in the batch
+    pub total: usize,
+    /// Path of the recording that was just processed
+    pub file_path: String,
+    /// Error message if this recording failed, None on success
+    pub error: Option<String>,
+}
+
+/// Payload for retranscribe_completed event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RetranscribeCompletedPayload {
+    /// Model version recorded on the new transcriptions
+    pub model_version: String,
+    /// Recordings that produced a new transcription
+    pub succeeded: usize,
+    /// Recordings that failed to transcribe or store
+    pub failed: usize,
+}
+
+/// Payload for transcription_benchmark_progress event (one per benchmark run)
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionBenchmarkProgressPayload {
+    /// Number of runs completed, including this one
+    pub run: usize,
+    /// Total number of runs in the benchmark
+    pub total_runs: usize,
+    /// Time this run took in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Payload for accessibility_permission_required event
+///
+/// The frontend prompts the user and offers a button that calls
+/// `open_accessibility_preferences`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AccessibilityPermissionRequiredPayload {
+    /// Keystroke that couldn't be simulated ("paste", "enter" or "type")
+    pub action: String,
+    /// User-facing explanation
+    pub message: String,
+}
+
+/// Payload for paste_paused event
+///
+/// Emitted when a transcription is copied to the clipboard but not pasted,
+/// so the frontend can show that paste is paused.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PastePausedPayload {
+    /// Why paste was skipped ("screen_capture")
+    pub reason: String,
+    /// ISO 8601 timestamp when paste was skipped
+    pub timestamp: String,
+}
+
+/// Payload for transcription_skipped event
+///
+/// Emitted when a finished recording isn't transcribed, so the loss of the
+/// text is never silent.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TranscriptionSkippedPayload {
+    /// Why transcription was skipped ("model_not_loaded")
+    pub reason: String,
+    /// Path of the kept WAV file that can be transcribed later, None if it was deleted
+    pub file_path: Option<String>,
+    /// ISO 8601 timestamp when transcription was skipped
+    pub timestamp: String,
+}
+
+/// Payload for transcription_output_failed event
+///
+/// Emitted when a transcription couldn't be written to the clipboard or typed,
+/// so the frontend can offer to copy the text manually.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TranscriptionOutputFailedPayload {
+    /// The transcribed text that wasn't delivered
+    pub text: String,
+    /// Why the clipboard write failed
+    pub error: String,
+    /// ISO 8601 timestamp when delivery failed
+    pub timestamp: String,
+}
+
+/// Payload for transcription_hallucination_suspected event
+///
+/// Emitted when a word the model repeated back to back too many times was
+/// collapsed, which usually means it hallucinated on silent or noisy input.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TranscriptionHallucinationPayload {
+    /// The transcription after collapsing the repeats
+    pub text: String,
+    /// Length of the longest run of repeats that was collapsed
+    pub longest_run: usize,
+    /// ISO 8601 timestamp when the repeats were collapsed
+    pub timestamp: String,
+}
+
+/// Payload for no_input_device event
+///
+/// Emitted at startup when no audio input device is present, so the frontend
+/// can warn before a recording fails.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct NoInputDevicePayload {
+    /// Seconds between device discovery retries (None when not retrying)
+    pub retry_interval_secs: Option<u64>,
+    /// ISO 8601 timestamp when the check ran
+    pub timestamp: String,
+}
+
+/// Payload for input_device_available event
+///
+/// Emitted when an input device appears after `no_input_device`, clearing the
+/// warning.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct InputDeviceAvailablePayload {
+    /// Name of the device that was found (the default device when several are)
+    pub device_name: String,
+    /// ISO 8601 timestamp when the device was found
+    pub timestamp: String,
+}
+
 /// Payload for command_matched event
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct CommandMatchedPayload {
@@ -218,6 +536,51 @@ pub struct CommandAmbiguousPayload {
     pub transcription: String,
     /// List of candidate commands
     pub candidates: Vec<CommandCandidate>,
+    /// Whether more candidates matched than are listed
+    pub truncated: bool,
+}
+
+/// Payload for command_auto_resolved event
+///
+/// Emitted when an ambiguous match times out without the user picking a candidate.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommandAutoResolvedPayload {
+    /// The transcribed text that matched ambiguously
+    pub transcription: String,
+    /// What auto-resolution did
+    pub action: AmbiguityTimeoutAction,
+    /// ID of the executed command (None when the text went to the output sinks)
+    pub command_id: Option<String>,
+    /// ISO 8601 timestamp of the auto-resolution
+    pub timestamp: String,
+}
+
+/// Payload for command_confirmation_required event
+///
+/// Emitted when a matched command must be confirmed before it runs.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommandConfirmationRequiredPayload {
+    /// The transcribed text that matched
+    pub transcription: String,
+    /// ID of the command awaiting confirmation
+    pub command_id: String,
+    /// Trigger phrase of the command
+    pub trigger: String,
+    /// Milliseconds until the command is cancelled unconfirmed
+    pub timeout_ms: u64,
+}
+
+/// Payload for command_confirmation_cancelled event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommandConfirmationCancelledPayload {
+    /// ID of the command that won't run
+    pub command_id: String,
+    /// Trigger phrase of the command
+    pub trigger: String,
+    /// Why it was cancelled ("cancelled", "timeout" or "replaced")
+    pub reason: String,
+    /// ISO 8601 timestamp of the cancellation
+    pub timestamp: String,
 }
 
 /// Payload for command_executed event
@@ -258,6 +621,18 @@ pub trait RecordingEventEmitter: Send + Sync {
 
     /// Emit recording_error event
     fn emit_recording_error(&self, payload: RecordingErrorPayload);
+
+    /// Emit recording_empty event
+    fn emit_recording_empty(&self, payload: RecordingEmptyPayload);
+
+    /// Emit silence_countdown event
+    fn emit_silence_countdown(&self, payload: SilenceCountdownPayload);
+
+    /// Emit recording_state_changed event
+    fn emit_recording_state_changed(&self, payload: RecordingStateChangedPayload);
+
+    /// Emit recording_state_recovered event
+    fn emit_recording_state_recovered(&self, payload: RecordingStateRecoveredPayload);
 }
 
 /// Trait for emitting transcription events
@@ -8,6 +8,10 @@ pub struct MockEventEmitter {
     pub stopped_events: Arc<Mutex<Vec<RecordingStoppedPayload>>>,
     pub cancelled_events: Arc<Mutex<Vec<RecordingCancelledPayload>>>,
     pub error_events: Arc<Mutex<Vec<RecordingErrorPayload>>>,
+    pub empty_events: Arc<Mutex<Vec<RecordingEmptyPayload>>>,
+    pub silence_countdown_events: Arc<Mutex<Vec<SilenceCountdownPayload>>>,
+    pub state_changed_events: Arc<Mutex<Vec<RecordingStateChangedPayload>>>,
+    pub state_recovered_events: Arc<Mutex<Vec<RecordingStateRecoveredPayload>>>,
     pub transcription_started_events: Arc<Mutex<Vec<TranscriptionStartedPayload>>>,
     pub transcription_completed_events: Arc<Mutex<Vec<TranscriptionCompletedPayload>>>,
     pub transcription_error_events: Arc<Mutex<Vec<TranscriptionErrorPayload>>>,
@@ -41,6 +45,22 @@ impl RecordingEventEmitter for MockEventEmitter {
     fn emit_recording_error(&self, payload: RecordingErrorPayload) {
         self.error_events.lock().unwrap().push(payload);
     }
+
+    fn emit_recording_empty(&self, payload: RecordingEmptyPayload) {
+        self.empty_events.lock().unwrap().push(payload);
+    }
+
+    fn emit_silence_countdown(&self, payload: SilenceCountdownPayload) {
+        self.silence_countdown_events.lock().unwrap().push(payload);
+    }
+
+    fn emit_recording_state_changed(&self, payload: RecordingStateChangedPayload) {
+        self.state_changed_events.lock().unwrap().push(payload);
+    }
+
+    fn emit_recording_state_recovered(&self, payload: RecordingStateRecoveredPayload) {
+        self.state_recovered_events.lock().unwrap().push(payload);
+    }
 }
 
 impl TranscriptionEventEmitter for MockEventEmitter {
@@ -129,6 +149,38 @@ fn test_serde_camel_case_rename() {
     assert!(!json.contains("file_name"));
 }
 
+#[test]
+fn test_all_event_names_are_unique() {
+    let names = all_event_names();
+    let unique: std::collections::HashSet<_> = names.iter().map(|info| info.name).collect();
+    assert_eq!(unique.len(), names.len());
+}
+
+#[test]
+fn test_all_event_names_covers_each_category() {
+    let names = all_event_names();
+    let has = |category: &str, name: &str| {
+        names
+            .iter()
+            .any(|info| info.category == category && info.name == name)
+    };
+    assert!(has("recording", event_names::RECORDING_STARTED));
+    assert!(has("recording", event_names::AUDIO_LEVEL));
+    assert!(has("recording", event_names::RECORDING_STATE_CHANGED));
+    assert!(has("recording", event_names::RETRANSCRIBE_PROGRESS));
+    assert!(has("recording", event_names::ACCESSIBILITY_PERMISSION_REQUIRED));
+    assert!(has("recording", event_names::PASTE_PAUSED));
+    assert!(has("recording", event_names::RECORDING_STATE_RECOVERED));
+    assert!(has("command", command_events::COMMAND_AMBIGUOUS));
+    assert!(has("hotkey", hotkey_events::KEY_BLOCKING_UNAVAILABLE));
+    assert!(has("dictionary", dictionary_events::DICTIONARY_UPDATED));
+    assert!(has("window_context", window_context_events::ACTIVE_WINDOW_CHANGED));
+    assert!(has("model", model_events::MODEL_RELOAD_FAILED));
+    assert!(has("settings", settings_events::SETTINGS_CHANGED));
+    assert!(has("database", "recordings_updated"));
+    assert!(has("database", "recordings_deleted"));
+}
+
 // MockEmitter tests - verify the mock infrastructure works correctly
 #[test]
 fn test_mock_emitter_records_recording_events() {
@@ -143,6 +195,8 @@ fn test_mock_emitter_records_recording_events() {
             file_path: "/tmp/test.wav".to_string(),
             sample_count: 48000,
             stop_reason: None,
+            native_sample_rate: None,
+            trigger_source: None,
         },
     });
     emitter.emit_recording_error(RecordingErrorPayload {
@@ -164,6 +218,7 @@ fn test_mock_emitter_records_transcription_events() {
     emitter.emit_transcription_completed(TranscriptionCompletedPayload {
         text: "Hello".to_string(),
         duration_ms: 100,
+        language: None,
     });
     emitter.emit_transcription_error(TranscriptionErrorPayload {
         error: "Test error".to_string(),
@@ -208,6 +263,7 @@ fn test_mock_emitter_records_command_events() {
     emitter.emit_command_ambiguous(CommandAmbiguousPayload {
         transcription: "open".to_string(),
         candidates: vec![],
+        truncated: false,
     });
 
     assert_eq!(emitter.command_matched_events.lock().unwrap().len(), 1);
@@ -430,3 +430,53 @@ fn test_key_blocking_unavailable_event_emitted_on_registration_failure() {
     );
     assert_eq!(emitter.started_count(), 1);
 }
+
+#[test]
+fn test_custom_cancel_shortcut_registered_instead_of_escape() {
+    ensure_test_model_files();
+
+    let emitter = MockEmitter::new();
+    let backend = Arc::new(MockShortcutBackend::new());
+    let callback_count = Arc::new(Mutex::new(0));
+    let callback_count_clone = callback_count.clone();
+
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0)
+        .with_shortcut_backend(backend.clone())
+        .with_escape_callback(Arc::new(move || {
+            *callback_count_clone.lock().unwrap() += 1;
+        }))
+        .with_cancel_shortcut("CmdOrCtrl+Period".to_string());
+    let state = Mutex::new(RecordingManager::new());
+
+    integration.handle_toggle(&state);
+    assert!(backend.is_registered("CmdOrCtrl+Period"));
+    assert!(!backend.is_registered("Escape"));
+
+    // Double-tap logic still applies to the custom shortcut
+    backend.simulate_press("CmdOrCtrl+Period");
+    backend.simulate_press("CmdOrCtrl+Period");
+    assert_eq!(*callback_count.lock().unwrap(), 1);
+}
+
+#[test]
+fn test_cancel_shortcut_change_during_recording_unregisters_original() {
+    ensure_test_model_files();
+
+    let emitter = MockEmitter::new();
+    let backend = Arc::new(MockShortcutBackend::new());
+
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0)
+        .with_shortcut_backend(backend.clone())
+        .with_escape_callback(Arc::new(|| {}));
+    let state = Mutex::new(RecordingManager::new());
+
+    integration.handle_toggle(&state);
+    assert!(backend.is_registered("Escape"));
+
+    integration.set_cancel_shortcut("F13".to_string());
+    integration.handle_toggle(&state);
+
+    assert!(!backend.is_registered("Escape"));
+    assert!(!backend.is_registered("F13"));
+    assert_eq!(integration.cancel_shortcut(), "F13");
+}
@@ -1,13 +1,15 @@
 //! Cancel recording handler for HotkeyIntegration.
 //!
-//! Handles the cancellation of recording via double-tap Escape key.
+//! Handles the cancellation of recording via double-tap Escape key, and of the
+//! transcription that follows once recording has stopped.
 
 use crate::events::{
     current_timestamp, RecordingCancelledPayload, RecordingErrorPayload, RecordingEventEmitter,
 };
 #[cfg(target_os = "macos")]
 use crate::keyboard_capture::cgeventtap::set_consume_escape;
-use crate::recording::{RecordingManager, RecordingState};
+use crate::recording::{lock_recording_state, RecordingManager, RecordingState};
+use crate::transcription::cancel::transcriptions;
 use std::sync::Mutex;
 
 use super::HotkeyIntegration;
@@ -33,10 +35,10 @@ where
     /// * `false` if not in recording state or an error occurred
     pub fn cancel_recording(&mut self, state: &Mutex<RecordingManager>, reason: &str) -> bool {
         // Check current state - can only cancel from Recording state
-        let current_state = match state.lock() {
-            Ok(guard) => guard.get_state(),
-            Err(e) => {
-                crate::error!("Failed to acquire lock for cancel: {}", e);
+        let current_state = match lock_recording_state(state) {
+            Some(guard) => guard.get_state(),
+            None => {
+                crate::error!("Failed to acquire lock for cancel");
                 self.recording_emitter
                     .emit_recording_error(RecordingErrorPayload {
                         message: "Internal error: state lock poisoned".to_string(),
@@ -76,10 +78,10 @@ where
 
         // 5. Abort recording - this clears the buffer and transitions directly to Idle
         //    (bypassing Processing state, so no transcription will be triggered)
-        let abort_result = match state.lock() {
-            Ok(mut guard) => guard.abort_recording(RecordingState::Idle),
-            Err(e) => {
-                crate::error!("Failed to acquire lock for abort: {}", e);
+        let abort_result = match lock_recording_state(state) {
+            Some(mut guard) => guard.abort_recording(RecordingState::Idle),
+            None => {
+                crate::error!("Failed to acquire lock for abort");
                 self.recording_emitter
                     .emit_recording_error(RecordingErrorPayload {
                         message: "Internal error: state lock poisoned".to_string(),
@@ -110,4 +112,25 @@ where
             }
         }
     }
+
+    /// Cancel in-flight transcriptions
+    ///
+    /// Called when the cancel shortcut is double-tapped after recording has
+    /// stopped. The running transcription finishes in the background but its
+    /// result is discarded. Also unregisters the cancel listener, which was only
+    /// kept registered for the transcription phase.
+    ///
+    /// # Returns
+    /// * `true` if a transcription was cancelled
+    /// * `false` if no transcription was in flight
+    pub fn cancel_transcription(&mut self, reason: &str) -> bool {
+        if !transcriptions().cancel() {
+            crate::debug!("Cancel ignored - no transcription in flight");
+            return false;
+        }
+
+        crate::info!("Cancelling transcription (reason: {})", reason);
+        self.unregister_escape_listener();
+        true
+    }
 }
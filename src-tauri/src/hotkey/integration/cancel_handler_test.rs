@@ -216,3 +216,14 @@ fn test_cancel_recording_can_restart_after_cancel() {
     );
     assert_eq!(emitter.started_count(), 2);
 }
+
+#[test]
+fn test_cancel_transcription_ignored_when_nothing_in_flight() {
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+
+    let cancelled = integration.cancel_transcription("double-tap-escape");
+
+    assert!(!cancelled, "Cancel should be ignored with no transcription running");
+    assert_eq!(emitter.cancelled_count(), 0);
+}
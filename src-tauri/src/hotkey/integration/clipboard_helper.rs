@@ -1,7 +1,9 @@
 //! Clipboard and paste simulation helpers.
 
+use crate::transcription::clipboard::app_clipboard;
+use crate::transcription::deliver_output;
+use crate::transcription::transforms::TextTransforms;
 use tauri::AppHandle;
-use tauri_plugin_clipboard_manager::ClipboardExt;
 
 /// Simulate Cmd+V paste keystroke on macOS using CoreGraphics
 #[cfg(target_os = "macos")]
@@ -24,8 +26,15 @@ pub fn simulate_paste() -> Result<(), String> {
 }
 
 /// Copy text to clipboard and auto-paste
+///
+/// Delivers through the same output path and clipboard writer as
+/// `RecordingTranscriptionService`: in clipboard-append output mode the text
+/// is appended to the clipboard buffer instead and no paste is simulated, the
+/// paste only happens when the paste sink is enabled, and the clipboard, file
+/// and HTTP sinks are applied too. The global timestamp prefix, if
+/// configured, is added to the text first.
 #[cfg_attr(coverage_nightly, coverage(off))]
-pub fn copy_and_paste(app_handle: &Option<AppHandle>, text: &str) {
+pub async fn copy_and_paste(app_handle: &Option<AppHandle>, text: &str) {
     // Safety check: don't paste during shutdown
     if crate::shutdown::is_shutting_down() {
         crate::debug!("Skipping copy_and_paste - app is shutting down");
@@ -33,16 +42,9 @@ pub fn copy_and_paste(app_handle: &Option<AppHandle>, text: &str) {
     }
 
     if let Some(ref handle) = app_handle {
-        if let Err(e) = handle.clipboard().write_text(text) {
-            crate::warn!("Failed to copy to clipboard: {}", e);
-        } else {
-            crate::debug!("Transcribed text copied to clipboard");
-            if let Err(e) = simulate_paste() {
-                crate::warn!("Failed to auto-paste: {}", e);
-            } else {
-                crate::debug!("Auto-pasted transcribed text");
-            }
-        }
+        let text =
+            TextTransforms::from_settings(handle).prefix_timestamp(text, &chrono::Local::now());
+        deliver_output(handle, app_clipboard(handle), text, false).await;
     } else {
         crate::warn!("Clipboard unavailable: no app handle configured");
     }
@@ -5,7 +5,7 @@
 
 use crate::events::{CommandEventEmitter, TranscriptionEventEmitter};
 use crate::hotkey::double_tap::DEFAULT_DOUBLE_TAP_WINDOW_MS;
-use crate::hotkey::{NullShortcutBackend, ShortcutBackend};
+use crate::hotkey::{NullShortcutBackend, ShortcutBackend, ESCAPE_SHORTCUT};
 use crate::parakeet::SharedTranscriptionModel;
 use crate::recording::SilenceConfig;
 use crate::turso::TursoClient;
@@ -22,9 +22,23 @@ pub const MAX_CONCURRENT_TRANSCRIPTIONS: usize = 2;
 /// If transcription takes longer than this, it will be cancelled and an error emitted
 pub const DEFAULT_TRANSCRIPTION_TIMEOUT_SECS: u64 = 60;
 
+/// Default upper bound on how long the cancel listener stays registered after
+/// recording stops while waiting for transcription (a timed-out primary plus a
+/// fallback attempt can take up to twice the transcription timeout)
+pub const DEFAULT_CANCEL_GRACE_MS: u64 = 2 * DEFAULT_TRANSCRIPTION_TIMEOUT_SECS * 1000;
+
+/// Settings key for the cancel listener grace period in milliseconds
+pub const CANCEL_GRACE_MS_KEY: &str = "hotkey.cancelGraceMs";
+
+/// Settings key for the shortcut that cancels recording on double-tap
+pub const CANCEL_SHORTCUT_KEY: &str = "hotkey.cancelShortcut";
+
 /// Debounce duration for hotkey presses (200ms)
 pub const DEBOUNCE_DURATION_MS: u64 = 200;
 
+/// Settings key for the toggle debounce interval in milliseconds
+pub const TOGGLE_DEBOUNCE_MS_KEY: &str = "hotkey.toggleDebounceMs";
+
 /// Configuration for transcription capabilities
 ///
 /// Groups all fields needed for automatic transcription after recording stops.
@@ -87,6 +101,7 @@ pub struct VoiceCommandConfig<C: CommandEventEmitter> {
 /// Configuration for Escape key cancel functionality
 ///
 /// Groups fields for double-tap Escape key detection to cancel recording.
+/// The cancel shortcut defaults to Escape but can be any key combination.
 ///
 /// Note: Both `backend` and `callback` must be Some for escape handling to work.
 /// The Option wrappers on callback allow incremental builder pattern configuration.
@@ -98,6 +113,8 @@ pub struct EscapeKeyConfig {
     /// Time window for double-tap detection in milliseconds.
     /// See [`DEFAULT_DOUBLE_TAP_WINDOW_MS`](crate::hotkey::double_tap::DEFAULT_DOUBLE_TAP_WINDOW_MS) for the default value (300ms).
     pub double_tap_window_ms: u64,
+    /// Shortcut that cancels recording when double-tapped (default: Escape)
+    pub shortcut: String,
 }
 
 impl Default for EscapeKeyConfig {
@@ -106,6 +123,7 @@ impl Default for EscapeKeyConfig {
             backend: Arc::new(NullShortcutBackend),
             callback: None,
             double_tap_window_ms: DEFAULT_DOUBLE_TAP_WINDOW_MS,
+            shortcut: ESCAPE_SHORTCUT.to_string(),
         }
     }
 }
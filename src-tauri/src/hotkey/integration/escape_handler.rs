@@ -1,9 +1,11 @@
-//! Escape key listener registration and handling for HotkeyIntegration.
+//! Cancel shortcut listener registration and handling for HotkeyIntegration.
 //!
-//! Manages the double-tap Escape key detection for cancelling recordings.
+//! Manages double-tap detection of the cancel shortcut (Escape by default,
+//! configurable via `hotkey.cancelShortcut`) for cancelling recordings.
 
 use crate::events::{current_timestamp, hotkey_events, CommandEventEmitter, RecordingEventEmitter, TranscriptionEventEmitter};
 use crate::hotkey::double_tap::DoubleTapDetector;
+use crate::transcription::cancel::transcriptions;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 
@@ -15,20 +17,23 @@ where
     T: TranscriptionEventEmitter + 'static,
     C: CommandEventEmitter + 'static,
 {
-    /// Register the Escape key listener for cancel functionality
+    /// Register the cancel shortcut listener (Escape unless configured otherwise)
     ///
     /// Called when recording starts. Only registers if both shortcut_backend
     /// and escape_callback are configured. The listener is automatically
     /// unregistered when recording stops.
     ///
-    /// Uses double-tap detection: single Escape presses are ignored, only
-    /// double-taps within the configured time window trigger the cancel callback.
+    /// Uses double-tap detection: single presses are ignored, only double-taps
+    /// within the configured time window trigger the cancel callback.
     ///
     /// IMPORTANT: The actual registration is deferred to a spawned thread to avoid
     /// re-entrancy deadlock. When this function is called from within a global shortcut
     /// callback (e.g., the recording hotkey), calling backend.register() synchronously
     /// would deadlock because the shortcut manager's lock is already held.
     pub(crate) fn register_escape_listener(&mut self) {
+        // A new recording supersedes any deferred unregister still waiting on transcription
+        self.escape_session.fetch_add(1, Ordering::SeqCst);
+
         // Skip if already registered or not configured
         if self.escape_registered.load(Ordering::SeqCst) {
             crate::debug!("Escape listener already registered, skipping");
@@ -45,6 +50,7 @@ where
         };
 
         let backend = escape_config.backend.clone();
+        let shortcut = escape_config.shortcut.clone();
 
         let callback = match &escape_config.callback {
             Some(c) => c.clone(),
@@ -62,13 +68,14 @@ where
             escape_config.double_tap_window_ms,
         )));
         self.double_tap_detector = Some(detector.clone());
+        self.registered_cancel_shortcut = Some(shortcut.clone());
 
         // In tests, use synchronous registration (mock backends don't have deadlock issues)
         // In production, spawn registration on a separate thread to avoid re-entrancy deadlock
         #[cfg(test)]
         {
             match backend.register(
-                super::super::ESCAPE_SHORTCUT,
+                &shortcut,
                 Box::new(move || {
                     // Use try_lock to avoid blocking the CGEventTap callback
                     // If lock is contended, skip this escape tap rather than freezing keyboard
@@ -86,17 +93,19 @@ where
                 Ok(()) => {
                     self.escape_registered.store(true, Ordering::SeqCst);
                     crate::info!(
-                        "Escape key listener registered for recording cancel (double-tap required)"
+                        "Cancel listener registered on {} (double-tap required)",
+                        shortcut
                     );
                 }
                 Err(e) => {
-                    crate::warn!("Failed to register Escape key listener: {}", e);
+                    crate::warn!("Failed to register cancel shortcut listener: {}", e);
                     self.double_tap_detector = None;
+                    self.registered_cancel_shortcut = None;
                     // Emit notification that key blocking is unavailable
                     if let Some(ref emitter) = self.hotkey_emitter {
                         emitter.emit_key_blocking_unavailable(
                             hotkey_events::KeyBlockingUnavailablePayload {
-                                reason: format!("Failed to register Escape key listener: {}", e),
+                                reason: format!("Failed to register cancel shortcut listener: {}", e),
                                 timestamp: current_timestamp(),
                             },
                         );
@@ -120,7 +129,7 @@ where
                 std::thread::sleep(std::time::Duration::from_millis(10));
 
                 match backend.register(
-                    crate::hotkey::ESCAPE_SHORTCUT,
+                    &shortcut,
                     Box::new(move || {
                         // Use try_lock to avoid blocking the CGEventTap callback
                         // If lock is contended, skip this escape tap rather than freezing keyboard
@@ -139,18 +148,19 @@ where
                         // Only set escape_registered to true AFTER successful registration
                         escape_registered.store(true, Ordering::SeqCst);
                         crate::info!(
-                            "Escape key listener registered for recording cancel (double-tap required)"
+                            "Cancel listener registered on {} (double-tap required)",
+                            shortcut
                         );
                     }
                     Err(e) => {
-                        crate::warn!("Failed to register Escape key listener: {}", e);
+                        crate::warn!("Failed to register cancel shortcut listener: {}", e);
                         // escape_registered remains false, so unregister won't attempt cleanup
                         // Emit notification that key blocking is unavailable
                         if let Some(ref emitter) = hotkey_emitter {
                             emitter.emit_key_blocking_unavailable(
                                 hotkey_events::KeyBlockingUnavailablePayload {
                                     reason: format!(
-                                        "Failed to register Escape key listener: {}",
+                                        "Failed to register cancel shortcut listener: {}",
                                         e
                                     ),
                                     timestamp: current_timestamp(),
@@ -163,7 +173,66 @@ where
         }
     }
 
-    /// Unregister the Escape key listener
+    /// Unregister the cancel listener once transcription has finished
+    ///
+    /// Called when recording stops normally. While a transcription is in flight
+    /// the listener stays registered so a double-tap can cancel it; a background
+    /// thread unregisters it once transcription ends, or after `cancel_grace`
+    /// at the latest. If a new recording registers the listener in the meantime,
+    /// the deferred unregister is dropped. With nothing in flight this is the
+    /// same as `unregister_escape_listener`.
+    pub(crate) fn release_escape_listener(&mut self) {
+        if !transcriptions().in_flight() || !self.escape_registered.load(Ordering::SeqCst) {
+            self.unregister_escape_listener();
+            return;
+        }
+
+        // Start the cancel gesture fresh for the transcription phase
+        if let Some(ref detector) = self.double_tap_detector {
+            if let Ok(mut det) = detector.try_lock() {
+                det.reset();
+            }
+        }
+
+        let (backend, shortcut) = match &self.escape {
+            Some(c) => (
+                c.backend.clone(),
+                self.registered_cancel_shortcut
+                    .clone()
+                    .unwrap_or_else(|| c.shortcut.clone()),
+            ),
+            None => return,
+        };
+        let escape_registered = self.escape_registered.clone();
+        let escape_session = self.escape_session.clone();
+        let session = escape_session.load(Ordering::SeqCst);
+        let deadline = std::time::Instant::now() + self.cancel_grace;
+
+        crate::debug!("Keeping cancel listener registered until transcription finishes");
+        std::thread::spawn(move || {
+            while transcriptions().in_flight() && std::time::Instant::now() < deadline {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+
+            if escape_session.load(Ordering::SeqCst) != session {
+                crate::debug!("Cancel listener reused by a new recording, keeping it");
+                return;
+            }
+            if escape_registered
+                .compare_exchange(true, false, Ordering::SeqCst, Ordering::SeqCst)
+                .is_err()
+            {
+                return;
+            }
+
+            match backend.unregister(&shortcut) {
+                Ok(()) => crate::debug!("Cancel listener unregistered after transcription"),
+                Err(e) => crate::warn!("Failed to unregister cancel shortcut listener: {}", e),
+            }
+        });
+    }
+
+    /// Unregister the cancel shortcut listener
     ///
     /// Called when recording stops (either normally or via cancellation).
     /// Safe to call even if listener was never registered. Also resets the
@@ -183,13 +252,17 @@ where
             // The detector will be dropped anyway, so skipping reset is fine.
         }
         self.double_tap_detector = None;
+        let registered_shortcut = self.registered_cancel_shortcut.take();
 
         if !self.escape_registered.load(Ordering::SeqCst) {
             return;
         }
 
-        let backend = match &self.escape {
-            Some(c) => c.backend.clone(),
+        let (backend, shortcut) = match &self.escape {
+            Some(c) => (
+                c.backend.clone(),
+                registered_shortcut.unwrap_or_else(|| c.shortcut.clone()),
+            ),
             None => return,
         };
 
@@ -200,12 +273,12 @@ where
         // In production, spawn unregistration on a separate thread to avoid re-entrancy deadlock
         #[cfg(test)]
         {
-            match backend.unregister(super::super::ESCAPE_SHORTCUT) {
+            match backend.unregister(&shortcut) {
                 Ok(()) => {
-                    crate::debug!("Escape key listener unregistered");
+                    crate::debug!("Cancel shortcut listener unregistered");
                 }
                 Err(e) => {
-                    crate::warn!("Failed to unregister Escape key listener: {}", e);
+                    crate::warn!("Failed to unregister cancel shortcut listener: {}", e);
                 }
             }
         }
@@ -219,13 +292,13 @@ where
                 // Small delay to ensure the calling shortcut callback has completed
                 std::thread::sleep(std::time::Duration::from_millis(10));
 
-                match backend.unregister(crate::hotkey::ESCAPE_SHORTCUT) {
+                match backend.unregister(&shortcut) {
                     Ok(()) => {
-                        crate::debug!("Escape key listener unregistered");
+                        crate::debug!("Cancel shortcut listener unregistered");
                     }
                     Err(e) => {
                         // This can happen if registration failed or was never completed
-                        crate::warn!("Failed to unregister Escape key listener: {}", e);
+                        crate::warn!("Failed to unregister cancel shortcut listener: {}", e);
                     }
                 }
             });
@@ -11,7 +11,7 @@
 //! - `cancel_handler`: Handle recording cancellation via double-tap Escape
 //! - `transcription_runner`: Core transcription execution and voice command matching
 //! - `silence_handler`: Silence detection for auto-stop recordings
-//! - `escape_handler`: Escape key listener registration/unregistration
+//! - `escape_handler`: Cancel shortcut listener registration/unregistration
 //! - `clipboard_helper`: Clipboard and paste simulation utilities
 
 mod cancel_handler;
@@ -32,19 +32,22 @@ mod toggle_handler_test;
 
 pub use config::{
     EscapeKeyConfig, SilenceDetectionConfig, TranscriptionConfig, VoiceCommandConfig,
-    DEBOUNCE_DURATION_MS, DEFAULT_TRANSCRIPTION_TIMEOUT_SECS, MAX_CONCURRENT_TRANSCRIPTIONS,
+    CANCEL_GRACE_MS_KEY, CANCEL_SHORTCUT_KEY, DEBOUNCE_DURATION_MS, DEFAULT_CANCEL_GRACE_MS,
+    DEFAULT_TRANSCRIPTION_TIMEOUT_SECS, MAX_CONCURRENT_TRANSCRIPTIONS, TOGGLE_DEBOUNCE_MS_KEY,
 };
 
 use crate::audio::{AudioMonitorHandle, AudioThreadHandle};
 use crate::events::{CommandEventEmitter, HotkeyEventEmitter, RecordingEventEmitter, TranscriptionEventEmitter};
 use crate::hotkey::double_tap::{DoubleTapDetector, DEFAULT_DOUBLE_TAP_WINDOW_MS};
-use crate::hotkey::{RecordingMode, ShortcutBackend};
+use crate::hotkey::{RecordingMode, ShortcutBackend, ESCAPE_SHORTCUT};
 use crate::parakeet::SharedTranscriptionModel;
-use crate::recording::{RecordingDetectors, RecordingManager, SilenceConfig};
+use crate::recording::{
+    lock_recording_state, RecordingDetectors, RecordingManager, RecordingState, SilenceConfig,
+};
 use crate::turso::TursoClient;
 use crate::voice_commands::executor::ActionDispatcher;
 use crate::voice_commands::matcher::CommandMatcher;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tauri::AppHandle;
@@ -75,6 +78,8 @@ pub struct HotkeyIntegration<
     // === Recording Mode ===
     /// Current recording mode (Toggle or PushToTalk)
     recording_mode: RecordingMode,
+    /// Mode change requested mid-recording, applied once the recording ends
+    pending_recording_mode: Option<RecordingMode>,
 
     // === Recording (required) ===
     pub(crate) recording_emitter: R,
@@ -108,8 +113,14 @@ pub struct HotkeyIntegration<
     // === Escape Key Runtime State ===
     /// Whether Escape key is currently registered (to track cleanup)
     pub(crate) escape_registered: Arc<AtomicBool>,
+    /// Cancel shortcut passed to the backend at registration (unregistered on stop)
+    pub(crate) registered_cancel_shortcut: Option<String>,
     /// Double-tap detector for Escape key (created on recording start)
     pub(crate) double_tap_detector: DoubleTapDetectorState,
+    /// Bumped on every registration so a deferred unregister can tell it was superseded
+    pub(crate) escape_session: Arc<AtomicU64>,
+    /// Upper bound on keeping the cancel listener registered while transcription runs
+    pub(crate) cancel_grace: Duration,
 
     // === Hotkey Events ===
     /// Optional emitter for hotkey-related events (e.g., key blocking unavailable)
@@ -125,6 +136,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
             last_toggle_time: None,
             debounce_duration: Duration::from_millis(DEBOUNCE_DURATION_MS),
             recording_mode: RecordingMode::default(),
+            pending_recording_mode: None,
             recording_emitter,
             transcription: None,
             voice_commands: None,
@@ -138,7 +150,10 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
             recordings_dir: crate::paths::get_recordings_dir(None)
                 .unwrap_or_else(|_| std::path::PathBuf::from(".").join("heycat").join("recordings")),
             escape_registered: Arc::new(AtomicBool::new(false)),
+            registered_cancel_shortcut: None,
             double_tap_detector: None,
+            escape_session: Arc::new(AtomicU64::new(0)),
+            cancel_grace: Duration::from_millis(DEFAULT_CANCEL_GRACE_MS),
             hotkey_emitter: None,
         }
     }
@@ -151,9 +166,62 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
     /// Update the recording mode at runtime
     pub fn set_recording_mode(&mut self, mode: RecordingMode) {
         self.recording_mode = mode;
+        self.pending_recording_mode = None;
         crate::debug!("Recording mode updated to: {:?}", mode);
     }
 
+    /// Queue a mode change that arrived while a recording was in progress
+    ///
+    /// Switching mid-recording would strand the current recording (a PTT
+    /// release is ignored in Toggle mode), so the change waits until idle.
+    pub fn defer_recording_mode(&mut self, mode: RecordingMode) {
+        self.pending_recording_mode = Some(mode);
+        crate::debug!("Recording mode change to {:?} deferred until idle", mode);
+    }
+
+    /// Apply a deferred mode change once recording is back to Idle
+    pub fn apply_pending_recording_mode(&mut self, state: &Mutex<RecordingManager>) {
+        if self.pending_recording_mode.is_none() {
+            return;
+        }
+        let idle = match lock_recording_state(state) {
+            Some(manager) => manager.get_state() == RecordingState::Idle,
+            None => {
+                crate::warn!("Failed to lock recording state to apply pending recording mode");
+                return;
+            }
+        };
+        if let Some(mode) = self.pending_recording_mode.filter(|_| idle) {
+            self.set_recording_mode(mode);
+        }
+    }
+
+    /// Get the minimum interval between accepted toggle presses
+    #[cfg(test)]
+    pub fn debounce_duration(&self) -> Duration {
+        self.debounce_duration
+    }
+
+    /// Update the toggle debounce interval at runtime
+    pub fn set_debounce_duration(&mut self, duration: Duration) {
+        self.debounce_duration = duration;
+        crate::debug!("Toggle debounce updated to: {:?}", duration);
+    }
+
+    /// Update how long the cancel listener may stay registered for a running transcription
+    pub fn set_cancel_grace(&mut self, grace: Duration) {
+        self.cancel_grace = grace;
+        crate::debug!("Cancel grace period updated to: {:?}", grace);
+    }
+
+    /// Update the timeout for transcriptions started from now on
+    pub fn set_transcription_timeout(&mut self, timeout: Duration) {
+        if let Some(ref mut config) = self.transcription {
+            config.timeout = timeout;
+        }
+        crate::debug!("Transcription timeout updated to: {:?}", timeout);
+    }
+
     /// Add app handle for clipboard access (builder pattern)
     pub fn with_app_handle(mut self, handle: AppHandle) -> Self {
         self.app_handle = Some(handle);
@@ -278,7 +346,6 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
     }
 
     /// Set custom transcription timeout (builder pattern)
-    #[allow(dead_code)]
     pub fn with_transcription_timeout(mut self, timeout: Duration) -> Self {
         if let Some(ref mut config) = self.transcription {
             config.timeout = timeout;
@@ -330,6 +397,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
                 backend,
                 callback: None,
                 double_tap_window_ms: DEFAULT_DOUBLE_TAP_WINDOW_MS,
+                shortcut: ESCAPE_SHORTCUT.to_string(),
             });
         }
         self
@@ -345,6 +413,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
                 backend: Arc::new(crate::hotkey::NullShortcutBackend),
                 callback: Some(callback),
                 double_tap_window_ms: DEFAULT_DOUBLE_TAP_WINDOW_MS,
+                shortcut: ESCAPE_SHORTCUT.to_string(),
             });
         }
         self
@@ -360,11 +429,46 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
                 backend: Arc::new(crate::hotkey::NullShortcutBackend),
                 callback: None,
                 double_tap_window_ms: window_ms,
+                shortcut: ESCAPE_SHORTCUT.to_string(),
             });
         }
         self
     }
 
+    /// Set the shortcut that cancels recording on double-tap (builder pattern)
+    #[cfg(test)]
+    pub fn with_cancel_shortcut(mut self, shortcut: String) -> Self {
+        self.set_cancel_shortcut(shortcut);
+        self
+    }
+
+    /// Update the cancel shortcut at runtime
+    ///
+    /// Takes effect from the next recording; a listener registered for the
+    /// current recording is still removed using the shortcut it was registered with.
+    pub fn set_cancel_shortcut(&mut self, shortcut: String) {
+        crate::debug!("Cancel shortcut updated to: {}", shortcut);
+        if let Some(ref mut config) = self.escape {
+            config.shortcut = shortcut;
+        } else {
+            self.escape = Some(EscapeKeyConfig {
+                backend: Arc::new(crate::hotkey::NullShortcutBackend),
+                callback: None,
+                double_tap_window_ms: DEFAULT_DOUBLE_TAP_WINDOW_MS,
+                shortcut,
+            });
+        }
+    }
+
+    /// Get the configured cancel shortcut
+    #[cfg(test)]
+    pub fn cancel_shortcut(&self) -> &str {
+        self.escape
+            .as_ref()
+            .map(|c| c.shortcut.as_str())
+            .unwrap_or(ESCAPE_SHORTCUT)
+    }
+
     /// Set the hotkey event emitter (builder pattern)
     pub fn with_hotkey_emitter(mut self, emitter: Arc<dyn HotkeyEventEmitter>) -> Self {
         self.hotkey_emitter = Some(emitter);
@@ -396,6 +500,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
                 backend: Arc::new(crate::hotkey::NullShortcutBackend),
                 callback: Some(callback),
                 double_tap_window_ms: DEFAULT_DOUBLE_TAP_WINDOW_MS,
+                shortcut: ESCAPE_SHORTCUT.to_string(),
             });
         }
     }
@@ -407,6 +512,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
             last_toggle_time: None,
             debounce_duration: Duration::from_millis(debounce_ms),
             recording_mode: RecordingMode::default(),
+            pending_recording_mode: None,
             recording_emitter,
             transcription: None,
             voice_commands: None,
@@ -419,7 +525,10 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
             app_handle: None,
             recordings_dir: std::env::temp_dir().join("heycat-test-recordings"),
             escape_registered: Arc::new(AtomicBool::new(false)),
+            registered_cancel_shortcut: None,
             double_tap_detector: None,
+            escape_session: Arc::new(AtomicU64::new(0)),
+            cancel_grace: Duration::from_millis(DEFAULT_CANCEL_GRACE_MS),
             hotkey_emitter: None,
         }
     }
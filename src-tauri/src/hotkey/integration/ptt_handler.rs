@@ -3,15 +3,17 @@
 //! Handles the PTT recording mode where holding the hotkey records
 //! and releasing it stops recording.
 
-use crate::commands::logic::{start_recording_impl, stop_recording_impl};
+use crate::audio::diagnostics::warn_if_microphone_busy;
+use crate::commands::logic::{start_recording_impl, stop_recording_impl_with_writer};
 use crate::events::{
-    current_timestamp, RecordingErrorPayload, RecordingEventEmitter, RecordingStartedPayload,
-    RecordingStoppedPayload,
+    current_timestamp, RecordingEmptyPayload, RecordingErrorPayload, RecordingEventEmitter,
+    RecordingStartedPayload, RecordingStoppedPayload,
 };
 #[cfg(target_os = "macos")]
 use crate::keyboard_capture::cgeventtap::set_consume_escape;
 use crate::model::{check_model_exists_for_type, ModelType};
-use crate::recording::{RecordingManager, RecordingState};
+use crate::recording::{lock_recording_state, RecordingManager, RecordingState, TriggerSource};
+use crate::sound::{play_cue, play_start_cue, SoundCue};
 use std::sync::Mutex;
 
 use super::HotkeyIntegration;
@@ -31,10 +33,10 @@ where
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub fn handle_hotkey_press(&mut self, state: &Mutex<RecordingManager>) -> bool {
         // PTT mode skips debounce for immediate response
-        let current_state = match state.lock() {
-            Ok(guard) => guard.get_state(),
-            Err(e) => {
-                crate::error!("Failed to acquire lock: {}", e);
+        let current_state = match lock_recording_state(state) {
+            Some(guard) => guard.get_state(),
+            None => {
+                crate::error!("Failed to acquire lock");
                 self.recording_emitter
                     .emit_recording_error(RecordingErrorPayload {
                         message: "Internal error: state lock poisoned".to_string(),
@@ -57,11 +59,21 @@ where
                     });
 
                 let device_name = self.get_selected_audio_device();
+
+                // Play the start cue; input is muted while it plays so it isn't recorded
+                if model_available {
+                    if let Some(ref app_handle) = self.app_handle {
+                        warn_if_microphone_busy(app_handle, device_name.as_deref());
+                        play_start_cue(app_handle);
+                    }
+                }
+
                 match start_recording_impl(
                     state,
                     self.audio_thread.as_deref(),
                     model_available,
                     device_name,
+                    TriggerSource::Hotkey,
                 ) {
                     Ok(()) => {
                         self.recording_emitter
@@ -111,10 +123,10 @@ where
     /// Returns true if recording was stopped, false otherwise.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub fn handle_hotkey_release(&mut self, state: &Mutex<RecordingManager>) -> bool {
-        let current_state = match state.lock() {
-            Ok(guard) => guard.get_state(),
-            Err(e) => {
-                crate::error!("Failed to acquire lock: {}", e);
+        let current_state = match lock_recording_state(state) {
+            Some(guard) => guard.get_state(),
+            None => {
+                crate::error!("Failed to acquire lock");
                 self.recording_emitter
                     .emit_recording_error(RecordingErrorPayload {
                         message: "Internal error: state lock poisoned".to_string(),
@@ -129,34 +141,46 @@ where
             RecordingState::Recording => {
                 crate::info!("PTT: Stopping recording on key release...");
 
-                // Unregister Escape key listener
-                self.unregister_escape_listener();
+                // The cancel listener stays registered until transcription has
+                // been spawned (below), so the transcription phase can be cancelled
 
                 // Disable Escape key consumption
                 #[cfg(target_os = "macos")]
                 set_consume_escape(false);
 
                 // Stop recording and process
-                match stop_recording_impl(
+                let writer = crate::storage::recording_file_writer(
+                    self.app_handle.as_ref(),
+                    self.recordings_dir.clone(),
+                );
+                match stop_recording_impl_with_writer(
                     state,
                     self.audio_thread.as_deref(),
                     false,
-                    self.recordings_dir.clone(),
-                ) {
+                    &writer,
+                )
+                .map(|result| result.metadata)
+                {
                     Ok(metadata) => {
                         crate::info!(
                             "PTT: Recording stopped: {} samples, {:.2}s duration",
                             metadata.sample_count,
                             metadata.duration_secs
                         );
+                        if let Some(ref app_handle) = self.app_handle {
+                            play_cue(app_handle, SoundCue::RecordingStop);
+                        }
 
                         // Store recording metadata in Turso using storage abstraction
-                        if let Some(ref app_handle) = self.app_handle {
-                            if !metadata.file_path.is_empty() {
-                                crate::storage::store_recording(app_handle, &metadata, "PTT");
-                            } else {
-                                crate::warn!("Recording completed with empty file_path - storage skipped (PTT mode)");
-                            }
+                        let is_empty = metadata.file_path.is_empty();
+                        if is_empty {
+                            crate::warn!("Recording produced no audio - storage and transcription skipped (PTT mode)");
+                            self.recording_emitter.emit_recording_empty(RecordingEmptyPayload {
+                                source: "PTT".to_string(),
+                                timestamp: current_timestamp(),
+                            });
+                        } else if let Some(ref app_handle) = self.app_handle {
+                            crate::storage::store_recording(app_handle, &metadata, "PTT");
                         }
 
                         let file_path_for_transcription = metadata.file_path.clone();
@@ -165,11 +189,15 @@ where
                         crate::debug!("PTT: Emitted recording_stopped event");
 
                         // Auto-transcribe
-                        self.spawn_transcription(file_path_for_transcription);
+                        if !is_empty {
+                            self.spawn_transcription(file_path_for_transcription);
+                        }
+                        self.release_escape_listener();
 
                         true
                     }
                     Err(e) => {
+                        self.unregister_escape_listener();
                         crate::error!("PTT: Failed to stop recording: {}", e);
                         self.recording_emitter
                             .emit_recording_error(RecordingErrorPayload { message: e });
@@ -83,6 +83,8 @@ fn test_ptt_release_stops_recording() {
     assert!(stopped);
     assert_eq!(state.lock().unwrap().get_state(), RecordingState::Idle);
     assert_eq!(emitter.stopped_count(), 1);
+    // No audio thread configured - the empty recording is reported, not persisted
+    assert_eq!(emitter.empty_count(), 1);
 }
 
 #[test]
@@ -117,3 +119,30 @@ fn test_ptt_release_ignored_when_not_recording() {
     assert_eq!(state.lock().unwrap().get_state(), RecordingState::Idle);
     assert_eq!(emitter.stopped_count(), 0);
 }
+
+#[test]
+fn test_deferred_recording_mode_waits_for_idle() {
+    ensure_test_model_files();
+
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter, 0);
+    integration.set_recording_mode(crate::hotkey::RecordingMode::PushToTalk);
+    let state = Mutex::new(RecordingManager::new());
+
+    assert!(integration.handle_hotkey_press(&state));
+    integration.defer_recording_mode(crate::hotkey::RecordingMode::Toggle);
+
+    // Still recording, so the change must not strand the current recording
+    integration.apply_pending_recording_mode(&state);
+    assert_eq!(
+        integration.recording_mode(),
+        crate::hotkey::RecordingMode::PushToTalk
+    );
+
+    assert!(integration.handle_hotkey_release(&state));
+    integration.apply_pending_recording_mode(&state);
+    assert_eq!(
+        integration.recording_mode(),
+        crate::hotkey::RecordingMode::Toggle
+    );
+}
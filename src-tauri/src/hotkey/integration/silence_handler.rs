@@ -6,7 +6,14 @@ use crate::events::{
     CommandEventEmitter, RecordingEventEmitter, TranscriptionCompletedPayload,
     TranscriptionEventEmitter,
 };
-use crate::recording::RecordingManager;
+use crate::recording::{lock_recording_state, RecordingManager};
+use crate::sound::{play_cue, SoundCue};
+use crate::transcription::cancel::transcriptions;
+use crate::transcription::fallback::resolve_fallback;
+use crate::transcription::queue::BusyPolicy;
+use crate::transcription::repetition::collapse_hallucinated_repeats;
+use crate::transcription::language::guess_transcription_language;
+use crate::transcription::skip_transcription_without_model;
 use std::sync::{Arc, Mutex};
 
 use super::clipboard_helper::copy_and_paste;
@@ -67,9 +74,9 @@ where
 
         // Get the audio buffer from recording state
         let buffer = {
-            let manager = match recording_state.lock() {
-                Ok(m) => m,
-                Err(_) => {
+            let manager = match lock_recording_state(recording_state) {
+                Some(m) => m,
+                None => {
                     crate::warn!("Failed to lock recording state for silence detection");
                     return;
                 }
@@ -98,6 +105,11 @@ where
         let transcription_callback: Option<Box<dyn Fn(String) + Send + 'static>> =
             if shared_model.is_some() && transcription_emitter_for_callback.is_some() {
                 Some(Box::new(move |file_path: String| {
+                    // Auto-stop has already closed the microphone
+                    if let Some(ref app_handle) = app_handle_for_callback {
+                        play_cue(app_handle, SoundCue::RecordingStop);
+                    }
+
                     // Extract required components from Option wrappers
                     let shared_model = match &shared_model {
                         Some(m) => m.clone(),
@@ -109,7 +121,15 @@ where
                     };
 
                     if !shared_model.is_loaded() {
-                        crate::info!("Transcription skipped: model not loaded");
+                        match &app_handle_for_callback {
+                            Some(handle) => skip_transcription_without_model(handle, &file_path),
+                            None => crate::info!("Transcription skipped: model not loaded"),
+                        }
+                        if let Some(ref state) = recording_state_for_callback {
+                            if let Some(mut manager) = lock_recording_state(state) {
+                                manager.clear_last_recording();
+                            }
+                        }
                         return;
                     }
 
@@ -123,6 +143,11 @@ where
                         file_path
                     );
 
+                    let fallback = app_handle.as_ref().and_then(resolve_fallback);
+                    let busy_policy =
+                        app_handle.as_ref().map(BusyPolicy::load).unwrap_or_default();
+                    let ticket = transcriptions().begin();
+
                     tauri::async_runtime::spawn(async move {
                         // Execute transcription using shared helper
                         let result = execute_transcription_task(
@@ -132,6 +157,9 @@ where
                             transcription_emitter.clone(),
                             timeout_duration,
                             recording_state.clone(),
+                            fallback,
+                            ticket,
+                            busy_policy,
                         )
                         .await;
 
@@ -141,15 +169,21 @@ where
                             Err(()) => return, // Error already emitted and buffer cleared by helper
                         };
 
+                        let text = match app_handle {
+                            Some(ref handle) => collapse_hallucinated_repeats(handle, text),
+                            None => text,
+                        };
+
                         // Silence detection auto-stop always goes to clipboard
                         // Voice command matching is only supported for manual hotkey recordings
                         // (via spawn_transcription). This is by design - auto-stop recordings
                         // are intended for quick dictation, not command execution.
-                        copy_and_paste(&app_handle, &text);
+                        copy_and_paste(&app_handle, &text).await;
 
                         // Emit completed
                         transcription_emitter
                             .emit_transcription_completed(TranscriptionCompletedPayload {
+                                language: guess_transcription_language(&text),
                                 text,
                                 duration_ms,
                             });
@@ -157,7 +191,7 @@ where
                         // Reset model and clear buffer
                         let _ = shared_model.reset_to_idle();
                         if let Some(ref state) = recording_state {
-                            if let Ok(mut manager) = state.lock() {
+                            if let Some(mut manager) = lock_recording_state(state) {
                                 manager.clear_last_recording();
                                 crate::debug!("Cleared recording buffer");
                             }
@@ -3,15 +3,18 @@
 //! Handles the toggle recording mode where pressing the hotkey toggles between
 //! recording and idle states.
 
-use crate::commands::logic::{start_recording_impl, stop_recording_impl};
+use crate::audio::diagnostics::warn_if_microphone_busy;
+use crate::commands::logic::{start_recording_impl, stop_recording_impl_with_writer};
 use crate::events::{
-    current_timestamp, RecordingErrorPayload, RecordingEventEmitter, RecordingStartedPayload,
-    RecordingStoppedPayload,
+    current_timestamp, RecordingEmptyPayload, RecordingErrorPayload, RecordingEventEmitter,
+    RecordingStartedPayload, RecordingStoppedPayload,
 };
 #[cfg(target_os = "macos")]
 use crate::keyboard_capture::cgeventtap::set_consume_escape;
 use crate::model::{check_model_exists_for_type, ModelType};
-use crate::recording::{RecordingManager, RecordingState};
+use crate::recording::{lock_recording_state, RecordingManager, RecordingState, TriggerSource};
+use crate::sound::{play_cue, play_start_cue, SoundCue};
+use crate::transcription::cancel::transcriptions;
 use std::sync::Mutex;
 use std::time::Instant;
 
@@ -39,8 +42,13 @@ where
 
         // Check debounce
         if let Some(last) = self.last_toggle_time {
-            if now.duration_since(last) < self.debounce_duration {
-                crate::trace!("Toggle debounced");
+            let elapsed = now.duration_since(last);
+            if elapsed < self.debounce_duration {
+                crate::debug!(
+                    "Toggle ignored - {:?} since last toggle (debounce {:?})",
+                    elapsed,
+                    self.debounce_duration
+                );
                 return false;
             }
         }
@@ -48,10 +56,10 @@ where
         self.last_toggle_time = Some(now);
 
         // Check current state to decide action
-        let current_state = match state.lock() {
-            Ok(guard) => guard.get_state(),
-            Err(e) => {
-                crate::error!("Failed to acquire lock: {}", e);
+        let current_state = match lock_recording_state(state) {
+            Some(guard) => guard.get_state(),
+            None => {
+                crate::error!("Failed to acquire lock");
                 self.recording_emitter
                     .emit_recording_error(RecordingErrorPayload {
                         message: "Internal error: state lock poisoned".to_string(),
@@ -63,7 +71,22 @@ where
         crate::debug!("Toggle received, current state: {:?}", current_state);
 
         match current_state {
-            RecordingState::Idle => self.start_recording_toggle(state),
+            RecordingState::Idle => {
+                // Wait for the previous transcription if queueing is enabled
+                let transcribing = transcriptions().in_flight();
+                let queued = transcribing
+                    && lock_recording_state(state)
+                        .map(|mut m| m.queue_start(transcribing))
+                        .unwrap_or(false);
+                if !queued {
+                    return self.start_recording_toggle(state);
+                }
+                // The transcription may have finished before the start was queued
+                if !transcriptions().in_flight() {
+                    self.start_queued_recording(state);
+                }
+                true
+            }
             RecordingState::Recording => self.stop_recording_toggle(state),
             RecordingState::Processing => {
                 // In Processing state - ignore toggle (busy)
@@ -73,6 +96,22 @@ where
         }
     }
 
+    /// Start a recording that was queued while a transcription was running
+    ///
+    /// Called once the last in-flight transcription finishes. Bypasses the
+    /// toggle debounce since the press was already accepted.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn start_queued_recording(&mut self, state: &Mutex<RecordingManager>) -> bool {
+        let queued = lock_recording_state(state)
+            .map(|mut m| m.get_state() == RecordingState::Idle && m.take_queued_start())
+            .unwrap_or(false);
+        if !queued {
+            return false;
+        }
+        crate::info!("Transcription complete, starting queued recording");
+        self.start_recording_toggle(state)
+    }
+
     /// Start recording in toggle mode
     fn start_recording_toggle(&mut self, state: &Mutex<RecordingManager>) -> bool {
         crate::info!("Starting recording from Idle state...");
@@ -85,8 +124,22 @@ where
 
         // Read selected device from persistent settings store
         let device_name = self.get_selected_audio_device();
-        match start_recording_impl(state, self.audio_thread.as_deref(), model_available, device_name)
-        {
+
+        // Play the start cue; input is muted while it plays so it isn't recorded
+        if model_available {
+            if let Some(ref app_handle) = self.app_handle {
+                warn_if_microphone_busy(app_handle, device_name.as_deref());
+                play_start_cue(app_handle);
+            }
+        }
+
+        match start_recording_impl(
+            state,
+            self.audio_thread.as_deref(),
+            model_available,
+            device_name,
+            TriggerSource::Hotkey,
+        ) {
             Ok(()) => {
                 self.recording_emitter
                     .emit_recording_started(RecordingStartedPayload {
@@ -119,8 +172,8 @@ where
     fn stop_recording_toggle(&mut self, state: &Mutex<RecordingManager>) -> bool {
         crate::info!("Stopping recording (manual stop via hotkey)...");
 
-        // Unregister Escape key listener first
-        self.unregister_escape_listener();
+        // The cancel listener stays registered until transcription has been
+        // spawned (below), so the transcription phase can be cancelled too
 
         // Disable Escape key consumption since recording is stopping
         #[cfg(target_os = "macos")]
@@ -131,26 +184,38 @@ where
         self.stop_silence_detection();
 
         // Use unified command implementation (always return to Idle)
-        match stop_recording_impl(
+        let writer = crate::storage::recording_file_writer(
+            self.app_handle.as_ref(),
+            self.recordings_dir.clone(),
+        );
+        match stop_recording_impl_with_writer(
             state,
             self.audio_thread.as_deref(),
             false,
-            self.recordings_dir.clone(),
-        ) {
+            &writer,
+        )
+        .map(|result| result.metadata)
+        {
             Ok(metadata) => {
                 crate::info!(
                     "Recording stopped: {} samples, {:.2}s duration",
                     metadata.sample_count,
                     metadata.duration_secs
                 );
+                if let Some(ref app_handle) = self.app_handle {
+                    play_cue(app_handle, SoundCue::RecordingStop);
+                }
 
                 // Store recording metadata in Turso using storage abstraction
-                if let Some(ref app_handle) = self.app_handle {
-                    if !metadata.file_path.is_empty() {
-                        crate::storage::store_recording(app_handle, &metadata, "hotkey");
-                    } else {
-                        crate::warn!("Recording completed with empty file_path - storage skipped (toggle mode)");
-                    }
+                let is_empty = metadata.file_path.is_empty();
+                if is_empty {
+                    crate::warn!("Recording produced no audio - storage and transcription skipped (toggle mode)");
+                    self.recording_emitter.emit_recording_empty(RecordingEmptyPayload {
+                        source: "hotkey".to_string(),
+                        timestamp: current_timestamp(),
+                    });
+                } else if let Some(ref app_handle) = self.app_handle {
+                    crate::storage::store_recording(app_handle, &metadata, "hotkey");
                 }
 
                 // Clone file_path before metadata is moved
@@ -160,11 +225,15 @@ where
                 crate::debug!("Emitted recording_stopped event");
 
                 // Auto-transcribe if transcription manager is configured
-                self.spawn_transcription(file_path_for_transcription);
+                if !is_empty {
+                    self.spawn_transcription(file_path_for_transcription);
+                }
+                self.release_escape_listener();
 
                 true
             }
             Err(e) => {
+                self.unregister_escape_listener();
                 crate::error!("Failed to stop recording: {}", e);
                 self.recording_emitter
                     .emit_recording_error(RecordingErrorPayload { message: e });
@@ -51,6 +51,22 @@ fn test_toggle_from_recording_stops() {
     assert_eq!(emitter.stopped_count(), 1);
 }
 
+#[test]
+fn test_toggle_stop_without_audio_emits_recording_empty() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+    let state = Mutex::new(RecordingManager::new());
+
+    // No audio thread is configured, so no samples are captured
+    integration.handle_toggle(&state);
+    integration.handle_toggle(&state);
+
+    assert_eq!(emitter.empty_count(), 1);
+    assert_eq!(emitter.empty.lock().unwrap()[0].source, "hotkey");
+    assert_eq!(emitter.stopped_count(), 1);
+}
+
 #[test]
 fn test_rapid_toggle_debounced() {
     ensure_test_model_files();
@@ -157,6 +173,25 @@ fn test_is_debouncing() {
     );
 }
 
+#[test]
+fn test_set_debounce_duration_applies_to_next_toggle() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+    let state = Mutex::new(RecordingManager::new());
+
+    integration.set_debounce_duration(Duration::from_millis(100));
+    assert_eq!(integration.debounce_duration(), Duration::from_millis(100));
+
+    assert!(integration.handle_toggle(&state));
+    assert!(
+        !integration.handle_toggle(&state),
+        "Toggle within new debounce window should be ignored"
+    );
+    assert_eq!(emitter.started_count(), 1);
+    assert_eq!(emitter.stopped_count(), 0);
+}
+
 #[test]
 fn test_multiple_rapid_toggles_only_first_accepted() {
     ensure_test_model_files();
@@ -8,8 +8,21 @@ use crate::events::{
     TranscriptionCompletedPayload, TranscriptionErrorPayload, TranscriptionEventEmitter,
     TranscriptionStartedPayload,
 };
-use crate::parakeet::{SharedTranscriptionModel, TranscriptionService};
-use crate::recording::RecordingManager;
+use crate::parakeet::SharedTranscriptionModel;
+use crate::recording::{lock_recording_state, RecordingManager};
+use crate::transcription::cancel::{transcriptions, TranscriptionTicket, TRANSCRIPTION_CANCELLED_MESSAGE};
+use crate::transcription::fallback::{resolve_fallback, transcribe_with_fallback, FallbackTarget};
+use crate::transcription::language::guess_transcription_language;
+use crate::transcription::match_log::record_match_outcome;
+use crate::transcription::queue::{wait_queue, BusyPolicy, TRANSCRIPTION_BUSY_MESSAGE};
+use crate::transcription::repetition::collapse_hallucinated_repeats;
+use crate::transcription::transforms::TextTransforms;
+use crate::transcription::verbose_logging::loggable_text;
+use crate::transcription::{
+    report_command_failures, skip_transcription_without_model, CommandOutcome,
+};
+use crate::voice_commands::confirmation::request_confirmation;
+use crate::voice_commands::disambiguation::{schedule_auto_resolve, AmbiguityMode};
 use crate::voice_commands::matcher::MatchResult;
 use crate::voice_commands::registry::CommandDefinition;
 use std::sync::{Arc, Mutex};
@@ -26,6 +39,15 @@ use super::HotkeyIntegration;
 /// - `spawn_transcription` (hotkey recordings with voice command matching)
 /// - `start_silence_detection` transcription callback (silence-triggered auto-stop)
 ///
+/// If the primary model errors or times out and a `fallback` is configured,
+/// the transcription is retried once with the fallback model.
+///
+/// `ticket` tracks the transcription for the cancel shortcut; a cancelled
+/// transcription is reported as an error and its text discarded.
+///
+/// When every transcription slot is busy, `busy_policy` decides whether the
+/// recording is dropped or waits in the bounded queue for a slot.
+///
 /// Returns `Ok(TranscriptionResult)` on success, `Err(())` on failure (errors already emitted).
 #[cfg_attr(coverage_nightly, coverage(off))]
 pub async fn execute_transcription_task<T: TranscriptionEventEmitter>(
@@ -35,11 +57,14 @@ pub async fn execute_transcription_task<T: TranscriptionEventEmitter>(
     transcription_emitter: Arc<T>,
     timeout_duration: Duration,
     recording_state: Option<Arc<Mutex<RecordingManager>>>,
+    fallback: Option<FallbackTarget>,
+    ticket: TranscriptionTicket,
+    busy_policy: BusyPolicy,
 ) -> Result<TranscriptionResult, ()> {
     // Helper to clear recording buffer - call this in all exit paths to prevent memory leaks
     let clear_recording_buffer = || {
         if let Some(ref state) = recording_state {
-            if let Ok(mut manager) = state.lock() {
+            if let Some(mut manager) = lock_recording_state(state) {
                 manager.clear_last_recording();
                 crate::debug!("Cleared recording buffer");
             }
@@ -47,13 +72,12 @@ pub async fn execute_transcription_task<T: TranscriptionEventEmitter>(
     };
 
     // Acquire semaphore permit to limit concurrent transcriptions
-    let _permit = match semaphore.try_acquire() {
-        Ok(permit) => permit,
-        Err(_) => {
+    let _permit = match wait_queue().acquire(&semaphore, busy_policy).await {
+        Some(permit) => permit,
+        None => {
             crate::warn!("Too many concurrent transcriptions, skipping this one");
             transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                error: "Too many transcriptions in progress. Please wait and try again."
-                    .to_string(),
+                error: TRANSCRIPTION_BUSY_MESSAGE.to_string(),
             });
             clear_recording_buffer();
             return Err(());
@@ -69,56 +93,36 @@ pub async fn execute_transcription_task<T: TranscriptionEventEmitter>(
     crate::debug!("Transcribing file: {}", file_path);
 
     // Perform transcription on blocking thread pool (CPU-intensive) with timeout
-    let transcriber = shared_model.clone();
-    let transcription_future =
-        tokio::task::spawn_blocking(move || transcriber.transcribe(&file_path));
-
-    let transcription_result = tokio::time::timeout(timeout_duration, transcription_future).await;
-
-    let text = match transcription_result {
-        Ok(Ok(Ok(text))) => text,
-        Ok(Ok(Err(e))) => {
-            crate::error!("Transcription failed: {}", e);
-            transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                error: e.to_string(),
-            });
-            if let Err(reset_err) = shared_model.reset_to_idle() {
-                crate::warn!("Failed to reset transcription state: {}", reset_err);
-            }
-            clear_recording_buffer();
-            return Err(());
-        }
-        Ok(Err(e)) => {
-            crate::error!("Transcription task panicked: {}", e);
-            transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                error: "Internal transcription error.".to_string(),
-            });
-            if let Err(reset_err) = shared_model.reset_to_idle() {
-                crate::warn!("Failed to reset transcription state: {}", reset_err);
-            }
-            clear_recording_buffer();
-            return Err(());
-        }
-        Err(_) => {
-            crate::error!("Transcription timed out after {:?}", timeout_duration);
-            transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                error: format!(
-                    "Transcription timed out after {} seconds. The audio may be too long or the model may be stuck.",
-                    timeout_duration.as_secs()
-                ),
-            });
-            if let Err(reset_err) = shared_model.reset_to_idle() {
-                crate::warn!("Failed to reset transcription state: {}", reset_err);
+    let (text, model_version) =
+        match transcribe_with_fallback(&shared_model, fallback, file_path, timeout_duration).await {
+            Ok(result) => result,
+            Err(error) => {
+                transcription_emitter.emit_transcription_error(TranscriptionErrorPayload { error });
+                if let Err(reset_err) = shared_model.reset_to_idle() {
+                    crate::warn!("Failed to reset transcription state: {}", reset_err);
+                }
+                clear_recording_buffer();
+                return Err(());
             }
-            clear_recording_buffer();
-            return Err(());
+        };
+
+    if ticket.is_cancelled() {
+        crate::info!("Transcription cancelled, discarding result");
+        transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
+            error: TRANSCRIPTION_CANCELLED_MESSAGE.to_string(),
+        });
+        if let Err(reset_err) = shared_model.reset_to_idle() {
+            crate::warn!("Failed to reset transcription state: {}", reset_err);
         }
-    };
+        clear_recording_buffer();
+        return Err(());
+    }
 
     let duration_ms = start_time.elapsed().as_millis() as u64;
     crate::info!(
-        "Transcription completed in {}ms: {} chars",
+        "Transcription completed in {}ms by {}: {} chars",
         duration_ms,
+        model_version,
         text.len()
     );
 
@@ -186,7 +190,15 @@ where
 
         // Check if model is loaded
         if !shared_model.is_loaded() {
-            crate::info!("Transcription skipped: transcription model not loaded");
+            match &self.app_handle {
+                Some(handle) => skip_transcription_without_model(handle, &file_path),
+                None => crate::info!("Transcription skipped: transcription model not loaded"),
+            }
+            if let Some(ref state) = self.recording_state {
+                if let Some(mut manager) = lock_recording_state(state) {
+                    manager.clear_last_recording();
+                }
+            }
             return;
         }
 
@@ -215,6 +227,12 @@ where
 
         crate::info!("Spawning transcription task...");
 
+        // Resolve the optional fallback model before leaving the caller's thread
+        let fallback = app_handle.as_ref().and_then(resolve_fallback);
+        let busy_policy = app_handle.as_ref().map(BusyPolicy::load).unwrap_or_default();
+        // Track the transcription before spawning so the cancel shortcut can reach it
+        let ticket = transcriptions().begin();
+
         // Spawn async task using Tauri's async runtime
         tauri::async_runtime::spawn(async move {
             // Execute transcription using shared helper
@@ -225,6 +243,9 @@ where
                 transcription_emitter.clone(),
                 timeout_duration,
                 recording_state.clone(),
+                fallback,
+                ticket,
+                busy_policy,
             )
             .await;
 
@@ -234,9 +255,16 @@ where
                 Err(()) => return, // Error already emitted and buffer cleared by helper
             };
 
-            crate::info!("=== spawn_transcription received text ===");
-            crate::info!("text content: {:?}", text);
-            crate::info!("=== end spawn_transcription text ===");
+            // Collapse runaway repeats, then apply the global text transforms
+            let text = match app_handle {
+                Some(ref handle) => {
+                    let text = collapse_hallucinated_repeats(handle, text);
+                    TextTransforms::from_settings(handle).apply(&text)
+                }
+                None => text,
+            };
+
+            crate::info!("spawn_transcription received text: {}", loggable_text(&text));
 
             // Try voice command matching if configured
             enum MatchOutcome {
@@ -247,6 +275,8 @@ where
                 },
                 Ambiguous {
                     candidates: Vec<CommandCandidate>,
+                    truncated: bool,
+                    top: Option<(CommandDefinition, f64)>,
                 },
                 NoMatch,
             }
@@ -254,14 +284,14 @@ where
             // Helper to clear recording buffer
             let clear_recording_buffer = || {
                 if let Some(ref state) = recording_state {
-                    if let Ok(mut manager) = state.lock() {
+                    if let Some(mut manager) = lock_recording_state(state) {
                         manager.clear_last_recording();
                         crate::debug!("Cleared recording buffer");
                     }
                 }
             };
 
-            let command_handled = if let (
+            let command_outcome = if let (
                 Some(client),
                 Some(matcher),
                 Some(dispatcher),
@@ -288,6 +318,17 @@ where
 
                 let match_result = matcher.match_commands(&text, &all_commands);
 
+                // Log the outcome for match statistics
+                if let Err(e) = client
+                    .record_command_match(match_result.outcome(), match_result.confidence())
+                    .await
+                {
+                    crate::warn!("Failed to record command match: {}", e);
+                }
+                if let Some(ref handle) = app_handle {
+                    record_match_outcome(handle, &text, &match_result);
+                }
+
                 let outcome = match match_result {
                     MatchResult::Exact {
                         command: matched_cmd,
@@ -312,7 +353,10 @@ where
                         },
                         None => MatchOutcome::NoMatch,
                     },
-                    MatchResult::Ambiguous { candidates } => {
+                    MatchResult::Ambiguous {
+                        candidates,
+                        truncated,
+                    } => {
                         let candidate_data: Vec<_> = candidates
                             .iter()
                             .map(|c| CommandCandidate {
@@ -321,13 +365,50 @@ where
                                 confidence: c.score,
                             })
                             .collect();
+                        let top = candidates
+                            .iter()
+                            .max_by(|a, b| a.score.total_cmp(&b.score))
+                            .and_then(|c| {
+                                commands_by_id
+                                    .get(&c.command.id)
+                                    .map(|cmd| ((*cmd).clone(), c.score))
+                            });
                         MatchOutcome::Ambiguous {
                             candidates: candidate_data,
+                            truncated,
+                            top,
                         }
                     }
                     MatchResult::NoMatch => MatchOutcome::NoMatch,
                 };
 
+                // The ambiguity mode can resolve an ambiguous match without asking
+                let ambiguity_mode = app_handle
+                    .as_ref()
+                    .map(AmbiguityMode::from_settings)
+                    .unwrap_or_default();
+                let outcome = match (ambiguity_mode, outcome) {
+                    (
+                        AmbiguityMode::AutoTop,
+                        MatchOutcome::Ambiguous {
+                            top: Some((cmd, confidence)),
+                            ..
+                        },
+                    ) => {
+                        crate::info!("Ambiguous match, executing top candidate: {}", cmd.trigger);
+                        MatchOutcome::Matched {
+                            trigger: cmd.trigger.clone(),
+                            cmd,
+                            confidence,
+                        }
+                    }
+                    (AmbiguityMode::Clipboard, MatchOutcome::Ambiguous { .. }) => {
+                        crate::info!("Ambiguous match, treating transcription as dictation");
+                        MatchOutcome::NoMatch
+                    }
+                    (_, outcome) => outcome,
+                };
+
                 match outcome {
                     MatchOutcome::Matched {
                         cmd,
@@ -348,62 +429,99 @@ where
                             confidence,
                         });
 
-                        // Execute command directly using await (no new runtime needed!)
-                        match dispatcher.execute(&cmd).await {
-                            Ok(action_result) => {
-                                crate::info!("Command executed: {}", action_result.message);
-                                emitter.emit_command_executed(CommandExecutedPayload {
-                                    command_id: cmd.id.to_string(),
-                                    trigger: trigger.clone(),
-                                    message: action_result.message,
-                                });
-                            }
-                            Err(action_error) => {
-                                crate::error!("Command execution failed: {}", action_error);
-                                emitter.emit_command_failed(CommandFailedPayload {
-                                    command_id: cmd.id.to_string(),
-                                    trigger: trigger.clone(),
-                                    error_code: action_error.code.to_string(),
-                                    error_message: action_error.message,
-                                });
+                        // Destructive commands wait for the user to confirm them
+                        if let (Some(handle), true) = (&app_handle, cmd.requires_confirmation) {
+                            request_confirmation(handle, cmd, text.clone(), confidence);
+                            CommandOutcome::AwaitingConfirmation
+                        } else {
+                            // Execute command directly using await (no new runtime needed!)
+                            match dispatcher
+                                .execute_with_transcription(&cmd, &text, confidence)
+                                .await
+                            {
+                                Ok(action_result) => {
+                                    crate::info!("Command executed: {}", action_result.message);
+                                    emitter.emit_command_executed(CommandExecutedPayload {
+                                        command_id: cmd.id.to_string(),
+                                        trigger: trigger.clone(),
+                                        message: action_result.message,
+                                    });
+                                    CommandOutcome::Executed
+                                }
+                                Err(action_error) => {
+                                    crate::error!("Command execution failed: {}", action_error);
+                                    emitter.emit_command_failed(CommandFailedPayload {
+                                        command_id: cmd.id.to_string(),
+                                        trigger: trigger.clone(),
+                                        error_code: action_error.code.to_string(),
+                                        error_message: action_error.message.clone(),
+                                    });
+                                    CommandOutcome::Failed {
+                                        trigger,
+                                        message: action_error.message,
+                                    }
+                                }
                             }
                         }
-                        true // Command was handled
                     }
-                    MatchOutcome::Ambiguous { candidates } => {
-                        crate::info!("Ambiguous match: {} candidates", candidates.len());
+                    MatchOutcome::Ambiguous {
+                        candidates,
+                        truncated,
+                        top,
+                    } => {
+                        crate::info!(
+                            "Ambiguous match: {} candidates{}",
+                            candidates.len(),
+                            if truncated { " (truncated)" } else { "" }
+                        );
 
                         // Emit command_ambiguous event for disambiguation UI
                         emitter.emit_command_ambiguous(CommandAmbiguousPayload {
                             transcription: text.clone(),
                             candidates,
+                            truncated,
                         });
-                        true // Command matching was handled (ambiguous)
+
+                        // Auto-resolve if the user doesn't pick a candidate in time
+                        if let Some(ref handle) = app_handle {
+                            schedule_auto_resolve(handle, dispatcher.clone(), text.clone(), top);
+                        }
+                        CommandOutcome::Ambiguous
                     }
                     MatchOutcome::NoMatch => {
-                        crate::debug!("No command match for: {}", text);
-                        false // Fall through to clipboard
+                        crate::debug!("No command match for: {}", loggable_text(&text));
+                        CommandOutcome::NotHandled // Fall through to clipboard
                     }
                 }
             } else {
                 crate::debug!("Voice commands not configured, skipping command matching");
-                false
+                CommandOutcome::NotHandled
             };
 
             // Fallback to clipboard if no command was handled
-            if !command_handled {
-                copy_and_paste(&app_handle, &text);
+            if !command_outcome.is_handled() {
+                copy_and_paste(&app_handle, &text).await;
             }
 
-            // Always emit transcription_completed (whether command handled or not)
-            // This ensures the frontend clears the "Transcribing..." state
-            crate::info!("=== Emitting transcription_completed ===");
-            crate::info!("text to emit: {:?}", text);
-            crate::info!("=== end emit ===");
-            transcription_emitter.emit_transcription_completed(TranscriptionCompletedPayload {
-                text,
-                duration_ms,
-            });
+            // Always end with transcription_completed or transcription_error so the
+            // frontend clears the "Transcribing..." state. A failed command is
+            // reported as the error when configured to.
+            let report_failures = app_handle.as_ref().is_some_and(report_command_failures);
+            match command_outcome.failure_error(report_failures) {
+                Some(error) => {
+                    crate::info!("Emitting transcription_error for failed command");
+                    transcription_emitter
+                        .emit_transcription_error(TranscriptionErrorPayload { error });
+                }
+                None => {
+                    crate::info!("Emitting transcription_completed: {}", loggable_text(&text));
+                    transcription_emitter.emit_transcription_completed(TranscriptionCompletedPayload {
+                        language: guess_transcription_language(&text),
+                        text,
+                        duration_ms,
+                    });
+                }
+            }
 
             // Reset transcription state to idle
             if let Err(e) = shared_model.reset_to_idle() {
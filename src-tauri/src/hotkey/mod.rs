@@ -29,6 +29,7 @@ mod mod_test;
 #[cfg(test)]
 mod integration_test;
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 /// The keyboard shortcut for cancel (Escape key)
@@ -187,11 +188,26 @@ impl<B: ShortcutBackend> HotkeyService<B> {
 pub struct HotkeyServiceDyn {
     /// The backend used for shortcut registration
     pub backend: Arc<dyn ShortcutBackend + Send + Sync>,
+    /// Whether the recording shortcut is currently registered
+    recording_registered: AtomicBool,
 }
 
 impl HotkeyServiceDyn {
     pub fn new(backend: Arc<dyn ShortcutBackend + Send + Sync>) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            recording_registered: AtomicBool::new(false),
+        }
+    }
+
+    /// Record whether the recording shortcut is registered with the backend
+    pub fn set_recording_registered(&self, registered: bool) {
+        self.recording_registered.store(registered, Ordering::SeqCst);
+    }
+
+    /// Whether the recording shortcut is registered with the backend
+    pub fn is_recording_registered(&self) -> bool {
+        self.recording_registered.load(Ordering::SeqCst)
     }
 }
 
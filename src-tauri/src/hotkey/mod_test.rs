@@ -214,3 +214,15 @@ fn test_register_with_release_failure() {
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), "registration failed");
 }
+
+#[test]
+fn test_hotkey_service_dyn_tracks_recording_registration() {
+    let service = HotkeyServiceDyn::new(Arc::new(NullShortcutBackend));
+    assert!(!service.is_recording_registered());
+
+    service.set_recording_registered(true);
+    assert!(service.is_recording_registered());
+
+    service.set_recording_registered(false);
+    assert!(!service.is_recording_registered());
+}
@@ -4,9 +4,33 @@
 
 pub mod synth;
 
+use crate::emit_or_warn;
+use crate::events::{event_names, AccessibilityPermissionRequiredPayload};
+use tauri::{AppHandle, Emitter};
+
 #[cfg(not(target_os = "macos"))]
 use enigo::{Enigo, Key, Keyboard, Settings};
 
+/// Emit `accessibility_permission_required` when a synthesis error was caused
+/// by missing Accessibility permission
+///
+/// `action` names the keystroke that failed ("paste", "enter" or "type").
+/// Other errors are left to the caller to log.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn notify_if_permission_missing(app_handle: &AppHandle, action: &str, error: &str) {
+    if !synth::is_accessibility_permission_error(error) {
+        return;
+    }
+    emit_or_warn!(
+        app_handle,
+        event_names::ACCESSIBILITY_PERMISSION_REQUIRED,
+        AccessibilityPermissionRequiredPayload {
+            action: action.to_string(),
+            message: error.to_string(),
+        }
+    );
+}
+
 /// Keyboard simulator for sending key events
 pub struct KeyboardSimulator {
     #[cfg(not(target_os = "macos"))]
@@ -1,5 +1,137 @@
+/// Error returned when keystrokes can't be simulated without Accessibility permission
+pub const ACCESSIBILITY_PERMISSION_ERROR: &str =
+    "Accessibility permission is required to simulate keystrokes. Grant it in System Settings > Privacy & Security > Accessibility.";
+
+/// Whether a synthesis error was caused by missing Accessibility permission
+pub fn is_accessibility_permission_error(error: &str) -> bool {
+    error == ACCESSIBILITY_PERMISSION_ERROR
+}
+
+/// Most UTF-16 units macOS accepts in a single keyboard event's string
+pub const MAX_BURST_UTF16_UNITS: usize = 20;
+
+/// How typed text is split into keyboard events
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypingMode {
+    /// One event per character, with the typing delay between them
+    #[default]
+    PerCharacter,
+    /// Runs of text in as few events as possible, like a paste
+    Burst,
+}
+
+/// How tabs and newlines in typed text are sent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ControlCharHandling {
+    /// Press the Return and Tab keys
+    #[default]
+    Keys,
+    /// Send them as text like any other character
+    Text,
+    /// Leave them out
+    Strip,
+}
+
+/// Options for typing text into the focused application
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypingOptions {
+    /// Delay after each event in milliseconds
+    pub delay_ms: u64,
+    /// How text is split into events
+    pub mode: TypingMode,
+    /// How tabs and newlines are sent
+    pub control_chars: ControlCharHandling,
+    /// Clear modifier flags on every event so a held key (e.g. the hotkey) can't alter them
+    pub clear_modifiers: bool,
+}
+
+/// A key sent in place of a control character
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialKey {
+    Return,
+    Tab,
+}
+
+impl SpecialKey {
+    /// macOS virtual keycode of the key
+    pub fn keycode(self) -> u16 {
+        match self {
+            SpecialKey::Return => 36,
+            SpecialKey::Tab => 48,
+        }
+    }
+}
+
+/// One keyboard event (or key press) in a typing plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypingStep {
+    /// Text carried as the event's Unicode string
+    Text(String),
+    /// A real key press
+    Key(SpecialKey),
+}
+
+/// Split text into the keyboard events that type it
+///
+/// "\r\n" counts as a single newline. In burst mode text runs are capped at
+/// `MAX_BURST_UTF16_UNITS` per event.
+pub fn plan_typing(text: &str, options: &TypingOptions) -> Vec<TypingStep> {
+    let mut steps = Vec::new();
+    let mut run = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let key = match c {
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    continue;
+                }
+                Some(SpecialKey::Return)
+            }
+            '\n' => Some(SpecialKey::Return),
+            '\t' => Some(SpecialKey::Tab),
+            _ => None,
+        };
+
+        match (key, options.control_chars) {
+            (Some(_), ControlCharHandling::Strip) => continue,
+            (Some(key), ControlCharHandling::Keys) => {
+                if !run.is_empty() {
+                    steps.push(TypingStep::Text(std::mem::take(&mut run)));
+                }
+                steps.push(TypingStep::Key(key));
+                continue;
+            }
+            _ => {}
+        }
+
+        // A lone carriage return is typed as a newline
+        let c = if key == Some(SpecialKey::Return) {
+            '\n'
+        } else {
+            c
+        };
+        match options.mode {
+            TypingMode::PerCharacter => steps.push(TypingStep::Text(c.to_string())),
+            TypingMode::Burst => {
+                let units: usize = run.chars().map(char::len_utf16).sum();
+                if units + c.len_utf16() > MAX_BURST_UTF16_UNITS {
+                    steps.push(TypingStep::Text(std::mem::take(&mut run)));
+                }
+                run.push(c);
+            }
+        }
+    }
+
+    if !run.is_empty() {
+        steps.push(TypingStep::Text(run));
+    }
+    steps
+}
+
 #[cfg(target_os = "macos")]
 mod macos {
+    use super::{plan_typing, TypingOptions, TypingStep};
     use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode};
     use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
     use std::sync::{Mutex, MutexGuard};
@@ -14,6 +146,18 @@ mod macos {
             .unwrap_or_else(|poisoned| poisoned.into_inner())
     }
 
+    /// Fail fast when Accessibility permission isn't granted
+    ///
+    /// Without it macOS silently drops synthetic events, so posting them would
+    /// look like success while nothing reaches the focused app.
+    fn ensure_permission() -> Result<(), String> {
+        if crate::keyboard_capture::permissions::check_accessibility_permission() {
+            Ok(())
+        } else {
+            Err(super::ACCESSIBILITY_PERMISSION_ERROR.to_string())
+        }
+    }
+
     /// Simulate Enter/Return keypress on macOS.
     ///
     /// Uses Session tap location for reliable cross-app event delivery.
@@ -24,6 +168,7 @@ mod macos {
         if crate::shutdown::is_shutting_down() {
             return Ok(());
         }
+        ensure_permission()?;
 
         // Serialize synthesis so key sequences can't interleave across tasks.
         let _guard = lock_synth();
@@ -73,6 +218,7 @@ mod macos {
         if crate::shutdown::is_shutting_down() {
             return Ok(());
         }
+        ensure_permission()?;
 
         // Serialize synthesis so key sequences can't interleave across tasks.
         let _guard = lock_synth();
@@ -117,11 +263,12 @@ mod macos {
     /// Type Unicode text into the currently focused application.
     ///
     /// We post both key-down and key-up events to avoid leaving the system with a key held down.
-    /// If shutdown is signaled mid-typing, we stop *between characters* (never between down/up).
-    pub fn type_unicode_text(text: &str, delay_ms: u64) -> Result<(), String> {
+    /// If shutdown is signaled mid-typing, we stop *between events* (never between down/up).
+    pub fn type_unicode_text(text: &str, options: &TypingOptions) -> Result<(), String> {
         if crate::shutdown::is_shutting_down() {
             return Ok(());
         }
+        ensure_permission()?;
 
         let _guard = lock_synth();
 
@@ -132,36 +279,38 @@ mod macos {
         let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
             .map_err(|_| "Failed to create event source")?;
 
-        for character in text.chars() {
-            // Allow shutdown to stop further typing between characters.
+        for step in plan_typing(text, options) {
+            // Allow shutdown to stop further typing between events.
             if crate::shutdown::is_shutting_down() {
                 break;
             }
 
-            // Encode the character to UTF-16
-            let mut buf = [0u16; 2];
-            let slice = character.encode_utf16(&mut buf);
-            let chars: Vec<u16> = slice.to_vec();
+            // Text rides on a dummy keycode 0; special keys use their real keycode
+            let (keycode, chars): (CGKeyCode, Vec<u16>) = match &step {
+                TypingStep::Text(text) => (0, text.encode_utf16().collect()),
+                TypingStep::Key(key) => (key.keycode(), Vec::new()),
+            };
 
-            if chars.is_empty() {
-                continue;
+            let event_down = CGEvent::new_keyboard_event(source.clone(), keycode, true)
+                .map_err(|_| "Failed to create key down event")?;
+            if !chars.is_empty() {
+                event_down.set_string_from_utf16_unchecked(&chars);
             }
+            // Key up (no unicode string to avoid any risk of double-typing)
+            let event_up = CGEvent::new_keyboard_event(source.clone(), keycode, false)
+                .map_err(|_| "Failed to create key up event")?;
 
-            // Key down with unicode string (dummy keycode 0)
-            let event_down = CGEvent::new_keyboard_event(source.clone(), 0, true)
-                .map_err(|_| "Failed to create key down event")?;
-            event_down.set_string_from_utf16_unchecked(&chars);
-            event_down.post(CGEventTapLocation::HID);
+            if options.clear_modifiers {
+                event_down.set_flags(CGEventFlags::empty());
+                event_up.set_flags(CGEventFlags::empty());
+            }
 
+            event_down.post(CGEventTapLocation::HID);
             std::thread::sleep(Duration::from_millis(1));
-
-            // Key up (no unicode string to avoid any risk of double-typing)
-            let event_up = CGEvent::new_keyboard_event(source.clone(), 0, false)
-                .map_err(|_| "Failed to create key up event")?;
             event_up.post(CGEventTapLocation::HID);
 
-            if delay_ms > 0 {
-                std::thread::sleep(Duration::from_millis(delay_ms));
+            if options.delay_ms > 0 {
+                std::thread::sleep(Duration::from_millis(options.delay_ms));
             }
         }
 
@@ -178,7 +327,7 @@ pub fn simulate_cmd_v_paste() -> Result<(), String> {
 }
 
 #[cfg(not(target_os = "macos"))]
-pub fn type_unicode_text(_text: &str, _delay_ms: u64) -> Result<(), String> {
+pub fn type_unicode_text(_text: &str, _options: &TypingOptions) -> Result<(), String> {
     Err("Text input is only supported on macOS".to_string())
 }
 
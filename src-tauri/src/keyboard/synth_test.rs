@@ -30,7 +30,7 @@ fn test_simulate_cmd_v_paste_integration() {
 #[test]
 #[ignore] // Requires display and Accessibility permissions
 fn test_type_unicode_text_integration() {
-    let result = type_unicode_text("hello", 0);
+    let result = type_unicode_text("hello", &TypingOptions::default());
     assert!(
         result.is_ok(),
         "Unicode text typing should succeed: {:?}",
@@ -53,3 +53,102 @@ fn test_paste_uses_session_tap_location() {
     // This test exists to document the requirement and will be updated if
     // the implementation changes.
 }
+
+#[test]
+fn test_is_accessibility_permission_error() {
+    assert!(is_accessibility_permission_error(ACCESSIBILITY_PERMISSION_ERROR));
+    assert!(!is_accessibility_permission_error("Failed to create event source"));
+}
+
+fn text(s: &str) -> TypingStep {
+    TypingStep::Text(s.to_string())
+}
+
+#[test]
+fn test_plan_presses_return_and_tab_keys() {
+    let steps = plan_typing("a\tb\nc", &TypingOptions::default());
+    assert_eq!(
+        steps,
+        vec![
+            text("a"),
+            TypingStep::Key(SpecialKey::Tab),
+            text("b"),
+            TypingStep::Key(SpecialKey::Return),
+            text("c"),
+        ]
+    );
+}
+
+#[test]
+fn test_plan_treats_crlf_as_one_newline() {
+    let steps = plan_typing("a\r\nb\rc", &TypingOptions::default());
+    assert_eq!(
+        steps,
+        vec![
+            text("a"),
+            TypingStep::Key(SpecialKey::Return),
+            text("b"),
+            TypingStep::Key(SpecialKey::Return),
+            text("c"),
+        ]
+    );
+}
+
+#[test]
+fn test_plan_control_chars_as_text_or_stripped() {
+    let as_text = TypingOptions {
+        control_chars: ControlCharHandling::Text,
+        ..Default::default()
+    };
+    assert_eq!(
+        plan_typing("a\tb\r\n", &as_text),
+        vec![text("a"), text("\t"), text("b"), text("\n")]
+    );
+
+    let stripped = TypingOptions {
+        control_chars: ControlCharHandling::Strip,
+        mode: TypingMode::Burst,
+        ..Default::default()
+    };
+    assert_eq!(plan_typing("a\tb\nc", &stripped), vec![text("abc")]);
+}
+
+#[test]
+fn test_plan_burst_groups_text_between_keys() {
+    let burst = TypingOptions {
+        mode: TypingMode::Burst,
+        ..Default::default()
+    };
+    assert_eq!(
+        plan_typing("git status\n", &burst),
+        vec![text("git status"), TypingStep::Key(SpecialKey::Return)]
+    );
+}
+
+#[test]
+fn test_plan_burst_caps_event_length() {
+    let burst = TypingOptions {
+        mode: TypingMode::Burst,
+        ..Default::default()
+    };
+    let long = "x".repeat(MAX_BURST_UTF16_UNITS + 5);
+    let steps = plan_typing(&long, &burst);
+    assert_eq!(
+        steps,
+        vec![text(&"x".repeat(MAX_BURST_UTF16_UNITS)), text("xxxxx")]
+    );
+
+    // A surrogate pair is never split across events
+    let emoji = format!("{}🎉", "x".repeat(MAX_BURST_UTF16_UNITS - 1));
+    let steps = plan_typing(&emoji, &burst);
+    assert_eq!(
+        steps,
+        vec![text(&"x".repeat(MAX_BURST_UTF16_UNITS - 1)), text("🎉")]
+    );
+}
+
+#[test]
+fn test_special_key_codes() {
+    assert_eq!(SpecialKey::Return.keycode(), 36);
+    assert_eq!(SpecialKey::Tab.keycode(), 48);
+}
@@ -0,0 +1,115 @@
+// Last error slot
+// Errors reach the UI as events, which a window that subscribes late never
+// sees. The most recent error from the recording, transcription and command
+// flows is also kept here until the UI acknowledges it, so it can show a
+// persistent banner.
+
+use serde::Serialize;
+use std::sync::Mutex;
+
+/// Flow an error came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCategory {
+    /// Recording could not start, stop or continue
+    Recording,
+    /// Audio input device missing, disconnected or failing
+    AudioDevice,
+    /// Transcription failed
+    Transcription,
+    /// A voice command failed to execute
+    Command,
+}
+
+/// The most recent error
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LastError {
+    /// Flow the error came from
+    pub category: ErrorCategory,
+    /// Error message as emitted
+    pub message: String,
+    /// ISO 8601 timestamp when the error was recorded
+    pub timestamp: String,
+}
+
+/// Holds the most recent error until it is cleared
+pub struct LastErrorSlot {
+    slot: Mutex<Option<LastError>>,
+}
+
+impl LastErrorSlot {
+    pub const fn new() -> Self {
+        Self {
+            slot: Mutex::new(None),
+        }
+    }
+
+    /// Record an error, replacing the previous one
+    pub fn record(&self, category: ErrorCategory, message: impl Into<String>) {
+        let error = LastError {
+            category,
+            message: message.into(),
+            timestamp: crate::events::current_timestamp(),
+        };
+        let mut slot = self.slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *slot = Some(error);
+    }
+
+    /// Record a failed voice command
+    pub fn record_command_failure(&self, trigger: &str, error_message: &str) {
+        self.record(
+            ErrorCategory::Command,
+            format!("Command \"{}\" failed: {}", trigger, error_message),
+        );
+    }
+
+    /// The most recent error, if it hasn't been cleared
+    pub fn get(&self) -> Option<LastError> {
+        self.slot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Clear the error; returns false when there was none
+    pub fn clear(&self) -> bool {
+        self.slot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+            .is_some()
+    }
+}
+
+impl Default for LastErrorSlot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide last error shared by all flows
+static LAST_ERROR: LastErrorSlot = LastErrorSlot::new();
+
+/// Get the process-wide last error slot
+pub fn last_error() -> &'static LastErrorSlot {
+    &LAST_ERROR
+}
+
+/// Get the most recent recording, transcription or command error
+///
+/// Returns null once the error has been cleared.
+#[tauri::command]
+pub fn get_last_error() -> Option<LastError> {
+    last_error().get()
+}
+
+/// Acknowledge the most recent error so it is no longer reported
+#[tauri::command]
+pub fn clear_last_error() -> bool {
+    last_error().clear()
+}
+
+#[cfg(test)]
+#[path = "last_error_test.rs"]
+mod tests;
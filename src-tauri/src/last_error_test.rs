@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_empty_slot() {
+    let slot = LastErrorSlot::new();
+    assert_eq!(slot.get(), None);
+    assert!(!slot.clear());
+}
+
+#[test]
+fn test_latest_error_replaces_previous() {
+    let slot = LastErrorSlot::new();
+    slot.record(ErrorCategory::Recording, "Microphone busy");
+    slot.record(ErrorCategory::Transcription, "Model not loaded");
+
+    let error = slot.get().expect("error should be recorded");
+    assert_eq!(error.category, ErrorCategory::Transcription);
+    assert_eq!(error.message, "Model not loaded");
+    assert!(!error.timestamp.is_empty());
+}
+
+#[test]
+fn test_command_failure_names_trigger() {
+    let slot = LastErrorSlot::new();
+    slot.record_command_failure("open slack", "App not found");
+
+    let error = slot.get().expect("error should be recorded");
+    assert_eq!(error.category, ErrorCategory::Command);
+    assert_eq!(error.message, "Command \"open slack\" failed: App not found");
+}
+
+#[test]
+fn test_clear_removes_error() {
+    let slot = LastErrorSlot::new();
+    slot.record(ErrorCategory::Command, "App not found");
+
+    assert!(slot.clear());
+    assert_eq!(slot.get(), None);
+}
+
+#[test]
+fn test_serializes_camel_case() {
+    let error = LastError {
+        category: ErrorCategory::AudioDevice,
+        message: "Device disconnected".to_string(),
+        timestamp: "2025-01-01T00:00:00Z".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(&error).unwrap(),
+        serde_json::json!({
+            "category": "audioDevice",
+            "message": "Device disconnected",
+            "timestamp": "2025-01-01T00:00:00Z",
+        })
+    );
+}
@@ -13,11 +13,13 @@ mod events;
 mod hotkey;
 mod keyboard;
 mod keyboard_capture;
+mod last_error;
 mod model;
 mod parakeet;
 mod paths;
 mod recording;
 mod shutdown;
+mod sound;
 mod storage;
 mod swift;
 mod transcription;
@@ -85,28 +87,63 @@ pub fn run() {
             commands::recording::start_recording,
             commands::recording::stop_recording,
             commands::recording::get_recording_state,
+            commands::recording::get_recording_elapsed,
             commands::recording::get_last_recording_buffer,
             commands::recording::clear_last_recording_buffer,
             commands::recording::list_recordings,
             commands::recording::delete_recording,
+            commands::recording::delete_recordings,
+            commands::recording::save_voice_note,
+            commands::recording::rename_recording,
+            commands::recording::set_recording_stop_reason,
+            commands::recording::export_recording_bundle,
             // Transcription commands
             commands::transcription::transcribe_file,
             commands::transcription::list_transcriptions,
+            commands::transcription::get_usage_summary,
+            commands::transcription::get_transcription_perf,
+            commands::transcription::get_verbose_transcription_logging,
+            commands::transcription::set_verbose_transcription_logging,
+            commands::transcription::get_transcription_timeout,
+            commands::transcription::set_transcription_timeout,
+            commands::transcription::get_match_log_settings,
+            commands::transcription::set_match_log_settings,
             commands::transcription::get_transcriptions_by_recording,
+            commands::transcription::paste_transcription,
+            commands::transcription::update_transcription_text,
+            commands::transcription::retranscribe_all,
+            commands::transcription::run_transcription_benchmark,
             // Audio commands
             commands::audio::list_audio_devices,
+            commands::audio::get_input_device_status,
+            commands::audio::is_microphone_busy,
+            commands::audio::get_resampler_info,
+            commands::audio::set_input_gain,
+            commands::audio::set_channel_selection,
+            commands::audio::get_vad_thresholds,
+            commands::audio::set_vad_threshold,
             commands::audio::start_audio_monitor,
             commands::audio::stop_audio_monitor,
             commands::audio::init_audio_monitor,
             // Model commands
             model::check_parakeet_model_status,
             model::download_model,
+            model::set_model_path,
+            model::list_available_models,
             // Voice commands
             voice_commands::get_commands,
             voice_commands::add_command,
             voice_commands::update_command,
             voice_commands::remove_command,
+            voice_commands::get_match_stats,
             voice_commands::executor::test_command,
+            voice_commands::executor::test_action,
+            voice_commands::disambiguation::dismiss_command_ambiguity,
+            voice_commands::disambiguation::get_ambiguity_mode,
+            voice_commands::disambiguation::set_ambiguity_mode,
+            voice_commands::confirmation::confirm_command,
+            voice_commands::confirmation::cancel_command,
+            voice_commands::last_command::rerun_last_command,
             // Hotkey commands
             commands::hotkey::suspend_recording_shortcut,
             commands::hotkey::resume_recording_shortcut,
@@ -114,18 +151,33 @@ pub fn run() {
             commands::hotkey::get_recording_shortcut,
             commands::hotkey::get_recording_mode,
             commands::hotkey::set_recording_mode,
+            commands::hotkey::get_cancel_shortcut,
+            commands::hotkey::set_cancel_shortcut,
             commands::hotkey::start_shortcut_recording,
             commands::hotkey::stop_shortcut_recording,
             commands::hotkey::open_accessibility_preferences,
             // Worktree commands
             commands::get_settings_file_name,
+            commands::get_data_dir_fallback,
+            // Setup commands
+            commands::setup::get_setup_status,
+            // Event commands
+            commands::list_event_names,
+            last_error::get_last_error,
+            last_error::clear_last_error,
             // Dictionary commands
             commands::dictionary::list_dictionary_entries,
             commands::dictionary::add_dictionary_entry,
             commands::dictionary::update_dictionary_entry,
             commands::dictionary::delete_dictionary_entry,
+            commands::dictionary::get_dictionary_suggestions,
+            commands::dictionary::apply_dictionary_preview,
+            commands::config::export_config,
+            commands::config::import_config,
+            commands::settings::reload_settings,
             // Window context commands
             commands::window_context::get_active_window_info,
+            commands::window_context::get_active_window,
             commands::window_context::list_running_applications,
             commands::window_context::list_window_contexts,
             commands::window_context::add_window_context,
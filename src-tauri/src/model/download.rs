@@ -160,7 +160,17 @@ fn check_model_files_exist_in_dir(dir: &std::path::Path, manifest: &ModelManifes
     if !dir.exists() {
         return false;
     }
-    manifest.files.iter().all(|f| dir.join(&f.name).exists())
+    missing_model_files(dir, manifest).is_empty()
+}
+
+/// List the manifest files that are missing from a given directory
+pub fn missing_model_files(dir: &std::path::Path, manifest: &ModelManifest) -> Vec<String> {
+    manifest
+        .files
+        .iter()
+        .filter(|f| !dir.join(&f.name).is_file())
+        .map(|f| f.name.clone())
+        .collect()
 }
 
 /// Check if a multi-file model exists (all files present) with worktree context
@@ -197,6 +207,50 @@ pub fn ensure_models_dir() -> Result<PathBuf, ModelError> {
     ensure_models_dir_with_context(None)
 }
 
+/// A Parakeet model directory found on disk
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledModel {
+    /// Directory name
+    pub name: String,
+    /// Full path to the model directory
+    pub path: String,
+    /// Whether this is the model currently loaded
+    pub active: bool,
+}
+
+/// List the valid model directories directly inside `models_dir`
+///
+/// A subdirectory counts as a model when it contains every file in the TDT
+/// manifest. Returns an empty list when `models_dir` doesn't exist or can't
+/// be read. Results are sorted by name.
+pub fn list_installed_models(
+    models_dir: &std::path::Path,
+    active_dir: Option<&std::path::Path>,
+) -> Vec<InstalledModel> {
+    let Ok(entries) = std::fs::read_dir(models_dir) else {
+        return Vec::new();
+    };
+
+    let manifest = ModelManifest::tdt();
+    let mut models: Vec<InstalledModel> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir() && check_model_files_exist_in_dir(path, &manifest))
+        .map(|path| InstalledModel {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            active: active_dir.is_some_and(|active| active == path),
+            path: path.to_string_lossy().to_string(),
+        })
+        .collect();
+
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    models
+}
+
 /// Trait for emitting model download progress events
 /// Allows mocking in tests while using real Tauri AppHandle in production
 pub trait ModelDownloadEventEmitter: Send + Sync {
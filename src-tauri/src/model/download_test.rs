@@ -133,3 +133,52 @@ fn test_check_model_files_exist_in_dir_returns_true_with_stub_files() {
     let _ = std::fs::remove_dir_all(&temp_dir);
     assert!(result);
 }
+
+#[test]
+fn test_missing_model_files_lists_only_absent_files() {
+    let temp_dir =
+        std::env::temp_dir().join(format!("heycat-test-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    std::fs::write(temp_dir.join("vocab.txt"), b"stub").unwrap();
+
+    let missing = missing_model_files(&temp_dir, &ModelManifest::tdt());
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    assert_eq!(missing.len(), 3);
+    assert!(!missing.contains(&"vocab.txt".to_string()));
+    assert!(missing.contains(&"encoder-model.onnx".to_string()));
+}
+
+fn write_stub_model(dir: &std::path::Path) {
+    std::fs::create_dir_all(dir).unwrap();
+    for file in &ModelManifest::tdt().files {
+        std::fs::write(dir.join(&file.name), b"stub").unwrap();
+    }
+}
+
+#[test]
+fn test_list_installed_models_missing_dir_is_empty() {
+    let missing =
+        std::env::temp_dir().join(format!("heycat-test-{}", uuid::Uuid::new_v4()));
+    assert!(list_installed_models(&missing, None).is_empty());
+}
+
+#[test]
+fn test_list_installed_models_skips_incomplete_dirs_and_marks_active() {
+    let temp_dir =
+        std::env::temp_dir().join(format!("heycat-test-{}", uuid::Uuid::new_v4()));
+    write_stub_model(&temp_dir.join("parakeet-tdt"));
+    write_stub_model(&temp_dir.join("custom"));
+    std::fs::create_dir_all(temp_dir.join("partial")).unwrap();
+    std::fs::write(temp_dir.join("partial").join("vocab.txt"), b"stub").unwrap();
+    std::fs::write(temp_dir.join("notes.txt"), b"stub").unwrap();
+
+    let active = temp_dir.join("parakeet-tdt");
+    let models = list_installed_models(&temp_dir, Some(&active));
+
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    let names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["custom", "parakeet-tdt"]);
+    assert!(!models[0].active);
+    assert!(models[1].active);
+}
@@ -5,16 +5,21 @@
 pub mod download;
 
 pub use download::{
-    check_model_exists_for_type, download_model_files, get_model_dir, ModelDownloadEventEmitter,
-    ModelManifest, ModelType,
+    check_model_exists_for_type, download_model_files, get_model_dir, list_installed_models,
+    missing_model_files, InstalledModel, ModelDownloadEventEmitter, ModelManifest, ModelType,
 };
 
+use std::path::PathBuf;
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tauri_plugin_store::StoreExt;
 
 use crate::events::model_events;
 use crate::parakeet::SharedTranscriptionModel;
 
+/// Settings key holding the model directory chosen with `set_model_path`
+pub const MODEL_PATH_KEY: &str = "transcription.modelPath";
+
 /// Check if a Parakeet model (TDT or EOU) is available
 /// model_type: "ParakeetTDT" or "ParakeetEOU"
 #[tauri::command]
@@ -90,3 +95,90 @@ pub async fn download_model(
 
     Ok(path.to_string_lossy().to_string())
 }
+
+/// List the Parakeet models installed in a models directory
+///
+/// Scans `models_dir` (default: the app's models directory) for
+/// subdirectories containing every file of the TDT manifest, flagging the
+/// one currently loaded. Use `set_model_path` to switch to another.
+#[tauri::command]
+pub fn list_available_models(
+    app_handle: AppHandle,
+    shared_model: State<'_, Arc<SharedTranscriptionModel>>,
+    models_dir: Option<String>,
+) -> Result<Vec<InstalledModel>, String> {
+    let models_dir = match models_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let worktree_context = app_handle
+                .try_state::<crate::worktree::WorktreeState>()
+                .and_then(|s| s.context.clone());
+            download::get_models_dir_with_context(worktree_context.as_ref())
+                .map_err(|e| e.to_string())?
+        }
+    };
+
+    let active_dir = shared_model.loaded_dir();
+    Ok(list_installed_models(&models_dir, active_dir.as_deref()))
+}
+
+/// Switch the Parakeet model to a different directory at runtime
+///
+/// Validates that the directory contains all files from the TDT manifest,
+/// then loads the new model and swaps it in; the current model stays loaded
+/// if the load fails. Rejected while a transcription is in progress. The
+/// directory is saved under `MODEL_PATH_KEY` and loaded again at startup.
+/// Emits `model_reloading` followed by `model_reloaded` or
+/// `model_reload_failed`.
+#[tauri::command]
+pub async fn set_model_path(
+    app_handle: AppHandle,
+    path: String,
+    shared_model: State<'_, Arc<SharedTranscriptionModel>>,
+) -> Result<String, String> {
+    let model_dir = PathBuf::from(&path);
+    if !model_dir.is_dir() {
+        return Err(format!("Model directory not found: {}", path));
+    }
+
+    let missing = missing_model_files(&model_dir, &ModelManifest::tdt());
+    if !missing.is_empty() {
+        return Err(format!(
+            "Model directory is missing required files: {}",
+            missing.join(", ")
+        ));
+    }
+
+    let _ = app_handle.emit(model_events::MODEL_RELOADING, ());
+
+    let model = shared_model.inner().clone();
+    let reload_dir = model_dir.clone();
+    let result = tokio::task::spawn_blocking(move || model.try_reload(&reload_dir))
+        .await
+        .map_err(|e| format!("Model reload task failed: {}", e))
+        .and_then(|r| r.map_err(|e| e.to_string()));
+
+    match result {
+        Ok(()) => {
+            crate::wake_handler::set_model_path(model_dir);
+            crate::info!("Switched Parakeet model directory to {}", path);
+            match app_handle.store(crate::util::get_settings_file(&app_handle)) {
+                Ok(store) => {
+                    store.set(MODEL_PATH_KEY, serde_json::json!(path));
+                    if let Err(e) = store.save() {
+                        crate::warn!("Failed to save model directory: {}", e);
+                    }
+                }
+                Err(e) => crate::warn!("Failed to access settings store: {}", e),
+            }
+            let _ = app_handle.emit(model_events::MODEL_RELOADED, ());
+            Ok(path)
+        }
+        Err(e) => {
+            let error_msg = format!("Failed to load model from {}: {}", path, e);
+            crate::error!("{}", error_msg);
+            let _ = app_handle.emit(model_events::MODEL_RELOAD_FAILED, &error_msg);
+            Err(error_msg)
+        }
+    }
+}
@@ -8,12 +8,14 @@
 
 use hound::WavReader;
 use parking_lot::{Mutex, MutexGuard};
-use parakeet_rs::ParakeetTDT;
-use std::path::Path;
+use parakeet_rs::{ParakeetTDT, TranscriptionResult as TdtResult};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use super::types::{TranscriptionError, TranscriptionResult, TranscriptionService, TranscriptionState};
 use super::utils::fix_parakeet_text;
+use crate::audio::TARGET_SAMPLE_RATE;
+use crate::transcription::verbose_logging::loggable_text;
 
 // ============================================================================
 // WAV Validation - Prevent panics in parakeet-rs
@@ -75,6 +77,30 @@ fn validate_wav_for_transcription(file_path: &str) -> TranscriptionResult<()> {
     Ok(())
 }
 
+/// Validates in-memory samples before transcription, for the same reason as
+/// `validate_wav_for_transcription`.
+///
+/// The model only accepts 16 kHz mono audio, so callers must resample first.
+fn validate_samples_for_transcription(
+    samples: &[f32],
+    sample_rate: u32,
+) -> TranscriptionResult<()> {
+    if samples.is_empty() {
+        return Err(TranscriptionError::InvalidAudio(
+            "No audio samples to transcribe".to_string(),
+        ));
+    }
+
+    if sample_rate != TARGET_SAMPLE_RATE {
+        return Err(TranscriptionError::InvalidAudio(format!(
+            "Audio must be sampled at {} Hz, got {} Hz",
+            TARGET_SAMPLE_RATE, sample_rate
+        )));
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // TranscribingGuard - RAII guard for state transitions
 // ============================================================================
@@ -183,6 +209,8 @@ pub struct SharedTranscriptionModel {
     state: Arc<Mutex<TranscriptionState>>,
     /// Transcription lock: ensures only one transcription operation at a time.
     transcription_lock: Arc<Mutex<()>>,
+    /// Directory the loaded model came from (None when unloaded)
+    loaded_dir: Arc<Mutex<Option<PathBuf>>>,
 }
 
 impl Default for SharedTranscriptionModel {
@@ -198,6 +226,7 @@ impl SharedTranscriptionModel {
             model: Arc::new(Mutex::new(None)),
             state: Arc::new(Mutex::new(TranscriptionState::Unloaded)),
             transcription_lock: Arc::new(Mutex::new(())),
+            loaded_dir: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -228,6 +257,7 @@ impl SharedTranscriptionModel {
             let mut guard = self.model.lock();
             *guard = Some(tdt);
         }
+        *self.loaded_dir.lock() = Some(model_dir.to_path_buf());
 
         {
             let mut state = self.state.lock();
@@ -243,6 +273,11 @@ impl SharedTranscriptionModel {
         self.model.lock().is_some()
     }
 
+    /// Directory the currently loaded model was loaded from
+    pub fn loaded_dir(&self) -> Option<PathBuf> {
+        self.loaded_dir.lock().clone()
+    }
+
     /// Get the current transcription state
     #[allow(dead_code)] // Will be used for UI state display
     pub fn state(&self) -> TranscriptionState {
@@ -277,6 +312,7 @@ impl SharedTranscriptionModel {
             let mut model_guard = self.model.lock();
             *model_guard = None;
         }
+        *self.loaded_dir.lock() = None;
 
         // Set state to Unloaded
         {
@@ -297,16 +333,46 @@ impl SharedTranscriptionModel {
     /// State transitions: current state -> Unloaded -> Idle (on success)
     ///
     /// Used by: create-wake-handler-module-for-sleep-wake-events (spec #3)
-    #[allow(dead_code)]
     pub fn reload(&self, model_dir: &Path) -> TranscriptionResult<()> {
         // Acquire exclusive transcription access - blocks if transcription is active
         let _transcription_permit = self.acquire_transcription_lock();
+        self.reload_locked(model_dir)
+    }
+
+    /// Switch to the model in a new directory, failing fast if busy.
+    ///
+    /// Unlike `reload`, this does not wait for an active transcription to
+    /// finish: it returns `TranscriptionInProgress` instead. The new model is
+    /// loaded before the current one is replaced, so a failed load leaves the
+    /// current model loaded. Used when the user switches model directories at
+    /// runtime.
+    pub fn try_reload(&self, model_dir: &Path) -> TranscriptionResult<()> {
+        let Some(_transcription_permit) = self.transcription_lock.try_lock() else {
+            return Err(TranscriptionError::TranscriptionInProgress);
+        };
 
-        // First unload (without acquiring lock again - we already have it)
+        let path_str = model_dir.to_str().ok_or_else(|| {
+            TranscriptionError::ModelLoadFailed("Invalid path encoding".to_string())
+        })?;
+        crate::info!("Loading Parakeet TDT model from {} to switch to...", path_str);
+        let tdt = ParakeetTDT::from_pretrained(path_str, None)
+            .map_err(|e| TranscriptionError::ModelLoadFailed(e.to_string()))?;
+
+        *self.model.lock() = Some(tdt);
+        *self.loaded_dir.lock() = Some(model_dir.to_path_buf());
+        *self.state.lock() = TranscriptionState::Idle;
+
+        crate::info!("Switched shared Parakeet TDT model to {}", path_str);
+        Ok(())
+    }
+
+    /// Unload and load the model. Caller must hold the transcription lock.
+    fn reload_locked(&self, model_dir: &Path) -> TranscriptionResult<()> {
         {
             let mut model_guard = self.model.lock();
             *model_guard = None;
         }
+        *self.loaded_dir.lock() = None;
         {
             let mut state = self.state.lock();
             *state = TranscriptionState::Unloaded;
@@ -327,6 +393,7 @@ impl SharedTranscriptionModel {
             let mut guard = self.model.lock();
             *guard = Some(tdt);
         }
+        *self.loaded_dir.lock() = Some(model_dir.to_path_buf());
 
         {
             let mut state = self.state.lock();
@@ -362,6 +429,34 @@ impl SharedTranscriptionModel {
         // This catches empty/invalid files that would cause 'index out of bounds' errors.
         validate_wav_for_transcription(file_path)?;
 
+        self.run_transcription(|tdt| tdt.transcribe_file(file_path, None))
+    }
+
+    /// Transcribe audio samples already in memory to text
+    ///
+    /// Feeds the model directly, skipping the WAV round-trip, for audio that
+    /// never lands on disk (e.g. the bundled benchmark sample). Recordings are
+    /// captured to a file by the Swift engine, so they keep using
+    /// `transcribe_file`. `samples` must be mono at 16 kHz. Same state handling and panic
+    /// resilience as `transcribe_file`.
+    pub fn transcribe_samples(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+    ) -> TranscriptionResult<String> {
+        validate_samples_for_transcription(samples, sample_rate)?;
+
+        self.run_transcription(|tdt| {
+            tdt.transcribe_samples(samples.to_vec(), sample_rate, 1, None)
+        })
+    }
+
+    /// Run `transcribe` against the loaded model with the transcription lock
+    /// and state guard held
+    fn run_transcription<F>(&self, transcribe: F) -> TranscriptionResult<String>
+    where
+        F: FnOnce(&mut ParakeetTDT) -> parakeet_rs::Result<TdtResult>,
+    {
         // Acquire exclusive transcription access - blocks if streaming is active
         let _transcription_permit = self.acquire_transcription_lock();
 
@@ -374,11 +469,11 @@ impl SharedTranscriptionModel {
 
             let tdt = model_guard.as_mut().ok_or(TranscriptionError::ModelNotLoaded)?;
 
-            match tdt.transcribe_file(file_path, None) {
+            match transcribe(tdt) {
                 Ok(transcribe_result) => {
                     let fixed_text = fix_parakeet_text(&transcribe_result.tokens);
 
-                    crate::debug!("Transcription result: {:?}", fixed_text);
+                    crate::debug!("Transcription result: {}", loggable_text(&fixed_text));
 
                     Ok(fixed_text)
                 }
@@ -405,6 +500,10 @@ impl TranscriptionService for SharedTranscriptionModel {
         self.transcribe_file(file_path)
     }
 
+    fn transcribe_samples(&self, samples: &[f32], sample_rate: u32) -> TranscriptionResult<String> {
+        self.transcribe_samples(samples, sample_rate)
+    }
+
     fn is_loaded(&self) -> bool {
         self.is_loaded()
     }
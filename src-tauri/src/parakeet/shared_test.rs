@@ -27,6 +27,31 @@ fn test_transcribe_file_returns_error_for_empty_path() {
     assert!(matches!(result, Err(TranscriptionError::InvalidAudio(_))));
 }
 
+#[test]
+fn test_transcribe_samples_rejects_empty_audio() {
+    let model = SharedTranscriptionModel::new();
+    let result = model.transcribe_samples(&[], 16000);
+    assert!(matches!(result, Err(TranscriptionError::InvalidAudio(_))));
+}
+
+#[test]
+fn test_transcribe_samples_rejects_other_sample_rates() {
+    let model = SharedTranscriptionModel::new();
+    let result = model.transcribe_samples(&[0.1; 480], 48000);
+    assert!(matches!(
+        result,
+        Err(TranscriptionError::InvalidAudio(msg)) if msg.contains("16000 Hz")
+    ));
+}
+
+#[test]
+fn test_transcribe_samples_requires_loaded_model() {
+    let model = SharedTranscriptionModel::new();
+    let result = model.transcribe_samples(&[0.1; 160], 16000);
+    assert!(matches!(result, Err(TranscriptionError::ModelNotLoaded)));
+    assert_eq!(model.state(), TranscriptionState::Unloaded);
+}
+
 #[test]
 fn test_load_fails_with_invalid_path() {
     let model = SharedTranscriptionModel::new();
@@ -201,6 +226,7 @@ fn test_unload_sets_model_to_none_and_state_to_unloaded() {
 
     // Model should be unloaded
     assert!(!model.is_loaded());
+    assert_eq!(model.loaded_dir(), None);
 
     // State should be Unloaded
     assert_eq!(model.state(), TranscriptionState::Unloaded);
@@ -249,6 +275,36 @@ fn test_reload_releases_lock_after_failure() {
     // If we get here, the lock was successfully acquired
 }
 
+#[test]
+fn test_try_reload_rejects_while_transcription_in_progress() {
+    let model = SharedTranscriptionModel::new();
+    {
+        let mut state = model.state.lock();
+        *state = TranscriptionState::Idle;
+    }
+
+    let _guard = model.acquire_transcription_lock();
+    let result = model.try_reload(Path::new("/nonexistent/model/path"));
+
+    assert!(matches!(result, Err(TranscriptionError::TranscriptionInProgress)));
+    // Rejected reload must not touch the current model state
+    assert_eq!(model.state(), TranscriptionState::Idle);
+}
+
+#[test]
+fn test_failed_try_reload_keeps_current_model() {
+    let model = SharedTranscriptionModel::new();
+    let current_dir = PathBuf::from("/models/current");
+    *model.loaded_dir.lock() = Some(current_dir.clone());
+    *model.state.lock() = TranscriptionState::Idle;
+
+    let result = model.try_reload(Path::new("/nonexistent/model/path"));
+
+    assert!(matches!(result, Err(TranscriptionError::ModelLoadFailed(_))));
+    assert_eq!(model.loaded_dir(), Some(current_dir));
+    assert_eq!(model.state(), TranscriptionState::Idle);
+}
+
 #[test]
 fn test_unload_is_thread_safe() {
     use std::thread;
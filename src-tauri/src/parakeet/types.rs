@@ -34,6 +34,9 @@ pub enum TranscriptionError {
     /// Audio data is invalid or empty
     #[error("Invalid audio: {0}")]
     InvalidAudio(String),
+    /// Operation rejected because a transcription is running
+    #[error("A transcription is in progress")]
+    TranscriptionInProgress,
     // NOTE: LockPoisoned variant removed - parking_lot::Mutex doesn't poison on panic,
     // so this error case is no longer possible.
 }
@@ -52,6 +55,10 @@ pub trait TranscriptionService: Send + Sync {
     #[must_use = "this returns a Result that should be handled"]
     fn transcribe(&self, file_path: &str) -> TranscriptionResult<String>;
 
+    /// Transcribe 16 kHz mono samples already in memory to text
+    #[must_use = "this returns a Result that should be handled"]
+    fn transcribe_samples(&self, samples: &[f32], sample_rate: u32) -> TranscriptionResult<String>;
+
     /// Check if a model is loaded
     fn is_loaded(&self) -> bool;
 
@@ -13,11 +13,18 @@
 // - Worktree:  ~/.config/heycat-{worktree_id}/
 
 use crate::worktree::WorktreeContext;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Base app directory name
 const APP_DIR_NAME: &str = "heycat";
 
+/// Directory created next to the executable when the OS data dir is unavailable
+const FALLBACK_DIR_NAME: &str = "heycat-data";
+
+/// Set once the fallback location has been warned about
+static FALLBACK_WARNED: AtomicBool = AtomicBool::new(false);
+
 /// Error types for path resolution
 #[derive(Debug, Clone, PartialEq)]
 pub enum PathError {
@@ -56,11 +63,52 @@ fn get_app_dir_name(worktree_context: Option<&WorktreeContext>) -> String {
 /// Returns:
 /// - Main repo: `~/.local/share/heycat/`
 /// - Worktree: `~/.local/share/heycat-{identifier}/`
+///
+/// If the OS data directory is unavailable, falls back to a `heycat-data`
+/// folder next to the executable and logs a one-time warning.
 pub fn get_data_dir(worktree_context: Option<&WorktreeContext>) -> Result<PathBuf, PathError> {
-    let data_dir = dirs::data_dir().ok_or(PathError::DataDirNotFound)?;
+    let exe_path = std::env::current_exe().ok();
+    let (data_dir, is_fallback) = resolve_base_data_dir(dirs::data_dir(), exe_path.as_deref())
+        .ok_or(PathError::DataDirNotFound)?;
+
+    if is_fallback && !FALLBACK_WARNED.swap(true, Ordering::SeqCst) {
+        crate::warn!(
+            "OS data directory unavailable; storing data in fallback location: {}",
+            data_dir.display()
+        );
+    }
+
     Ok(data_dir.join(get_app_dir_name(worktree_context)))
 }
 
+/// Choose the base data directory.
+///
+/// Prefers the OS data directory; otherwise uses a folder next to the
+/// executable. Returns the directory and whether it is the fallback, or
+/// `None` when neither location is known.
+pub fn resolve_base_data_dir(
+    os_data_dir: Option<PathBuf>,
+    exe_path: Option<&Path>,
+) -> Option<(PathBuf, bool)> {
+    if let Some(dir) = os_data_dir {
+        return Some((dir, false));
+    }
+    exe_path
+        .and_then(Path::parent)
+        .map(|parent| (parent.join(FALLBACK_DIR_NAME), true))
+}
+
+/// Get the fallback data directory if it is in use.
+///
+/// Returns `None` when the OS data directory is available.
+pub fn data_dir_fallback_in_use() -> Option<PathBuf> {
+    let exe_path = std::env::current_exe().ok();
+    match resolve_base_data_dir(dirs::data_dir(), exe_path.as_deref()) {
+        Some((dir, true)) => Some(dir),
+        _ => None,
+    }
+}
+
 /// Get the models directory path.
 ///
 /// Returns:
@@ -107,6 +155,20 @@ pub fn ensure_dir_exists(path: &PathBuf) -> Result<PathBuf, PathError> {
     Ok(path.clone())
 }
 
+/// Check whether files can be created in a directory.
+///
+/// Creates the directory if it doesn't exist yet, then writes and removes a
+/// small probe file.
+pub fn is_dir_writable(path: &Path) -> bool {
+    if std::fs::create_dir_all(path).is_err() {
+        return false;
+    }
+    let probe = path.join(".heycat-write-probe");
+    let writable = std::fs::write(&probe, b"").is_ok();
+    let _ = std::fs::remove_file(&probe);
+    writable
+}
+
 #[cfg(test)]
 #[path = "paths_test.rs"]
 mod tests;
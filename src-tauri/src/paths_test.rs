@@ -138,3 +138,47 @@ fn test_ensure_dir_exists_succeeds_if_already_exists() {
     // Cleanup
     let _ = std::fs::remove_dir_all(&temp_dir);
 }
+
+// ==================== Fallback Resolution Tests ====================
+
+#[test]
+fn test_resolve_base_data_dir_prefers_os_dir() {
+    let result = resolve_base_data_dir(
+        Some(PathBuf::from("/home/user/.local/share")),
+        Some(Path::new("/opt/heycat/bin/heycat")),
+    );
+    assert_eq!(result, Some((PathBuf::from("/home/user/.local/share"), false)));
+}
+
+#[test]
+fn test_resolve_base_data_dir_falls_back_next_to_executable() {
+    let result = resolve_base_data_dir(None, Some(Path::new("/opt/heycat/bin/heycat")));
+    assert_eq!(
+        result,
+        Some((PathBuf::from("/opt/heycat/bin/heycat-data"), true))
+    );
+}
+
+#[test]
+fn test_resolve_base_data_dir_none_when_nothing_known() {
+    assert_eq!(resolve_base_data_dir(None, None), None);
+}
+
+// ==================== Writability Tests ====================
+
+#[test]
+fn test_is_dir_writable_creates_missing_dir() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let dir = temp.path().join("recordings");
+    assert!(is_dir_writable(&dir));
+    assert!(dir.is_dir());
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[test]
+fn test_is_dir_writable_false_when_path_is_a_file() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let file = temp.path().join("not-a-dir");
+    std::fs::write(&file, b"x").unwrap();
+    assert!(!is_dir_writable(&file));
+}
@@ -2,12 +2,18 @@
 // Manages silence detection during recording phase
 
 use super::silence::{SilenceConfig, SilenceDetectionResult, SilenceDetector, SilenceStopReason};
-use super::{RecordingManager, RecordingMetadata, RecordingState};
-use crate::audio::{encode_wav, AudioBuffer, SystemFileWriter, TARGET_SAMPLE_RATE};
+use super::{lock_recording_state, RecordingManager, RecordingMetadata, RecordingState};
+use crate::audio::{
+    duration_secs, encode_wav, AudioBuffer, SystemFileWriter, WavBitDepth, WavEncodingError,
+    TARGET_SAMPLE_RATE,
+};
 use crate::audio_constants::{DETECTION_INTERVAL_MS, MIN_DETECTION_SAMPLES};
-use crate::events::{RecordingEventEmitter, RecordingStoppedPayload};
+use crate::events::{
+    current_timestamp, RecordingEmptyPayload, RecordingEventEmitter, RecordingStoppedPayload,
+    SilenceCountdownPayload,
+};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 use std::time::Duration;
@@ -25,8 +31,12 @@ pub struct RecordingDetectors {
     detection_thread: Option<JoinHandle<()>>,
     /// Flag to stop the detection thread
     should_stop: Arc<AtomicBool>,
+    /// Speech threshold (f32 bits) shared with the running detection thread
+    vad_threshold: Arc<AtomicU32>,
     /// Directory for saving recordings (supports worktree isolation)
     recordings_dir: PathBuf,
+    /// Sample format of recordings saved when silence stops them
+    wav_bit_depth: WavBitDepth,
 }
 
 impl RecordingDetectors {
@@ -59,14 +69,41 @@ impl RecordingDetectors {
         silence_config: SilenceConfig,
         recordings_dir: PathBuf,
     ) -> Self {
+        let vad_threshold = Arc::new(AtomicU32::new(
+            silence_config.vad_speech_threshold.to_bits(),
+        ));
         Self {
             silence_config,
             detection_thread: None,
             should_stop: Arc::new(AtomicBool::new(false)),
+            vad_threshold,
             recordings_dir,
+            wav_bit_depth: WavBitDepth::default(),
         }
     }
 
+    /// Change the sample format of recordings saved when silence stops them
+    ///
+    /// Applies from the next recording.
+    pub fn set_wav_bit_depth(&mut self, bit_depth: WavBitDepth) {
+        self.wav_bit_depth = bit_depth;
+    }
+
+    /// Speech threshold used for silence detection
+    pub fn vad_threshold(&self) -> f32 {
+        self.silence_config.vad_speech_threshold
+    }
+
+    /// Change the silence detection speech threshold
+    ///
+    /// Applies to future recordings and to the detector of a recording in
+    /// progress, which picks it up on its next detection pass.
+    pub fn set_vad_threshold(&mut self, threshold: f32) {
+        self.silence_config.vad_speech_threshold = threshold;
+        self.vad_threshold
+            .store(threshold.to_bits(), Ordering::SeqCst);
+    }
+
     /// Check if detection is currently running
     ///
     /// Returns true only if the detection thread exists AND is still actively running.
@@ -117,12 +154,17 @@ impl RecordingDetectors {
         // Reset stop flag
         self.should_stop.store(false, Ordering::SeqCst);
 
-        // Create silence detector
-        let mut silence_detector = SilenceDetector::with_config(self.silence_config.clone());
+        // Create silence detector (countdown progress is forwarded to the UI)
+        let mut silence_detector = SilenceDetector::with_config(SilenceConfig {
+            report_countdown: true,
+            ..self.silence_config.clone()
+        });
         silence_detector.reset();
 
         let should_stop = self.should_stop.clone();
+        let vad_threshold = self.vad_threshold.clone();
         let recordings_dir = self.recordings_dir.clone();
+        let wav_bit_depth = self.wav_bit_depth;
 
         // Spawn detection thread
         let thread_handle = thread::spawn(move || {
@@ -133,8 +175,10 @@ impl RecordingDetectors {
                 audio_thread,
                 emitter,
                 should_stop,
+                vad_threshold,
                 transcription_callback,
                 recordings_dir,
+                wav_bit_depth,
             );
         });
 
@@ -179,8 +223,10 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
     audio_thread: Arc<crate::audio::AudioThreadHandle>,
     emitter: Arc<E>,
     should_stop: Arc<AtomicBool>,
+    vad_threshold: Arc<AtomicU32>,
     transcription_callback: Option<Box<dyn Fn(String) + Send + 'static>>,
     recordings_dir: PathBuf,
+    wav_bit_depth: WavBitDepth,
 ) {
     crate::debug!("[coordinator] Detection loop starting");
 
@@ -201,8 +247,7 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
         }
 
         // Check if still recording
-        let is_recording = recording_manager
-            .lock()
+        let is_recording = lock_recording_state(&recording_manager)
             .map(|m| m.get_state() == RecordingState::Recording)
             .unwrap_or(false);
 
@@ -234,6 +279,12 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                 samples_since_last_check.len()
             );
 
+            // Pick up a threshold changed while recording
+            let threshold = f32::from_bits(vad_threshold.load(Ordering::SeqCst));
+            if threshold != silence_detector.config().vad_speech_threshold {
+                silence_detector.set_vad_speech_threshold(threshold);
+            }
+
             // Feed to silence detector
             let silence_result = silence_detector.process_samples(&samples_since_last_check);
 
@@ -249,7 +300,7 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                     let _ = audio_thread.stop();
 
                     // Transition to appropriate state
-                    if let Ok(mut manager) = recording_manager.lock() {
+                    if let Some(mut manager) = lock_recording_state(&recording_manager) {
                         match reason {
                             SilenceStopReason::SilenceAfterSpeech => {
                                 // Normal completion - save recording
@@ -268,13 +319,22 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                                         match buf.lock() {
                                             Ok(samples) => {
                                                 let count = samples.len();
-                                                let duration = count as f64 / sample_rate as f64;
-                                                let writer = SystemFileWriter::new(recordings_dir.clone());
+                                                let duration = duration_secs(count as u64, sample_rate);
+                                                let writer = SystemFileWriter::new(recordings_dir.clone())
+                                                    .with_bit_depth(wav_bit_depth);
                                                 match encode_wav(&samples, sample_rate, &writer) {
                                                     Ok(path) => {
                                                         crate::info!("[coordinator] WAV saved to: {}", path);
                                                         (path, count, duration)
                                                     }
+                                                    Err(WavEncodingError::EmptyInput) => {
+                                                        crate::warn!("[coordinator] No audio captured, skipping WAV");
+                                                        emitter.emit_recording_empty(RecordingEmptyPayload {
+                                                            source: "silence-detection".to_string(),
+                                                            timestamp: current_timestamp(),
+                                                        });
+                                                        (String::new(), 0, 0.0)
+                                                    }
                                                     Err(e) => {
                                                         crate::error!("[coordinator] WAV encoding failed: {:?}", e);
                                                         (String::new(), count, duration)
@@ -282,7 +342,8 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                                                 }
                                             }
                                             Err(e) => {
-                                                crate::error!("[coordinator] Buffer lock failed: {:?}", e);
+                                                // Recovered (and reported) when the buffer is dropped on the way to Idle
+                                                crate::error!("[coordinator] Buffer lock poisoned, discarding audio: {:?}", e);
                                                 (String::new(), 0, 0.0)
                                             }
                                         }
@@ -299,6 +360,8 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                                     file_path: file_path.clone(),
                                     sample_count,
                                     stop_reason: None,
+                                    native_sample_rate: None,
+                                    trigger_source: manager.trigger_source(),
                                 };
                                 emitter.emit_recording_stopped(RecordingStoppedPayload {
                                     metadata: metadata.clone(),
@@ -331,6 +394,17 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                     }
                     break;
                 }
+                SilenceDetectionResult::Countdown(countdown) => {
+                    crate::trace!(
+                        "[coordinator] Silence countdown: {}ms remaining",
+                        countdown.remaining_ms()
+                    );
+                    emitter.emit_silence_countdown(SilenceCountdownPayload {
+                        elapsed_ms: countdown.elapsed_ms,
+                        threshold_ms: countdown.threshold_ms,
+                        remaining_ms: countdown.remaining_ms(),
+                    });
+                }
                 SilenceDetectionResult::Continue => {
                     // Keep recording
                     crate::trace!("[coordinator] Silence detection: continue");
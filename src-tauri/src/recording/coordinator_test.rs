@@ -17,3 +17,17 @@ fn test_stop_without_start() {
     detectors.stop_monitoring();
     assert!(!detectors.is_running());
 }
+
+#[test]
+fn test_set_vad_threshold_updates_config_and_shared_value() {
+    let mut detectors = RecordingDetectors::new();
+    let default = SilenceConfig::default().vad_speech_threshold;
+    assert_eq!(detectors.vad_threshold(), default);
+
+    detectors.set_vad_threshold(0.3);
+    assert_eq!(detectors.vad_threshold(), 0.3);
+    assert_eq!(
+        f32::from_bits(detectors.vad_threshold.load(Ordering::SeqCst)),
+        0.3
+    );
+}
@@ -6,8 +6,15 @@ mod state;
 mod vad;
 
 pub use coordinator::RecordingDetectors;
-pub use silence::SilenceConfig;
-pub use state::{AudioData, RecordingManager, RecordingMetadata, RecordingState};
+pub use silence::{SilenceConfig, SilenceTiming, SILENCE_TIMING_KEY};
+pub use vad::{
+    silence_vad_threshold_from_setting, validate_vad_threshold, VadContext, VadThresholds,
+    SILENCE_VAD_THRESHOLD_KEY, VAD_MODEL_PATH_KEY,
+};
+pub use state::{
+    lock_recording_state, AudioData, LockPoisonRecovery, RecordingManager, RecordingMetadata,
+    RecordingState, TriggerSource, LOCK_POISON_RECOVERY_KEY, MAX_QUEUED_STARTS, QUEUE_DEPTH_KEY,
+};
 
 #[cfg(test)]
 pub use state::RecordingStateError;
@@ -2,13 +2,47 @@
 // Uses VAD (Voice Activity Detection) to identify end of speech
 
 use super::vad::{create_vad, VadConfig};
+use std::path::PathBuf;
 use crate::audio_constants::{
     DEFAULT_SAMPLE_RATE, NO_SPEECH_TIMEOUT_MS, PAUSE_TOLERANCE_MS, SILENCE_DURATION_MS,
-    SILENCE_MIN_SPEECH_FRAMES, VAD_CHUNK_SIZE_16KHZ, VAD_THRESHOLD_SILENCE,
+    SILENCE_MIN_ONSET_SPEECH_FRAMES, SILENCE_MIN_SPEECH_FRAMES, VAD_CHUNK_SIZE_16KHZ,
+    VAD_THRESHOLD_SILENCE,
 };
 use std::time::Instant;
 use voice_activity_detector::VoiceActivityDetector;
 
+/// Settings key for how silence durations are timed ("wallClock" or "sampleCount")
+pub const SILENCE_TIMING_KEY: &str = "silence.timing";
+
+/// Clock used to time silence periods and the no-speech timeout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SilenceTiming {
+    /// Time elapsed between calls to `process_samples`
+    #[default]
+    WallClock,
+    /// Audio time derived from the number of samples processed
+    ///
+    /// Independent of when frames arrive, so a loaded system that delivers
+    /// audio in irregular bursts auto-stops at the same point in the audio.
+    SampleCount,
+}
+
+impl SilenceTiming {
+    /// Resolve the timing mode from its settings value
+    ///
+    /// Unset or unrecognised values fall back to wall-clock timing.
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            Some("sampleCount") => SilenceTiming::SampleCount,
+            Some("wallClock") | None => SilenceTiming::WallClock,
+            Some(other) => {
+                crate::warn!("Ignoring unknown silence timing '{}', using wall clock", other);
+                SilenceTiming::WallClock
+            }
+        }
+    }
+}
+
 /// Reason why recording was automatically stopped due to silence detection
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SilenceStopReason {
@@ -32,6 +66,24 @@ pub struct SilenceConfig {
     pub pause_tolerance_ms: u32,
     /// Sample rate for VAD processing (default: 16000)
     pub sample_rate: u32,
+    /// Report silence-after-speech progress as `Countdown` results (default: false)
+    pub report_countdown: bool,
+    /// Consecutive VAD chunks over threshold required to declare speech (default: 2)
+    ///
+    /// A value of 1 reacts to any single chunk; higher values filter out brief
+    /// noise spikes at the cost of slightly later speech onset detection.
+    pub min_speech_frames: usize,
+    /// Consecutive VAD chunks required before the recording counts as having
+    /// speech (default: 8, ~256ms at 16kHz)
+    ///
+    /// Until then only the no-speech timeout applies, so a brief blip before
+    /// the user starts talking doesn't switch to the silence-after-speech
+    /// timer. Raised to at least `min_speech_frames`.
+    pub min_onset_speech_frames: usize,
+    /// How silence and no-speech durations are measured (default: wall clock)
+    pub timing: SilenceTiming,
+    /// Custom ONNX VAD model (default: None, the bundled Silero model)
+    pub vad_model_path: Option<PathBuf>,
 }
 
 impl Default for SilenceConfig {
@@ -42,19 +94,74 @@ impl Default for SilenceConfig {
             no_speech_timeout_ms: NO_SPEECH_TIMEOUT_MS,
             pause_tolerance_ms: PAUSE_TOLERANCE_MS,
             sample_rate: DEFAULT_SAMPLE_RATE,
+            report_countdown: false,
+            min_speech_frames: SILENCE_MIN_SPEECH_FRAMES,
+            min_onset_speech_frames: SILENCE_MIN_ONSET_SPEECH_FRAMES,
+            timing: SilenceTiming::WallClock,
+            vad_model_path: None,
         }
     }
 }
 
+/// Progress of the current silence period toward auto-stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SilenceCountdown {
+    /// How long the current silence has lasted in milliseconds
+    pub elapsed_ms: u64,
+    /// Silence duration that triggers auto-stop in milliseconds
+    pub threshold_ms: u64,
+}
+
+impl SilenceCountdown {
+    /// Milliseconds left before auto-stop fires
+    pub fn remaining_ms(&self) -> u64 {
+        self.threshold_ms.saturating_sub(self.elapsed_ms)
+    }
+}
+
 /// Result of processing audio samples
 #[derive(Debug, Clone, PartialEq)]
 pub enum SilenceDetectionResult {
     /// Continue recording, no action needed
     Continue,
+    /// Continue recording, but silence after speech is counting toward
+    /// auto-stop (only reported when `report_countdown` is enabled)
+    Countdown(SilenceCountdown),
     /// Stop recording due to silence
     Stop(SilenceStopReason),
 }
 
+/// Advance the run of consecutive over-threshold VAD chunks
+///
+/// Returns the new run length: incremented for a speech chunk, reset to 0
+/// otherwise.
+fn advance_speech_run(run: usize, probability: f32, threshold: f32) -> usize {
+    if probability >= threshold {
+        run + 1
+    } else {
+        0
+    }
+}
+
+/// Create the VAD for silence detection
+///
+/// A custom model that fails to load is logged and replaced by the bundled
+/// model, so auto-stop keeps working.
+fn create_silence_vad(vad_config: &VadConfig) -> Option<VoiceActivityDetector> {
+    match create_vad(vad_config) {
+        Ok(vad) => Some(vad),
+        Err(e) if vad_config.vad_model_path.is_some() => {
+            crate::warn!("[silence] {}, falling back to the bundled VAD model", e);
+            create_vad(&VadConfig {
+                vad_model_path: None,
+                ..vad_config.clone()
+            })
+            .ok()
+        }
+        Err(_) => None,
+    }
+}
+
 /// Silence detector for automatic recording stop
 ///
 /// Processes audio samples and determines when to stop recording based on:
@@ -70,6 +177,16 @@ pub struct SilenceDetector {
     recording_start: Instant,
     /// Voice activity detector for speech detection
     vad: Option<VoiceActivityDetector>,
+    /// Consecutive VAD chunks required before declaring speech
+    min_speech_frames: usize,
+    /// Consecutive VAD chunks required before the first speech counts
+    min_onset_speech_frames: usize,
+    /// Current run of consecutive over-threshold chunks (spans process calls)
+    consecutive_speech_frames: usize,
+    /// Samples processed since recording started (sample-count timing)
+    samples_processed: u64,
+    /// Samples in the current silence period (sample-count timing)
+    silence_samples: u64,
 }
 
 impl SilenceDetector {
@@ -86,10 +203,11 @@ impl SilenceDetector {
         let vad_config = VadConfig {
             speech_threshold: config.vad_speech_threshold,
             sample_rate: config.sample_rate,
-            min_speech_frames: SILENCE_MIN_SPEECH_FRAMES,
+            min_speech_frames: config.min_speech_frames.max(1),
+            vad_model_path: config.vad_model_path.clone(),
         };
 
-        let vad = create_vad(&vad_config).ok();
+        let vad = create_silence_vad(&vad_config);
 
         if vad.is_some() {
             crate::debug!("[silence] VAD initialized (threshold={})", config.vad_speech_threshold);
@@ -97,12 +215,21 @@ impl SilenceDetector {
             crate::debug!("[silence] VAD initialization failed, speech detection will be disabled");
         }
 
+        let min_onset_speech_frames = config
+            .min_onset_speech_frames
+            .max(vad_config.min_speech_frames);
+
         Self {
             config,
             has_detected_speech: false,
             silence_start: None,
             recording_start: Instant::now(),
             vad,
+            min_speech_frames: vad_config.min_speech_frames,
+            min_onset_speech_frames,
+            consecutive_speech_frames: 0,
+            samples_processed: 0,
+            silence_samples: 0,
         }
     }
 
@@ -112,18 +239,29 @@ impl SilenceDetector {
         self.has_detected_speech = false;
         self.silence_start = None;
         self.recording_start = Instant::now();
+        self.consecutive_speech_frames = 0;
+        self.samples_processed = 0;
+        self.silence_samples = 0;
 
         // Reinitialize VAD for fresh state using unified factory
         let vad_config = VadConfig {
             speech_threshold: self.config.vad_speech_threshold,
             sample_rate: self.config.sample_rate,
-            min_speech_frames: SILENCE_MIN_SPEECH_FRAMES,
+            min_speech_frames: self.min_speech_frames,
+            vad_model_path: self.config.vad_model_path.clone(),
         };
-        self.vad = create_vad(&vad_config).ok();
+        self.vad = create_silence_vad(&vad_config);
+    }
+
+    /// Change the speech threshold without resetting the session
+    ///
+    /// Applies from the next processed chunk; the current speech run is kept.
+    pub fn set_vad_speech_threshold(&mut self, threshold: f32) {
+        crate::debug!("[silence] VAD threshold changed to {}", threshold);
+        self.config.vad_speech_threshold = threshold;
     }
 
     /// Get the configuration
-    #[allow(dead_code)] // Utility method for introspection
     pub fn config(&self) -> &SilenceConfig {
         &self.config
     }
@@ -134,11 +272,49 @@ impl SilenceDetector {
         self.has_detected_speech
     }
 
+    /// Convert a sample count to milliseconds of audio
+    fn samples_to_ms(&self, samples: u64) -> u64 {
+        samples * 1000 / self.config.sample_rate.max(1) as u64
+    }
+
+    /// Time since recording started, per the configured timing mode
+    fn elapsed_since_start_ms(&self) -> u64 {
+        match self.config.timing {
+            SilenceTiming::WallClock => self.recording_start.elapsed().as_millis() as u64,
+            SilenceTiming::SampleCount => self.samples_to_ms(self.samples_processed),
+        }
+    }
+
+    /// Length of the current silence period, per the configured timing mode
+    fn silence_elapsed_ms(&self) -> u64 {
+        match self.config.timing {
+            SilenceTiming::WallClock => self
+                .silence_start
+                .map(|start| start.elapsed().as_millis() as u64)
+                .unwrap_or(0),
+            SilenceTiming::SampleCount => self.samples_to_ms(self.silence_samples),
+        }
+    }
+
+    /// Consecutive speech chunks needed for the current state to count as speech
+    ///
+    /// Before any speech has been detected the stricter onset requirement
+    /// applies; afterwards `min_speech_frames` is enough to resume.
+    fn required_speech_frames(&self) -> usize {
+        if self.has_detected_speech {
+            self.min_speech_frames
+        } else {
+            self.min_onset_speech_frames
+        }
+    }
+
     /// Check if speech is present using VAD
     ///
     /// Processes audio in 512-sample chunks (required by Silero VAD at 16kHz).
-    /// Returns true if any chunk has speech probability above threshold.
-    fn check_vad(&mut self, samples: &[f32]) -> bool {
+    /// Returns true once `required` consecutive chunks have speech probability
+    /// above threshold. The run carries over between calls, so speech
+    /// straddling two batches is still counted.
+    fn check_vad(&mut self, samples: &[f32], required: usize) -> bool {
         let vad = match &mut self.vad {
             Some(v) => v,
             None => {
@@ -155,7 +331,12 @@ impl SilenceDetector {
             if chunk.len() == chunk_size {
                 let probability = vad.predict(chunk.to_vec());
                 max_probability = max_probability.max(probability);
-                if probability >= self.config.vad_speech_threshold {
+                self.consecutive_speech_frames = advance_speech_run(
+                    self.consecutive_speech_frames,
+                    probability,
+                    self.config.vad_speech_threshold,
+                );
+                if self.consecutive_speech_frames >= required {
                     return true; // Speech detected
                 }
             }
@@ -168,12 +349,16 @@ impl SilenceDetector {
     /// Process a frame of audio samples and return detection result
     ///
     /// Call this periodically with frames of audio (e.g., 100ms chunks).
-    /// Returns whether to continue recording or stop (with reason).
+    /// Returns whether to continue recording or stop (with reason). With
+    /// `report_countdown` enabled, silence after speech yields `Countdown`
+    /// with the elapsed silence and threshold instead of `Continue`.
     pub fn process_samples(&mut self, samples: &[f32]) -> SilenceDetectionResult {
         let now = Instant::now();
+        self.samples_processed += samples.len() as u64;
 
         // Use VAD to detect speech
-        let has_speech = self.check_vad(samples);
+        let required = self.required_speech_frames();
+        let has_speech = self.check_vad(samples, required);
         let is_silent = !has_speech;
 
         if is_silent {
@@ -182,39 +367,47 @@ impl SilenceDetector {
                 // Start tracking silence period
                 crate::debug!("[silence] Silence period started (VAD)");
                 self.silence_start = Some(now);
+                self.silence_samples = 0;
             }
+            self.silence_samples += samples.len() as u64;
 
-            let silence_duration = self.silence_start.unwrap().elapsed();
+            let silence_ms = self.silence_elapsed_ms();
 
             if !self.has_detected_speech {
                 // No speech yet - check for no-speech timeout
-                let total_elapsed = self.recording_start.elapsed();
+                let total_elapsed_ms = self.elapsed_since_start_ms();
                 crate::trace!(
-                    "[silence] No speech yet, elapsed={:?}, timeout={}ms",
-                    total_elapsed,
+                    "[silence] No speech yet, elapsed={}ms, timeout={}ms",
+                    total_elapsed_ms,
                     self.config.no_speech_timeout_ms
                 );
-                if total_elapsed.as_millis() >= self.config.no_speech_timeout_ms as u128 {
+                if total_elapsed_ms >= self.config.no_speech_timeout_ms as u64 {
                     crate::info!(
-                        "[silence] NO_SPEECH_TIMEOUT triggered after {:?}",
-                        total_elapsed
+                        "[silence] NO_SPEECH_TIMEOUT triggered after {}ms",
+                        total_elapsed_ms
                     );
                     return SilenceDetectionResult::Stop(SilenceStopReason::NoSpeechTimeout);
                 }
             } else {
                 // Had speech - check for silence after speech (ignoring brief pauses)
                 crate::trace!(
-                    "[silence] Silence after speech, duration={:?}, threshold={}ms",
-                    silence_duration,
+                    "[silence] Silence after speech, duration={}ms, threshold={}ms",
+                    silence_ms,
                     self.config.silence_duration_ms
                 );
-                if silence_duration.as_millis() >= self.config.silence_duration_ms as u128 {
+                if silence_ms >= self.config.silence_duration_ms as u64 {
                     crate::info!(
-                        "[silence] SILENCE_AFTER_SPEECH triggered after {:?} of silence",
-                        silence_duration
+                        "[silence] SILENCE_AFTER_SPEECH triggered after {}ms of silence",
+                        silence_ms
                     );
                     return SilenceDetectionResult::Stop(SilenceStopReason::SilenceAfterSpeech);
                 }
+                if self.config.report_countdown {
+                    return SilenceDetectionResult::Countdown(SilenceCountdown {
+                        elapsed_ms: silence_ms,
+                        threshold_ms: self.config.silence_duration_ms as u64,
+                    });
+                }
             }
         } else {
             // Speech detected by VAD
@@ -226,6 +419,7 @@ impl SilenceDetector {
             }
             self.has_detected_speech = true;
             self.silence_start = None;
+            self.silence_samples = 0;
         }
 
         SilenceDetectionResult::Continue
@@ -59,3 +59,171 @@ fn test_continues_while_waiting() {
     assert_eq!(result, SilenceDetectionResult::Continue);
     assert!(!detector.has_detected_speech());
 }
+
+#[test]
+fn test_countdown_reported_during_silence_after_speech() {
+    let config = SilenceConfig {
+        silence_duration_ms: 2000,
+        report_countdown: true,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    let silent_samples = vec![0.0; 512];
+    detector.has_detected_speech = true;
+
+    let result = detector.process_samples(&silent_samples);
+
+    match result {
+        SilenceDetectionResult::Countdown(countdown) => {
+            assert_eq!(countdown.threshold_ms, 2000);
+            assert!(countdown.elapsed_ms < 2000);
+            assert_eq!(countdown.remaining_ms(), 2000 - countdown.elapsed_ms);
+        }
+        other => panic!("Expected Countdown, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_countdown_not_reported_when_disabled() {
+    let mut detector = SilenceDetector::new();
+    let silent_samples = vec![0.0; 512];
+    detector.has_detected_speech = true;
+
+    let result = detector.process_samples(&silent_samples);
+    assert_eq!(result, SilenceDetectionResult::Continue);
+}
+
+#[test]
+fn test_speech_run_requires_consecutive_frames() {
+    let threshold = 0.5;
+    let mut run = 0;
+
+    run = advance_speech_run(run, 0.9, threshold);
+    assert_eq!(run, 1);
+
+    // A single quiet chunk breaks the run (noise spike filtered)
+    run = advance_speech_run(run, 0.1, threshold);
+    assert_eq!(run, 0);
+
+    run = advance_speech_run(run, 0.6, threshold);
+    run = advance_speech_run(run, 0.5, threshold);
+    assert_eq!(run, 2);
+}
+
+#[test]
+fn test_min_speech_frames_clamped_to_one() {
+    let config = SilenceConfig {
+        min_speech_frames: 0,
+        ..Default::default()
+    };
+    let detector = SilenceDetector::with_config(config);
+    assert_eq!(detector.min_speech_frames, 1);
+}
+
+#[test]
+fn test_onset_frames_apply_until_speech_detected() {
+    let config = SilenceConfig {
+        min_speech_frames: 2,
+        min_onset_speech_frames: 6,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    assert_eq!(detector.required_speech_frames(), 6);
+
+    detector.has_detected_speech = true;
+    assert_eq!(detector.required_speech_frames(), 2);
+}
+
+#[test]
+fn test_onset_frames_raised_to_min_speech_frames() {
+    let config = SilenceConfig {
+        min_speech_frames: 3,
+        min_onset_speech_frames: 1,
+        ..Default::default()
+    };
+    let detector = SilenceDetector::with_config(config);
+    assert_eq!(detector.required_speech_frames(), 3);
+}
+
+fn sample_count_config() -> SilenceConfig {
+    SilenceConfig {
+        timing: SilenceTiming::SampleCount,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_sample_count_no_speech_timeout_ignores_wall_clock() {
+    let mut detector = SilenceDetector::with_config(SilenceConfig {
+        no_speech_timeout_ms: 100,
+        ..sample_count_config()
+    });
+    // 512 samples at 16kHz = 32ms per batch, delivered back to back
+    let silent_samples = vec![0.0; 512];
+
+    for _ in 0..3 {
+        assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+    }
+    assert_eq!(
+        detector.process_samples(&silent_samples),
+        SilenceDetectionResult::Stop(SilenceStopReason::NoSpeechTimeout)
+    );
+}
+
+#[test]
+fn test_sample_count_silence_after_speech() {
+    let mut detector = SilenceDetector::with_config(SilenceConfig {
+        silence_duration_ms: 64,
+        ..sample_count_config()
+    });
+    detector.has_detected_speech = true;
+    let silent_samples = vec![0.0; 512];
+
+    assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+    assert_eq!(
+        detector.process_samples(&silent_samples),
+        SilenceDetectionResult::Stop(SilenceStopReason::SilenceAfterSpeech)
+    );
+}
+
+#[test]
+fn test_sample_count_countdown_uses_audio_time() {
+    let mut detector = SilenceDetector::with_config(SilenceConfig {
+        silence_duration_ms: 2000,
+        report_countdown: true,
+        ..sample_count_config()
+    });
+    detector.has_detected_speech = true;
+
+    let result = detector.process_samples(&[0.0; 1600]);
+    assert_eq!(
+        result,
+        SilenceDetectionResult::Countdown(SilenceCountdown {
+            elapsed_ms: 100,
+            threshold_ms: 2000,
+        })
+    );
+}
+
+#[test]
+fn test_silence_timing_from_setting() {
+    assert_eq!(SilenceTiming::from_setting(None), SilenceTiming::WallClock);
+    assert_eq!(SilenceTiming::from_setting(Some("wallClock")), SilenceTiming::WallClock);
+    assert_eq!(SilenceTiming::from_setting(Some("sampleCount")), SilenceTiming::SampleCount);
+    assert_eq!(SilenceTiming::from_setting(Some("bogus")), SilenceTiming::WallClock);
+}
+
+#[test]
+fn test_invalid_custom_vad_model_falls_back_to_bundled() {
+    let detector = SilenceDetector::with_config(SilenceConfig {
+        vad_model_path: Some(PathBuf::from("/nonexistent/custom-vad.onnx")),
+        ..Default::default()
+    });
+    assert!(detector.vad.is_some());
+}
+
+#[test]
+fn test_default_onset_frames_stricter_than_min_speech_frames() {
+    let detector = SilenceDetector::with_config(SilenceConfig::default());
+    assert!(detector.required_speech_frames() > detector.min_speech_frames);
+}
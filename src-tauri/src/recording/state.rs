@@ -1,7 +1,46 @@
 // Recording state management for Tauri application
 
-use crate::audio::{AudioBuffer, StopReason, TARGET_SAMPLE_RATE};
+use crate::audio::{duration_secs, AudioBuffer, StopReason, TARGET_SAMPLE_RATE};
+use crate::events::{
+    current_timestamp, RecordingEventEmitter, RecordingStateChangedPayload,
+    RecordingStateRecoveredPayload,
+};
 use serde::Serialize;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Settings key for how many start requests may wait while transcribing (0 disables)
+pub const QUEUE_DEPTH_KEY: &str = "recording.queueDepth";
+
+/// Settings key for what to do when a recording lock is poisoned ("reset" or "fail")
+pub const LOCK_POISON_RECOVERY_KEY: &str = "recording.lockPoisonRecovery";
+
+/// What to do when a thread panicked while holding a recording lock
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LockPoisonRecovery {
+    /// Discard the recording, reset to Idle and keep going (default)
+    #[default]
+    Reset,
+    /// Refuse to use the poisoned state; recording stays unavailable until restart
+    Fail,
+}
+
+impl LockPoisonRecovery {
+    /// Parse the settings value, falling back to `Reset` for unknown values
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            None | Some("reset") => Self::Reset,
+            Some("fail") => Self::Fail,
+            Some(other) => {
+                crate::warn!("Unknown lock poison recovery '{}', using reset", other);
+                Self::Reset
+            }
+        }
+    }
+}
+
+/// Most start requests that can be queued behind a transcription in progress
+pub const MAX_QUEUED_STARTS: usize = 1;
 
 /// Recording state enum representing the current state of the recording process
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -58,6 +97,39 @@ pub struct AudioData {
     pub duration_secs: f64,
 }
 
+/// What started a recording
+///
+/// Stored in Turso by variant name; `as_str` and `FromStr` use the same names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, serde::Deserialize)]
+pub enum TriggerSource {
+    /// The global recording hotkey (toggle or push-to-talk)
+    Hotkey,
+    /// The record button in the UI
+    Button,
+}
+
+impl TriggerSource {
+    /// Stable name of the trigger, as serialized and stored
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TriggerSource::Hotkey => "Hotkey",
+            TriggerSource::Button => "Button",
+        }
+    }
+}
+
+impl std::str::FromStr for TriggerSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Hotkey" => Ok(TriggerSource::Hotkey),
+            "Button" => Ok(TriggerSource::Button),
+            _ => Err(format!("Unknown trigger source: {}", s)),
+        }
+    }
+}
+
 /// Metadata returned after a successful recording
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RecordingMetadata {
@@ -69,6 +141,10 @@ pub struct RecordingMetadata {
     pub sample_count: usize,
     /// Why recording stopped (None = user initiated, Some = auto-stopped)
     pub stop_reason: Option<StopReason>,
+    /// Whether the device delivered 16kHz without resampling (None if unknown)
+    pub native_sample_rate: Option<bool>,
+    /// What started the recording (None if unknown)
+    pub trigger_source: Option<TriggerSource>,
 }
 
 /// Retained recording data from the last completed recording
@@ -83,6 +159,10 @@ struct LastRecording {
 struct ActiveRecording {
     /// Actual sample rate from the audio device
     sample_rate: u32,
+    /// When the recording started
+    started_at: Instant,
+    /// What started the recording
+    trigger_source: Option<TriggerSource>,
 }
 
 /// Manager for recording state with thread-safe access
@@ -94,6 +174,14 @@ pub struct RecordingManager {
     active_recording: Option<ActiveRecording>,
     /// Retained audio data from the last recording for transcription
     last_recording: Option<LastRecording>,
+    /// Receives a recording_state_changed event on every state change
+    state_emitter: Option<Arc<dyn RecordingEventEmitter>>,
+    /// How many start requests may queue while transcribing (0 disables queueing)
+    queue_depth: usize,
+    /// Start requests waiting for the transcriptions in progress to finish
+    queued_starts: usize,
+    /// What to do when a recording lock is found poisoned
+    poison_recovery: LockPoisonRecovery,
 }
 
 impl RecordingManager {
@@ -104,6 +192,84 @@ impl RecordingManager {
             audio_buffer: None,
             active_recording: None,
             last_recording: None,
+            state_emitter: None,
+            queue_depth: 0,
+            queued_starts: 0,
+            poison_recovery: LockPoisonRecovery::default(),
+        }
+    }
+
+    /// Create a new RecordingManager that reports state changes to `emitter`
+    pub fn with_state_emitter(emitter: Arc<dyn RecordingEventEmitter>) -> Self {
+        Self {
+            state_emitter: Some(emitter),
+            ..Self::new()
+        }
+    }
+
+    /// Set how many start requests may queue while transcribing
+    ///
+    /// 0 disables queueing; values above `MAX_QUEUED_STARTS` are capped.
+    pub fn set_queue_depth(&mut self, depth: usize) {
+        self.queue_depth = depth.min(MAX_QUEUED_STARTS);
+        self.queued_starts = self.queued_starts.min(self.queue_depth);
+    }
+
+    /// Set what to do when a recording lock is found poisoned
+    pub fn set_lock_poison_recovery(&mut self, recovery: LockPoisonRecovery) {
+        self.poison_recovery = recovery;
+    }
+
+    /// Queue a start request behind the transcriptions in progress
+    ///
+    /// A stopped recording returns to Idle as soon as its audio is saved, while
+    /// its transcription still runs, so `transcribing` is the busy signal.
+    /// Returns true if the request was queued: only while Idle and
+    /// transcribing, with queueing enabled and the queue not full.
+    pub fn queue_start(&mut self, transcribing: bool) -> bool {
+        if self.state != RecordingState::Idle
+            || !transcribing
+            || self.queued_starts >= self.queue_depth
+        {
+            return false;
+        }
+        self.queued_starts += 1;
+        crate::info!(
+            "Recording start queued until transcription completes ({}/{})",
+            self.queued_starts,
+            self.queue_depth
+        );
+        true
+    }
+
+    /// Number of start requests waiting for transcription to complete
+    #[cfg(test)]
+    pub fn queued_starts(&self) -> usize {
+        self.queued_starts
+    }
+
+    /// Take one queued start request, if any, to start it
+    pub fn take_queued_start(&mut self) -> bool {
+        if self.queued_starts == 0 {
+            return false;
+        }
+        self.queued_starts -= 1;
+        true
+    }
+
+    /// Change state and emit recording_state_changed if it actually changed
+    fn set_state(&mut self, new_state: RecordingState) {
+        let from = self.state;
+        self.state = new_state;
+        if from == new_state {
+            return;
+        }
+        if let Some(ref emitter) = self.state_emitter {
+            emitter.emit_recording_state_changed(RecordingStateChangedPayload {
+                from,
+                to: new_state,
+                timestamp: current_timestamp(),
+            });
         }
     }
 
@@ -131,8 +297,12 @@ impl RecordingManager {
 
         let buffer = AudioBuffer::new();
         self.audio_buffer = Some(buffer.clone());
-        self.active_recording = Some(ActiveRecording { sample_rate });
-        self.state = RecordingState::Recording;
+        self.active_recording = Some(ActiveRecording {
+            sample_rate,
+            started_at: Instant::now(),
+            trigger_source: None,
+        });
+        self.set_state(RecordingState::Recording);
         Ok(buffer)
     }
 
@@ -146,6 +316,22 @@ impl RecordingManager {
         }
     }
 
+    /// Record what started the current recording
+    ///
+    /// Only works while a recording is active.
+    pub fn set_trigger_source(&mut self, source: TriggerSource) {
+        if let Some(ref mut active) = self.active_recording {
+            active.trigger_source = Some(source);
+        }
+    }
+
+    /// What started the current recording, None if unknown or not recording
+    pub fn trigger_source(&self) -> Option<TriggerSource> {
+        self.active_recording
+            .as_ref()
+            .and_then(|r| r.trigger_source)
+    }
+
     /// Transition to a new state with validation
     ///
     /// Valid transitions:
@@ -177,7 +363,7 @@ impl RecordingManager {
             self.active_recording = None;
         }
 
-        self.state = new_state;
+        self.set_state(new_state);
         Ok(())
     }
 
@@ -188,6 +374,16 @@ impl RecordingManager {
         self.active_recording.as_ref().map(|r| r.sample_rate)
     }
 
+    /// Get the time elapsed since the current recording started
+    ///
+    /// Returns None unless in Recording state.
+    pub fn get_elapsed(&self) -> Option<Duration> {
+        if self.state != RecordingState::Recording {
+            return None;
+        }
+        self.active_recording.as_ref().map(|r| r.started_at.elapsed())
+    }
+
     /// Retain audio buffer samples for transcription before clearing.
     ///
     /// Called during Processing -> Idle transition. The audio_buffer should always
@@ -209,7 +405,13 @@ impl RecordingManager {
                     });
                 }
                 Err(e) => {
+                    // The buffer is dropped on the way to Idle, so a fresh one is
+                    // created for the next recording; just tell the UI the audio is gone
                     crate::error!("Failed to retain buffer (lock poisoned): {}", e);
+                    self.last_recording = None;
+                    if self.poison_recovery == LockPoisonRecovery::Reset {
+                        self.emit_state_recovered("audio_buffer", self.state);
+                    }
                 }
             }
         }
@@ -230,12 +432,8 @@ impl RecordingManager {
         match &self.last_recording {
             Some(recording) => {
                 let sample_count = recording.samples.len();
-                // Guard against division by zero (should never happen, but defensive)
-                let duration_secs = if recording.sample_rate > 0 {
-                    sample_count as f64 / recording.sample_rate as f64
-                } else {
-                    0.0
-                };
+                // duration_secs guards against a zero sample rate
+                let duration_secs = duration_secs(sample_count as u64, recording.sample_rate);
                 Ok(AudioData {
                     samples: recording.samples.clone(),
                     sample_rate: recording.sample_rate,
@@ -256,13 +454,52 @@ impl RecordingManager {
     /// Force reset to Idle state, clearing any audio buffer
     ///
     /// Use for error recovery when normal state transitions aren't possible
-    /// (e.g., capture failure during start_recording)
+    /// (e.g., capture failure during start_recording). Queued starts are dropped.
     pub fn reset_to_idle(&mut self) {
-        self.state = RecordingState::Idle;
+        self.queued_starts = 0;
+        self.set_state(RecordingState::Idle);
         self.audio_buffer = None;
         self.active_recording = None;
     }
 
+    /// Reset to Idle after a poisoned lock, discarding all audio
+    ///
+    /// The audio buffer is dropped, so the next recording starts with a fresh
+    /// one; a recording in progress also has its engine capture stopped and
+    /// the capture file deleted. Emits recording_state_recovered. Returns false (and changes
+    /// nothing) when recovery is configured as `LockPoisonRecovery::Fail`.
+    pub fn recover_from_poisoned_lock(&mut self, lock: &str) -> bool {
+        if self.poison_recovery == LockPoisonRecovery::Fail {
+            crate::error!("Recording {} lock poisoned, recovery disabled", lock);
+            return false;
+        }
+
+        let from = self.state;
+        crate::error!(
+            "Recording {} lock poisoned in {:?} state, resetting to Idle",
+            lock,
+            from
+        );
+        if from == RecordingState::Recording {
+            discard_engine_capture();
+        }
+        self.last_recording = None;
+        self.reset_to_idle();
+        self.emit_state_recovered(lock, from);
+        true
+    }
+
+    /// Emit recording_state_recovered for the given lock
+    fn emit_state_recovered(&self, lock: &str, from: RecordingState) {
+        if let Some(ref emitter) = self.state_emitter {
+            emitter.emit_recording_state_recovered(RecordingStateRecoveredPayload {
+                lock: lock.to_string(),
+                from,
+                timestamp: current_timestamp(),
+            });
+        }
+    }
+
     /// Abort the current recording without saving
     ///
     /// Transitions from Recording to Idle, discarding the audio buffer
@@ -302,7 +539,7 @@ impl RecordingManager {
         // Discard the buffer without retaining - this is the key difference from stop_recording
         self.audio_buffer = None;
         self.active_recording = None;
-        self.state = target_state;
+        self.set_state(target_state);
 
         Ok(())
     }
@@ -313,3 +550,37 @@ impl Default for RecordingManager {
         Self::new()
     }
 }
+
+/// Stop the audio engine capture of an abandoned recording and delete its file
+fn discard_engine_capture() {
+    let result = crate::swift::audio_engine_stop_capture();
+    if result.file_path.is_empty() {
+        return;
+    }
+    match std::fs::remove_file(&result.file_path) {
+        Ok(()) => crate::info!("Discarded capture file {}", result.file_path),
+        Err(e) => crate::warn!("Failed to discard capture file {}: {}", result.file_path, e),
+    }
+}
+
+/// Lock the recording state, recovering from a poisoned lock
+///
+/// A poisoned lock means a thread panicked mid-update, so the manager is
+/// reset to Idle (see `RecordingManager::recover_from_poisoned_lock`) and the
+/// poison is cleared so later callers lock normally. Returns None only when
+/// recovery is disabled.
+pub fn lock_recording_state(
+    state: &Mutex<RecordingManager>,
+) -> Option<MutexGuard<'_, RecordingManager>> {
+    match state.lock() {
+        Ok(guard) => Some(guard),
+        Err(poisoned) => {
+            let mut guard = poisoned.into_inner();
+            if !guard.recover_from_poisoned_lock("recording_state") {
+                return None;
+            }
+            state.clear_poison();
+            Some(guard)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use super::*;
 use crate::audio::TARGET_SAMPLE_RATE;
+use crate::test_utils::MockEmitter;
+use std::sync::{Arc, Mutex};
 
 /// Test complete recording flow: Idle -> Recording -> Processing -> Idle
 /// Verifies buffer creation, data capture, and proper cleanup
@@ -60,6 +62,20 @@ fn test_abort_discards_recording() {
     assert!(manager.get_last_recording_buffer().is_err());
 }
 
+/// Elapsed time is only reported while actively recording
+#[test]
+fn test_elapsed_only_in_recording_state() {
+    let mut manager = RecordingManager::new();
+    assert!(manager.get_elapsed().is_none());
+
+    manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert!(manager.get_elapsed().unwrap() >= std::time::Duration::from_millis(10));
+
+    manager.transition_to(RecordingState::Processing).unwrap();
+    assert!(manager.get_elapsed().is_none());
+}
+
 /// Test that invalid operations don't corrupt state
 /// After invalid transitions, manager remains in valid state
 #[test]
@@ -155,3 +171,148 @@ fn test_audio_duration_calculation() {
     let audio = manager.get_last_recording_buffer().unwrap();
     assert!((audio.duration_secs - 1.0).abs() < 0.001);
 }
+
+#[test]
+fn test_state_changes_are_emitted() {
+    let emitter = MockEmitter::new();
+    let mut manager = RecordingManager::with_state_emitter(Arc::new(emitter.clone()));
+
+    let _buffer = manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    manager.transition_to(RecordingState::Processing).unwrap();
+    manager.transition_to(RecordingState::Idle).unwrap();
+    // Already idle: no event
+    manager.reset_to_idle();
+    // Rejected transition: no event
+    assert!(manager.transition_to(RecordingState::Processing).is_err());
+
+    let changes: Vec<_> = emitter
+        .state_changes
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|c| (c.from, c.to))
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (RecordingState::Idle, RecordingState::Recording),
+            (RecordingState::Recording, RecordingState::Processing),
+            (RecordingState::Processing, RecordingState::Idle),
+        ]
+    );
+}
+
+#[test]
+fn test_abort_emits_state_change() {
+    let emitter = MockEmitter::new();
+    let mut manager = RecordingManager::with_state_emitter(Arc::new(emitter.clone()));
+
+    let _buffer = manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    manager.abort_recording(RecordingState::Idle).unwrap();
+
+    let changes = emitter.state_changes.lock().unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[1].from, RecordingState::Recording);
+    assert_eq!(changes[1].to, RecordingState::Idle);
+}
+
+#[test]
+fn test_queue_start_disabled_by_default() {
+    let mut manager = RecordingManager::new();
+    assert!(!manager.queue_start(true));
+}
+
+#[test]
+fn test_queue_start_only_while_idle_and_transcribing() {
+    let mut manager = RecordingManager::new();
+    manager.set_queue_depth(5);
+
+    // Nothing to wait for
+    assert!(!manager.queue_start(false));
+
+    // A recording in progress is stopped, not queued behind
+    let _buffer = manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    assert!(!manager.queue_start(true));
+    manager.transition_to(RecordingState::Processing).unwrap();
+    manager.transition_to(RecordingState::Idle).unwrap();
+
+    // Back in Idle while the transcription runs; depth is capped at MAX_QUEUED_STARTS
+    assert!(manager.queue_start(true));
+    assert!(!manager.queue_start(true));
+    assert_eq!(manager.queued_starts(), MAX_QUEUED_STARTS);
+}
+
+#[test]
+fn test_take_queued_start_releases_once() {
+    let mut manager = RecordingManager::new();
+    manager.set_queue_depth(1);
+    assert!(manager.queue_start(true));
+
+    assert!(manager.take_queued_start());
+    assert!(!manager.take_queued_start());
+    assert_eq!(manager.queued_starts(), 0);
+}
+
+#[test]
+fn test_reset_to_idle_drops_queued_start() {
+    let mut manager = RecordingManager::new();
+    manager.set_queue_depth(1);
+    assert!(manager.queue_start(true));
+
+    manager.reset_to_idle();
+    assert_eq!(manager.queued_starts(), 0);
+}
+
+/// Poison the lock by panicking while holding it
+fn poison(state: &Arc<Mutex<RecordingManager>>) {
+    let state = state.clone();
+    let _ = std::thread::spawn(move || {
+        let _guard = state.lock().unwrap();
+        panic!("poisoning recording state for test");
+    })
+    .join();
+}
+
+#[test]
+fn test_poisoned_state_lock_resets_to_idle() {
+    let emitter = MockEmitter::new();
+    let mut manager = RecordingManager::with_state_emitter(Arc::new(emitter.clone()));
+    let _buffer = manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    let state = Arc::new(Mutex::new(manager));
+    poison(&state);
+    assert!(state.is_poisoned());
+
+    {
+        let mut manager = lock_recording_state(&state).expect("recovered");
+        assert_eq!(manager.get_state(), RecordingState::Idle);
+        assert!(manager.get_audio_buffer().is_err());
+        // A fresh recording can start straight away
+        assert!(manager.start_recording(TARGET_SAMPLE_RATE).is_ok());
+    }
+    assert!(!state.is_poisoned());
+
+    let recoveries = emitter.state_recoveries.lock().unwrap();
+    assert_eq!(recoveries.len(), 1);
+    assert_eq!(recoveries[0].lock, "recording_state");
+    assert_eq!(recoveries[0].from, RecordingState::Recording);
+}
+
+#[test]
+fn test_poisoned_state_lock_fails_when_recovery_disabled() {
+    let mut manager = RecordingManager::new();
+    manager.set_lock_poison_recovery(LockPoisonRecovery::Fail);
+    let state = Arc::new(Mutex::new(manager));
+    poison(&state);
+
+    assert!(lock_recording_state(&state).is_none());
+    assert!(state.is_poisoned());
+}
+
+#[test]
+fn test_lock_poison_recovery_from_setting() {
+    use LockPoisonRecovery::{Fail, Reset};
+    assert_eq!(LockPoisonRecovery::from_setting(None), Reset);
+    assert_eq!(LockPoisonRecovery::from_setting(Some("reset")), Reset);
+    assert_eq!(LockPoisonRecovery::from_setting(Some("fail")), Fail);
+    assert_eq!(LockPoisonRecovery::from_setting(Some("bogus")), Reset);
+}
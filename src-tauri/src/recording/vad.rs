@@ -4,8 +4,61 @@
 use crate::audio_constants::{
     chunk_size_for_sample_rate, DEFAULT_SAMPLE_RATE, VAD_THRESHOLD_SILENCE,
 };
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use voice_activity_detector::VoiceActivityDetector;
 
+/// Settings key for a custom ONNX VAD model path (string, unset = bundled Silero model)
+pub const VAD_MODEL_PATH_KEY: &str = "audio.vadModelPath";
+
+/// Settings key for the silence detection speech threshold (0.0-1.0)
+pub const SILENCE_VAD_THRESHOLD_KEY: &str = "silence.vadThreshold";
+
+/// Detector whose speech threshold is being inspected or adjusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VadContext {
+    /// Silence detection that auto-stops recordings
+    Silence,
+}
+
+/// Speech thresholds currently in effect
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VadThresholds {
+    /// Silence detection threshold
+    pub silence: f32,
+}
+
+/// Check a speech threshold before applying it
+///
+/// Thresholds of 0 or 1 would treat all or no audio as speech, so only
+/// values strictly between them are accepted.
+pub fn validate_vad_threshold(value: f64) -> Result<f32, String> {
+    if value.is_finite() && value > 0.0 && value < 1.0 {
+        Ok(value as f32)
+    } else {
+        Err(format!(
+            "VAD threshold must be between 0 and 1 (exclusive), got {}",
+            value
+        ))
+    }
+}
+
+/// Resolve the silence detection threshold from its settings value
+///
+/// Unset or invalid values fall back to `VAD_THRESHOLD_SILENCE`.
+pub fn silence_vad_threshold_from_setting(value: Option<f64>) -> f32 {
+    match value.map(validate_vad_threshold) {
+        Some(Ok(threshold)) => threshold,
+        Some(Err(e)) => {
+            crate::warn!("Ignoring silence VAD threshold: {}", e);
+            VAD_THRESHOLD_SILENCE
+        }
+        None => VAD_THRESHOLD_SILENCE,
+    }
+}
+
 /// Error type for VAD operations
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum VadError {
@@ -32,8 +85,9 @@ pub enum VadError {
 /// - Values above 0.5 are confident speech detection
 ///
 /// Note: `speech_threshold` and `min_speech_frames` are not used by `create_vad()`
-/// (Silero VAD doesn't accept thresholds at init time). They exist for documentation
-/// and future use if consumers want to extract threshold config from a unified source.
+/// (Silero VAD doesn't accept thresholds at init time). Consumers apply them to
+/// the per-chunk probabilities; `SilenceDetector` requires `min_speech_frames`
+/// consecutive chunks over threshold before declaring speech.
 #[derive(Debug, Clone)]
 pub struct VadConfig {
     /// Speech probability threshold (0.0-1.0)
@@ -54,8 +108,13 @@ pub struct VadConfig {
     ///
     /// Helps filter out brief noise spikes. Setting to 2 catches
     /// short utterances like "hello" while filtering random pops.
-    #[allow(dead_code)]
     pub min_speech_frames: usize,
+
+    /// Custom ONNX VAD model to load instead of the bundled Silero model
+    ///
+    /// The model must take the same inputs as Silero VAD (audio chunk,
+    /// sample rate and recurrent state).
+    pub vad_model_path: Option<PathBuf>,
 }
 
 impl Default for VadConfig {
@@ -65,6 +124,7 @@ impl Default for VadConfig {
             speech_threshold: VAD_THRESHOLD_SILENCE,
             sample_rate: DEFAULT_SAMPLE_RATE,
             min_speech_frames: 2,
+            vad_model_path: None,
         }
     }
 }
@@ -95,12 +155,14 @@ impl VadConfig {
 
 /// Factory function for creating VAD detector
 ///
-/// Initializes a Silero VAD model with the given configuration.
+/// Initializes the bundled Silero VAD model, or the model at
+/// `vad_model_path` if one is configured, with the given configuration.
 ///
 /// # Errors
 ///
 /// Returns `VadError::ConfigurationInvalid` if the sample rate is not 8000 or 16000 Hz.
-/// Returns `VadError::InitializationFailed` if the VAD model fails to load.
+/// Returns `VadError::InitializationFailed` if the VAD model fails to load, or
+/// a custom model can't process audio at the configured sample rate.
 pub fn create_vad(config: &VadConfig) -> Result<VoiceActivityDetector, VadError> {
     // Validate sample rate - Silero VAD only supports 8kHz and 16kHz
     match config.sample_rate {
@@ -116,11 +178,75 @@ pub fn create_vad(config: &VadConfig) -> Result<VoiceActivityDetector, VadError>
     // Calculate chunk size from sample rate (32ms window)
     let chunk_size = chunk_size_for_sample_rate(config.sample_rate);
 
-    VoiceActivityDetector::builder()
+    let Some(model_path) = &config.vad_model_path else {
+        return VoiceActivityDetector::builder()
+            .sample_rate(config.sample_rate as i32)
+            .chunk_size(chunk_size)
+            .build()
+            .map_err(|e| VadError::InitializationFailed(e.to_string()));
+    };
+
+    let session = load_vad_session(model_path)?;
+    let mut vad = VoiceActivityDetector::builder()
         .sample_rate(config.sample_rate as i32)
         .chunk_size(chunk_size)
+        .session(Arc::new(Mutex::new(session)))
         .build()
-        .map_err(|e| VadError::InitializationFailed(e.to_string()))
+        .map_err(|e| VadError::InitializationFailed(e.to_string()))?;
+
+    probe_vad(&mut vad, config.sample_rate, chunk_size)?;
+    crate::info!("Loaded custom VAD model: {}", model_path.display());
+    Ok(vad)
+}
+
+/// Load a custom ONNX VAD model
+fn load_vad_session(path: &Path) -> Result<ort::session::Session, VadError> {
+    if !path.is_file() {
+        return Err(VadError::InitializationFailed(format!(
+            "VAD model not found: {}",
+            path.display()
+        )));
+    }
+
+    ort::session::Session::builder()
+        .and_then(|builder| builder.commit_from_file(path))
+        .map_err(|e| {
+            VadError::InitializationFailed(format!(
+                "Failed to load VAD model {}: {}",
+                path.display(),
+                e
+            ))
+        })
+}
+
+/// Run one chunk of silence through a custom model to check it accepts the sample rate
+///
+/// The detector panics on inference errors, so the probe is isolated with
+/// `catch_unwind`. The detector state is reset afterwards.
+fn probe_vad(
+    vad: &mut VoiceActivityDetector,
+    sample_rate: u32,
+    chunk_size: usize,
+) -> Result<(), VadError> {
+    let probability = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vad.predict(vec![0.0f32; chunk_size])
+    }))
+    .map_err(|_| {
+        VadError::InitializationFailed(format!(
+            "VAD model failed to process {} Hz audio",
+            sample_rate
+        ))
+    })?;
+
+    if !(0.0..=1.0).contains(&probability) {
+        return Err(VadError::InitializationFailed(format!(
+            "VAD model returned an invalid speech probability ({}) at {} Hz",
+            probability, sample_rate
+        )));
+    }
+
+    vad.reset();
+    Ok(())
 }
 
 #[cfg(test)]
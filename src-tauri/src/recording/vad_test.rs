@@ -45,3 +45,71 @@ fn test_sample_rate_error_message_mentions_supported_rates() {
     let msg = format!("{}", err);
     assert!(msg.contains("8000") && msg.contains("16000"));
 }
+
+#[test]
+fn test_default_config_uses_bundled_model() {
+    assert!(VadConfig::default().vad_model_path.is_none());
+}
+
+#[test]
+fn test_create_vad_with_missing_custom_model_fails() {
+    let config = VadConfig {
+        vad_model_path: Some(PathBuf::from("/nonexistent/custom-vad.onnx")),
+        ..Default::default()
+    };
+    let err = create_vad(&config).unwrap_err();
+    assert!(matches!(err, VadError::InitializationFailed(_)));
+    assert!(err.to_string().contains("not found"));
+}
+
+#[test]
+fn test_create_vad_with_invalid_custom_model_fails() {
+    let temp = tempfile::tempdir().unwrap();
+    let model_path = temp.path().join("not-a-model.onnx");
+    std::fs::write(&model_path, b"definitely not onnx").unwrap();
+
+    let config = VadConfig {
+        vad_model_path: Some(model_path),
+        ..Default::default()
+    };
+    assert!(matches!(
+        create_vad(&config),
+        Err(VadError::InitializationFailed(_))
+    ));
+}
+
+#[test]
+fn test_custom_model_sample_rate_still_validated_first() {
+    let config = VadConfig {
+        sample_rate: 44100,
+        vad_model_path: Some(PathBuf::from("/nonexistent/custom-vad.onnx")),
+        ..Default::default()
+    };
+    assert!(matches!(
+        create_vad(&config),
+        Err(VadError::ConfigurationInvalid(_))
+    ));
+}
+
+#[test]
+fn test_validate_vad_threshold_rejects_out_of_range() {
+    assert_eq!(validate_vad_threshold(0.35), Ok(0.35));
+    assert!(validate_vad_threshold(0.0).is_err());
+    assert!(validate_vad_threshold(1.0).is_err());
+    assert!(validate_vad_threshold(f64::NAN).is_err());
+}
+
+#[test]
+fn test_silence_vad_threshold_from_setting() {
+    let default = VAD_THRESHOLD_SILENCE;
+    assert_eq!(silence_vad_threshold_from_setting(None), default);
+    assert_eq!(silence_vad_threshold_from_setting(Some(0.7)), 0.7);
+    assert_eq!(silence_vad_threshold_from_setting(Some(1.5)), default);
+}
+
+#[test]
+fn test_vad_context_deserializes_from_snake_case() {
+    let context: VadContext = serde_json::from_str("\"silence\"").unwrap();
+    assert_eq!(context, VadContext::Silence);
+    assert!(serde_json::from_str::<VadContext>("\"wake_word\"").is_err());
+}
@@ -0,0 +1,151 @@
+//! Audible feedback cues for recording start/stop.
+//!
+//! Cues are played by a separate `afplay` process so playback never shares
+//! the capture stream. The start cue mutes the engine's input for a short
+//! bounded lead-in so the beep isn't captured into the recording; the stop
+//! cue is played only after capture has stopped.
+
+use crate::util::{get_settings_file, SettingsAccess};
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Settings key enabling sound cues (bool, default false)
+pub const SOUNDS_ENABLED_KEY: &str = "sounds.enabled";
+
+/// Settings key for how long input stays muted after the start cue
+pub const START_LEAD_MS_KEY: &str = "sounds.startLeadMs";
+
+/// Default lead-in so short system sounds aren't recorded
+pub const DEFAULT_START_LEAD_MS: u64 = 150;
+
+/// Upper bound on the lead-in, so a bad setting can't silence the recording start
+pub const MAX_START_LEAD_MS: u64 = 1_000;
+
+/// A feedback sound played at a point in the recording lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundCue {
+    /// Recording is about to start
+    RecordingStart,
+    /// Recording has stopped
+    RecordingStop,
+}
+
+impl SoundCue {
+    /// Settings key holding a custom sound file path for this cue
+    pub fn file_setting_key(self) -> &'static str {
+        match self {
+            SoundCue::RecordingStart => "sounds.startFile",
+            SoundCue::RecordingStop => "sounds.stopFile",
+        }
+    }
+
+    /// Built-in macOS system sound used when no custom file is configured
+    pub fn default_file(self) -> &'static str {
+        match self {
+            SoundCue::RecordingStart => "/System/Library/Sounds/Tink.aiff",
+            SoundCue::RecordingStop => "/System/Library/Sounds/Pop.aiff",
+        }
+    }
+
+    /// Resolve the file to play, preferring a non-empty custom path
+    pub fn resolve_file(self, custom: Option<String>) -> PathBuf {
+        custom
+            .filter(|path| !path.trim().is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(self.default_file()))
+    }
+}
+
+/// Resolve the start cue lead-in from its settings value
+pub fn start_lead_from_setting(value: Option<u64>) -> Duration {
+    Duration::from_millis(value.unwrap_or(DEFAULT_START_LEAD_MS).min(MAX_START_LEAD_MS))
+}
+
+/// Whether sound cues are enabled in settings
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn sounds_enabled(app_handle: &AppHandle) -> bool {
+    app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(SOUNDS_ENABLED_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Play a cue without blocking the caller
+///
+/// Does nothing when sounds are disabled or the app is shutting down.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn play_cue(app_handle: &AppHandle, cue: SoundCue) {
+    if crate::shutdown::is_shutting_down() || !sounds_enabled(app_handle) {
+        return;
+    }
+
+    let file = cue.resolve_file(app_handle.get_setting(cue.file_setting_key()));
+    spawn_player(file);
+}
+
+/// Play the start cue without blocking the caller
+///
+/// Playback runs in a separate process. Instead of delaying capture, the
+/// engine's input is muted for the configured lead-in (capped at
+/// `MAX_START_LEAD_MS`), so the cue isn't recorded while the caller goes on to
+/// open the microphone. Nothing is muted when sounds are disabled.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn play_start_cue(app_handle: &AppHandle) {
+    if crate::shutdown::is_shutting_down() || !sounds_enabled(app_handle) {
+        return;
+    }
+
+    let file = SoundCue::RecordingStart
+        .resolve_file(app_handle.get_setting(SoundCue::RecordingStart.file_setting_key()));
+    spawn_player(file);
+
+    let lead_ms = app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(START_LEAD_MS_KEY))
+        .and_then(|v| v.as_u64());
+    let lead = start_lead_from_setting(lead_ms);
+    if !lead.is_zero() {
+        crate::swift::audio_engine_mute_input(lead);
+    }
+}
+
+/// Spawn the system audio player and reap it on a background thread
+#[cfg(target_os = "macos")]
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn spawn_player(file: PathBuf) {
+    use std::process::{Command, Stdio};
+
+    if !file.exists() {
+        crate::warn!("Sound cue file not found: {}", file.display());
+        return;
+    }
+
+    match Command::new("afplay")
+        .arg(&file)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(mut child) => {
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        }
+        Err(e) => crate::warn!("Failed to play sound cue {}: {}", file.display(), e),
+    }
+}
+
+#[cfg(not(target_os = "macos"))]
+fn spawn_player(_file: PathBuf) {
+    crate::debug!("Sound cues only supported on macOS");
+}
+
+#[cfg(test)]
+#[path = "sound_test.rs"]
+mod tests;
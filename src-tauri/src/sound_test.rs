@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn test_resolve_file_uses_default_when_unset() {
+    assert_eq!(
+        SoundCue::RecordingStart.resolve_file(None),
+        PathBuf::from("/System/Library/Sounds/Tink.aiff")
+    );
+}
+
+#[test]
+fn test_resolve_file_ignores_blank_custom_path() {
+    assert_eq!(
+        SoundCue::RecordingStop.resolve_file(Some("  ".to_string())),
+        PathBuf::from(SoundCue::RecordingStop.default_file())
+    );
+}
+
+#[test]
+fn test_resolve_file_prefers_custom_path() {
+    assert_eq!(
+        SoundCue::RecordingStart.resolve_file(Some("/tmp/ding.wav".to_string())),
+        PathBuf::from("/tmp/ding.wav")
+    );
+}
+
+#[test]
+fn test_each_cue_has_distinct_setting_key() {
+    assert_ne!(
+        SoundCue::RecordingStart.file_setting_key(),
+        SoundCue::RecordingStop.file_setting_key()
+    );
+}
+
+#[test]
+fn test_start_lead_defaults_and_clamps() {
+    assert_eq!(
+        start_lead_from_setting(None),
+        Duration::from_millis(DEFAULT_START_LEAD_MS)
+    );
+    assert_eq!(start_lead_from_setting(Some(0)), Duration::ZERO);
+    assert_eq!(
+        start_lead_from_setting(Some(10_000)),
+        Duration::from_millis(MAX_START_LEAD_MS)
+    );
+}
@@ -0,0 +1,118 @@
+//! Recording export bundles.
+//!
+//! Packs a recording's WAV file together with a JSON sidecar (transcription,
+//! metadata, app context and stop reason) into a single zip for sharing.
+
+use crate::audio::StopReason;
+use crate::turso::{RecordingRecord, TranscriptionRecord};
+use serde::Serialize;
+use std::io::Write;
+use std::path::Path;
+
+/// Name of the JSON sidecar inside the bundle
+pub const BUNDLE_SIDECAR_NAME: &str = "recording.json";
+
+/// JSON sidecar describing a recording in an export bundle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingBundleSidecar {
+    /// Name of the WAV file in the bundle
+    pub filename: String,
+    /// Transcription text (the user's correction if there is one, empty if never transcribed)
+    pub text: String,
+    /// Whether the recording has a transcription
+    pub has_transcription: bool,
+    /// Detected language of the transcription
+    pub language: Option<String>,
+    /// Model that produced the transcription
+    pub model_version: Option<String>,
+    /// Duration of the recording in seconds
+    pub duration_secs: Option<f64>,
+    /// Number of audio samples
+    pub sample_count: Option<u64>,
+    /// When the recording was made (ISO 8601)
+    pub created_at: Option<String>,
+    /// Why the recording stopped
+    pub stop_reason: Option<StopReason>,
+    /// App name of the active window when recording started
+    pub active_window_app_name: Option<String>,
+    /// Bundle ID of the active window when recording started
+    pub active_window_bundle_id: Option<String>,
+    /// Window title of the active window when recording started
+    pub active_window_title: Option<String>,
+}
+
+impl RecordingBundleSidecar {
+    /// Build the sidecar from the stored recording and its latest transcription
+    ///
+    /// Either may be missing (e.g. a recording never transcribed, or a file
+    /// with no database row); the corresponding fields are left empty.
+    pub fn new(
+        file_path: &Path,
+        recording: Option<&RecordingRecord>,
+        transcription: Option<&TranscriptionRecord>,
+    ) -> Self {
+        let filename = file_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "recording.wav".to_string());
+
+        Self {
+            filename,
+            text: transcription
+                .map(|t| t.edited_text.clone().unwrap_or_else(|| t.text.clone()))
+                .unwrap_or_default(),
+            has_transcription: transcription.is_some(),
+            language: transcription.and_then(|t| t.language.clone()),
+            model_version: transcription.map(|t| t.model_version.clone()),
+            duration_secs: recording.map(|r| r.duration_secs),
+            sample_count: recording.map(|r| r.sample_count),
+            created_at: recording.map(|r| r.created_at.clone()),
+            stop_reason: recording.and_then(|r| r.stop_reason.clone()),
+            active_window_app_name: recording.and_then(|r| r.active_window_app_name.clone()),
+            active_window_bundle_id: recording.and_then(|r| r.active_window_bundle_id.clone()),
+            active_window_title: recording.and_then(|r| r.active_window_title.clone()),
+        }
+    }
+}
+
+/// Write a zip bundle containing the WAV file and its JSON sidecar
+///
+/// # Arguments
+/// * `wav_path` - Path to the recording's WAV file
+/// * `sidecar` - Sidecar describing the recording
+/// * `out_path` - Where to write the zip (overwritten if it exists)
+pub fn write_recording_bundle(
+    wav_path: &Path,
+    sidecar: &RecordingBundleSidecar,
+    out_path: &Path,
+) -> Result<(), String> {
+    let mut wav_file = std::fs::File::open(wav_path)
+        .map_err(|e| format!("Failed to open recording {}: {}", wav_path.display(), e))?;
+    let sidecar_json = serde_json::to_vec_pretty(sidecar)
+        .map_err(|e| format!("Failed to serialize recording sidecar: {}", e))?;
+
+    let out_file = std::fs::File::create(out_path)
+        .map_err(|e| format!("Failed to create bundle {}: {}", out_path.display(), e))?;
+    let mut zip = zip::ZipWriter::new(out_file);
+    let zip_error = |e: zip::result::ZipError| format!("Failed to write bundle: {}", e);
+    let io_error = |e: std::io::Error| format!("Failed to write bundle: {}", e);
+
+    // WAV data barely compresses, so it is stored as-is
+    let stored =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    zip.start_file(sidecar.filename.as_str(), stored).map_err(zip_error)?;
+    std::io::copy(&mut wav_file, &mut zip).map_err(io_error)?;
+
+    let deflated = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    zip.start_file(BUNDLE_SIDECAR_NAME, deflated).map_err(zip_error)?;
+    zip.write_all(&sidecar_json).map_err(io_error)?;
+
+    zip.finish().map_err(zip_error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "export_test.rs"]
+mod tests;
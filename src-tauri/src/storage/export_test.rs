@@ -0,0 +1,118 @@
+// Tests for recording export bundles
+
+use super::*;
+use std::io::Read;
+
+fn recording() -> RecordingRecord {
+    RecordingRecord {
+        id: "rec-1".to_string(),
+        file_path: "/tmp/recording-1.wav".to_string(),
+        duration_secs: 1.5,
+        sample_count: 24000,
+        stop_reason: Some(StopReason::SilenceAfterSpeech),
+        trigger_source: None,
+        created_at: "2025-12-01T14:30:25Z".to_string(),
+        active_window_app_name: Some("Slack".to_string()),
+        active_window_bundle_id: Some("com.tinyspeck.slackmacgap".to_string()),
+        active_window_title: None,
+    }
+}
+
+fn transcription(edited_text: Option<&str>) -> TranscriptionRecord {
+    TranscriptionRecord {
+        id: "tr-1".to_string(),
+        recording_id: "rec-1".to_string(),
+        text: "hello world".to_string(),
+        language: Some("en".to_string()),
+        model_version: "tdt-0.6b-v3".to_string(),
+        duration_ms: 420,
+        created_at: "2025-12-01T14:30:27Z".to_string(),
+        edited_text: edited_text.map(String::from),
+    }
+}
+
+#[test]
+fn test_sidecar_includes_transcription_and_context() {
+    let sidecar = RecordingBundleSidecar::new(
+        Path::new("/tmp/recording-1.wav"),
+        Some(&recording()),
+        Some(&transcription(None)),
+    );
+
+    assert_eq!(sidecar.filename, "recording-1.wav");
+    assert_eq!(sidecar.text, "hello world");
+    assert!(sidecar.has_transcription);
+    assert_eq!(sidecar.stop_reason, Some(StopReason::SilenceAfterSpeech));
+    assert_eq!(sidecar.active_window_app_name.as_deref(), Some("Slack"));
+    assert_eq!(sidecar.sample_count, Some(24000));
+}
+
+#[test]
+fn test_sidecar_prefers_edited_text() {
+    let sidecar = RecordingBundleSidecar::new(
+        Path::new("/tmp/recording-1.wav"),
+        Some(&recording()),
+        Some(&transcription(Some("Hello, world!"))),
+    );
+    assert_eq!(sidecar.text, "Hello, world!");
+}
+
+#[test]
+fn test_sidecar_without_transcription_has_empty_text_and_flag() {
+    let sidecar =
+        RecordingBundleSidecar::new(Path::new("/tmp/recording-1.wav"), Some(&recording()), None);
+
+    assert_eq!(sidecar.text, "");
+    assert!(!sidecar.has_transcription);
+    assert_eq!(sidecar.model_version, None);
+
+    let json = serde_json::to_value(&sidecar).unwrap();
+    assert_eq!(json["text"], "");
+    assert_eq!(json["hasTranscription"], false);
+}
+
+#[test]
+fn test_bundle_contains_wav_and_sidecar() {
+    let temp = tempfile::tempdir().unwrap();
+    let wav_path = temp.path().join("recording-1.wav");
+    let wav_bytes = b"RIFF fake wav data";
+    std::fs::write(&wav_path, wav_bytes).unwrap();
+    let out_path = temp.path().join("bundle.zip");
+
+    let sidecar = RecordingBundleSidecar::new(&wav_path, None, None);
+    write_recording_bundle(&wav_path, &sidecar, &out_path).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::fs::File::open(&out_path).unwrap()).unwrap();
+    assert_eq!(archive.len(), 2);
+
+    let mut wav = Vec::new();
+    archive
+        .by_name("recording-1.wav")
+        .unwrap()
+        .read_to_end(&mut wav)
+        .unwrap();
+    assert_eq!(wav, wav_bytes);
+
+    let mut json = String::new();
+    archive
+        .by_name(BUNDLE_SIDECAR_NAME)
+        .unwrap()
+        .read_to_string(&mut json)
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["filename"], "recording-1.wav");
+    assert_eq!(value["hasTranscription"], false);
+}
+
+#[test]
+fn test_bundle_fails_for_missing_wav() {
+    let temp = tempfile::tempdir().unwrap();
+    let wav_path = temp.path().join("missing.wav");
+    let out_path = temp.path().join("bundle.zip");
+
+    let sidecar = RecordingBundleSidecar::new(&wav_path, None, None);
+    let result = write_recording_bundle(&wav_path, &sidecar, &out_path);
+
+    assert!(result.is_err());
+    assert!(!out_path.exists());
+}
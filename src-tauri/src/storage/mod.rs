@@ -16,10 +16,16 @@
 //! store_transcription(&app_handle, &file_path, &text, duration_ms);
 //! ```
 
+mod export;
 mod recording;
 mod transcription;
 
-pub use recording::{store_recording, RecordingStorage, WindowContext};
+pub use export::{write_recording_bundle, RecordingBundleSidecar};
+pub use recording::{
+    discard_recording_audio, keep_audio_without_model, persist_transcriptions,
+    reconcile_orphaned_recordings, recording_file_writer, retain_audio, store_recording,
+    RecordingStorage, WindowContext,
+};
 pub use transcription::{store_transcription, TranscriptionStorage};
 
 #[cfg(test)]
@@ -3,11 +3,32 @@
 //! Provides a unified interface for storing recordings, eliminating
 //! duplicated code from hotkey/integration.rs and commands/mod.rs.
 
+use crate::audio::{
+    parse_duration_from_file, parse_sample_count_from_file, StopReason, SystemFileWriter,
+    WavBitDepth, WAV_BIT_DEPTH_KEY,
+};
 use crate::recording::RecordingMetadata;
 use crate::turso::{events as turso_events, TursoClient};
+use crate::util::{get_settings_file, SettingsAccess};
 use crate::window_context::get_active_window;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// Settings key controlling whether WAV files are kept after transcription
+/// (bool, default true)
+pub const RETAIN_AUDIO_KEY: &str = "recordings.retainAudio";
+
+/// Settings key controlling whether recordings and their transcriptions are
+/// stored in the database at all (bool, default true). When off, transcribed
+/// text only goes to its output and the WAV is deleted after transcription.
+pub const PERSIST_TRANSCRIPTIONS_KEY: &str = "recordings.persistTranscriptions";
+
+/// Settings key controlling whether WAV files are kept when no transcription
+/// model is loaded to transcribe them (bool, default true)
+pub const KEEP_WITHOUT_MODEL_KEY: &str = "recordings.keepWithoutModel";
 
 /// Window context information for a recording.
 pub struct WindowContext {
@@ -41,6 +62,38 @@ impl WindowContext {
     }
 }
 
+/// Build the file writer used to name a finished recording.
+///
+/// Applies the user's `audio.filenameTemplate` and `audio.wavBitDepth` settings
+/// (if any) and, when the template uses `{app}`, the name of the currently
+/// active application. The bit depth only matters when the writer encodes
+/// samples; captured recordings are already in that format when renamed.
+pub fn recording_file_writer(
+    app_handle: Option<&AppHandle>,
+    recordings_dir: PathBuf,
+) -> SystemFileWriter {
+    let mut writer = SystemFileWriter::new(recordings_dir);
+
+    if let Some(template) = app_handle.and_then(|h| h.get_setting("audio.filenameTemplate")) {
+        writer = writer.with_filename_template(&template);
+    }
+
+    if let Some(handle) = app_handle {
+        let bit_depth = handle
+            .store(get_settings_file(handle))
+            .ok()
+            .and_then(|store| store.get(WAV_BIT_DEPTH_KEY))
+            .and_then(|v| v.as_u64());
+        writer = writer.with_bit_depth(WavBitDepth::from_setting(bit_depth));
+    }
+
+    if writer.filename_template().contains("{app}") {
+        writer = writer.with_app_name(WindowContext::capture().app_name);
+    }
+
+    writer
+}
+
 /// High-level recording storage interface.
 ///
 /// Provides methods for storing recordings with automatic window context
@@ -71,6 +124,7 @@ impl RecordingStorage {
                 metadata.duration_secs,
                 metadata.sample_count as u64,
                 metadata.stop_reason.clone(),
+                metadata.trigger_source,
                 window_context.app_name,
                 window_context.bundle_id,
                 window_context.title,
@@ -95,6 +149,11 @@ impl RecordingStorage {
 /// * `metadata` - Recording metadata to store
 /// * `flow_name` - Name of the flow for logging (e.g., "hotkey", "PTT", "button")
 pub fn store_recording(app_handle: &AppHandle, metadata: &RecordingMetadata, flow_name: &str) {
+    if !persist_transcriptions(app_handle) {
+        crate::debug!("Transcription history disabled, recording not stored ({} flow)", flow_name);
+        return;
+    }
+
     // Get TursoClient from managed state
     let turso_client: Option<tauri::State<'_, Arc<TursoClient>>> = app_handle.try_state();
 
@@ -107,6 +166,7 @@ pub fn store_recording(app_handle: &AppHandle, metadata: &RecordingMetadata, flo
         let duration_secs = metadata.duration_secs;
         let sample_count = metadata.sample_count as u64;
         let stop_reason = metadata.stop_reason.clone();
+        let trigger_source = metadata.trigger_source;
         let client = client.inner().clone();
         let app_handle_clone = app_handle.clone();
         let flow_name = flow_name.to_string();
@@ -120,6 +180,7 @@ pub fn store_recording(app_handle: &AppHandle, metadata: &RecordingMetadata, flo
                     duration_secs,
                     sample_count,
                     stop_reason,
+                    trigger_source,
                     window_context.app_name,
                     window_context.bundle_id,
                     window_context.title,
@@ -137,6 +198,132 @@ pub fn store_recording(app_handle: &AppHandle, metadata: &RecordingMetadata, flo
     }
 }
 
+/// Insert database rows for WAV files that exist on disk but not in Turso.
+///
+/// A crash between saving a recording and storing its metadata leaves an
+/// orphaned file. Orphans get a minimal row (duration from the WAV header, no
+/// window context) marked with `StopReason::Recovered`. Debug `-raw.wav`
+/// captures and unreadable files are skipped.
+///
+/// Returns the file paths of recovered recordings.
+pub async fn reconcile_orphaned_recordings(
+    client: &TursoClient,
+    recordings_dir: &Path,
+) -> Result<Vec<String>, String> {
+    let entries = match std::fs::read_dir(recordings_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Failed to read recordings directory: {}", e)),
+    };
+
+    let known_paths: HashSet<String> = client
+        .list_recordings()
+        .await
+        .map_err(|e| format!("Failed to list recordings: {}", e))?
+        .into_iter()
+        .map(|r| r.file_path)
+        .collect();
+
+    let mut recovered = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_wav = path.extension().is_some_and(|ext| ext == "wav");
+        let is_raw_capture = path
+            .file_stem()
+            .is_some_and(|stem| stem.to_string_lossy().ends_with("-raw"));
+        if !path.is_file() || !is_wav || is_raw_capture {
+            continue;
+        }
+
+        let file_path = path.to_string_lossy().to_string();
+        if known_paths.contains(&file_path) {
+            continue;
+        }
+
+        let header = parse_duration_from_file(&path)
+            .and_then(|duration| Ok((duration, parse_sample_count_from_file(&path)?)));
+        let (duration_secs, sample_count) = match header {
+            Ok(header) => header,
+            Err(e) => {
+                crate::warn!("Skipping unreadable orphaned recording {}: {}", file_path, e);
+                continue;
+            }
+        };
+
+        client
+            .add_recording(
+                uuid::Uuid::new_v4().to_string(),
+                file_path.clone(),
+                duration_secs,
+                sample_count,
+                Some(StopReason::Recovered),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .map_err(|e| format!("Failed to store orphaned recording: {}", e))?;
+
+        crate::info!("Recovered orphaned recording: {}", file_path);
+        recovered.push(file_path);
+    }
+
+    Ok(recovered)
+}
+
+/// Whether recordings should be kept on disk after a successful transcription.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn retain_audio(app_handle: &AppHandle) -> bool {
+    app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(RETAIN_AUDIO_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+/// Whether recordings and transcriptions should be stored in the database.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn persist_transcriptions(app_handle: &AppHandle) -> bool {
+    app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(PERSIST_TRANSCRIPTIONS_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+/// Whether recordings should be kept on disk when they can't be transcribed
+/// because no model is loaded.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn keep_audio_without_model(app_handle: &AppHandle) -> bool {
+    app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(KEEP_WITHOUT_MODEL_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true)
+}
+
+/// Delete a recording's WAV file once its transcription has been stored.
+///
+/// The Turso recording row and its transcriptions are kept, so the row
+/// becomes audio-less: it no longer appears in the file-based recordings list
+/// but its text remains available. Saved voice-note copies are left alone.
+///
+/// Returns `Ok(false)` when the file was already gone.
+pub fn discard_recording_audio(file_path: &Path) -> Result<bool, String> {
+    match std::fs::remove_file(file_path) {
+        Ok(()) => {
+            crate::info!("Deleted recording audio after transcription: {}", file_path.display());
+            Ok(true)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(format!("Failed to delete recording audio: {}", e)),
+    }
+}
+
 #[cfg(test)]
 #[path = "recording_test.rs"]
 mod tests;
@@ -1,6 +1,7 @@
 // Tests for the recording storage module
 
 use super::*;
+use crate::audio::TARGET_SAMPLE_RATE;
 
 #[test]
 fn test_window_context_default_values() {
@@ -29,3 +30,149 @@ fn test_window_context_with_values() {
     assert_eq!(ctx.bundle_id, Some("com.test.app".to_string()));
     assert_eq!(ctx.title, Some("Test Window".to_string()));
 }
+
+async fn setup_client() -> (TursoClient, tempfile::TempDir) {
+    let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    crate::turso::initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+fn write_test_wav(path: &Path, sample_rate: u32, sample_count: usize) {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for _ in 0..sample_count {
+        writer.write_sample(0.0f32).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+#[tokio::test]
+async fn test_reconcile_inserts_orphaned_wav() {
+    let (client, _db_dir) = setup_client().await;
+    let recordings_dir = tempfile::TempDir::new().unwrap();
+
+    let orphan = recordings_dir.path().join("orphan.wav");
+    write_test_wav(&orphan, TARGET_SAMPLE_RATE, TARGET_SAMPLE_RATE as usize);
+    write_test_wav(
+        &recordings_dir.path().join("orphan-raw.wav"),
+        TARGET_SAMPLE_RATE,
+        100,
+    );
+    std::fs::write(recordings_dir.path().join("notes.txt"), "ignored").unwrap();
+
+    let recovered = reconcile_orphaned_recordings(&client, recordings_dir.path())
+        .await
+        .unwrap();
+
+    let orphan_path = orphan.to_string_lossy().to_string();
+    assert_eq!(recovered, vec![orphan_path.clone()]);
+
+    let record = client
+        .get_recording_by_path(&orphan_path)
+        .await
+        .unwrap()
+        .expect("orphan should now have a row");
+    assert!((record.duration_secs - 1.0).abs() < 0.001);
+    assert_eq!(record.sample_count, TARGET_SAMPLE_RATE as u64);
+    assert_eq!(record.stop_reason, Some(StopReason::Recovered));
+}
+
+#[tokio::test]
+async fn test_reconcile_uses_the_wav_sample_rate() {
+    let (client, _db_dir) = setup_client().await;
+    let recordings_dir = tempfile::TempDir::new().unwrap();
+
+    // Captured at the device's native rate rather than 16kHz
+    let orphan = recordings_dir.path().join("native.wav");
+    write_test_wav(&orphan, 48000, 24000);
+
+    reconcile_orphaned_recordings(&client, recordings_dir.path())
+        .await
+        .unwrap();
+
+    let record = client
+        .get_recording_by_path(&orphan.to_string_lossy())
+        .await
+        .unwrap()
+        .expect("orphan should now have a row");
+    assert!((record.duration_secs - 0.5).abs() < 0.001);
+    assert_eq!(record.sample_count, 24000);
+}
+
+#[tokio::test]
+async fn test_reconcile_skips_known_and_is_idempotent() {
+    let (client, _db_dir) = setup_client().await;
+    let recordings_dir = tempfile::TempDir::new().unwrap();
+
+    let known = recordings_dir.path().join("known.wav");
+    write_test_wav(&known, TARGET_SAMPLE_RATE, 160);
+    client
+        .add_recording(
+            "rec-known".to_string(),
+            known.to_string_lossy().to_string(),
+            0.01,
+            160,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    write_test_wav(
+        &recordings_dir.path().join("orphan.wav"),
+        TARGET_SAMPLE_RATE,
+        160,
+    );
+
+    let first = reconcile_orphaned_recordings(&client, recordings_dir.path())
+        .await
+        .unwrap();
+    assert_eq!(first.len(), 1);
+
+    let second = reconcile_orphaned_recordings(&client, recordings_dir.path())
+        .await
+        .unwrap();
+    assert!(second.is_empty());
+    assert_eq!(client.list_recordings().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_reconcile_missing_dir_is_noop() {
+    let (client, db_dir) = setup_client().await;
+
+    let recovered = reconcile_orphaned_recordings(&client, &db_dir.path().join("missing"))
+        .await
+        .unwrap();
+
+    assert!(recovered.is_empty());
+}
+
+#[test]
+fn test_discard_recording_audio_removes_file() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("recording.wav");
+    write_test_wav(&path, TARGET_SAMPLE_RATE, 160);
+
+    assert_eq!(discard_recording_audio(&path), Ok(true));
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_discard_recording_audio_missing_file_is_noop() {
+    let dir = tempfile::TempDir::new().unwrap();
+
+    assert_eq!(discard_recording_audio(&dir.path().join("gone.wav")), Ok(false));
+}
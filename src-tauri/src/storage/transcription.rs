@@ -3,6 +3,8 @@
 //! Provides a unified interface for storing transcriptions, eliminating
 //! duplicated code from transcription/service.rs.
 
+use crate::transcription::fallback::PRIMARY_MODEL_VERSION;
+use crate::transcription::language::guess_language;
 use crate::turso::{events as turso_events, TursoClient};
 use crate::util::run_async;
 use std::sync::Arc;
@@ -20,7 +22,8 @@ impl TranscriptionStorage {
     /// This method:
     /// 1. Looks up the recording by file path
     /// 2. Generates a unique transcription ID
-    /// 3. Stores the transcription linked to the recording
+    /// 3. Stores the transcription linked to the recording, tagged with the
+    ///    model version that produced it
     /// 4. Emits a transcriptions_updated event on success
     ///
     /// Returns the transcription ID on success.
@@ -28,6 +31,7 @@ impl TranscriptionStorage {
         client: &TursoClient,
         file_path: &str,
         text: &str,
+        model_version: &str,
         duration_ms: u64,
         app_handle: &AppHandle,
     ) -> Result<String, String> {
@@ -53,15 +57,14 @@ impl TranscriptionStorage {
 
         // Store the transcription
         let transcription_id = uuid::Uuid::new_v4().to_string();
-        let model_version = "parakeet-tdt".to_string();
 
         client
             .add_transcription(
                 transcription_id.clone(),
                 recording_id.clone(),
                 text.to_string(),
-                None, // language - could be detected in future
-                model_version,
+                guess_language(text).map(String::from),
+                model_version.to_string(),
                 duration_ms,
             )
             .await
@@ -108,8 +111,15 @@ pub fn store_transcription(app_handle: &AppHandle, file_path: &str, text: &str,
         // Run the async storage operation synchronously
         run_async(async move {
             if let Err(e) =
-                TranscriptionStorage::store(&client, &file_path, &text, duration_ms, &app_handle)
-                    .await
+                TranscriptionStorage::store(
+                    &client,
+                    &file_path,
+                    &text,
+                    PRIMARY_MODEL_VERSION,
+                    duration_ms,
+                    &app_handle,
+                )
+                .await
             {
                 crate::warn!("Failed to store transcription: {}", e);
             }
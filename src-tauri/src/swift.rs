@@ -3,7 +3,10 @@
 //! This module provides safe Rust wrappers around Swift functions
 //! compiled via swift-rs.
 
+use crate::audio::channel::ChannelSelection;
+use crate::audio::WavBitDepth;
 use swift_rs::SRString;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // Define the FFI function signatures
 // SRString is the Swift-Rs string type that can be safely passed across FFI
@@ -43,6 +46,11 @@ swift_rs::swift!(fn swift_unregister_device_change_callback());
 swift_rs::swift!(fn swift_refresh_audio_devices() -> i64);
 swift_rs::swift!(fn swift_get_device_name(index: i64) -> SRString);
 swift_rs::swift!(fn swift_get_device_is_default(index: i64) -> bool);
+swift_rs::swift!(fn swift_microphone_permission_granted() -> bool);
+swift_rs::swift!(fn swift_is_microphone_busy(device_name: &SRString) -> bool);
+
+// Screen capture detection
+swift_rs::swift!(fn swift_is_screen_captured() -> bool);
 
 // =============================================================================
 // Unified Audio Engine (single AVAudioEngine for both capture and monitoring)
@@ -59,6 +67,15 @@ swift_rs::swift!(fn swift_audio_engine_is_capturing() -> bool);
 swift_rs::swift!(fn swift_audio_engine_get_duration_ms() -> i64);
 swift_rs::swift!(fn swift_audio_engine_get_sample_count() -> i64);
 swift_rs::swift!(fn swift_audio_engine_get_error() -> SRString);
+swift_rs::swift!(fn swift_audio_engine_get_source_sample_rate() -> f64);
+swift_rs::swift!(fn swift_audio_engine_get_source_channel_count() -> i64);
+swift_rs::swift!(fn swift_audio_engine_get_resampler_latency_frames() -> i64);
+swift_rs::swift!(fn swift_audio_engine_get_device_buffer_frames() -> i64);
+swift_rs::swift!(fn swift_audio_engine_set_input_gain(gain: f64));
+swift_rs::swift!(fn swift_audio_engine_mute_input(milliseconds: i64));
+swift_rs::swift!(fn swift_audio_engine_set_channel_selection(selection: i64));
+swift_rs::swift!(fn swift_audio_engine_set_prefer_native_sample_rate(enabled: bool));
+swift_rs::swift!(fn swift_audio_engine_set_wav_bit_depth(bits: i64));
 
 /// Call the Swift hello function.
 /// Returns "Hello from Swift!" to verify the interop is working.
@@ -96,6 +113,25 @@ pub fn list_audio_devices() -> Vec<SwiftAudioDevice> {
     }
 }
 
+/// Check whether microphone access has been granted, without prompting.
+pub fn microphone_permission_granted() -> bool {
+    unsafe { swift_microphone_permission_granted() }
+}
+
+/// Best-effort check whether another app is using the input device.
+///
+/// `None` checks the system default input device. heycat's own capture is
+/// excluded. Returns false when the device can't be found or queried.
+pub fn is_microphone_busy(device_name: Option<&str>) -> bool {
+    let name = SRString::from(device_name.unwrap_or(""));
+    unsafe { swift_is_microphone_busy(&name) }
+}
+
+/// Check whether the screen is being recorded, shared or mirrored.
+pub fn is_screen_captured() -> bool {
+    unsafe { swift_is_screen_captured() }
+}
+
 /// Result of stopping audio capture.
 #[derive(Debug)]
 pub struct AudioCaptureStopResult {
@@ -285,6 +321,79 @@ pub fn audio_engine_get_sample_count() -> usize {
     unsafe { swift_audio_engine_get_sample_count() as usize }
 }
 
+/// Get the input device's native sample rate.
+/// Returns None if the engine isn't running.
+pub fn audio_engine_source_sample_rate() -> Option<u32> {
+    let rate = unsafe { swift_audio_engine_get_source_sample_rate() };
+    (rate > 0.0).then(|| rate.round() as u32)
+}
+
+/// Get the input device's channel count.
+/// Returns None if the engine isn't running.
+pub fn audio_engine_source_channel_count() -> Option<u16> {
+    let channels = unsafe { swift_audio_engine_get_source_channel_count() };
+    u16::try_from(channels).ok().filter(|&channels| channels > 0)
+}
+
+/// Get the resampler latency in 16kHz output samples.
+/// Measured on the first converted buffer after the engine (re)starts; returns
+/// None until then, and Some(0) when the device needs no conversion.
+pub fn audio_engine_resampler_latency_samples() -> Option<usize> {
+    let frames = unsafe { swift_audio_engine_get_resampler_latency_frames() };
+    usize::try_from(frames).ok()
+}
+
+/// Get the I/O buffer size reported by the input device, in frames.
+/// Returns None if the engine isn't running or the device doesn't report one.
+pub fn audio_engine_device_buffer_frames() -> Option<u32> {
+    let frames = unsafe { swift_audio_engine_get_device_buffer_frames() };
+    u32::try_from(frames).ok().filter(|&frames| frames > 0)
+}
+
+/// Set the software gain applied to input samples before resampling.
+/// Samples are clamped to [-1.0, 1.0] after the gain; 1.0 leaves them untouched.
+pub fn audio_engine_set_input_gain(gain: f32) {
+    unsafe { swift_audio_engine_set_input_gain(gain as f64) }
+}
+
+/// Replace input with silence for `duration` from now, e.g. while a cue plays.
+/// Applies to capture and level monitoring alike.
+pub fn audio_engine_mute_input(duration: std::time::Duration) {
+    unsafe { swift_audio_engine_mute_input(duration.as_millis() as i64) }
+}
+
+/// Whether the engine was last asked to prefer the device's native 16kHz path
+static PREFER_NATIVE_SAMPLE_RATE: AtomicBool = AtomicBool::new(false);
+
+/// Prefer running the input device at 16kHz so capture skips resampling.
+/// Applied the next time the engine starts or switches device; devices that
+/// can't run at 16kHz keep resampling.
+pub fn audio_engine_set_prefer_native_sample_rate(enabled: bool) {
+    PREFER_NATIVE_SAMPLE_RATE.store(enabled, Ordering::SeqCst);
+    unsafe { swift_audio_engine_set_prefer_native_sample_rate(enabled) }
+}
+
+/// Whether the engine prefers the device's native 16kHz path.
+pub fn audio_engine_prefers_native_sample_rate() -> bool {
+    PREFER_NATIVE_SAMPLE_RATE.load(Ordering::SeqCst)
+}
+
+/// Set the sample format of the capture file recordings are saved from.
+/// Applied the next time capture starts.
+pub fn audio_engine_set_wav_bit_depth(bit_depth: WavBitDepth) {
+    let bits = match bit_depth {
+        WavBitDepth::Int16 => 16,
+        WavBitDepth::Float32 => 32,
+    };
+    unsafe { swift_audio_engine_set_wav_bit_depth(bits) }
+}
+
+/// Set which channel of multi-channel input is captured.
+/// Mono devices are unaffected.
+pub fn audio_engine_set_channel_selection(selection: ChannelSelection) {
+    unsafe { swift_audio_engine_set_channel_selection(selection.ffi_code()) }
+}
+
 // =============================================================================
 // System Wake Notification API
 // =============================================================================
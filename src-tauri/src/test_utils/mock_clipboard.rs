@@ -0,0 +1,48 @@
+//! Mock clipboard writer for testing output paths.
+
+use std::sync::Mutex;
+
+use crate::transcription::clipboard::ClipboardWriter;
+
+/// Mock clipboard writer that records every write.
+#[derive(Default)]
+pub struct MockClipboardWriter {
+    written: Mutex<Vec<String>>,
+    fail: bool,
+}
+
+impl MockClipboardWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a writer whose writes always fail.
+    pub fn failing() -> Self {
+        Self {
+            fail: true,
+            ..Self::default()
+        }
+    }
+
+    /// Text written so far, oldest first.
+    pub fn written(&self) -> Vec<String> {
+        self.written.lock().unwrap().clone()
+    }
+}
+
+impl ClipboardWriter for MockClipboardWriter {
+    fn write_text(&self, text: &str) -> Result<(), String> {
+        if self.fail {
+            return Err("Mock clipboard failure".to_string());
+        }
+        self.written.lock().unwrap().push(text.to_string());
+        Ok(())
+    }
+
+    fn read_text(&self) -> Result<String, String> {
+        if self.fail {
+            return Err("Mock clipboard failure".to_string());
+        }
+        Ok(self.written.lock().unwrap().last().cloned().unwrap_or_default())
+    }
+}
@@ -2,8 +2,8 @@
 
 use crate::events::{
     CommandAmbiguousPayload, CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload,
-    RecordingCancelledPayload, RecordingErrorPayload, RecordingStartedPayload,
-    RecordingStoppedPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
+    RecordingCancelledPayload, RecordingEmptyPayload, RecordingErrorPayload, RecordingStartedPayload,
+    RecordingStateChangedPayload, RecordingStateRecoveredPayload, RecordingStoppedPayload, SilenceCountdownPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
     TranscriptionStartedPayload,
 };
 use std::sync::{Arc, Mutex};
@@ -15,6 +15,10 @@ pub struct MockEmitter {
     pub stopped: Arc<Mutex<Vec<RecordingStoppedPayload>>>,
     pub cancelled: Arc<Mutex<Vec<RecordingCancelledPayload>>>,
     pub errors: Arc<Mutex<Vec<RecordingErrorPayload>>>,
+    pub empty: Arc<Mutex<Vec<RecordingEmptyPayload>>>,
+    pub silence_countdown: Arc<Mutex<Vec<SilenceCountdownPayload>>>,
+    pub state_changes: Arc<Mutex<Vec<RecordingStateChangedPayload>>>,
+    pub state_recoveries: Arc<Mutex<Vec<RecordingStateRecoveredPayload>>>,
     pub transcription_started: Arc<Mutex<Vec<TranscriptionStartedPayload>>>,
     pub transcription_completed: Arc<Mutex<Vec<TranscriptionCompletedPayload>>>,
     pub transcription_errors: Arc<Mutex<Vec<TranscriptionErrorPayload>>>,
@@ -47,6 +51,10 @@ impl MockEmitter {
         self.cancelled.lock().unwrap().last().cloned()
     }
 
+    pub fn empty_count(&self) -> usize {
+        self.empty.lock().unwrap().len()
+    }
+
     pub fn key_blocking_unavailable_count(&self) -> usize {
         self.key_blocking_unavailable.lock().unwrap().len()
     }
@@ -68,6 +76,22 @@ impl crate::events::RecordingEventEmitter for MockEmitter {
     fn emit_recording_error(&self, payload: RecordingErrorPayload) {
         self.errors.lock().unwrap().push(payload);
     }
+
+    fn emit_recording_empty(&self, payload: RecordingEmptyPayload) {
+        self.empty.lock().unwrap().push(payload);
+    }
+
+    fn emit_silence_countdown(&self, payload: SilenceCountdownPayload) {
+        self.silence_countdown.lock().unwrap().push(payload);
+    }
+
+    fn emit_recording_state_changed(&self, payload: RecordingStateChangedPayload) {
+        self.state_changes.lock().unwrap().push(payload);
+    }
+
+    fn emit_recording_state_recovered(&self, payload: RecordingStateRecoveredPayload) {
+        self.state_recoveries.lock().unwrap().push(payload);
+    }
 }
 
 impl crate::events::TranscriptionEventEmitter for MockEmitter {
@@ -5,8 +5,10 @@
 
 pub mod fixtures;
 pub mod mock_backends;
+pub mod mock_clipboard;
 pub mod mock_emitters;
 
 pub use fixtures::ensure_test_model_files;
 pub use mock_backends::{FailingShortcutBackend, MockShortcutBackend};
+pub use mock_clipboard::MockClipboardWriter;
 pub use mock_emitters::MockEmitter;
@@ -0,0 +1,64 @@
+// Transcription benchmark
+// Transcribes a bundled speech sample a few times so the user can see how
+// fast the loaded model runs on their hardware before tuning settings.
+
+use serde::Serialize;
+use std::time::Duration;
+
+/// Bundled speech sample (16 kHz mono 16-bit WAV)
+pub const BENCHMARK_SAMPLE: &[u8] = include_bytes!("benchmark_sample.wav");
+
+/// Length of the bundled sample in seconds
+pub const BENCHMARK_SAMPLE_SECS: f64 = 10.0;
+
+/// Number of times the sample is transcribed
+pub const BENCHMARK_RUNS: usize = 3;
+
+/// Aggregate timing of a benchmark
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionBenchmark {
+    /// Number of transcriptions timed
+    pub runs: usize,
+    /// Length of the sample in seconds
+    pub audio_secs: f64,
+    /// Fastest run in milliseconds
+    pub min_ms: u64,
+    /// Mean run time in milliseconds
+    pub avg_ms: f64,
+    /// Slowest run in milliseconds
+    pub max_ms: u64,
+    /// Audio seconds transcribed per second of processing
+    pub real_time_factor: f64,
+    /// Whether transcription runs faster than the audio plays (factor above 1)
+    pub faster_than_real_time: bool,
+}
+
+/// Summarize the run times of a benchmark over a sample of `audio_secs`
+pub fn summarize_benchmark(audio_secs: f64, durations: &[Duration]) -> TranscriptionBenchmark {
+    let runs = durations.len();
+    let total_secs: f64 = durations.iter().map(Duration::as_secs_f64).sum();
+    let real_time_factor = if total_secs == 0.0 {
+        0.0
+    } else {
+        audio_secs * runs as f64 / total_secs
+    };
+
+    TranscriptionBenchmark {
+        runs,
+        audio_secs,
+        min_ms: durations.iter().min().map_or(0, |d| d.as_millis() as u64),
+        avg_ms: if runs == 0 {
+            0.0
+        } else {
+            total_secs * 1000.0 / runs as f64
+        },
+        max_ms: durations.iter().max().map_or(0, |d| d.as_millis() as u64),
+        real_time_factor,
+        faster_than_real_time: real_time_factor > 1.0,
+    }
+}
+
+#[cfg(test)]
+#[path = "benchmark_test.rs"]
+mod tests;
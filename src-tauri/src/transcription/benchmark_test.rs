@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn test_bundled_sample_matches_declared_length() {
+    let reader = hound::WavReader::new(std::io::Cursor::new(BENCHMARK_SAMPLE)).unwrap();
+    let spec = reader.spec();
+    assert_eq!(spec.sample_rate, 16000);
+    assert_eq!(spec.channels, 1);
+    let secs = reader.duration() as f64 / spec.sample_rate as f64;
+    assert!((secs - BENCHMARK_SAMPLE_SECS).abs() < 0.01);
+}
+
+#[test]
+fn test_summary_of_runs() {
+    let durations = [
+        Duration::from_millis(2000),
+        Duration::from_millis(4000),
+        Duration::from_millis(3000),
+    ];
+    let summary = summarize_benchmark(10.0, &durations);
+
+    assert_eq!(summary.runs, 3);
+    assert_eq!(summary.min_ms, 2000);
+    assert_eq!(summary.max_ms, 4000);
+    assert!((summary.avg_ms - 3000.0).abs() < 1e-9);
+    // 30 seconds of audio in 9 seconds of processing
+    assert!((summary.real_time_factor - 30.0 / 9.0).abs() < 1e-9);
+    assert!(summary.faster_than_real_time);
+}
+
+#[test]
+fn test_slower_than_real_time() {
+    let summary = summarize_benchmark(10.0, &[Duration::from_secs(20)]);
+    assert!((summary.real_time_factor - 0.5).abs() < 1e-9);
+    assert!(!summary.faster_than_real_time);
+}
+
+#[test]
+fn test_summary_without_runs_is_zero() {
+    let summary = summarize_benchmark(10.0, &[]);
+    assert_eq!(summary.runs, 0);
+    assert_eq!(summary.real_time_factor, 0.0);
+    assert!(!summary.faster_than_real_time);
+}
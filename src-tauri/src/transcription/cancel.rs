@@ -0,0 +1,124 @@
+// Transcription cancellation
+// After recording stops, the cancel shortcut stays active until transcription
+// finishes so it can be cancelled too. The model call itself can't be
+// interrupted, so a cancelled transcription runs to completion and its result
+// is discarded (nothing is pasted and no command is executed).
+//
+// The tracker also reports when the last in-flight transcription finishes, so
+// a recording start queued behind it can go ahead.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Error reported for a transcription discarded by the cancel shortcut
+pub const TRANSCRIPTION_CANCELLED_MESSAGE: &str = "Transcription cancelled";
+
+/// Callback fired when the last in-flight transcription finishes
+///
+/// Runs on the thread that finished the transcription, so it should hand any
+/// lengthy work off to another thread.
+pub type TranscriptionsFinishedHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Tracks in-flight transcriptions and cancellation requests
+pub struct TranscriptionCancellation {
+    /// Number of transcriptions that have started and not finished
+    in_flight: AtomicUsize,
+    /// Bumped on every cancel; tickets from an older generation are cancelled
+    generation: AtomicU64,
+    /// Fired when `in_flight` drops back to zero
+    finished_handler: Mutex<Option<TranscriptionsFinishedHandler>>,
+}
+
+impl TranscriptionCancellation {
+    pub const fn new() -> Self {
+        Self {
+            in_flight: AtomicUsize::new(0),
+            generation: AtomicU64::new(0),
+            finished_handler: Mutex::new(None),
+        }
+    }
+
+    /// Set the callback fired when the last in-flight transcription finishes
+    pub fn set_finished_handler(&self, handler: TranscriptionsFinishedHandler) {
+        if let Ok(mut finished_handler) = self.finished_handler.lock() {
+            *finished_handler = Some(handler);
+        }
+    }
+
+    /// Mark a transcription as started
+    ///
+    /// Call before spawning the transcription task so a cancel issued while it
+    /// is queued is not missed. The returned ticket ends it when dropped.
+    pub fn begin(&'static self) -> TranscriptionTicket {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        TranscriptionTicket {
+            owner: self,
+            generation: self.generation.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Cancel every in-flight transcription
+    ///
+    /// Returns false when nothing was in flight.
+    pub fn cancel(&self) -> bool {
+        if !self.in_flight() {
+            return false;
+        }
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        true
+    }
+
+    /// Whether any transcription is currently in flight
+    pub fn in_flight(&self) -> bool {
+        self.in_flight.load(Ordering::SeqCst) > 0
+    }
+}
+
+impl Default for TranscriptionCancellation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle for one in-flight transcription
+pub struct TranscriptionTicket {
+    owner: &'static TranscriptionCancellation,
+    generation: u64,
+}
+
+impl TranscriptionTicket {
+    /// Whether the transcription was cancelled after it started
+    pub fn is_cancelled(&self) -> bool {
+        self.owner.generation.load(Ordering::SeqCst) != self.generation
+    }
+}
+
+impl Drop for TranscriptionTicket {
+    fn drop(&mut self) {
+        if self.owner.in_flight.fetch_sub(1, Ordering::SeqCst) != 1 {
+            return;
+        }
+        // Clone out of the lock so the handler may replace itself
+        let handler = self
+            .owner
+            .finished_handler
+            .lock()
+            .ok()
+            .and_then(|handler| handler.clone());
+        if let Some(handler) = handler {
+            handler();
+        }
+    }
+}
+
+/// Process-wide transcription tracker shared by all transcription paths
+static TRANSCRIPTIONS: TranscriptionCancellation = TranscriptionCancellation::new();
+
+/// Get the process-wide transcription tracker
+pub fn transcriptions() -> &'static TranscriptionCancellation {
+    &TRANSCRIPTIONS
+}
+
+#[cfg(test)]
+#[path = "cancel_test.rs"]
+mod tests;
@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn test_cancel_with_nothing_in_flight_is_noop() {
+    static TRACKER: TranscriptionCancellation = TranscriptionCancellation::new();
+
+    assert!(!TRACKER.in_flight());
+    assert!(!TRACKER.cancel());
+}
+
+#[test]
+fn test_cancel_marks_in_flight_ticket_cancelled() {
+    static TRACKER: TranscriptionCancellation = TranscriptionCancellation::new();
+
+    let ticket = TRACKER.begin();
+    assert!(TRACKER.in_flight());
+    assert!(!ticket.is_cancelled());
+
+    assert!(TRACKER.cancel());
+    assert!(ticket.is_cancelled());
+}
+
+#[test]
+fn test_ticket_started_after_cancel_is_not_cancelled() {
+    static TRACKER: TranscriptionCancellation = TranscriptionCancellation::new();
+
+    let first = TRACKER.begin();
+    assert!(TRACKER.cancel());
+    let second = TRACKER.begin();
+
+    assert!(first.is_cancelled());
+    assert!(!second.is_cancelled());
+}
+
+#[test]
+fn test_dropping_ticket_ends_transcription() {
+    static TRACKER: TranscriptionCancellation = TranscriptionCancellation::new();
+
+    let ticket = TRACKER.begin();
+    drop(ticket);
+
+    assert!(!TRACKER.in_flight());
+}
+
+#[test]
+fn test_finished_handler_fires_when_last_ticket_drops() {
+    static TRACKER: TranscriptionCancellation = TranscriptionCancellation::new();
+    let fired = Arc::new(AtomicUsize::new(0));
+    let fired_clone = fired.clone();
+    TRACKER.set_finished_handler(Arc::new(move || {
+        fired_clone.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    let first = TRACKER.begin();
+    let second = TRACKER.begin();
+    drop(first);
+    assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+    drop(second);
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,105 @@
+// Clipboard writing abstraction
+// Output paths write transcribed text through `ClipboardWriter` rather than the
+// Tauri clipboard plugin directly, so they can be exercised in unit tests.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// Destination for transcribed text on the clipboard output path
+pub trait ClipboardWriter: Send + Sync {
+    /// Replace the clipboard contents with `text`
+    fn write_text(&self, text: &str) -> Result<(), String>;
+
+    /// Read the current clipboard contents as text
+    fn read_text(&self) -> Result<String, String>;
+}
+
+/// How often the clipboard is re-read while waiting for a write to land
+const CLIPBOARD_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Clipboard writer backed by the Tauri clipboard plugin
+pub struct TauriClipboardWriter {
+    app_handle: AppHandle,
+}
+
+impl TauriClipboardWriter {
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self { app_handle }
+    }
+}
+
+impl ClipboardWriter for TauriClipboardWriter {
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn write_text(&self, text: &str) -> Result<(), String> {
+        self.app_handle
+            .clipboard()
+            .write_text(text)
+            .map_err(|e| e.to_string())
+    }
+
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn read_text(&self) -> Result<String, String> {
+        self.app_handle
+            .clipboard()
+            .read_text()
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Clipboard writer the app was set up with
+///
+/// Paths outside `RecordingTranscriptionService` use the same writer the
+/// service was given, managed as app state. Falls back to the Tauri clipboard
+/// before setup has managed one.
+pub fn app_clipboard(app_handle: &AppHandle) -> Arc<dyn ClipboardWriter> {
+    match app_handle.try_state::<Arc<dyn ClipboardWriter>>() {
+        Some(clipboard) => clipboard.inner().clone(),
+        None => Arc::new(TauriClipboardWriter::new(app_handle.clone())),
+    }
+}
+
+/// Copy transcribed text to the clipboard, logging any failure
+///
+/// Callers only simulate a paste after a successful write so stale clipboard
+/// contents are never pasted. On failure the text should be handed to
+/// `output::fall_back_from_clipboard_failure` so it isn't lost.
+pub fn write_to_clipboard(clipboard: &dyn ClipboardWriter, text: &str) -> Result<(), String> {
+    match clipboard.write_text(text) {
+        Ok(()) => {
+            crate::debug!("Transcribed text copied to clipboard");
+            Ok(())
+        }
+        Err(e) => {
+            crate::warn!("Failed to copy to clipboard: {}", e);
+            Err(e)
+        }
+    }
+}
+
+/// Wait until the clipboard reads back `expected`, for at most `timeout`
+///
+/// Returns false if the text never showed up, in which case a paste may still
+/// pick up the previous clipboard contents. Blocks the calling thread while
+/// polling, so async callers run it on the blocking pool.
+pub fn wait_for_clipboard_text(
+    clipboard: &dyn ClipboardWriter,
+    expected: &str,
+    timeout: Duration,
+) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if clipboard.read_text().is_ok_and(|text| text == expected) {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        std::thread::sleep(CLIPBOARD_POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+#[path = "clipboard_test.rs"]
+mod tests;
@@ -0,0 +1,47 @@
+use super::*;
+use crate::test_utils::MockClipboardWriter;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_write_to_clipboard_writes_text() {
+    let clipboard = MockClipboardWriter::new();
+
+    assert!(write_to_clipboard(&clipboard, "hello world").is_ok());
+    assert_eq!(clipboard.written(), vec!["hello world".to_string()]);
+}
+
+#[test]
+fn test_write_to_clipboard_reports_failure() {
+    let clipboard = MockClipboardWriter::failing();
+
+    assert!(write_to_clipboard(&clipboard, "hello world").is_err());
+    assert!(clipboard.written().is_empty());
+}
+
+#[test]
+fn test_wait_for_clipboard_text_returns_once_written() {
+    let clipboard = MockClipboardWriter::new();
+    clipboard.write_text("hello world").unwrap();
+
+    let start = Instant::now();
+    assert!(wait_for_clipboard_text(
+        &clipboard,
+        "hello world",
+        Duration::from_secs(5)
+    ));
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn test_wait_for_clipboard_text_times_out_on_stale_contents() {
+    let clipboard = MockClipboardWriter::new();
+    clipboard.write_text("old text").unwrap();
+
+    let start = Instant::now();
+    assert!(!wait_for_clipboard_text(
+        &clipboard,
+        "new text",
+        Duration::from_millis(20)
+    ));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+}
@@ -0,0 +1,184 @@
+// Fallback transcription with a secondary model
+// When the primary model errors or times out on a recording, the transcription
+// is retried once with an optional secondary model before giving up. The
+// secondary model is loaded lazily on first use, so it costs no memory unless
+// a fallback actually happens.
+
+use crate::parakeet::{SharedTranscriptionModel, TranscriptionService};
+use crate::util::SettingsAccess;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+/// Settings key for the secondary model directory (empty or unset disables fallback)
+pub const FALLBACK_MODEL_PATH_KEY: &str = "transcription.fallbackModelPath";
+
+/// Model version recorded for text produced by the primary model
+pub const PRIMARY_MODEL_VERSION: &str = "parakeet-tdt";
+
+/// Model version recorded for text produced by the fallback model
+pub const FALLBACK_MODEL_VERSION: &str = "parakeet-tdt-fallback";
+
+/// Model version recorded for text produced by an explicitly chosen model directory
+///
+/// Includes the directory so re-transcriptions with different models can be
+/// told apart when diffing them.
+pub fn override_model_version(model_dir: &Path) -> String {
+    format!("{} ({})", PRIMARY_MODEL_VERSION, model_dir.display())
+}
+
+/// Lazily loaded secondary transcription model
+pub struct FallbackModel {
+    model: SharedTranscriptionModel,
+    /// Directory the model was loaded from, if loaded
+    loaded_dir: Mutex<Option<PathBuf>>,
+}
+
+impl Default for FallbackModel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallbackModel {
+    /// Create a fallback model holder without loading anything
+    pub fn new() -> Self {
+        Self {
+            model: SharedTranscriptionModel::new(),
+            loaded_dir: Mutex::new(None),
+        }
+    }
+
+    /// Transcribe a file with the model in `model_dir`, loading it first if needed
+    ///
+    /// The model is reloaded when the configured directory changes.
+    pub fn transcribe(&self, model_dir: &Path, file_path: &str) -> Result<String, String> {
+        {
+            let mut loaded_dir = self
+                .loaded_dir
+                .lock()
+                .map_err(|_| "Fallback model state is unavailable".to_string())?;
+            if loaded_dir.as_deref() != Some(model_dir) {
+                crate::info!("Loading fallback model from {}", model_dir.display());
+                *loaded_dir = None;
+                self.model
+                    .reload(model_dir)
+                    .map_err(|e| format!("Failed to load fallback model: {}", e))?;
+                *loaded_dir = Some(model_dir.to_path_buf());
+            }
+        }
+
+        let result = self.model.transcribe(file_path).map_err(|e| e.to_string());
+        if let Err(e) = self.model.reset_to_idle() {
+            crate::warn!("Failed to reset fallback model state: {}", e);
+        }
+        result
+    }
+}
+
+/// A fallback model together with the directory it should be loaded from
+#[derive(Clone)]
+pub struct FallbackTarget {
+    pub model: Arc<FallbackModel>,
+    pub model_dir: PathBuf,
+}
+
+/// Resolve the fallback model directory from its settings value
+///
+/// Returns None when the setting is unset or blank.
+pub fn fallback_model_dir_from_setting(value: Option<String>) -> Option<PathBuf> {
+    value
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Resolve the configured fallback target, if a fallback model is set up
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn resolve_fallback(app_handle: &AppHandle) -> Option<FallbackTarget> {
+    let model_dir =
+        fallback_model_dir_from_setting(app_handle.get_setting(FALLBACK_MODEL_PATH_KEY))?;
+    let model = app_handle.try_state::<Arc<FallbackModel>>()?.inner().clone();
+    Some(FallbackTarget { model, model_dir })
+}
+
+/// Run a blocking transcription on the blocking thread pool with a timeout
+///
+/// Returns the text, or a user-facing error message (already logged).
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn run_timed_transcription<F>(timeout: Duration, transcribe: F) -> Result<String, String>
+where
+    F: FnOnce() -> Result<String, String> + Send + 'static,
+{
+    match tokio::time::timeout(timeout, tokio::task::spawn_blocking(transcribe)).await {
+        Ok(Ok(Ok(text))) => Ok(text),
+        Ok(Ok(Err(e))) => {
+            crate::error!("Transcription failed: {}", e);
+            Err(e)
+        }
+        Ok(Err(e)) => {
+            crate::error!("Transcription task panicked: {}", e);
+            Err("Internal transcription error.".to_string())
+        }
+        Err(_) => {
+            crate::error!("Transcription timed out after {:?}", timeout);
+            Err(super::timeout::timed_out_message(timeout))
+        }
+    }
+}
+
+/// Transcribe with the primary model, retrying once with the fallback model
+///
+/// Returns the text and the model version that produced it. On failure the
+/// primary model's error is returned (with the fallback error appended when a
+/// fallback was attempted). The caller is responsible for resetting the
+/// primary model to idle.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn transcribe_with_fallback(
+    primary: &Arc<SharedTranscriptionModel>,
+    fallback: Option<FallbackTarget>,
+    file_path: String,
+    timeout: Duration,
+) -> Result<(String, &'static str), String> {
+    let transcriber = primary.clone();
+    let primary_path = file_path.clone();
+    let primary_error = match run_timed_transcription(timeout, move || {
+        transcriber
+            .transcribe(&primary_path)
+            .map_err(|e| e.to_string())
+    })
+    .await
+    {
+        Ok(text) => return Ok((text, PRIMARY_MODEL_VERSION)),
+        Err(e) => e,
+    };
+
+    let Some(FallbackTarget { model, model_dir }) = fallback else {
+        return Err(primary_error);
+    };
+
+    crate::warn!(
+        "Primary transcription failed, retrying with fallback model from {}",
+        model_dir.display()
+    );
+    if let Err(e) = primary.reset_to_idle() {
+        crate::warn!("Failed to reset transcription state: {}", e);
+    }
+
+    match run_timed_transcription(timeout, move || model.transcribe(&model_dir, &file_path)).await
+    {
+        Ok(text) => {
+            crate::info!("Fallback model produced the transcription");
+            Ok((text, FALLBACK_MODEL_VERSION))
+        }
+        Err(fallback_error) => Err(format!(
+            "{} (fallback model also failed: {})",
+            primary_error, fallback_error
+        )),
+    }
+}
+
+#[cfg(test)]
+#[path = "fallback_test.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_fallback_model_dir_unset_or_blank_disables_fallback() {
+    assert_eq!(fallback_model_dir_from_setting(None), None);
+    assert_eq!(fallback_model_dir_from_setting(Some(String::new())), None);
+    assert_eq!(fallback_model_dir_from_setting(Some("   ".to_string())), None);
+}
+
+#[test]
+fn test_fallback_model_dir_trims_path() {
+    assert_eq!(
+        fallback_model_dir_from_setting(Some(" /models/secondary ".to_string())),
+        Some(PathBuf::from("/models/secondary"))
+    );
+}
+
+#[test]
+fn test_override_model_version_includes_model_dir() {
+    assert_eq!(
+        override_model_version(Path::new("/models/tdt-v3")),
+        "parakeet-tdt (/models/tdt-v3)"
+    );
+}
+
+#[test]
+fn test_fallback_model_missing_dir_fails_to_load() {
+    let model = FallbackModel::new();
+    let result = model.transcribe(Path::new("/nonexistent/heycat-fallback"), "/tmp/a.wav");
+
+    assert!(result.unwrap_err().contains("Failed to load fallback model"));
+    assert!(model.loaded_dir.lock().unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_run_timed_transcription_returns_text() {
+    let result = run_timed_transcription(Duration::from_secs(1), || Ok("hello".to_string())).await;
+    assert_eq!(result, Ok("hello".to_string()));
+}
+
+#[tokio::test]
+async fn test_run_timed_transcription_passes_through_error() {
+    let result =
+        run_timed_transcription(Duration::from_secs(1), || Err("bad audio".to_string())).await;
+    assert_eq!(result, Err("bad audio".to_string()));
+}
+
+#[tokio::test]
+async fn test_run_timed_transcription_times_out() {
+    let result = run_timed_transcription(Duration::from_millis(10), || {
+        std::thread::sleep(Duration::from_millis(200));
+        Ok("late".to_string())
+    })
+    .await;
+    assert!(result.unwrap_err().contains("timed out"));
+}
@@ -0,0 +1,200 @@
+// Lightweight language guessing for transcription text
+// The Parakeet models are English-only, so speaking another language tends to
+// produce garbage output. This guesses the language from the text itself
+// (script detection, then common-word matching for Latin text) so the UI can
+// warn when a transcription didn't look like English.
+
+/// Minimum number of letters before a guess is attempted
+const MIN_LETTERS: usize = 4;
+
+/// Minimum number of common-word hits before a Latin-script language is reported
+const MIN_WORD_HITS: usize = 1;
+
+/// Language code reported for English text
+pub const ENGLISH: &str = "en";
+
+/// Common function words for Latin-script languages, checked in order
+const LATIN_WORD_LISTS: [(&str, &[&str]); 7] = [
+    (
+        "en",
+        &[
+            "the", "and", "is", "are", "was", "to", "of", "in", "it", "that", "this", "with",
+            "for", "you", "i", "my", "on", "what", "can", "be",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ich", "ein", "eine", "mit", "auf", "zu",
+            "den", "sie", "wir", "es",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "est", "une", "je", "pas", "des", "du", "que", "pour", "vous",
+            "nous", "avec", "c'est",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "los", "las", "y", "es", "una", "que", "por", "para", "con", "del", "yo", "pero",
+            "muy", "está",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "gli", "e", "è", "di", "che", "non", "per", "sono", "una", "con", "della",
+            "questo",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "os", "as", "e", "é", "um", "uma", "não", "que", "para", "com", "do", "da", "eu",
+            "você",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "is", "niet", "ik", "van", "dat", "op", "je", "wij", "zijn",
+        ],
+    ),
+];
+
+/// Writing systems distinguished by the script check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Thai,
+    Hangul,
+    Kana,
+    Han,
+}
+
+impl Script {
+    /// Classify a single alphabetic character
+    fn of(c: char) -> Option<Self> {
+        match c as u32 {
+            0x0041..=0x024F => Some(Script::Latin),
+            0x0370..=0x03FF => Some(Script::Greek),
+            0x0400..=0x052F => Some(Script::Cyrillic),
+            0x0590..=0x05FF => Some(Script::Hebrew),
+            0x0600..=0x06FF | 0x0750..=0x077F => Some(Script::Arabic),
+            0x0900..=0x097F => Some(Script::Devanagari),
+            0x0E00..=0x0E7F => Some(Script::Thai),
+            0x1100..=0x11FF | 0xAC00..=0xD7AF => Some(Script::Hangul),
+            0x3040..=0x30FF => Some(Script::Kana),
+            0x4E00..=0x9FFF | 0x3400..=0x4DBF => Some(Script::Han),
+            _ => None,
+        }
+    }
+
+    /// Language code most commonly associated with a non-Latin script
+    fn language(self) -> Option<&'static str> {
+        match self {
+            Script::Latin => None,
+            Script::Cyrillic => Some("ru"),
+            Script::Greek => Some("el"),
+            Script::Arabic => Some("ar"),
+            Script::Hebrew => Some("he"),
+            Script::Devanagari => Some("hi"),
+            Script::Thai => Some("th"),
+            Script::Hangul => Some("ko"),
+            Script::Kana => Some("ja"),
+            Script::Han => Some("zh"),
+        }
+    }
+}
+
+/// Guess the language of a transcription from its text
+///
+/// Returns an ISO 639-1 code, or `None` when the text is too short or has no
+/// recognisable signal (e.g. a two-word voice command).
+pub fn guess_language(text: &str) -> Option<&'static str> {
+    let mut counts: Vec<(Script, usize)> = Vec::new();
+    let mut letters = 0;
+    for script in text
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .filter_map(Script::of)
+    {
+        letters += 1;
+        match counts.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+
+    if letters < MIN_LETTERS {
+        return None;
+    }
+
+    // Kana mixed with Han is Japanese, even when Han characters dominate
+    let has_kana = counts.iter().any(|(s, _)| *s == Script::Kana);
+    let (dominant, _) = counts.iter().copied().max_by_key(|(_, count)| *count)?;
+    if dominant == Script::Han && has_kana {
+        return Script::Kana.language();
+    }
+    if dominant != Script::Latin {
+        return dominant.language();
+    }
+
+    guess_latin_language(text)
+}
+
+/// Score Latin-script text against the common-word lists
+fn guess_latin_language(text: &str) -> Option<&'static str> {
+    let words: Vec<String> = text
+        .split(|c: char| !(c.is_alphabetic() || c == '\''))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+
+    let mut best: Option<(&'static str, usize)> = None;
+    for &(language, list) in LATIN_WORD_LISTS.iter() {
+        let hits = words.iter().filter(|w| list.contains(&w.as_str())).count();
+        // Earlier lists win ties, so English is preferred for ambiguous text
+        let better = match best {
+            Some((_, best_hits)) => hits > best_hits,
+            None => true,
+        };
+        if hits >= MIN_WORD_HITS && better {
+            best = Some((language, hits));
+        }
+    }
+
+    best.map(|(language, _)| language)
+}
+
+/// Whether a guessed language indicates the text likely wasn't English
+pub fn is_likely_non_english(language: Option<&str>) -> bool {
+    language.is_some_and(|l| l != ENGLISH)
+}
+
+/// Guess the language of a finished transcription for its completed event
+///
+/// Logs a warning when the text likely wasn't English, since the model's
+/// output is then usually garbage.
+pub fn guess_transcription_language(text: &str) -> Option<String> {
+    let language = guess_language(text);
+    if let Some(language) = language.filter(|l| is_likely_non_english(Some(l))) {
+        crate::warn!(
+            "Transcription didn't look like English (guessed {})",
+            language
+        );
+    }
+    language.map(String::from)
+}
+
+#[cfg(test)]
+#[path = "language_test.rs"]
+mod tests;
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn test_english_sentence_is_detected() {
+    assert_eq!(
+        guess_language("What is the weather going to be like this weekend?"),
+        Some("en")
+    );
+}
+
+#[test]
+fn test_short_text_is_undetermined() {
+    assert_eq!(guess_language("ok"), None);
+    assert_eq!(guess_language(""), None);
+}
+
+#[test]
+fn test_latin_text_without_common_words_is_undetermined() {
+    assert_eq!(guess_language("open slack"), None);
+}
+
+#[test]
+fn test_other_latin_languages_are_detected() {
+    assert_eq!(
+        guess_language("Ich weiß nicht, ob das eine gute Idee ist"),
+        Some("de")
+    );
+    assert_eq!(
+        guess_language("Je ne sais pas si c'est une bonne idée pour nous"),
+        Some("fr")
+    );
+    assert_eq!(
+        guess_language("Los niños están en el parque con sus amigos"),
+        Some("es")
+    );
+}
+
+#[test]
+fn test_non_latin_scripts_are_detected() {
+    assert_eq!(guess_language("Привет, как дела?"), Some("ru"));
+    assert_eq!(guess_language("Καλημέρα σας"), Some("el"));
+    assert_eq!(guess_language("안녕하세요 반갑습니다"), Some("ko"));
+    assert_eq!(guess_language("你好世界今天"), Some("zh"));
+}
+
+#[test]
+fn test_kana_with_han_is_japanese() {
+    assert_eq!(guess_language("今日は良い天気です"), Some("ja"));
+}
+
+#[test]
+fn test_is_likely_non_english() {
+    assert!(!is_likely_non_english(None));
+    assert!(!is_likely_non_english(Some("en")));
+    assert!(is_likely_non_english(Some("de")));
+}
@@ -0,0 +1,132 @@
+// Local log of command match outcomes
+// An opt-in, append-only JSONL file with one line per transcription that went
+// through command matching, for users who want to analyse how their voice
+// commands match. It is written locally only and is separate from the match
+// statistics kept in the database.
+
+use super::sinks::append_line;
+use crate::util::{get_settings_file, SettingsAccess};
+use crate::voice_commands::matcher::MatchResult;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+/// Settings key enabling the match log (default off)
+pub const MATCH_LOG_ENABLED_KEY: &str = "commands.matchLog";
+
+/// Settings key for the match log file (empty or unset uses the data directory)
+pub const MATCH_LOG_PATH_KEY: &str = "commands.matchLogPath";
+
+/// File name of the match log in the data directory
+pub const DEFAULT_MATCH_LOG_FILE: &str = "match-log.jsonl";
+
+/// One line of the match log
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MatchLogEntry {
+    /// ISO 8601 timestamp when the text was matched
+    pub timestamp: String,
+    /// The transcribed text
+    pub text: String,
+    /// Match outcome: exact, fuzzy, ambiguous or no_match
+    pub outcome: String,
+    /// ID of the matched command (exact and fuzzy matches only)
+    pub command_id: Option<String>,
+    /// Match confidence (best candidate score when ambiguous)
+    pub confidence: Option<f64>,
+}
+
+impl MatchLogEntry {
+    /// Build the entry for a match result
+    pub fn new(timestamp: String, text: &str, result: &MatchResult) -> Self {
+        let command_id = match result {
+            MatchResult::Exact { command, .. } | MatchResult::Fuzzy { command, .. } => {
+                Some(command.id.to_string())
+            }
+            MatchResult::Ambiguous { .. } | MatchResult::NoMatch => None,
+        };
+        Self {
+            timestamp,
+            text: text.to_string(),
+            outcome: result.outcome().to_string(),
+            command_id,
+            confidence: result.confidence(),
+        }
+    }
+
+    /// Serialize the entry as a single JSONL line
+    pub fn to_line(&self) -> String {
+        let mut line = serde_json::to_string(self).unwrap_or_default();
+        line.push('\n');
+        line
+    }
+}
+
+/// Resolve the match log file from its settings value
+///
+/// An empty or unset path falls back to `DEFAULT_MATCH_LOG_FILE` in `data_dir`.
+pub fn match_log_path_from_setting(
+    value: Option<&str>,
+    data_dir: Option<&Path>,
+) -> Option<PathBuf> {
+    match value.map(str::trim).filter(|path| !path.is_empty()) {
+        Some(path) => Some(PathBuf::from(path)),
+        None => data_dir.map(|dir| dir.join(DEFAULT_MATCH_LOG_FILE)),
+    }
+}
+
+/// Match log settings shown in the UI
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MatchLogSettings {
+    /// Whether match outcomes are being logged
+    pub enabled: bool,
+    /// File the log is written to, or None if no path could be resolved
+    pub path: Option<String>,
+}
+
+/// Read the match log settings
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn match_log_settings(app_handle: &AppHandle) -> MatchLogSettings {
+    let enabled = app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(MATCH_LOG_ENABLED_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let worktree_context = app_handle
+        .try_state::<crate::worktree::WorktreeState>()
+        .and_then(|s| s.context.clone());
+    let data_dir = crate::paths::get_data_dir(worktree_context.as_ref()).ok();
+    let path = match_log_path_from_setting(
+        app_handle.get_setting(MATCH_LOG_PATH_KEY).as_deref(),
+        data_dir.as_deref(),
+    );
+    MatchLogSettings {
+        enabled,
+        path: path.map(|p| p.to_string_lossy().to_string()),
+    }
+}
+
+/// Append a match outcome to the match log if it is enabled
+///
+/// Failures are logged and never affect the transcription.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn record_match_outcome(app_handle: &AppHandle, text: &str, result: &MatchResult) {
+    let settings = match_log_settings(app_handle);
+    if !settings.enabled {
+        return;
+    }
+    let Some(path) = settings.path else {
+        crate::warn!("Match log enabled but no file path could be resolved");
+        return;
+    };
+
+    let entry = MatchLogEntry::new(crate::events::current_timestamp(), text, result);
+    if let Err(e) = append_line(Path::new(&path), &entry.to_line()) {
+        crate::warn!("Failed to write match log: {}", e);
+    }
+}
+
+#[cfg(test)]
+#[path = "match_log_test.rs"]
+mod tests;
@@ -0,0 +1,54 @@
+use super::*;
+use crate::voice_commands::matcher::MatchedCommand;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[test]
+fn test_entry_for_fuzzy_match_has_command_and_confidence() {
+    let id = Uuid::new_v4();
+    let result = MatchResult::Fuzzy {
+        command: MatchedCommand {
+            id,
+            trigger: "open slack".to_string(),
+        },
+        score: 0.82,
+        parameters: HashMap::new(),
+    };
+
+    let entry = MatchLogEntry::new("2025-01-01T12:00:00Z".to_string(), "open slak", &result);
+    assert_eq!(entry.outcome, "fuzzy");
+    assert_eq!(entry.command_id, Some(id.to_string()));
+    assert_eq!(entry.confidence, Some(0.82));
+}
+
+#[test]
+fn test_entry_serializes_as_one_jsonl_line() {
+    let entry = MatchLogEntry::new("ts".to_string(), "hello\nworld", &MatchResult::NoMatch);
+    let line = entry.to_line();
+
+    assert!(line.ends_with('\n'));
+    assert_eq!(line.matches('\n').count(), 1);
+    let value: serde_json::Value = serde_json::from_str(line.trim_end()).unwrap();
+    assert_eq!(value["text"], "hello\nworld");
+    assert_eq!(value["outcome"], "no_match");
+    assert!(value["command_id"].is_null());
+    assert!(value["confidence"].is_null());
+}
+
+#[test]
+fn test_path_from_setting_falls_back_to_data_dir() {
+    let data_dir = Path::new("/data/heycat");
+    assert_eq!(
+        match_log_path_from_setting(None, Some(data_dir)),
+        Some(data_dir.join(DEFAULT_MATCH_LOG_FILE))
+    );
+    assert_eq!(
+        match_log_path_from_setting(Some("  "), Some(data_dir)),
+        Some(data_dir.join(DEFAULT_MATCH_LOG_FILE))
+    );
+    assert_eq!(
+        match_log_path_from_setting(Some("/tmp/matches.jsonl"), None),
+        Some(PathBuf::from("/tmp/matches.jsonl"))
+    );
+    assert_eq!(match_log_path_from_setting(None, None), None);
+}
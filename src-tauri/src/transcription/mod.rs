@@ -1,6 +1,23 @@
 // Transcription service module
 // Provides unified transcription flow for all recording triggers (hotkey, UI button, wake word)
 
+pub mod benchmark;
+pub mod cancel;
+pub mod clipboard;
+pub mod fallback;
+pub mod language;
+pub mod match_log;
+pub mod output;
+pub mod perf;
+pub mod queue;
+pub mod repetition;
+pub mod sinks;
+pub mod timeout;
+pub mod transforms;
+pub mod verbose_logging;
 mod service;
 
 pub use service::RecordingTranscriptionService;
+pub(crate) use service::{
+    deliver_output, report_command_failures, skip_transcription_without_model, CommandOutcome,
+};
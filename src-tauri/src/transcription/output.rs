@@ -0,0 +1,264 @@
+// Transcription output modes
+// Decides what happens to transcribed text once it is ready: paste it into the
+// focused app (default), or append it to a running clipboard buffer. Text the
+// clipboard refuses is typed or reported rather than dropped.
+
+use super::clipboard::{wait_for_clipboard_text, ClipboardWriter};
+use crate::emit_or_warn;
+use crate::events::{
+    current_timestamp, event_names, PastePausedPayload, TranscriptionOutputFailedPayload,
+};
+use crate::keyboard::synth::TypingOptions;
+use crate::util::{get_settings_file, SettingsAccess};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+/// Settings key for the output mode ("paste" or "clipboardAppend")
+pub const OUTPUT_MODE_KEY: &str = "output.mode";
+
+/// Settings key for the separator placed between appended transcriptions
+pub const APPEND_SEPARATOR_KEY: &str = "output.appendSeparator";
+
+/// Settings key for the maximum clipboard buffer size in characters
+pub const APPEND_MAX_CHARS_KEY: &str = "output.appendMaxChars";
+
+/// Settings key for how long to wait for a clipboard write to land before pasting
+pub const PASTE_SYNC_TIMEOUT_MS_KEY: &str = "output.pasteSyncTimeoutMs";
+
+/// Settings key: copy without pasting while the screen is recorded or shared (bool, default false)
+pub const PAUSE_PASTE_ON_SCREEN_CAPTURE_KEY: &str = "output.pausePasteOnScreenCapture";
+
+/// Settings key for what happens when the clipboard write fails ("type" or "notify")
+pub const CLIPBOARD_FAILURE_FALLBACK_KEY: &str = "output.clipboardFailureFallback";
+
+/// `paste_paused` reason when screen capture is active
+pub const PASTE_PAUSED_SCREEN_CAPTURE: &str = "screen_capture";
+
+/// Default wait for a clipboard write to land before simulating paste
+pub const DEFAULT_PASTE_SYNC_TIMEOUT_MS: u64 = 250;
+
+/// Upper bound on the configurable paste sync wait, so a bad setting can't stall output
+pub const MAX_PASTE_SYNC_TIMEOUT_MS: u64 = 2_000;
+
+/// Default separator between appended transcriptions
+pub const DEFAULT_APPEND_SEPARATOR: &str = "\n";
+
+/// Default cap on the clipboard buffer size in characters
+pub const DEFAULT_APPEND_MAX_CHARS: usize = 100_000;
+
+/// How transcribed text is delivered to the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputMode {
+    /// Replace the clipboard and simulate Cmd+V
+    #[default]
+    Paste,
+    /// Append to the existing clipboard contents without pasting
+    ClipboardAppend,
+}
+
+impl OutputMode {
+    /// Parse an output mode from its settings value, defaulting to Paste
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            Some("clipboardAppend") => OutputMode::ClipboardAppend,
+            _ => OutputMode::Paste,
+        }
+    }
+
+    /// Read the configured output mode from settings
+    pub fn from_settings(app_handle: &AppHandle) -> Self {
+        Self::from_setting(app_handle.get_setting(OUTPUT_MODE_KEY).as_deref())
+    }
+}
+
+/// What happens to transcribed text the clipboard refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClipboardFailureFallback {
+    /// Type the text into the focused app, reporting it if typing fails too
+    #[default]
+    Type,
+    /// Only report the text so the user can copy it manually
+    Notify,
+}
+
+impl ClipboardFailureFallback {
+    /// Parse the fallback from its settings value, defaulting to Type
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            Some("notify") => ClipboardFailureFallback::Notify,
+            _ => ClipboardFailureFallback::Type,
+        }
+    }
+
+    /// Read the configured fallback from settings
+    pub fn from_settings(app_handle: &AppHandle) -> Self {
+        Self::from_setting(app_handle.get_setting(CLIPBOARD_FAILURE_FALLBACK_KEY).as_deref())
+    }
+}
+
+/// Keep a transcription the clipboard refused from being lost
+///
+/// When the text was going to be pasted and the fallback is "type", it is
+/// typed into the focused app instead, which needs Accessibility permission.
+/// Otherwise, or if typing fails, `transcription_output_failed` is emitted
+/// with the text so the UI can offer to copy it manually.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn fall_back_from_clipboard_failure(
+    app_handle: &AppHandle,
+    text: &str,
+    error: &str,
+    paste: bool,
+) {
+    if paste
+        && ClipboardFailureFallback::from_settings(app_handle) == ClipboardFailureFallback::Type
+    {
+        match crate::keyboard::synth::type_unicode_text(text, &TypingOptions::default()) {
+            Ok(()) => {
+                crate::info!("Clipboard write failed, typed transcription instead");
+                return;
+            }
+            Err(e) => {
+                crate::warn!("Failed to type transcription after clipboard failure: {}", e);
+                crate::keyboard::notify_if_permission_missing(app_handle, "type", &e);
+            }
+        }
+    }
+
+    emit_or_warn!(
+        app_handle,
+        event_names::TRANSCRIPTION_OUTPUT_FAILED,
+        TranscriptionOutputFailedPayload {
+            text: text.to_string(),
+            error: error.to_string(),
+            timestamp: current_timestamp(),
+        }
+    );
+}
+
+/// Resolve the paste sync wait from its settings value
+///
+/// Falls back to the default when unset and clamps to `MAX_PASTE_SYNC_TIMEOUT_MS`.
+pub fn paste_sync_timeout_from_setting(value: Option<u64>) -> Duration {
+    Duration::from_millis(
+        value
+            .unwrap_or(DEFAULT_PASTE_SYNC_TIMEOUT_MS)
+            .min(MAX_PASTE_SYNC_TIMEOUT_MS),
+    )
+}
+
+/// Wait for a clipboard write to propagate before simulating paste
+///
+/// Some apps read the pasteboard before a fresh write is visible to them and
+/// paste stale content, so the clipboard is read back until it holds `text`.
+/// The wait is bounded by `output.pasteSyncTimeoutMs`; on timeout the paste
+/// goes ahead anyway. This blocks the calling thread, so async callers run the
+/// paste path on the blocking pool.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn wait_for_clipboard_sync(
+    app_handle: &AppHandle,
+    clipboard: &dyn ClipboardWriter,
+    text: &str,
+) {
+    let value = app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(PASTE_SYNC_TIMEOUT_MS_KEY))
+        .and_then(|v| v.as_u64());
+    let timeout = paste_sync_timeout_from_setting(value);
+    if !wait_for_clipboard_text(clipboard, text, timeout) {
+        crate::warn!(
+            "Clipboard write not visible after {:?}, pasting anyway",
+            timeout
+        );
+    }
+}
+
+/// Whether auto-paste should be skipped because the screen is being captured
+///
+/// Only checks for screen capture when `output.pausePasteOnScreenCapture` is
+/// enabled. When paste is paused, `paste_paused` is emitted so the UI can
+/// show that the text was left on the clipboard instead.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn paste_paused_for_screen_capture(app_handle: &AppHandle) -> bool {
+    let enabled = app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(PAUSE_PASTE_ON_SCREEN_CAPTURE_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    if !enabled || !crate::swift::is_screen_captured() {
+        return false;
+    }
+
+    crate::info!("Screen capture active, copying transcription without pasting");
+    emit_or_warn!(
+        app_handle,
+        event_names::PASTE_PAUSED,
+        PastePausedPayload {
+            reason: PASTE_PAUSED_SCREEN_CAPTURE.to_string(),
+            timestamp: current_timestamp(),
+        }
+    );
+    true
+}
+
+/// Append text to an existing buffer, keeping the result within `max_chars`
+///
+/// When the combined buffer exceeds the cap, the oldest characters are dropped
+/// so the most recent dictation is always preserved. A cap of 0 disables the
+/// limit.
+pub fn append_to_buffer(existing: &str, text: &str, separator: &str, max_chars: usize) -> String {
+    let combined = if existing.is_empty() {
+        text.to_string()
+    } else {
+        format!("{}{}{}", existing, separator, text)
+    };
+
+    let char_count = combined.chars().count();
+    if max_chars == 0 || char_count <= max_chars {
+        return combined;
+    }
+
+    combined.chars().skip(char_count - max_chars).collect()
+}
+
+/// Append text to the clipboard contents through `clipboard`
+///
+/// An empty or unreadable clipboard starts a fresh buffer. Returns the write
+/// error, if any.
+pub fn append_to_clipboard_buffer(
+    clipboard: &dyn ClipboardWriter,
+    text: &str,
+    separator: &str,
+    max_chars: usize,
+) -> Result<(), String> {
+    let existing = clipboard.read_text().unwrap_or_default();
+    clipboard.write_text(&append_to_buffer(&existing, text, separator, max_chars))
+}
+
+/// Append a transcription to the clipboard buffer (no paste is simulated)
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn append_to_clipboard(app_handle: &AppHandle, clipboard: &dyn ClipboardWriter, text: &str) {
+    let separator = app_handle
+        .get_setting(APPEND_SEPARATOR_KEY)
+        .unwrap_or_else(|| DEFAULT_APPEND_SEPARATOR.to_string());
+    let max_chars = app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(APPEND_MAX_CHARS_KEY))
+        .and_then(|v| v.as_u64())
+        .map(|v| v as usize)
+        .unwrap_or(DEFAULT_APPEND_MAX_CHARS);
+
+    if let Err(e) = append_to_clipboard_buffer(clipboard, text, &separator, max_chars) {
+        crate::warn!("Failed to append to clipboard: {}", e);
+        fall_back_from_clipboard_failure(app_handle, text, &e, false);
+        return;
+    }
+    crate::debug!("Transcribed text appended to clipboard buffer");
+}
+
+#[cfg(test)]
+#[path = "output_test.rs"]
+mod tests;
@@ -0,0 +1,105 @@
+use super::*;
+use crate::test_utils::MockClipboardWriter;
+
+#[test]
+fn test_output_mode_from_setting() {
+    assert_eq!(OutputMode::from_setting(None), OutputMode::Paste);
+    assert_eq!(OutputMode::from_setting(Some("paste")), OutputMode::Paste);
+    assert_eq!(
+        OutputMode::from_setting(Some("clipboardAppend")),
+        OutputMode::ClipboardAppend
+    );
+    assert_eq!(OutputMode::from_setting(Some("bogus")), OutputMode::Paste);
+}
+
+#[test]
+fn test_clipboard_failure_fallback_from_setting() {
+    assert_eq!(
+        ClipboardFailureFallback::from_setting(None),
+        ClipboardFailureFallback::Type
+    );
+    assert_eq!(
+        ClipboardFailureFallback::from_setting(Some("type")),
+        ClipboardFailureFallback::Type
+    );
+    assert_eq!(
+        ClipboardFailureFallback::from_setting(Some("notify")),
+        ClipboardFailureFallback::Notify
+    );
+    assert_eq!(
+        ClipboardFailureFallback::from_setting(Some("bogus")),
+        ClipboardFailureFallback::Type
+    );
+}
+
+#[test]
+fn test_append_to_empty_buffer_has_no_separator() {
+    assert_eq!(append_to_buffer("", "hello", "\n", 100), "hello");
+}
+
+#[test]
+fn test_append_uses_separator() {
+    assert_eq!(append_to_buffer("first", "second", " | ", 100), "first | second");
+}
+
+#[test]
+fn test_append_drops_oldest_when_over_cap() {
+    let result = append_to_buffer("abcdef", "xyz", "-", 6);
+    assert_eq!(result, "ef-xyz");
+}
+
+#[test]
+fn test_append_cap_respects_multibyte_chars() {
+    let result = append_to_buffer("héllo", "wörld", " ", 7);
+    assert_eq!(result, "o wörld");
+    assert_eq!(result.chars().count(), 7);
+}
+
+#[test]
+fn test_append_zero_cap_is_unlimited() {
+    let existing = "a".repeat(1000);
+    let result = append_to_buffer(&existing, "b", "", 0);
+    assert_eq!(result.len(), 1001);
+}
+
+#[test]
+fn test_paste_sync_timeout_defaults_when_unset() {
+    assert_eq!(
+        paste_sync_timeout_from_setting(None),
+        Duration::from_millis(DEFAULT_PASTE_SYNC_TIMEOUT_MS)
+    );
+}
+
+#[test]
+fn test_paste_sync_timeout_uses_configured_value() {
+    assert_eq!(paste_sync_timeout_from_setting(Some(0)), Duration::ZERO);
+    assert_eq!(
+        paste_sync_timeout_from_setting(Some(150)),
+        Duration::from_millis(150)
+    );
+}
+
+#[test]
+fn test_paste_sync_timeout_is_clamped() {
+    assert_eq!(
+        paste_sync_timeout_from_setting(Some(60_000)),
+        Duration::from_millis(MAX_PASTE_SYNC_TIMEOUT_MS)
+    );
+}
+
+#[test]
+fn test_append_to_clipboard_buffer_appends_to_current_contents() {
+    let clipboard = MockClipboardWriter::new();
+    clipboard.write_text("first").unwrap();
+
+    append_to_clipboard_buffer(&clipboard, "second", "\n", 100).unwrap();
+
+    assert_eq!(clipboard.read_text().unwrap(), "first\nsecond");
+}
+
+#[test]
+fn test_append_to_clipboard_buffer_reports_write_failure() {
+    let clipboard = MockClipboardWriter::failing();
+
+    assert!(append_to_clipboard_buffer(&clipboard, "text", "\n", 100).is_err());
+}
@@ -0,0 +1,97 @@
+// Transcription latency tracking
+// Keeps the durations of the most recent transcriptions so the user can see
+// whether the model is slowing down and judge the speed of a model size.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Number of recent transcriptions kept for the latency summary
+pub const PERF_WINDOW: usize = 50;
+
+/// One completed transcription
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PerfSample {
+    duration_ms: u64,
+    chars: usize,
+}
+
+/// Latency summary over the recent transcriptions
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionPerf {
+    /// Number of transcriptions summarized (at most `PERF_WINDOW`)
+    pub count: usize,
+    /// Fastest transcription in milliseconds
+    pub min_ms: u64,
+    /// Mean transcription time in milliseconds
+    pub avg_ms: f64,
+    /// Slowest transcription in milliseconds
+    pub max_ms: u64,
+    /// 95th percentile transcription time in milliseconds
+    pub p95_ms: u64,
+    /// Characters of text produced per second of transcription time
+    pub chars_per_second: f64,
+}
+
+/// Ring of recent transcription durations
+#[derive(Debug, Clone)]
+pub struct TranscriptionPerfTracker {
+    samples: VecDeque<PerfSample>,
+    capacity: usize,
+}
+
+impl TranscriptionPerfTracker {
+    /// Create a tracker keeping the last `capacity` transcriptions
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Record a completed transcription, evicting the oldest when full
+    pub fn record(&mut self, duration_ms: u64, chars: usize) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(PerfSample { duration_ms, chars });
+    }
+
+    /// Summarize the recorded transcriptions (all zero when there are none)
+    pub fn summary(&self) -> TranscriptionPerf {
+        if self.samples.is_empty() {
+            return TranscriptionPerf::default();
+        }
+
+        let mut durations: Vec<u64> = self.samples.iter().map(|s| s.duration_ms).collect();
+        durations.sort_unstable();
+        let count = durations.len();
+        let total_ms: u64 = durations.iter().sum();
+        let total_chars: usize = self.samples.iter().map(|s| s.chars).sum();
+        // Nearest-rank percentile
+        let p95_index = ((count as f64 * 0.95).ceil() as usize).clamp(1, count) - 1;
+
+        TranscriptionPerf {
+            count,
+            min_ms: durations[0],
+            avg_ms: total_ms as f64 / count as f64,
+            max_ms: durations[count - 1],
+            p95_ms: durations[p95_index],
+            chars_per_second: if total_ms == 0 {
+                0.0
+            } else {
+                total_chars as f64 * 1000.0 / total_ms as f64
+            },
+        }
+    }
+}
+
+impl Default for TranscriptionPerfTracker {
+    fn default() -> Self {
+        Self::new(PERF_WINDOW)
+    }
+}
+
+#[cfg(test)]
+#[path = "perf_test.rs"]
+mod tests;
@@ -0,0 +1,38 @@
+use super::*;
+
+#[test]
+fn test_empty_summary_is_zero() {
+    assert_eq!(
+        TranscriptionPerfTracker::default().summary(),
+        TranscriptionPerf::default()
+    );
+}
+
+#[test]
+fn test_summary_stats() {
+    let mut tracker = TranscriptionPerfTracker::new(PERF_WINDOW);
+    for duration_ms in 1..=20 {
+        tracker.record(duration_ms * 100, 50);
+    }
+
+    let perf = tracker.summary();
+    assert_eq!(perf.count, 20);
+    assert_eq!(perf.min_ms, 100);
+    assert_eq!(perf.max_ms, 2000);
+    assert_eq!(perf.avg_ms, 1050.0);
+    assert_eq!(perf.p95_ms, 1900);
+    // 1000 chars over 21 seconds
+    assert!((perf.chars_per_second - 1000.0 / 21.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_oldest_samples_are_evicted() {
+    let mut tracker = TranscriptionPerfTracker::new(2);
+    tracker.record(5000, 10);
+    tracker.record(100, 10);
+    tracker.record(300, 10);
+
+    let perf = tracker.summary();
+    assert_eq!(perf.count, 2);
+    assert_eq!((perf.min_ms, perf.max_ms), (100, 300));
+}
@@ -0,0 +1,164 @@
+// Waiting for a transcription slot
+//
+// Transcriptions are limited by a semaphore. By default a recording that
+// arrives while every permit is taken is dropped with an error. With queueing
+// turned on it instead waits for a permit, up to a bounded number of waiting
+// recordings and a timeout, after which it is dropped with the same error.
+
+use crate::util::get_settings_file;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+/// Settings key for queueing recordings while all transcription slots are busy
+pub const QUEUE_WHEN_BUSY_KEY: &str = "transcription.queueWhenBusy";
+
+/// Settings key for how many recordings may wait for a slot at once
+pub const QUEUE_LIMIT_KEY: &str = "transcription.queueLimit";
+
+/// Settings key for how long a queued recording waits in seconds
+pub const QUEUE_TIMEOUT_SECS_KEY: &str = "transcription.queueTimeoutSecs";
+
+/// Default number of recordings that may wait for a slot
+pub const DEFAULT_QUEUE_LIMIT: usize = 4;
+
+/// Default seconds a queued recording waits before giving up
+pub const DEFAULT_QUEUE_TIMEOUT_SECS: u64 = 120;
+
+/// Error shown when a recording can't get a transcription slot
+pub const TRANSCRIPTION_BUSY_MESSAGE: &str =
+    "Too many transcriptions in progress. Please wait and try again.";
+
+/// What to do with a recording when all transcription slots are busy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BusyPolicy {
+    /// Drop the recording straight away
+    #[default]
+    Drop,
+    /// Wait for a slot, with at most `limit` recordings waiting for `timeout`
+    Queue { limit: usize, timeout: Duration },
+}
+
+impl BusyPolicy {
+    /// Resolve the policy from its settings values
+    ///
+    /// A limit or timeout of 0 leaves nothing to wait for, so it drops.
+    pub fn from_settings(queue: Option<bool>, limit: Option<u64>, timeout: Option<u64>) -> Self {
+        if !queue.unwrap_or(false) {
+            return Self::Drop;
+        }
+        let limit = limit.map_or(DEFAULT_QUEUE_LIMIT, |limit| limit as usize);
+        let timeout = timeout.unwrap_or(DEFAULT_QUEUE_TIMEOUT_SECS);
+        if limit == 0 || timeout == 0 {
+            return Self::Drop;
+        }
+        Self::Queue {
+            limit,
+            timeout: Duration::from_secs(timeout),
+        }
+    }
+
+    /// Read the policy from the settings store
+    pub fn load(app_handle: &AppHandle) -> Self {
+        let store = app_handle.store(get_settings_file(app_handle)).ok();
+        let get = |key: &str| store.as_ref().and_then(|store| store.get(key));
+        Self::from_settings(
+            get(QUEUE_WHEN_BUSY_KEY).and_then(|v| v.as_bool()),
+            get(QUEUE_LIMIT_KEY).and_then(|v| v.as_u64()),
+            get(QUEUE_TIMEOUT_SECS_KEY).and_then(|v| v.as_u64()),
+        )
+    }
+}
+
+/// Counts recordings waiting for a transcription slot
+pub struct WaitQueue {
+    waiting: AtomicUsize,
+}
+
+impl WaitQueue {
+    pub const fn new() -> Self {
+        Self {
+            waiting: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of recordings currently waiting
+    #[cfg(test)]
+    pub fn waiting(&self) -> usize {
+        self.waiting.load(Ordering::SeqCst)
+    }
+
+    /// Take a waiting place if fewer than `limit` recordings are waiting
+    pub fn try_enter(&self, limit: usize) -> bool {
+        self.waiting
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |waiting| {
+                (waiting < limit).then_some(waiting + 1)
+            })
+            .is_ok()
+    }
+
+    /// Give a waiting place back
+    pub fn leave(&self) {
+        let _ = self
+            .waiting
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |waiting| {
+                waiting.checked_sub(1)
+            });
+    }
+
+    /// Get a permit from `semaphore` according to `policy`
+    ///
+    /// Returns None when the recording should be dropped: the policy is
+    /// `Drop`, the queue is full, or no permit freed up within the timeout.
+    pub async fn acquire<'a>(
+        &self,
+        semaphore: &'a Semaphore,
+        policy: BusyPolicy,
+    ) -> Option<SemaphorePermit<'a>> {
+        if let Ok(permit) = semaphore.try_acquire() {
+            return Some(permit);
+        }
+        let BusyPolicy::Queue { limit, timeout } = policy else {
+            return None;
+        };
+        if !self.try_enter(limit) {
+            crate::warn!("Transcription queue is full ({} waiting)", limit);
+            return None;
+        }
+
+        crate::info!("All transcription slots busy, waiting up to {:?}", timeout);
+        let permit = tokio::time::timeout(timeout, semaphore.acquire()).await;
+        self.leave();
+        match permit {
+            Ok(Ok(permit)) => Some(permit),
+            Ok(Err(_)) => None,
+            Err(_) => {
+                crate::warn!(
+                    "Gave up waiting for a transcription slot after {:?}",
+                    timeout
+                );
+                None
+            }
+        }
+    }
+}
+
+impl Default for WaitQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide queue of recordings waiting for a transcription slot
+static WAIT_QUEUE: WaitQueue = WaitQueue::new();
+
+/// Get the process-wide transcription wait queue
+pub fn wait_queue() -> &'static WaitQueue {
+    &WAIT_QUEUE
+}
+
+#[cfg(test)]
+#[path = "queue_test.rs"]
+mod tests;
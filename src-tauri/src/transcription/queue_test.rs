@@ -0,0 +1,82 @@
+use super::*;
+
+#[test]
+fn test_policy_defaults_to_drop() {
+    assert_eq!(
+        BusyPolicy::from_settings(None, None, None),
+        BusyPolicy::Drop
+    );
+    assert_eq!(
+        BusyPolicy::from_settings(Some(false), Some(8), Some(30)),
+        BusyPolicy::Drop
+    );
+}
+
+#[test]
+fn test_policy_queue_uses_defaults_and_drops_on_zero() {
+    assert_eq!(
+        BusyPolicy::from_settings(Some(true), None, None),
+        BusyPolicy::Queue {
+            limit: DEFAULT_QUEUE_LIMIT,
+            timeout: Duration::from_secs(DEFAULT_QUEUE_TIMEOUT_SECS),
+        }
+    );
+    assert_eq!(
+        BusyPolicy::from_settings(Some(true), Some(0), None),
+        BusyPolicy::Drop
+    );
+    assert_eq!(
+        BusyPolicy::from_settings(Some(true), None, Some(0)),
+        BusyPolicy::Drop
+    );
+}
+
+#[test]
+fn test_wait_queue_is_bounded() {
+    let queue = WaitQueue::new();
+    assert!(queue.try_enter(2));
+    assert!(queue.try_enter(2));
+    assert!(!queue.try_enter(2));
+    queue.leave();
+    assert_eq!(queue.waiting(), 1);
+    assert!(queue.try_enter(2));
+}
+
+#[tokio::test]
+async fn test_acquire_drops_when_busy_by_default() {
+    let semaphore = Semaphore::new(1);
+    let _held = semaphore.try_acquire().unwrap();
+    let queue = WaitQueue::new();
+    assert!(queue.acquire(&semaphore, BusyPolicy::Drop).await.is_none());
+}
+
+#[tokio::test]
+async fn test_acquire_waits_for_released_permit() {
+    let semaphore = std::sync::Arc::new(Semaphore::new(1));
+    let held = semaphore.clone().try_acquire_owned().unwrap();
+    let queue = WaitQueue::new();
+    let policy = BusyPolicy::Queue {
+        limit: 1,
+        timeout: Duration::from_secs(5),
+    };
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        drop(held);
+    });
+    assert!(queue.acquire(&semaphore, policy).await.is_some());
+    assert_eq!(queue.waiting(), 0);
+}
+
+#[tokio::test]
+async fn test_acquire_gives_up_after_timeout() {
+    let semaphore = Semaphore::new(1);
+    let _held = semaphore.try_acquire().unwrap();
+    let queue = WaitQueue::new();
+    let policy = BusyPolicy::Queue {
+        limit: 1,
+        timeout: Duration::from_millis(20),
+    };
+    assert!(queue.acquire(&semaphore, policy).await.is_none());
+    assert_eq!(queue.waiting(), 0);
+}
@@ -0,0 +1,154 @@
+// Hallucinated repeat collapsing
+// On silent or noisy input the model sometimes gets stuck repeating a word
+// ("the the the the the"). When enabled, a word repeated back to back more
+// than the configured number of times is collapsed to a single occurrence
+// before output, and the transcription is flagged as likely hallucinated.
+
+use crate::events::{current_timestamp, event_names, TranscriptionHallucinationPayload};
+use crate::util::get_settings_file;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+/// Settings key: collapse runaway word repeats (bool, default false)
+pub const COLLAPSE_REPEATS_KEY: &str = "transcription.collapseRepeats";
+
+/// Settings key: how many times a word may repeat back to back before it is collapsed
+pub const MAX_REPEATS_KEY: &str = "transcription.maxRepeats";
+
+/// Repeats allowed when none is configured
+pub const DEFAULT_MAX_REPEATS: usize = 3;
+
+/// Lowest accepted limit, so deliberate doubles ("very very") are kept
+pub const MIN_MAX_REPEATS: usize = 2;
+
+/// Resolve the repeat limit from its settings values, None when collapsing is off
+pub fn max_repeats_from_settings(enabled: Option<bool>, max_repeats: Option<u64>) -> Option<usize> {
+    if !enabled.unwrap_or(false) {
+        return None;
+    }
+    let max_repeats = max_repeats.map_or(DEFAULT_MAX_REPEATS, |max| max as usize);
+    Some(max_repeats.max(MIN_MAX_REPEATS))
+}
+
+/// Text with its runaway repeats collapsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollapsedRepeats {
+    /// The text with each runaway repeat reduced to one word
+    pub text: String,
+    /// Length of the longest run that was collapsed
+    pub longest_run: usize,
+}
+
+/// Collapse words repeated back to back more than `max_repeats` times
+///
+/// Words are compared case-insensitively ignoring surrounding punctuation, so
+/// "The the the the." counts as four repeats. A collapsed run keeps the first
+/// word and the trailing punctuation of the last one ("The."). Returns None
+/// when no run is longer than `max_repeats`.
+pub fn collapse_repeats(text: &str, max_repeats: usize) -> Option<CollapsedRepeats> {
+    let words = word_spans(text);
+    let mut result = String::with_capacity(text.len());
+    let mut copied_to = 0;
+    let mut longest_run = 0;
+
+    let mut start = 0;
+    while start < words.len() {
+        let key = normalize(&text[words[start].0..words[start].1]);
+        let mut end = start + 1;
+        while end < words.len()
+            && !key.is_empty()
+            && normalize(&text[words[end].0..words[end].1]) == key
+        {
+            end += 1;
+        }
+
+        let run = end - start;
+        if run > max_repeats {
+            let (first_start, first_end) = words[start];
+            let (last_start, last_end) = words[end - 1];
+            let first = &text[first_start..first_end];
+            let last = &text[last_start..last_end];
+            result.push_str(&text[copied_to..first_start]);
+            result.push_str(first.trim_end_matches(is_punctuation));
+            result.push_str(&last[last.trim_end_matches(is_punctuation).len()..]);
+            copied_to = last_end;
+            longest_run = longest_run.max(run);
+        }
+        start = end;
+    }
+
+    if longest_run == 0 {
+        return None;
+    }
+    result.push_str(&text[copied_to..]);
+    Some(CollapsedRepeats {
+        text: result,
+        longest_run,
+    })
+}
+
+/// Byte ranges of the whitespace-separated words in `text`
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut word_start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), word_start) {
+            (true, Some(start)) => {
+                spans.push((start, i));
+                word_start = None;
+            }
+            (false, None) => word_start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(start) = word_start {
+        spans.push((start, text.len()));
+    }
+    spans
+}
+
+fn is_punctuation(c: char) -> bool {
+    !c.is_alphanumeric() && c != '\''
+}
+
+fn normalize(word: &str) -> String {
+    word.trim_matches(is_punctuation).to_lowercase()
+}
+
+/// Collapse runaway repeats in a transcription if enabled in settings
+///
+/// Emits `transcription_hallucination_suspected` when something was collapsed.
+/// Returns the text to use from here on.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn collapse_hallucinated_repeats(app_handle: &AppHandle, text: String) -> String {
+    let store = app_handle.store(get_settings_file(app_handle)).ok();
+    let get = |key: &str| store.as_ref().and_then(|store| store.get(key));
+    let Some(max_repeats) = max_repeats_from_settings(
+        get(COLLAPSE_REPEATS_KEY).and_then(|v| v.as_bool()),
+        get(MAX_REPEATS_KEY).and_then(|v| v.as_u64()),
+    ) else {
+        return text;
+    };
+    let Some(collapsed) = collapse_repeats(&text, max_repeats) else {
+        return text;
+    };
+
+    crate::warn!(
+        "Transcription looks hallucinated: collapsed a word repeated {} times",
+        collapsed.longest_run
+    );
+    crate::emit_or_warn!(
+        app_handle,
+        event_names::TRANSCRIPTION_HALLUCINATION_SUSPECTED,
+        TranscriptionHallucinationPayload {
+            text: collapsed.text.clone(),
+            longest_run: collapsed.longest_run,
+            timestamp: current_timestamp(),
+        }
+    );
+    collapsed.text
+}
+
+#[cfg(test)]
+#[path = "repetition_test.rs"]
+mod tests;
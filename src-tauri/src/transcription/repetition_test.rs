@@ -0,0 +1,48 @@
+use super::*;
+
+#[test]
+fn test_max_repeats_off_by_default() {
+    assert_eq!(max_repeats_from_settings(None, Some(5)), None);
+    assert_eq!(max_repeats_from_settings(Some(false), None), None);
+}
+
+#[test]
+fn test_max_repeats_default_and_minimum() {
+    assert_eq!(
+        max_repeats_from_settings(Some(true), None),
+        Some(DEFAULT_MAX_REPEATS)
+    );
+    assert_eq!(max_repeats_from_settings(Some(true), Some(6)), Some(6));
+    assert_eq!(
+        max_repeats_from_settings(Some(true), Some(0)),
+        Some(MIN_MAX_REPEATS)
+    );
+}
+
+#[test]
+fn test_collapse_runaway_repeat() {
+    let collapsed = collapse_repeats("I think the the the the the answer is yes", 3).unwrap();
+    assert_eq!(collapsed.text, "I think the answer is yes");
+    assert_eq!(collapsed.longest_run, 5);
+}
+
+#[test]
+fn test_collapse_keeps_runs_within_limit() {
+    assert_eq!(collapse_repeats("that was very very good", 3), None);
+    assert_eq!(collapse_repeats("no no no", 3), None);
+    assert_eq!(collapse_repeats("", 3), None);
+}
+
+#[test]
+fn test_collapse_ignores_case_and_punctuation() {
+    let collapsed = collapse_repeats("The the, the the.", 3).unwrap();
+    assert_eq!(collapsed.text, "The.");
+    assert_eq!(collapsed.longest_run, 4);
+}
+
+#[test]
+fn test_collapse_handles_several_runs() {
+    let collapsed = collapse_repeats("you you you you and me me me me me", 3).unwrap();
+    assert_eq!(collapsed.text, "you and me");
+    assert_eq!(collapsed.longest_run, 5);
+}
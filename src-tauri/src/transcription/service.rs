@@ -4,24 +4,44 @@
 // This service decouples transcription from HotkeyIntegration, enabling
 // button-initiated recordings and wake word flows to share the same logic.
 
+use super::cancel::{transcriptions, TRANSCRIPTION_CANCELLED_MESSAGE};
+use super::clipboard::{write_to_clipboard, ClipboardWriter};
+use super::fallback::{resolve_fallback, transcribe_with_fallback};
+use super::language::guess_transcription_language;
+use super::match_log::record_match_outcome;
+use super::output::{
+    append_to_clipboard, fall_back_from_clipboard_failure, paste_paused_for_screen_capture,
+    wait_for_clipboard_sync, OutputMode,
+};
+use super::perf::{TranscriptionPerf, TranscriptionPerfTracker};
+use super::queue::{wait_queue, BusyPolicy, TRANSCRIPTION_BUSY_MESSAGE};
+use super::sinks::{deliver_to_external_sinks, OutputSinks};
+use super::repetition::collapse_hallucinated_repeats;
+use super::transforms::{effective_transforms, TextTransforms};
+use super::verbose_logging::loggable_text;
 use crate::dictionary::{DictionaryEntry, DictionaryExpander, ExpansionResult};
+use crate::emit_or_warn;
 use crate::events::{
-    current_timestamp, CommandAmbiguousPayload, CommandCandidate, CommandEventEmitter,
+    current_timestamp, event_names, CommandAmbiguousPayload, CommandCandidate, CommandEventEmitter,
     CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload,
     TranscriptionCompletedPayload, TranscriptionErrorPayload, TranscriptionEventEmitter,
-    TranscriptionStartedPayload,
+    TranscriptionSkippedPayload, TranscriptionStartedPayload,
 };
-use crate::parakeet::{SharedTranscriptionModel, TranscriptionService as TranscriptionServiceTrait};
+use crate::parakeet::SharedTranscriptionModel;
 use crate::recording::RecordingManager;
 use crate::turso::TursoClient;
+use crate::util::get_settings_file;
+use crate::voice_commands::confirmation::request_confirmation;
+use crate::voice_commands::disambiguation::{schedule_auto_resolve, AmbiguityMode};
 use crate::voice_commands::executor::ActionDispatcher;
 use crate::voice_commands::matcher::{CommandMatcher, MatchResult};
 use crate::voice_commands::registry::CommandDefinition;
 use crate::window_context::ContextResolver;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Manager};
-use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_store::StoreExt;
 use tokio::sync::Semaphore;
 
 /// Type alias for Turso client state
@@ -53,6 +73,313 @@ fn simulate_paste() -> Result<(), String> {
     Err("Paste simulation only supported on macOS".to_string())
 }
 
+/// Copy text to the clipboard, auto-paste it, and optionally press Enter
+///
+/// This is the output path shared by fresh transcriptions and re-pasted history.
+/// In clipboard-append output mode the text is appended to the clipboard buffer
+/// instead, and neither paste nor Enter is simulated. The same happens after the
+/// clipboard write when paste is paused for screen capture.
+///
+/// Returns an error when the clipboard write or a simulated keystroke fails;
+/// the failure has already been logged and reported to the frontend.
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn paste_output(
+    app_handle: &AppHandle,
+    clipboard: &dyn ClipboardWriter,
+    text: &str,
+    press_enter: bool,
+) -> Result<(), String> {
+    if OutputMode::from_settings(app_handle) == OutputMode::ClipboardAppend {
+        append_to_clipboard(app_handle, clipboard, text);
+        return Ok(());
+    }
+
+    if let Err(e) = write_to_clipboard(clipboard, text) {
+        fall_back_from_clipboard_failure(app_handle, text, &e, true);
+        return Err(format!("Failed to copy to clipboard: {}", e));
+    }
+
+    if paste_paused_for_screen_capture(app_handle) {
+        return Ok(());
+    }
+
+    wait_for_clipboard_sync(app_handle, clipboard, text);
+    if let Err(e) = simulate_paste() {
+        crate::warn!("Failed to auto-paste: {}", e);
+        crate::keyboard::notify_if_permission_missing(app_handle, "paste", &e);
+        return Err(format!("Failed to paste: {}", e));
+    }
+    crate::debug!("Auto-pasted transcribed text");
+
+    // Simulate Enter keypress if auto_enter was triggered
+    if press_enter {
+        crate::debug!("Auto-enter triggered, simulating Enter keypress");
+        match crate::keyboard::KeyboardSimulator::new() {
+            Ok(mut simulator) => {
+                if let Err(e) = simulator.simulate_enter_keypress() {
+                    crate::warn!("Failed to simulate enter keypress: {}", e);
+                    crate::keyboard::notify_if_permission_missing(app_handle, "enter", &e);
+                    return Err(format!("Failed to press Enter: {}", e));
+                }
+                crate::debug!("Successfully simulated Enter keypress");
+            }
+            Err(e) => {
+                crate::warn!("Failed to create keyboard simulator: {}", e);
+                return Err(format!("Failed to press Enter: {}", e));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Settings key: report a failed voice command as the transcription outcome (bool, default false)
+pub const REPORT_COMMAND_FAILURES_KEY: &str = "commands.reportFailureAsError";
+
+/// How a transcription was handled by voice command matching
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum CommandOutcome {
+    /// No command matched; the text goes to the output sinks
+    NotHandled,
+    /// A command matched and its action succeeded
+    Executed,
+    /// Several commands matched; the disambiguation UI takes over
+    Ambiguous,
+    /// A command matched but waits for the user to confirm it
+    AwaitingConfirmation,
+    /// A command matched but its action failed
+    Failed { trigger: String, message: String },
+}
+
+impl CommandOutcome {
+    /// Whether matching consumed the text (so it must not be pasted)
+    pub(crate) fn is_handled(&self) -> bool {
+        !matches!(self, CommandOutcome::NotHandled)
+    }
+
+    /// Error to report as the transcription outcome, if any
+    ///
+    /// Only a failed command produces one, and only when `report_failures` is set.
+    pub(crate) fn failure_error(&self, report_failures: bool) -> Option<String> {
+        match self {
+            CommandOutcome::Failed { trigger, message } if report_failures => {
+                Some(format!("Command \"{}\" failed: {}", trigger, message))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Expand dictionary triggers in `text`
+///
+/// `context_entries` are the entries resolved for the focused window's context
+/// (per its `dictionary_mode`). When they could be resolved they are used as-is,
+/// even if empty, so entries assigned to other contexts never fire here. The
+/// global expander is only used when no context resolution was possible.
+pub(crate) fn expand_text(
+    text: &str,
+    context_entries: Option<&[DictionaryEntry]>,
+    global_expander: Option<&DictionaryExpander>,
+) -> ExpansionResult {
+    let unchanged = || ExpansionResult {
+        expanded_text: text.to_string(),
+        should_press_enter: false,
+    };
+
+    match (context_entries, global_expander) {
+        (Some([]), _) => {
+            crate::debug!("[DictionaryExpansion] No dictionary entries active in this context");
+            unchanged()
+        }
+        (Some(entries), _) => {
+            crate::debug!(
+                "[DictionaryExpansion] Using {} context-resolved entries",
+                entries.len()
+            );
+            DictionaryExpander::new(entries).expand(text)
+        }
+        (None, Some(expander)) => {
+            crate::debug!("[DictionaryExpansion] Falling back to global dictionary expander");
+            expander.expand(text)
+        }
+        (None, None) => {
+            crate::debug!("[DictionaryExpansion] No global expander configured");
+            unchanged()
+        }
+    }
+}
+
+/// Expand dictionary triggers with the entries active for the focused window
+///
+/// Resolves the window context's dictionary entries from Turso, falling back
+/// to the global expander when there is no context resolver or the entries
+/// can't be loaded.
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn expand_dictionary(
+    text: &str,
+    app_handle: &AppHandle,
+    context_resolver: &Option<Arc<ContextResolver>>,
+    dictionary_expander: &RwLock<Option<DictionaryExpander>>,
+) -> ExpansionResult {
+    let turso_client = app_handle.try_state::<TursoClientState>();
+    let context_entries = match (context_resolver, turso_client) {
+        (Some(resolver), Some(client)) => match client.list_dictionary_entries().await {
+            Ok(all_entries) => Some(resolver.get_effective_dictionary_async(&all_entries).await),
+            Err(e) => {
+                crate::warn!("[DictionaryExpansion] Failed to get dictionary entries: {}", e);
+                None
+            }
+        },
+        _ => None,
+    };
+
+    let result = match dictionary_expander.read() {
+        Ok(guard) => expand_text(text, context_entries.as_deref(), guard.as_ref()),
+        Err(e) => {
+            crate::warn!(
+                "[DictionaryExpansion] Failed to acquire dictionary expander lock: {}",
+                e
+            );
+            expand_text(text, context_entries.as_deref(), None)
+        }
+    };
+    if result.expanded_text != text {
+        crate::debug!(
+            "[DictionaryExpansion] Expansion applied: {} -> {}",
+            loggable_text(text),
+            loggable_text(&result.expanded_text)
+        );
+    }
+    result
+}
+
+/// Whether command failures should be reported as the transcription outcome
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(crate) fn report_command_failures(app_handle: &AppHandle) -> bool {
+    app_handle
+        .store(get_settings_file(app_handle))
+        .ok()
+        .and_then(|store| store.get(REPORT_COMMAND_FAILURES_KEY))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Emit how a transcription ended once command matching has run
+///
+/// A failed command ends the transcription with an error when `report_failures`
+/// is set; otherwise transcription_completed is emitted whether a command ran or not.
+pub(crate) fn emit_transcription_outcome<T: TranscriptionEventEmitter + ?Sized>(
+    transcription_emitter: &T,
+    command_outcome: &CommandOutcome,
+    report_failures: bool,
+    text: String,
+    duration_ms: u64,
+) {
+    match command_outcome.failure_error(report_failures) {
+        Some(error) => {
+            crate::info!("Emitting transcription_error for failed command");
+            transcription_emitter.emit_transcription_error(TranscriptionErrorPayload { error });
+        }
+        None => {
+            crate::info!("Emitting transcription_completed");
+            transcription_emitter.emit_transcription_completed(TranscriptionCompletedPayload {
+                language: guess_transcription_language(&text),
+                text,
+                duration_ms,
+            });
+        }
+    }
+}
+
+/// Deliver a fresh transcription to every enabled output sink
+///
+/// The paste sink goes through `paste_output`; the clipboard sink only copies
+/// (or appends, in clipboard-append mode) without pasting. File and HTTP sinks
+/// are applied independently of both.
+///
+/// Runs on the blocking pool, since pasting waits for the clipboard write to
+/// become visible.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(crate) async fn deliver_output(
+    app_handle: &AppHandle,
+    clipboard: Arc<dyn ClipboardWriter>,
+    text: String,
+    press_enter: bool,
+) {
+    let app_handle = app_handle.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        deliver_output_blocking(&app_handle, clipboard.as_ref(), &text, press_enter)
+    })
+    .await;
+    if let Err(e) = result {
+        crate::warn!("Output delivery task failed: {}", e);
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn deliver_output_blocking(
+    app_handle: &AppHandle,
+    clipboard: &dyn ClipboardWriter,
+    text: &str,
+    press_enter: bool,
+) {
+    let sinks = OutputSinks::from_settings(app_handle);
+    if sinks.paste {
+        // Failures are already logged and reported to the frontend
+        let _ = paste_output(app_handle, clipboard, text, press_enter);
+    } else if sinks.clipboard {
+        if OutputMode::from_settings(app_handle) == OutputMode::ClipboardAppend {
+            append_to_clipboard(app_handle, clipboard, text);
+        } else if let Err(e) = write_to_clipboard(clipboard, text) {
+            fall_back_from_clipboard_failure(app_handle, text, &e, false);
+        }
+    }
+    deliver_to_external_sinks(app_handle, sinks, text);
+}
+
+/// Delete a transcribed recording's WAV and refresh the recordings list
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn discard_transcribed_audio(app_handle: &AppHandle, file_path: &str) {
+    match crate::storage::discard_recording_audio(std::path::Path::new(file_path)) {
+        Ok(true) => {
+            crate::turso::events::emit_recordings_updated(app_handle, "delete", Some(file_path))
+        }
+        Ok(false) => {}
+        Err(e) => crate::warn!("{}", e),
+    }
+}
+
+/// `transcription_skipped` reason when no transcription model is loaded
+pub const SKIPPED_MODEL_NOT_LOADED: &str = "model_not_loaded";
+
+/// Report a recording that can't be transcribed because no model is loaded
+///
+/// Emits `transcription_skipped`. The WAV is kept so it can be transcribed with
+/// `transcribe_file` once the model has loaded, unless
+/// `recordings.keepWithoutModel` is off, in which case it is deleted.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub(crate) fn skip_transcription_without_model(app_handle: &AppHandle, file_path: &str) {
+    crate::info!("Transcription skipped: transcription model not loaded");
+    let kept_path = if crate::storage::keep_audio_without_model(app_handle) {
+        Some(file_path.to_string())
+    } else {
+        if let Err(e) = crate::storage::discard_recording_audio(std::path::Path::new(file_path)) {
+            crate::warn!("{}", e);
+        }
+        None
+    };
+
+    emit_or_warn!(
+        app_handle,
+        event_names::TRANSCRIPTION_SKIPPED,
+        TranscriptionSkippedPayload {
+            reason: SKIPPED_MODEL_NOT_LOADED.to_string(),
+            file_path: kept_path,
+            timestamp: current_timestamp(),
+        }
+    );
+}
+
 /// Service for handling recording transcription and command matching
 ///
 /// This service provides a unified transcription flow that can be used by:
@@ -70,6 +397,8 @@ where
     shared_transcription_model: Arc<SharedTranscriptionModel>,
     /// Event emitter for transcription events
     transcription_emitter: Arc<T>,
+    /// Clipboard the transcribed text is written to before pasting
+    clipboard: Arc<dyn ClipboardWriter>,
     /// Recording state for buffer cleanup
     recording_state: Arc<Mutex<RecordingManager>>,
     /// Optional Turso client for fetching voice commands
@@ -82,14 +411,16 @@ where
     command_emitter: Option<Arc<C>>,
     /// Semaphore to limit concurrent transcriptions
     transcription_semaphore: Arc<Semaphore>,
-    /// App handle for clipboard access
+    /// App handle for settings access
     app_handle: AppHandle,
-    /// Transcription timeout duration
-    transcription_timeout: Duration,
+    /// Transcription timeout in seconds (atomic for runtime updates)
+    transcription_timeout_secs: AtomicU64,
     /// Dictionary expander for text expansion (interior mutable for runtime updates)
     dictionary_expander: Arc<RwLock<Option<DictionaryExpander>>>,
     /// Optional context resolver for window-aware command/dictionary resolution
     context_resolver: Option<Arc<ContextResolver>>,
+    /// Durations of recent transcriptions for the latency summary
+    perf: Arc<Mutex<TranscriptionPerfTracker>>,
 }
 
 impl<T, C> RecordingTranscriptionService<T, C>
@@ -101,12 +432,14 @@ where
     pub fn new(
         shared_transcription_model: Arc<SharedTranscriptionModel>,
         transcription_emitter: Arc<T>,
+        clipboard: Arc<dyn ClipboardWriter>,
         recording_state: Arc<Mutex<RecordingManager>>,
         app_handle: AppHandle,
     ) -> Self {
         Self {
             shared_transcription_model,
             transcription_emitter,
+            clipboard,
             recording_state,
             turso_client: None,
             command_matcher: None,
@@ -114,9 +447,10 @@ where
             command_emitter: None,
             transcription_semaphore: Arc::new(Semaphore::new(MAX_CONCURRENT_TRANSCRIPTIONS)),
             app_handle,
-            transcription_timeout: Duration::from_secs(DEFAULT_TRANSCRIPTION_TIMEOUT_SECS),
+            transcription_timeout_secs: AtomicU64::new(DEFAULT_TRANSCRIPTION_TIMEOUT_SECS),
             dictionary_expander: Arc::new(RwLock::new(None)),
             context_resolver: None,
+            perf: Arc::new(Mutex::new(TranscriptionPerfTracker::default())),
         }
     }
 
@@ -145,9 +479,8 @@ where
     }
 
     /// Set custom transcription timeout (builder pattern)
-    #[allow(dead_code)]
-    pub fn with_transcription_timeout(mut self, timeout: Duration) -> Self {
-        self.transcription_timeout = timeout;
+    pub fn with_transcription_timeout(self, timeout: Duration) -> Self {
+        self.set_transcription_timeout(timeout);
         self
     }
 
@@ -163,6 +496,36 @@ where
         self
     }
 
+    /// Semaphore limiting concurrent transcriptions, shared with batch jobs
+    pub fn transcription_semaphore(&self) -> Arc<Semaphore> {
+        self.transcription_semaphore.clone()
+    }
+
+    /// Clipboard writer transcribed text is delivered through
+    pub fn clipboard(&self) -> Arc<dyn ClipboardWriter> {
+        self.clipboard.clone()
+    }
+
+    /// Timeout applied to each transcription
+    pub fn transcription_timeout(&self) -> Duration {
+        Duration::from_secs(self.transcription_timeout_secs.load(Ordering::SeqCst))
+    }
+
+    /// Update the timeout for transcriptions started from now on
+    pub fn set_transcription_timeout(&self, timeout: Duration) {
+        self.transcription_timeout_secs
+            .store(timeout.as_secs(), Ordering::SeqCst);
+        crate::debug!("Transcription timeout updated to: {:?}", timeout);
+    }
+
+    /// Latency summary over the recent transcriptions
+    pub fn transcription_perf(&self) -> TranscriptionPerf {
+        match self.perf.lock() {
+            Ok(tracker) => tracker.summary(),
+            Err(poisoned) => poisoned.into_inner().summary(),
+        }
+    }
+
     /// Update the dictionary expander with new entries at runtime
     ///
     /// This method is called when dictionary entries are added, updated, or deleted
@@ -190,6 +553,40 @@ where
         }
     }
 
+    /// Paste previously transcribed text through the clipboard flow
+    ///
+    /// Applies the dictionary expansion for the focused window (including
+    /// auto-enter) and then copies and pastes the result, without
+    /// re-transcribing any audio. Returns the text that was pasted, or the
+    /// error if copying or pasting it failed.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub async fn paste_text(&self, text: &str) -> Result<String, String> {
+        let expansion_result = expand_dictionary(
+            text,
+            &self.app_handle,
+            &self.context_resolver,
+            &self.dictionary_expander,
+        )
+        .await;
+
+        if crate::shutdown::is_shutting_down() {
+            crate::debug!("Skipping paste - app is shutting down");
+        } else {
+            // Pasting waits for the clipboard write, so keep it off the async workers
+            let app_handle = self.app_handle.clone();
+            let clipboard = self.clipboard.clone();
+            let text = expansion_result.expanded_text.clone();
+            let press_enter = expansion_result.should_press_enter;
+            tauri::async_runtime::spawn_blocking(move || {
+                paste_output(&app_handle, clipboard.as_ref(), &text, press_enter)
+            })
+            .await
+            .map_err(|e| format!("Paste task failed: {}", e))??;
+        }
+
+        Ok(expansion_result.expanded_text)
+    }
+
     /// Process a recording file: transcribe → match commands → clipboard fallback
     ///
     /// This is the main entry point for transcription. It:
@@ -204,7 +601,10 @@ where
     pub fn process_recording(&self, file_path: String) {
         // Check if model is loaded
         if !self.shared_transcription_model.is_loaded() {
-            crate::info!("Transcription skipped: transcription model not loaded");
+            skip_transcription_without_model(&self.app_handle, &file_path);
+            if let Ok(mut manager) = self.recording_state.lock() {
+                manager.clear_last_recording();
+            }
             return;
         }
 
@@ -217,13 +617,18 @@ where
         let action_dispatcher = self.action_dispatcher.clone();
         let command_emitter = self.command_emitter.clone();
         let app_handle = self.app_handle.clone();
+        let clipboard = self.clipboard.clone();
         let semaphore = self.transcription_semaphore.clone();
-        let timeout_duration = self.transcription_timeout;
+        let timeout_duration = self.transcription_timeout();
         let dictionary_expander = self.dictionary_expander.clone();
         let context_resolver = self.context_resolver.clone();
+        let perf = self.perf.clone();
 
         crate::info!("Spawning transcription task for: {}", file_path);
 
+        // Track the transcription before spawning so the cancel shortcut can reach it
+        let ticket = transcriptions().begin();
+
         // Spawn async task using Tauri's async runtime
         tauri::async_runtime::spawn(async move {
             // Helper to clear recording buffer - call this in all exit paths to prevent memory leaks
@@ -234,14 +639,15 @@ where
                 }
             };
 
-            // Acquire semaphore permit to limit concurrent transcriptions
-            let _permit = match semaphore.try_acquire() {
-                Ok(permit) => permit,
-                Err(_) => {
+            // Acquire semaphore permit to limit concurrent transcriptions, waiting
+            // in the bounded queue when that is turned on
+            let busy_policy = BusyPolicy::load(&app_handle);
+            let _permit = match wait_queue().acquire(&semaphore, busy_policy).await {
+                Some(permit) => permit,
+                None => {
                     crate::warn!("Too many concurrent transcriptions, skipping this one");
                     transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                        error: "Too many transcriptions in progress. Please wait and try again."
-                            .to_string(),
+                        error: TRANSCRIPTION_BUSY_MESSAGE.to_string(),
                     });
                     clear_recording_buffer();
                     return;
@@ -256,57 +662,40 @@ where
 
             crate::debug!("Transcribing file: {}", file_path);
 
-            // Clone file_path before it's moved into the closure
+            // Clone file_path before it's moved into the transcription task
             let file_path_for_storage = file_path.clone();
 
-            // Perform transcription on blocking thread pool (CPU-intensive) with timeout
-            let transcriber = shared_model.clone();
-            let transcription_future =
-                tokio::task::spawn_blocking(move || transcriber.transcribe(&file_path));
-
-            let transcription_result =
-                tokio::time::timeout(timeout_duration, transcription_future).await;
-
-            let text = match transcription_result {
-                Ok(Ok(Ok(text))) => text,
-                Ok(Ok(Err(e))) => {
-                    crate::error!("Transcription failed: {}", e);
-                    transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                        error: e.to_string(),
-                    });
-                    if let Err(reset_err) = shared_model.reset_to_idle() {
-                        crate::warn!("Failed to reset transcription state: {}", reset_err);
-                    }
-                    clear_recording_buffer();
-                    return;
-                }
-                Ok(Err(e)) => {
-                    crate::error!("Transcription task panicked: {}", e);
-                    transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                        error: "Internal transcription error.".to_string(),
-                    });
-                    if let Err(reset_err) = shared_model.reset_to_idle() {
-                        crate::warn!("Failed to reset transcription state: {}", reset_err);
-                    }
-                    clear_recording_buffer();
-                    return;
-                }
-                Err(_) => {
-                    // Timeout error
-                    crate::error!("Transcription timed out after {:?}", timeout_duration);
-                    transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
-                        error: format!(
-                            "Transcription timed out after {} seconds. The audio may be too long or the model may be stuck.",
-                            timeout_duration.as_secs()
-                        ),
-                    });
-                    if let Err(reset_err) = shared_model.reset_to_idle() {
-                        crate::warn!("Failed to reset transcription state: {}", reset_err);
+            // Transcribe on the blocking thread pool with timeout, retrying once
+            // with the fallback model if one is configured
+            let fallback = resolve_fallback(&app_handle);
+            let (text, model_version) =
+                match transcribe_with_fallback(&shared_model, fallback, file_path, timeout_duration)
+                    .await
+                {
+                    Ok(result) => result,
+                    Err(error) => {
+                        transcription_emitter
+                            .emit_transcription_error(TranscriptionErrorPayload { error });
+                        if let Err(reset_err) = shared_model.reset_to_idle() {
+                            crate::warn!("Failed to reset transcription state: {}", reset_err);
+                        }
+                        clear_recording_buffer();
+                        return;
                     }
-                    clear_recording_buffer();
-                    return;
+                };
+
+            // Discard the result if the cancel shortcut was used meanwhile
+            if ticket.is_cancelled() {
+                crate::info!("Transcription cancelled, discarding result");
+                transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
+                    error: TRANSCRIPTION_CANCELLED_MESSAGE.to_string(),
+                });
+                if let Err(reset_err) = shared_model.reset_to_idle() {
+                    crate::warn!("Failed to reset transcription state: {}", reset_err);
                 }
-            };
+                clear_recording_buffer();
+                return;
+            }
 
             let duration_ms = start_time.elapsed().as_millis() as u64;
             crate::info!(
@@ -314,168 +703,91 @@ where
                 duration_ms,
                 text.len()
             );
+            if let Ok(mut tracker) = perf.lock() {
+                tracker.record(duration_ms, text.chars().count());
+            }
 
             // Store transcription in Turso using storage abstraction (async since we're in async context)
-            if let Some(turso) = app_handle.try_state::<TursoClientState>() {
-                if let Err(e) = crate::storage::TranscriptionStorage::store(
+            if !crate::storage::persist_transcriptions(&app_handle) {
+                // Nothing is stored, so the audio goes as soon as it has been transcribed
+                discard_transcribed_audio(&app_handle, &file_path_for_storage);
+            } else if let Some(turso) = app_handle.try_state::<TursoClientState>() {
+                match crate::storage::TranscriptionStorage::store(
                     &turso,
                     &file_path_for_storage,
                     &text,
+                    model_version,
                     duration_ms,
                     &app_handle,
                 )
                 .await
                 {
-                    crate::warn!("Failed to store transcription: {}", e);
+                    // Only drop the audio once its text is safely stored
+                    Ok(_) if !crate::storage::retain_audio(&app_handle) => {
+                        discard_transcribed_audio(&app_handle, &file_path_for_storage);
+                    }
+                    Ok(_) => {}
+                    Err(e) => crate::warn!("Failed to store transcription: {}", e),
                 }
             }
 
-            // Apply dictionary expansion using context-resolved entries when available
-            let expansion_result = {
-                // Try context-aware dictionary expansion first
-                // Get TursoClient from app state for dictionary entries
-                let turso_client: Option<tauri::State<'_, TursoClientState>> =
-                    app_handle.try_state();
-
-                let context_entries = match (&context_resolver, &turso_client) {
-                    (Some(resolver), Some(client)) => {
-                        crate::debug!("[DictionaryExpansion] Context resolver available, attempting context-aware expansion");
-                        // Get all dictionary entries from Turso
-                        let all_entries = match client.list_dictionary_entries().await {
-                            Ok(entries) => {
-                                crate::debug!(
-                                    "[DictionaryExpansion] Retrieved {} total dictionary entries",
-                                    entries.len()
-                                );
-                                Some(entries)
-                            }
-                            Err(e) => {
-                                crate::warn!("[DictionaryExpansion] Failed to get dictionary entries: {}", e);
-                                None
-                            }
-                        };
-                        // Apply context resolver to filter entries (use async version since we're in async context)
-                        match all_entries {
-                            Some(all_entries) => {
-                                let entries = resolver.get_effective_dictionary_async(&all_entries).await;
-                                if !entries.is_empty() {
-                                    crate::debug!(
-                                        "[DictionaryExpansion] Using {} context-resolved entries for expansion",
-                                        entries.len()
-                                    );
-                                    Some(entries)
-                                } else {
-                                    crate::debug!(
-                                        "[DictionaryExpansion] Context resolver returned empty, will fall back to global expander"
-                                    );
-                                    None
-                                }
-                            }
-                            None => None,
-                        }
-                    }
-                    _ => {
-                        crate::debug!("[DictionaryExpansion] No context resolver or Turso client available");
-                        None
-                    }
-                };
+            // Collapse runaway repeats the model produced on silent or noisy input
+            let text = collapse_hallucinated_repeats(&app_handle, text);
 
-                // Use context entries if available, otherwise fall back to global expander
-                if let Some(entries) = context_entries {
-                    crate::debug!("[DictionaryExpansion] Using context-resolved expander");
-                    let context_expander = DictionaryExpander::new(&entries);
-                    let result = context_expander.expand(&text);
-                    if result.expanded_text != text {
-                        crate::debug!(
-                            "[DictionaryExpansion] Context-aware expansion applied: '{}' -> '{}'",
-                            text,
-                            result.expanded_text
-                        );
-                    } else {
-                        crate::debug!("[DictionaryExpansion] No expansion matched in context entries");
-                    }
-                    result
-                } else {
-                    // Fall back to global dictionary expander
-                    crate::debug!("[DictionaryExpansion] Falling back to global dictionary expander");
-                    match dictionary_expander.read() {
-                        Ok(guard) => {
-                            if let Some(ref expander) = *guard {
-                                crate::debug!("[DictionaryExpansion] Global expander available, expanding text");
-                                let result = expander.expand(&text);
-                                if result.expanded_text != text {
-                                    crate::debug!(
-                                        "[DictionaryExpansion] Global expansion applied: '{}' -> '{}'",
-                                        text,
-                                        result.expanded_text
-                                    );
-                                } else {
-                                    crate::debug!("[DictionaryExpansion] No expansion matched in global entries");
-                                }
-                                result
-                            } else {
-                                crate::debug!("[DictionaryExpansion] No global expander configured");
-                                ExpansionResult {
-                                    expanded_text: text,
-                                    should_press_enter: false,
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            crate::warn!("[DictionaryExpansion] Failed to acquire dictionary expander lock: {}", e);
-                            ExpansionResult {
-                                expanded_text: text,
-                                should_press_enter: false,
-                            }
-                        }
-                    }
-                }
+            // Apply dictionary expansion with the entries active for the focused window
+            let expansion_result =
+                expand_dictionary(&text, &app_handle, &context_resolver, &dictionary_expander)
+                    .await;
+
+            // Apply text transforms; the active window context's set replaces the global one
+            let context_transforms = match &context_resolver {
+                Some(resolver) => resolver.get_context_text_transforms_async().await,
+                None => None,
+            };
+            let transforms =
+                effective_transforms(TextTransforms::from_settings(&app_handle), context_transforms);
+            let expanded_text = transforms.apply(&expansion_result.expanded_text);
+
+            // A record-only window context keeps the stored transcription but skips
+            // command matching and every output sink
+            let dictation_disabled = match &context_resolver {
+                Some(resolver) => resolver.is_dictation_disabled_async().await,
+                None => false,
             };
-            let expanded_text = expansion_result.expanded_text;
 
             // Try voice command matching if configured (using expanded text)
-            let command_handled =
-                Self::try_command_matching(&expanded_text, &turso_client, &command_matcher, &action_dispatcher, &command_emitter, &transcription_emitter, &context_resolver)
-                    .await;
+            let command_outcome = if dictation_disabled {
+                crate::info!("Dictation disabled for the active window, transcription stored only");
+                CommandOutcome::NotHandled
+            } else {
+                Self::try_command_matching(&expanded_text, &turso_client, &command_matcher, &action_dispatcher, &command_emitter, &transcription_emitter, &context_resolver, Some(&app_handle))
+                    .await
+            };
 
-            // Fallback to clipboard if no command was handled (using expanded text)
+            // Fall back to the output sinks if no command was handled (using expanded text)
             // Safety check: don't paste during shutdown
-            if !command_handled && !crate::shutdown::is_shutting_down() {
-                if let Err(e) = app_handle.clipboard().write_text(&expanded_text) {
-                    crate::warn!("Failed to copy to clipboard: {}", e);
-                } else {
-                    crate::debug!("Transcribed text copied to clipboard");
-                    if let Err(e) = simulate_paste() {
-                        crate::warn!("Failed to auto-paste: {}", e);
-                    } else {
-                        crate::debug!("Auto-pasted transcribed text");
-
-                        // Simulate Enter keypress if auto_enter was triggered
-                        if expansion_result.should_press_enter {
-                            crate::debug!("Auto-enter triggered, simulating Enter keypress");
-                            match crate::keyboard::KeyboardSimulator::new() {
-                                Ok(mut simulator) => {
-                                    if let Err(e) = simulator.simulate_enter_keypress() {
-                                        crate::warn!("Failed to simulate enter keypress: {}", e);
-                                    } else {
-                                        crate::debug!("Successfully simulated Enter keypress");
-                                    }
-                                }
-                                Err(e) => {
-                                    crate::warn!("Failed to create keyboard simulator: {}", e);
-                                }
-                            }
-                        }
-                    }
-                }
+            if !dictation_disabled
+                && !command_outcome.is_handled()
+                && !crate::shutdown::is_shutting_down()
+            {
+                let output_text =
+                    transforms.prefix_timestamp(&expanded_text, &chrono::Local::now());
+                deliver_output(
+                    &app_handle,
+                    clipboard.clone(),
+                    output_text,
+                    expansion_result.should_press_enter,
+                )
+                .await;
             }
 
-            // Always emit transcription_completed with expanded text (whether command handled or not)
-            crate::info!("Emitting transcription_completed");
-            transcription_emitter.emit_transcription_completed(TranscriptionCompletedPayload {
-                text: expanded_text,
+            emit_transcription_outcome(
+                transcription_emitter.as_ref(),
+                &command_outcome,
+                report_command_failures(&app_handle),
+                expanded_text,
                 duration_ms,
-            });
+            );
 
             // Reset transcription state to idle
             if let Err(e) = shared_model.reset_to_idle() {
@@ -489,8 +801,11 @@ where
 
     /// Try to match the transcribed text against voice commands
     ///
-    /// Returns true if a command was matched and handled, false otherwise.
+    /// Returns how the text was handled; `CommandOutcome::NotHandled` means it
+    /// should go to the output sinks.
     /// When a context_resolver is provided, uses context-resolved commands for matching.
+    /// An ambiguous match is auto-resolved later when `app_handle` is provided.
+    #[allow(clippy::too_many_arguments)]
     async fn try_command_matching(
         text: &str,
         turso_client: &Option<Arc<TursoClient>>,
@@ -499,7 +814,8 @@ where
         command_emitter: &Option<Arc<C>>,
         transcription_emitter: &Arc<T>,
         context_resolver: &Option<Arc<ContextResolver>>,
-    ) -> bool {
+        app_handle: Option<&AppHandle>,
+    ) -> CommandOutcome {
         // Check if all voice command components are configured
         let (client, matcher, dispatcher, emitter) = match (
             turso_client,
@@ -510,7 +826,7 @@ where
             (Some(c), Some(m), Some(d), Some(e)) => (c, m, d, e),
             _ => {
                 crate::debug!("Voice commands not configured, skipping command matching");
-                return false;
+                return CommandOutcome::NotHandled;
             }
         };
 
@@ -522,7 +838,7 @@ where
                 transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
                     error: "Failed to load voice commands. Please try again.".to_string(),
                 });
-                return false;
+                return CommandOutcome::NotHandled;
             }
         };
 
@@ -535,6 +851,8 @@ where
             },
             Ambiguous {
                 candidates: Vec<CommandCandidate>,
+                truncated: bool,
+                top: Option<(CommandDefinition, f64)>,
             },
             NoMatch,
         }
@@ -557,6 +875,17 @@ where
             None => matcher.match_commands(text, &all_commands),
         };
 
+        // Log the outcome for match statistics
+        if let Err(e) = client
+            .record_command_match(match_result.outcome(), match_result.confidence())
+            .await
+        {
+            crate::warn!("Failed to record command match: {}", e);
+        }
+        if let Some(handle) = app_handle {
+            record_match_outcome(handle, text, &match_result);
+        }
+
         // Build a lookup map for finding commands by ID
         let commands_by_id: std::collections::HashMap<uuid::Uuid, &CommandDefinition> =
             all_commands.iter().map(|cmd| (cmd.id, cmd)).collect();
@@ -585,7 +914,10 @@ where
                 },
                 None => MatchOutcome::NoMatch,
             },
-            MatchResult::Ambiguous { candidates } => {
+            MatchResult::Ambiguous {
+                candidates,
+                truncated,
+            } => {
                 let candidate_data: Vec<_> = candidates
                     .iter()
                     .map(|c| CommandCandidate {
@@ -594,13 +926,47 @@ where
                         confidence: c.score,
                     })
                     .collect();
+                let top = candidates
+                    .iter()
+                    .max_by(|a, b| a.score.total_cmp(&b.score))
+                    .and_then(|c| {
+                        commands_by_id
+                            .get(&c.command.id)
+                            .map(|cmd| ((*cmd).clone(), c.score))
+                    });
                 MatchOutcome::Ambiguous {
                     candidates: candidate_data,
+                    truncated,
+                    top,
                 }
             }
             MatchResult::NoMatch => MatchOutcome::NoMatch,
         };
 
+        // The ambiguity mode can resolve an ambiguous match without asking
+        let ambiguity_mode = app_handle.map(AmbiguityMode::from_settings).unwrap_or_default();
+        let outcome = match (ambiguity_mode, outcome) {
+            (
+                AmbiguityMode::AutoTop,
+                MatchOutcome::Ambiguous {
+                    top: Some((cmd, confidence)),
+                    ..
+                },
+            ) => {
+                crate::info!("Ambiguous match, executing top candidate: {}", cmd.trigger);
+                MatchOutcome::Matched {
+                    trigger: cmd.trigger.clone(),
+                    cmd,
+                    confidence,
+                }
+            }
+            (AmbiguityMode::Clipboard, MatchOutcome::Ambiguous { .. }) => {
+                crate::info!("Ambiguous match, treating transcription as dictation");
+                MatchOutcome::NoMatch
+            }
+            (_, outcome) => outcome,
+        };
+
         match outcome {
             MatchOutcome::Matched {
                 cmd,
@@ -620,8 +986,14 @@ where
                     confidence,
                 });
 
+                // Destructive commands wait for the user to confirm them
+                if let (true, Some(handle)) = (cmd.requires_confirmation, app_handle) {
+                    request_confirmation(handle, cmd, text.to_string(), confidence);
+                    return CommandOutcome::AwaitingConfirmation;
+                }
+
                 // Execute command
-                match dispatcher.execute(&cmd).await {
+                match dispatcher.execute_with_transcription(&cmd, text, confidence).await {
                     Ok(action_result) => {
                         crate::info!("Command executed: {}", action_result.message);
                         emitter.emit_command_executed(CommandExecutedPayload {
@@ -629,6 +1001,7 @@ where
                             trigger: trigger.clone(),
                             message: action_result.message,
                         });
+                        CommandOutcome::Executed
                     }
                     Err(action_error) => {
                         crate::error!("Command execution failed: {}", action_error);
@@ -636,25 +1009,42 @@ where
                             command_id: cmd.id.to_string(),
                             trigger: trigger.clone(),
                             error_code: action_error.code.to_string(),
-                            error_message: action_error.message,
+                            error_message: action_error.message.clone(),
                         });
+                        CommandOutcome::Failed {
+                            trigger,
+                            message: action_error.message,
+                        }
                     }
                 }
-                true // Command was handled
             }
-            MatchOutcome::Ambiguous { candidates } => {
-                crate::info!("Ambiguous match: {} candidates", candidates.len());
+            MatchOutcome::Ambiguous {
+                candidates,
+                truncated,
+                top,
+            } => {
+                crate::info!(
+                    "Ambiguous match: {} candidates{}",
+                    candidates.len(),
+                    if truncated { " (truncated)" } else { "" }
+                );
 
                 // Emit command_ambiguous event for disambiguation UI
                 emitter.emit_command_ambiguous(CommandAmbiguousPayload {
                     transcription: text.to_string(),
                     candidates,
+                    truncated,
                 });
-                true // Command matching was handled (ambiguous)
+
+                // Auto-resolve if the user doesn't pick a candidate in time
+                if let Some(handle) = app_handle {
+                    schedule_auto_resolve(handle, dispatcher.clone(), text.to_string(), top);
+                }
+                CommandOutcome::Ambiguous
             }
             MatchOutcome::NoMatch => {
-                crate::debug!("No command match for: {}", text);
-                false // Fall through to clipboard
+                crate::debug!("No command match for: {}", loggable_text(text));
+                CommandOutcome::NotHandled // Fall through to clipboard
             }
         }
     }
@@ -73,6 +73,7 @@ fn test_mock_emitter_tracks_calls() {
     emitter.emit_transcription_completed(TranscriptionCompletedPayload {
         text: "test".to_string(),
         duration_ms: 100,
+        language: None,
     });
     assert!(emitter.completed_called.load(Ordering::SeqCst));
 
@@ -162,6 +163,47 @@ fn test_dictionary_expander_graceful_fallback_no_expander() {
     assert_eq!(result.expanded_text, text);
 }
 
+fn entry(id: &str, trigger: &str, expansion: &str) -> DictionaryEntry {
+    DictionaryEntry {
+        id: id.to_string(),
+        trigger: trigger.to_string(),
+        expansion: expansion.to_string(),
+        suffix: None,
+        auto_enter: false,
+        disable_suffix: false,
+        complete_match_only: false,
+    }
+}
+
+#[test]
+fn test_expand_text_prefers_context_entries_over_global() {
+    let global = DictionaryExpander::new(&[entry("1", "k8s", "Kubernetes")]);
+    let context = [entry("2", "pr", "pull request")];
+
+    let result = expand_text("open a pr for k8s", Some(&context[..]), Some(&global));
+    assert_eq!(result.expanded_text, "open a pull request for k8s");
+}
+
+#[test]
+fn test_expand_text_empty_context_skips_global_entries() {
+    // A context that resolves to no entries must not fall back to jargon
+    // assigned to other apps
+    let global = DictionaryExpander::new(&[entry("1", "k8s", "Kubernetes")]);
+
+    let result = expand_text("deploy k8s", Some(&[] as &[DictionaryEntry]), Some(&global));
+    assert_eq!(result.expanded_text, "deploy k8s");
+    assert!(!result.should_press_enter);
+}
+
+#[test]
+fn test_expand_text_falls_back_to_global_without_context() {
+    let global = DictionaryExpander::new(&[entry("1", "k8s", "Kubernetes")]);
+
+    let result = expand_text("deploy k8s", None, Some(&global));
+    assert_eq!(result.expanded_text, "deploy Kubernetes");
+    assert_eq!(expand_text("deploy k8s", None, None).expanded_text, "deploy k8s");
+}
+
 #[test]
 fn test_dictionary_expander_graceful_fallback_empty_entries() {
     // When expander has no entries, text should pass through unchanged
@@ -248,3 +290,83 @@ fn test_dictionary_expander_runtime_update() {
     };
     assert_eq!(result2.expanded_text, "i need to be right back");
 }
+
+#[tokio::test]
+async fn test_unmatched_transcription_falls_through_to_output() {
+    let expander = DictionaryExpander::new(&[DictionaryEntry {
+        id: "1".to_string(),
+        trigger: "brb".to_string(),
+        expansion: "be right back".to_string(),
+        suffix: None,
+        auto_enter: false,
+        disable_suffix: false,
+        complete_match_only: false,
+    }]);
+    let expanded_text = expander.expand("i need to brb").expanded_text;
+
+    // Voice commands aren't configured, so no command handles the text
+    let transcription_emitter = Arc::new(MockTranscriptionEmitter::new());
+    let command_outcome =
+        RecordingTranscriptionService::<MockTranscriptionEmitter, MockCommandEmitter>::try_command_matching(
+            &expanded_text,
+            &None,
+            &None,
+            &None,
+            &None,
+            &transcription_emitter,
+            &None,
+            None,
+        )
+        .await;
+    // An unhandled outcome sends the expanded text to the output sinks. Delivery
+    // itself reads output settings through the app handle, so it isn't run here.
+    assert_eq!(command_outcome, CommandOutcome::NotHandled);
+    assert!(!command_outcome.is_handled());
+    assert_eq!(expanded_text, "i need to be right back");
+    assert!(!transcription_emitter.error_called.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_command_failure_reported_only_when_enabled() {
+    let failed = CommandOutcome::Failed {
+        trigger: "open slack".to_string(),
+        message: "App not found".to_string(),
+    };
+
+    assert!(failed.is_handled());
+    assert_eq!(failed.failure_error(false), None);
+    assert_eq!(
+        failed.failure_error(true),
+        Some("Command \"open slack\" failed: App not found".to_string())
+    );
+    assert_eq!(CommandOutcome::Executed.failure_error(true), None);
+    assert_eq!(CommandOutcome::NotHandled.failure_error(true), None);
+}
+
+#[test]
+fn test_failed_command_emits_error_when_reporting_enabled() {
+    let failed = CommandOutcome::Failed {
+        trigger: "open slack".to_string(),
+        message: "App not found".to_string(),
+    };
+    let emitter = MockTranscriptionEmitter::new();
+
+    emit_transcription_outcome(&emitter, &failed, true, "open slack".to_string(), 10);
+
+    assert!(emitter.error_called.load(Ordering::SeqCst));
+    assert!(!emitter.completed_called.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_failed_command_completes_when_reporting_disabled() {
+    let failed = CommandOutcome::Failed {
+        trigger: "open slack".to_string(),
+        message: "App not found".to_string(),
+    };
+    let emitter = MockTranscriptionEmitter::new();
+
+    emit_transcription_outcome(&emitter, &failed, false, "open slack".to_string(), 10);
+
+    assert!(emitter.completed_called.load(Ordering::SeqCst));
+    assert!(!emitter.error_called.load(Ordering::SeqCst));
+}
@@ -0,0 +1,212 @@
+// Transcription output sinks
+// A finished transcription can go to several destinations at once, each
+// toggled independently in settings: the clipboard, a paste into the focused
+// app (default), a timestamped log file, and an HTTP endpoint.
+
+use crate::util::{get_settings_file, SettingsAccess};
+use serde::Serialize;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Settings key enabling the clipboard sink (copy without pasting)
+pub const SINK_CLIPBOARD_KEY: &str = "output.sinks.clipboard";
+
+/// Settings key enabling the paste sink (copy and simulate Cmd+V, default on)
+pub const SINK_PASTE_KEY: &str = "output.sinks.paste";
+
+/// Settings key enabling the file-append sink
+pub const SINK_FILE_APPEND_KEY: &str = "output.sinks.fileAppend";
+
+/// Settings key enabling the HTTP POST sink
+pub const SINK_HTTP_KEY: &str = "output.sinks.http";
+
+/// Settings key for the file the file-append sink writes to
+pub const FILE_APPEND_PATH_KEY: &str = "output.fileAppendPath";
+
+/// Settings key for the URL the HTTP sink posts to
+pub const HTTP_URL_KEY: &str = "output.httpUrl";
+
+/// Settings key for the HTTP sink request timeout
+pub const HTTP_TIMEOUT_MS_KEY: &str = "output.httpTimeoutMs";
+
+/// Default HTTP sink request timeout
+pub const DEFAULT_HTTP_TIMEOUT_MS: u64 = 5_000;
+
+/// Upper bound on the HTTP sink timeout, so a bad setting can't pile up requests
+pub const MAX_HTTP_TIMEOUT_MS: u64 = 60_000;
+
+/// Which output destinations are enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputSinks {
+    pub clipboard: bool,
+    pub paste: bool,
+    pub file_append: bool,
+    pub http: bool,
+}
+
+impl Default for OutputSinks {
+    fn default() -> Self {
+        Self {
+            clipboard: false,
+            paste: true,
+            file_append: false,
+            http: false,
+        }
+    }
+}
+
+impl OutputSinks {
+    /// Build the sink set from settings values, using defaults for unset keys
+    pub fn from_values(
+        clipboard: Option<bool>,
+        paste: Option<bool>,
+        file_append: Option<bool>,
+        http: Option<bool>,
+    ) -> Self {
+        let defaults = Self::default();
+        Self {
+            clipboard: clipboard.unwrap_or(defaults.clipboard),
+            paste: paste.unwrap_or(defaults.paste),
+            file_append: file_append.unwrap_or(defaults.file_append),
+            http: http.unwrap_or(defaults.http),
+        }
+    }
+
+    /// Read the enabled sinks from settings
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_settings(app_handle: &AppHandle) -> Self {
+        let store = app_handle.store(get_settings_file(app_handle)).ok();
+        let flag = |key: &str| {
+            store
+                .as_ref()
+                .and_then(|s| s.get(key))
+                .and_then(|v| v.as_bool())
+        };
+        Self::from_values(
+            flag(SINK_CLIPBOARD_KEY),
+            flag(SINK_PASTE_KEY),
+            flag(SINK_FILE_APPEND_KEY),
+            flag(SINK_HTTP_KEY),
+        )
+    }
+}
+
+/// JSON body posted by the HTTP sink
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpSinkPayload {
+    /// The transcribed text
+    pub text: String,
+    /// ISO 8601 timestamp when the transcription finished
+    pub timestamp: String,
+}
+
+/// Resolve the HTTP sink timeout from its settings value
+pub fn http_timeout_from_setting(value: Option<u64>) -> Duration {
+    Duration::from_millis(value.unwrap_or(DEFAULT_HTTP_TIMEOUT_MS).min(MAX_HTTP_TIMEOUT_MS))
+}
+
+/// Format a transcription as a single timestamped log line
+///
+/// Line breaks inside the text are collapsed to spaces so each transcription
+/// stays on one line.
+pub fn format_log_line(timestamp: &str, text: &str) -> String {
+    let flattened = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    format!("{} {}\n", timestamp, flattened)
+}
+
+/// Append a line to a file, creating the file and its directory if needed
+pub fn append_line(path: &Path, line: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    file.write_all(line.as_bytes())
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// POST a transcription to the HTTP sink URL
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn post_transcription(
+    url: &str,
+    payload: &HttpSinkPayload,
+    timeout: Duration,
+) -> Result<(), String> {
+    use tauri_plugin_http::reqwest;
+
+    let body = serde_json::to_string(payload).map_err(|e| e.to_string())?;
+    let response = reqwest::Client::new()
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .timeout(timeout)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+    Ok(())
+}
+
+/// Send a transcription to the enabled file-append and HTTP sinks
+///
+/// Failures are logged and never affect the other sinks. The HTTP request
+/// runs in the background so a slow endpoint doesn't delay the paste.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn deliver_to_external_sinks(app_handle: &AppHandle, sinks: OutputSinks, text: &str) {
+    let timestamp = crate::events::current_timestamp();
+
+    if sinks.file_append {
+        match app_handle
+            .get_setting(FILE_APPEND_PATH_KEY)
+            .filter(|path| !path.trim().is_empty())
+        {
+            Some(path) => {
+                if let Err(e) = append_line(Path::new(&path), &format_log_line(&timestamp, text)) {
+                    crate::warn!("File output sink failed: {}", e);
+                }
+            }
+            None => crate::warn!("File output sink enabled but no file path configured"),
+        }
+    }
+
+    if sinks.http {
+        let Some(url) = app_handle
+            .get_setting(HTTP_URL_KEY)
+            .filter(|url| !url.trim().is_empty())
+        else {
+            crate::warn!("HTTP output sink enabled but no URL configured");
+            return;
+        };
+        let timeout = http_timeout_from_setting(
+            app_handle
+                .store(get_settings_file(app_handle))
+                .ok()
+                .and_then(|store| store.get(HTTP_TIMEOUT_MS_KEY))
+                .and_then(|v| v.as_u64()),
+        );
+        let payload = HttpSinkPayload {
+            text: text.to_string(),
+            timestamp,
+        };
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = post_transcription(&url, &payload, timeout).await {
+                crate::warn!("HTTP output sink failed: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+#[path = "sinks_test.rs"]
+mod tests;
@@ -0,0 +1,68 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_default_sinks_paste_only() {
+    assert_eq!(
+        OutputSinks::from_values(None, None, None, None),
+        OutputSinks {
+            clipboard: false,
+            paste: true,
+            file_append: false,
+            http: false,
+        }
+    );
+}
+
+#[test]
+fn test_sinks_toggle_independently() {
+    let sinks = OutputSinks::from_values(Some(true), Some(false), Some(true), None);
+    assert!(sinks.clipboard);
+    assert!(!sinks.paste);
+    assert!(sinks.file_append);
+    assert!(!sinks.http);
+}
+
+#[test]
+fn test_http_timeout_defaults_and_clamps() {
+    assert_eq!(
+        http_timeout_from_setting(None),
+        Duration::from_millis(DEFAULT_HTTP_TIMEOUT_MS)
+    );
+    assert_eq!(http_timeout_from_setting(Some(250)), Duration::from_millis(250));
+    assert_eq!(
+        http_timeout_from_setting(Some(10 * MAX_HTTP_TIMEOUT_MS)),
+        Duration::from_millis(MAX_HTTP_TIMEOUT_MS)
+    );
+}
+
+#[test]
+fn test_log_line_is_single_timestamped_line() {
+    assert_eq!(
+        format_log_line("2025-01-01T12:00:00Z", "first line\nsecond  line"),
+        "2025-01-01T12:00:00Z first line second line\n"
+    );
+}
+
+#[test]
+fn test_append_line_creates_and_appends() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("logs").join("transcriptions.log");
+
+    append_line(&path, "one\n").unwrap();
+    append_line(&path, "two\n").unwrap();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+}
+
+#[test]
+fn test_http_payload_serializes_camel_case() {
+    let payload = HttpSinkPayload {
+        text: "hello".to_string(),
+        timestamp: "2025-01-01T12:00:00Z".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(&payload).unwrap(),
+        serde_json::json!({"text": "hello", "timestamp": "2025-01-01T12:00:00Z"})
+    );
+}
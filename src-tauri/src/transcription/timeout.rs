@@ -0,0 +1,51 @@
+// Transcription timeout
+// Each transcription is cancelled if the model hasn't returned within the
+// timeout. The default suits dictation, but transcribing very long files can
+// need more, so the timeout can be raised in settings up to a fixed maximum
+// that still catches a model that is stuck.
+
+use std::time::Duration;
+
+/// Settings key for the transcription timeout in seconds
+pub const TRANSCRIPTION_TIMEOUT_SECS_KEY: &str = "transcription.timeoutSecs";
+
+/// Shortest accepted transcription timeout in seconds
+pub const MIN_TRANSCRIPTION_TIMEOUT_SECS: u64 = 1;
+
+/// Longest accepted transcription timeout in seconds (one hour)
+pub const MAX_TRANSCRIPTION_TIMEOUT_SECS: u64 = 3600;
+
+/// Check a requested timeout against the accepted range
+pub fn validate_transcription_timeout(secs: u64) -> Result<Duration, String> {
+    if !(MIN_TRANSCRIPTION_TIMEOUT_SECS..=MAX_TRANSCRIPTION_TIMEOUT_SECS).contains(&secs) {
+        return Err(format!(
+            "Transcription timeout must be between {} and {} seconds.",
+            MIN_TRANSCRIPTION_TIMEOUT_SECS, MAX_TRANSCRIPTION_TIMEOUT_SECS
+        ));
+    }
+    Ok(Duration::from_secs(secs))
+}
+
+/// Resolve the timeout from its settings value
+///
+/// Falls back to `default` when unset or out of range, so a hand-edited
+/// settings file can't disable the timeout.
+pub fn transcription_timeout_from_setting(value: Option<u64>, default: Duration) -> Duration {
+    value
+        .and_then(|secs| validate_transcription_timeout(secs).ok())
+        .unwrap_or(default)
+}
+
+/// Error shown when a transcription hits the timeout
+pub fn timed_out_message(timeout: Duration) -> String {
+    format!(
+        "Transcription timed out after {} seconds. The audio may be too long or the model may be stuck. \
+         The timeout can be raised in Settings > Transcription (up to {} seconds).",
+        timeout.as_secs(),
+        MAX_TRANSCRIPTION_TIMEOUT_SECS
+    )
+}
+
+#[cfg(test)]
+#[path = "timeout_test.rs"]
+mod tests;
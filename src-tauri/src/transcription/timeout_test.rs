@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn test_validate_accepts_range_bounds() {
+    assert_eq!(
+        validate_transcription_timeout(MIN_TRANSCRIPTION_TIMEOUT_SECS),
+        Ok(Duration::from_secs(MIN_TRANSCRIPTION_TIMEOUT_SECS))
+    );
+    assert_eq!(
+        validate_transcription_timeout(MAX_TRANSCRIPTION_TIMEOUT_SECS),
+        Ok(Duration::from_secs(MAX_TRANSCRIPTION_TIMEOUT_SECS))
+    );
+}
+
+#[test]
+fn test_validate_rejects_zero_and_above_max() {
+    assert!(validate_transcription_timeout(0).is_err());
+    let error = validate_transcription_timeout(MAX_TRANSCRIPTION_TIMEOUT_SECS + 1).unwrap_err();
+    assert!(error.contains(&MAX_TRANSCRIPTION_TIMEOUT_SECS.to_string()));
+}
+
+#[test]
+fn test_from_setting_falls_back_to_default_when_unset_or_invalid() {
+    let default = Duration::from_secs(60);
+    assert_eq!(transcription_timeout_from_setting(None, default), default);
+    assert_eq!(
+        transcription_timeout_from_setting(Some(0), default),
+        default
+    );
+    assert_eq!(
+        transcription_timeout_from_setting(Some(MAX_TRANSCRIPTION_TIMEOUT_SECS * 2), default),
+        default
+    );
+    assert_eq!(
+        transcription_timeout_from_setting(Some(600), default),
+        Duration::from_secs(600)
+    );
+}
+
+#[test]
+fn test_timed_out_message_mentions_timeout_and_max() {
+    let message = timed_out_message(Duration::from_secs(90));
+    assert!(message.starts_with("Transcription timed out after 90 seconds."));
+    assert!(message.contains(&format!("up to {} seconds", MAX_TRANSCRIPTION_TIMEOUT_SECS)));
+}
@@ -0,0 +1,236 @@
+// Post-transcription text transforms
+// Small clean-up rules applied to every transcription before it is delivered,
+// for targets where the model's punctuation and capitalisation get in the way
+// (e.g. a terminal). Rules are set globally in settings and a window context
+// can replace them with its own set. An optional timestamp prefix is added to
+// delivered text only, after command matching has seen the plain text.
+
+use crate::util::get_settings_file;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Settings key: strip a single trailing period (bool, default false)
+pub const STRIP_TRAILING_PERIOD_KEY: &str = "transforms.stripTrailingPeriod";
+
+/// Settings key: lowercase the first character (bool, default false)
+pub const LOWERCASE_FIRST_KEY: &str = "transforms.lowercaseFirst";
+
+/// Settings key: trim surrounding whitespace (bool, default false)
+pub const TRIM_WHITESPACE_KEY: &str = "transforms.trimWhitespace";
+
+/// Settings key: collapse runs of spaces into one (bool, default false)
+pub const COLLAPSE_SPACES_KEY: &str = "transforms.collapseSpaces";
+
+/// Settings key: capitalize the first letter of each sentence (bool, default false)
+pub const CAPITALIZE_SENTENCES_KEY: &str = "transforms.capitalizeSentences";
+
+/// Settings key: words whose casing sentence capitalization keeps (array of strings)
+pub const PROTECTED_WORDS_KEY: &str = "transforms.protectedWords";
+
+/// Settings key: strftime format of a timestamp prefixed to delivered text (e.g. "[%H:%M] ")
+pub const TIMESTAMP_FORMAT_KEY: &str = "transforms.timestampFormat";
+
+/// Check that a timestamp format is a usable strftime string
+pub fn validate_timestamp_format(format: &str) -> Result<(), String> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("Invalid timestamp format: {}", format));
+    }
+    Ok(())
+}
+
+/// Text transforms applied to a finished transcription
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TextTransforms {
+    /// Remove a single trailing "." (an ellipsis is left alone)
+    pub strip_trailing_period: bool,
+    /// Lowercase the first character
+    pub lowercase_first: bool,
+    /// Trim leading and trailing whitespace
+    pub trim_whitespace: bool,
+    /// Replace runs of spaces with a single space
+    pub collapse_spaces: bool,
+    /// Capitalize the first letter of the text and of each following sentence
+    pub capitalize_sentences: bool,
+    /// Words left as written when they start a sentence (e.g. "iPhone")
+    pub protected_words: Vec<String>,
+    /// strftime format of a timestamp prefixed to delivered text (None = no timestamp)
+    pub timestamp_format: Option<String>,
+}
+
+impl TextTransforms {
+    /// Whether no transform is enabled
+    pub fn is_noop(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check the timestamp format, if one is set
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.timestamp_format {
+            Some(format) => validate_timestamp_format(format),
+            None => Ok(()),
+        }
+    }
+
+    /// Read the global transforms from settings
+    ///
+    /// An empty or invalid timestamp format is ignored.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_settings(app_handle: &AppHandle) -> Self {
+        let store = app_handle.store(get_settings_file(app_handle)).ok();
+        let flag = |key: &str| {
+            store
+                .as_ref()
+                .and_then(|store| store.get(key))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+        };
+        let timestamp_format = store
+            .as_ref()
+            .and_then(|store| store.get(TIMESTAMP_FORMAT_KEY))
+            .and_then(|v| v.as_str().map(String::from))
+            .filter(|format| !format.is_empty())
+            .filter(|format| match validate_timestamp_format(format) {
+                Ok(()) => true,
+                Err(e) => {
+                    crate::warn!("Ignoring timestamp prefix: {}", e);
+                    false
+                }
+            });
+        let protected_words = store
+            .as_ref()
+            .and_then(|store| store.get(PROTECTED_WORDS_KEY))
+            .and_then(|v| serde_json::from_value::<Vec<String>>(v).ok())
+            .unwrap_or_default();
+        Self {
+            strip_trailing_period: flag(STRIP_TRAILING_PERIOD_KEY),
+            lowercase_first: flag(LOWERCASE_FIRST_KEY),
+            trim_whitespace: flag(TRIM_WHITESPACE_KEY),
+            collapse_spaces: flag(COLLAPSE_SPACES_KEY),
+            capitalize_sentences: flag(CAPITALIZE_SENTENCES_KEY),
+            protected_words,
+            timestamp_format,
+        }
+    }
+
+    /// Apply the enabled transforms to a transcription
+    ///
+    /// Whitespace is normalised first so the trailing period and first
+    /// character checks see the text as it will be delivered. Lowercasing the
+    /// first character runs after sentence capitalization, so it wins for the
+    /// first word. The timestamp prefix is not part of this; see
+    /// `prefix_timestamp`.
+    pub fn apply(&self, text: &str) -> String {
+        if self.is_noop() {
+            return text.to_string();
+        }
+        let mut result = text.to_string();
+
+        if self.trim_whitespace {
+            result = result.trim().to_string();
+        }
+
+        if self.collapse_spaces {
+            let mut collapsed = String::with_capacity(result.len());
+            for c in result.chars() {
+                if c == ' ' && collapsed.ends_with(' ') {
+                    continue;
+                }
+                collapsed.push(c);
+            }
+            result = collapsed;
+        }
+
+        if self.capitalize_sentences {
+            result = capitalize_sentences(&result, &self.protected_words);
+        }
+
+        if self.strip_trailing_period && result.ends_with('.') && !result.ends_with("..") {
+            result.pop();
+        }
+
+        if self.lowercase_first {
+            let mut chars = result.chars();
+            if let Some(first) = chars.next() {
+                result = first.to_lowercase().chain(chars).collect();
+            }
+        }
+
+        result
+    }
+
+    /// Prefix text that is about to be delivered with the formatted timestamp
+    ///
+    /// Text is returned unchanged when no format is set, or the format is
+    /// invalid (a context saved before validation existed).
+    pub fn prefix_timestamp(&self, text: &str, now: &DateTime<Local>) -> String {
+        match &self.timestamp_format {
+            Some(format) if !format.is_empty() && validate_timestamp_format(format).is_ok() => {
+                format!("{}{}", now.format(format), text)
+            }
+            _ => text.to_string(),
+        }
+    }
+}
+
+/// Capitalize the first letter of the text and of every sentence after it
+///
+/// A sentence starts after ".", "!" or "?" followed by whitespace, so
+/// decimals like "3.5" are left alone. Opening quotes and brackets before the
+/// first letter are skipped; a sentence starting with a digit isn't changed.
+/// Words in `protected_words` keep their casing.
+pub fn capitalize_sentences(text: &str, protected_words: &[String]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_sentence_start = true;
+    let mut after_terminator = false;
+
+    for (index, c) in text.char_indices() {
+        if c.is_whitespace() {
+            if after_terminator {
+                at_sentence_start = true;
+                after_terminator = false;
+            }
+            result.push(c);
+            continue;
+        }
+        after_terminator = matches!(c, '.' | '!' | '?');
+
+        if at_sentence_start && c.is_alphanumeric() {
+            at_sentence_start = false;
+            if c.is_alphabetic() && !is_protected_word(&text[index..], protected_words) {
+                result.extend(c.to_uppercase());
+                continue;
+            }
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+/// Whether the word at the start of `text` is one of `protected_words`
+fn is_protected_word(text: &str, protected_words: &[String]) -> bool {
+    let word = text
+        .split(|c: char| c.is_whitespace())
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(|c: char| !c.is_alphanumeric());
+    protected_words.iter().any(|protected| protected == word)
+}
+
+/// Choose the transforms for the active window context
+///
+/// A context's own transforms replace the global ones entirely.
+pub fn effective_transforms(
+    global: TextTransforms,
+    context: Option<TextTransforms>,
+) -> TextTransforms {
+    context.unwrap_or(global)
+}
+
+#[cfg(test)]
+#[path = "transforms_test.rs"]
+mod tests;
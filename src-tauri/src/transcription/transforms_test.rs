@@ -0,0 +1,151 @@
+use super::*;
+use chrono::TimeZone;
+
+fn all() -> TextTransforms {
+    TextTransforms {
+        strip_trailing_period: true,
+        lowercase_first: true,
+        trim_whitespace: true,
+        collapse_spaces: true,
+        capitalize_sentences: true,
+        protected_words: Vec::new(),
+        timestamp_format: None,
+    }
+}
+
+#[test]
+fn test_default_is_noop() {
+    let transforms = TextTransforms::default();
+    assert!(transforms.is_noop());
+    assert_eq!(transforms.apply("  Hello  world. "), "  Hello  world. ");
+}
+
+#[test]
+fn test_all_transforms() {
+    assert_eq!(all().apply("  Git  status. "), "git status");
+}
+
+#[test]
+fn test_strip_trailing_period_keeps_ellipsis() {
+    let transforms = TextTransforms {
+        strip_trailing_period: true,
+        ..Default::default()
+    };
+    assert_eq!(transforms.apply("Done."), "Done");
+    assert_eq!(transforms.apply("Wait..."), "Wait...");
+    assert_eq!(transforms.apply("Really?"), "Really?");
+}
+
+#[test]
+fn test_lowercase_first_only_touches_first_char() {
+    let transforms = TextTransforms {
+        lowercase_first: true,
+        ..Default::default()
+    };
+    assert_eq!(transforms.apply("Open README"), "open README");
+    assert_eq!(transforms.apply("Über"), "über");
+    assert_eq!(transforms.apply(""), "");
+}
+
+#[test]
+fn test_collapse_spaces() {
+    let transforms = TextTransforms {
+        collapse_spaces: true,
+        ..Default::default()
+    };
+    assert_eq!(transforms.apply("a   b  c"), "a b c");
+}
+
+#[test]
+fn test_context_transforms_replace_global() {
+    let context = TextTransforms {
+        lowercase_first: true,
+        ..Default::default()
+    };
+    assert_eq!(effective_transforms(all(), Some(context.clone())), context);
+    assert_eq!(effective_transforms(all(), None), all());
+}
+
+#[test]
+fn test_deserialize_partial_camel_case() {
+    let transforms: TextTransforms =
+        serde_json::from_str(r#"{"stripTrailingPeriod":true}"#).unwrap();
+    assert!(transforms.strip_trailing_period);
+    assert!(!transforms.lowercase_first);
+}
+
+#[test]
+fn test_validate_timestamp_format() {
+    assert!(validate_timestamp_format("[%H:%M] ").is_ok());
+    assert!(validate_timestamp_format("%Y-%m-%d %H:%M:%S - ").is_ok());
+    assert!(validate_timestamp_format("[%Q] ").is_err());
+    assert!(validate_timestamp_format("%").is_err());
+}
+
+#[test]
+fn test_prefix_timestamp() {
+    let now = chrono::Local.with_ymd_and_hms(2025, 3, 7, 14, 5, 9).unwrap();
+    let transforms = TextTransforms {
+        timestamp_format: Some("[%H:%M] ".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(transforms.prefix_timestamp("Buy milk", &now), "[14:05] Buy milk");
+    assert!(!transforms.is_noop());
+}
+
+#[test]
+fn test_prefix_timestamp_skips_missing_or_invalid_format() {
+    let now = chrono::Local.with_ymd_and_hms(2025, 3, 7, 14, 5, 9).unwrap();
+    assert_eq!(
+        TextTransforms::default().prefix_timestamp("Buy milk", &now),
+        "Buy milk"
+    );
+    let invalid = TextTransforms {
+        timestamp_format: Some("[%Q] ".to_string()),
+        ..Default::default()
+    };
+    assert!(invalid.validate().is_err());
+    assert_eq!(invalid.prefix_timestamp("Buy milk", &now), "Buy milk");
+}
+
+#[test]
+fn test_timestamp_is_not_applied_by_apply() {
+    let transforms = TextTransforms {
+        timestamp_format: Some("[%H:%M] ".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(transforms.apply("open slack"), "open slack");
+}
+
+#[test]
+fn test_capitalize_sentences() {
+    let transforms = TextTransforms {
+        capitalize_sentences: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        transforms.apply("hello there. how are you? fine! it costs 3.5 dollars"),
+        "Hello there. How are you? Fine! It costs 3.5 dollars"
+    );
+    assert_eq!(transforms.apply("\"quoted\" start"), "\"Quoted\" start");
+    assert_eq!(transforms.apply("42 apples. ok"), "42 apples. Ok");
+}
+
+#[test]
+fn test_capitalize_sentences_keeps_protected_words() {
+    let protected = vec!["iPhone".to_string(), "macOS".to_string()];
+    assert_eq!(
+        capitalize_sentences("iPhone sales rose. macOS, too. iphone", &protected),
+        "iPhone sales rose. macOS, too. Iphone"
+    );
+}
+
+#[test]
+fn test_lowercase_first_wins_over_capitalize_sentences() {
+    let transforms = TextTransforms {
+        capitalize_sentences: true,
+        lowercase_first: true,
+        ..Default::default()
+    };
+    assert_eq!(transforms.apply("git status. then push"), "git status. Then push");
+}
@@ -0,0 +1,40 @@
+// Verbose transcription logging
+// Transcribed text only reaches the log when verbose logging is switched on for
+// debugging. Otherwise log lines describe the text by its length, so shared
+// logs don't leak what the user dictated. The flag is runtime-only and starts
+// off on every launch.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether transcribed text is written to the log
+static VERBOSE_TRANSCRIPTION_LOGGING: AtomicBool = AtomicBool::new(false);
+
+/// Whether verbose transcription logging is on
+pub fn verbose_transcription_logging() -> bool {
+    VERBOSE_TRANSCRIPTION_LOGGING.load(Ordering::SeqCst)
+}
+
+/// Turn verbose transcription logging on or off
+pub fn set_verbose_transcription_logging(enabled: bool) {
+    VERBOSE_TRANSCRIPTION_LOGGING.store(enabled, Ordering::SeqCst);
+}
+
+/// Describe transcribed text for a log line
+///
+/// Quotes the text when verbose logging is on, otherwise gives only its length.
+pub fn loggable_text(text: &str) -> String {
+    format_loggable_text(text, verbose_transcription_logging())
+}
+
+/// Describe `text` for a log line, including its content only when `verbose`
+pub fn format_loggable_text(text: &str, verbose: bool) -> String {
+    if verbose {
+        format!("{:?}", text)
+    } else {
+        format!("<{} chars>", text.chars().count())
+    }
+}
+
+#[cfg(test)]
+#[path = "verbose_logging_test.rs"]
+mod tests;
@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn test_text_is_hidden_unless_verbose() {
+    assert_eq!(format_loggable_text("call mom", false), "<8 chars>");
+    assert_eq!(format_loggable_text("", false), "<0 chars>");
+}
+
+#[test]
+fn test_verbose_text_is_quoted() {
+    assert_eq!(format_loggable_text("call mom", true), "\"call mom\"");
+}
+
+#[test]
+fn test_length_counts_characters_not_bytes() {
+    assert_eq!(format_loggable_text("café", false), "<4 chars>");
+}
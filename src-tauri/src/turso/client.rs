@@ -6,7 +6,7 @@
 use libsql::{Builder, Connection, Database};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, MutexGuard};
 
 /// Error types for Turso operations
 #[derive(Debug)]
@@ -58,7 +58,6 @@ const DB_FILE: &str = "heycat.db";
 /// client to be cloned and shared across multiple Tauri commands.
 #[derive(Clone)]
 pub struct TursoClient {
-    #[allow(dead_code)]
     db: Arc<Database>,
     conn: Arc<Mutex<Connection>>,
     db_path: PathBuf,
@@ -176,6 +175,21 @@ impl TursoClient {
             .map_err(TursoError::from)
     }
 
+    /// Hold the shared connection until the returned guard is dropped.
+    ///
+    /// The returned client runs its statements on the held connection, so a
+    /// multi-statement transaction can use the regular query helpers without
+    /// other writers interleaving with it.
+    pub(super) async fn hold_connection(&self) -> (MutexGuard<'_, Connection>, TursoClient) {
+        let conn = self.conn.lock().await;
+        let scoped = TursoClient {
+            db: self.db.clone(),
+            conn: Arc::new(Mutex::new(conn.clone())),
+            db_path: self.db_path.clone(),
+        };
+        (conn, scoped)
+    }
+
     /// Check if the database connection is valid.
     /// Note: Currently only used in tests - will be used for health checks
     #[allow(dead_code)]
@@ -0,0 +1,117 @@
+// Voice command match log using Turso/libsql
+//
+// Records the outcome of every command match attempt so match quality can be
+// reviewed over time, e.g. when deciding whether to raise the fuzzy threshold.
+
+use libsql::params;
+use serde::Serialize;
+
+use super::client::{TursoClient, TursoError};
+
+/// Match attempts older than this are deleted when a new one is recorded
+pub const COMMAND_MATCH_RETENTION_DAYS: u32 = 90;
+
+/// Aggregate statistics for one match outcome
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchOutcomeStats {
+    /// Outcome label ("exact", "fuzzy", "ambiguous", "no_match")
+    pub outcome: String,
+    /// Number of match attempts with this outcome
+    pub count: u64,
+    /// Average confidence, None when the outcome carries no score (no_match)
+    pub average_confidence: Option<f64>,
+}
+
+/// Match statistics over a time window
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchStats {
+    /// Total number of match attempts
+    pub total: u64,
+    /// Per-outcome counts, ordered by outcome label
+    pub outcomes: Vec<MatchOutcomeStats>,
+}
+
+impl TursoClient {
+    /// Record the outcome of a voice command match attempt.
+    ///
+    /// Attempts older than `COMMAND_MATCH_RETENTION_DAYS` are pruned at the
+    /// same time, so the table doesn't grow without bound.
+    ///
+    /// # Arguments
+    /// * `outcome` - Outcome label (see `MatchResult::outcome`)
+    /// * `confidence` - Match confidence, if the outcome has one
+    pub async fn record_command_match(
+        &self,
+        outcome: &str,
+        confidence: Option<f64>,
+    ) -> Result<(), TursoError> {
+        let created_at = chrono::Utc::now().to_rfc3339();
+
+        self.execute(
+            r#"INSERT INTO command_match (id, outcome, confidence, created_at)
+               VALUES (?1, ?2, ?3, ?4)"#,
+            params![
+                uuid::Uuid::new_v4().to_string(),
+                outcome.to_string(),
+                confidence,
+                created_at
+            ],
+        )
+        .await?;
+
+        self.execute(
+            r#"DELETE FROM command_match
+               WHERE julianday(created_at) < julianday('now', ?1)"#,
+            params![format!("-{} days", COMMAND_MATCH_RETENTION_DAYS)],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Aggregate match outcomes, optionally only those recorded since a timestamp.
+    ///
+    /// Timestamps are compared as instants rather than strings, so `since`
+    /// may use any UTC offset.
+    ///
+    /// # Arguments
+    /// * `since` - RFC 3339 timestamp; attempts before it are excluded
+    pub async fn get_match_stats(&self, since: Option<&str>) -> Result<MatchStats, TursoError> {
+        let mut rows = self
+            .query(
+                r#"SELECT outcome, COUNT(*), AVG(confidence) FROM command_match
+                   WHERE ?1 IS NULL OR julianday(created_at) >= julianday(?1)
+                   GROUP BY outcome ORDER BY outcome"#,
+                params![since.map(String::from)],
+            )
+            .await?;
+
+        let mut outcomes = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .map_err(|e| TursoError::Query(e.to_string()))?
+        {
+            let outcome: String = row.get(0).map_err(|e| TursoError::Query(e.to_string()))?;
+            let count: i64 = row.get(1).map_err(|e| TursoError::Query(e.to_string()))?;
+            let average_confidence: Option<f64> =
+                row.get(2).map_err(|e| TursoError::Query(e.to_string()))?;
+            outcomes.push(MatchOutcomeStats {
+                outcome,
+                count: count as u64,
+                average_confidence,
+            });
+        }
+
+        Ok(MatchStats {
+            total: outcomes.iter().map(|o| o.count).sum(),
+            outcomes,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "command_match_test.rs"]
+mod tests;
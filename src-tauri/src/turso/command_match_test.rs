@@ -0,0 +1,107 @@
+use super::COMMAND_MATCH_RETENTION_DAYS;
+use crate::turso::{initialize_schema, TursoClient};
+use libsql::params;
+use tempfile::TempDir;
+
+async fn setup_client() -> (TursoClient, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+#[tokio::test]
+async fn test_match_stats_empty() {
+    let (client, _temp) = setup_client().await;
+
+    let stats = client.get_match_stats(None).await.expect("Failed to get stats");
+
+    assert_eq!(stats.total, 0);
+    assert!(stats.outcomes.is_empty());
+}
+
+#[tokio::test]
+async fn test_match_stats_counts_by_outcome() {
+    let (client, _temp) = setup_client().await;
+
+    client.record_command_match("exact", Some(1.0)).await.unwrap();
+    client.record_command_match("fuzzy", Some(0.8)).await.unwrap();
+    client.record_command_match("fuzzy", Some(0.9)).await.unwrap();
+    client.record_command_match("no_match", None).await.unwrap();
+
+    let stats = client.get_match_stats(None).await.expect("Failed to get stats");
+
+    assert_eq!(stats.total, 4);
+    let outcomes: Vec<_> = stats
+        .outcomes
+        .iter()
+        .map(|o| (o.outcome.as_str(), o.count))
+        .collect();
+    assert_eq!(outcomes, vec![("exact", 1), ("fuzzy", 2), ("no_match", 1)]);
+
+    let fuzzy = &stats.outcomes[1];
+    assert!((fuzzy.average_confidence.unwrap() - 0.85).abs() < 1e-9);
+    assert_eq!(stats.outcomes[2].average_confidence, None);
+}
+
+#[tokio::test]
+async fn test_match_stats_since_excludes_older_attempts() {
+    let (client, _temp) = setup_client().await;
+
+    client.record_command_match("exact", Some(1.0)).await.unwrap();
+
+    let stats = client
+        .get_match_stats(Some("2999-01-01T00:00:00+00:00"))
+        .await
+        .expect("Failed to get stats");
+    assert_eq!(stats.total, 0);
+
+    let stats = client
+        .get_match_stats(Some("2000-01-01T00:00:00+00:00"))
+        .await
+        .expect("Failed to get stats");
+    assert_eq!(stats.total, 1);
+}
+
+#[tokio::test]
+async fn test_match_stats_since_compares_across_offsets() {
+    let (client, _temp) = setup_client().await;
+
+    client.record_command_match("exact", Some(1.0)).await.unwrap();
+
+    // An hour ago, written with a +14:00 offset: sorts after the stored UTC
+    // timestamp as a string, but is earlier as an instant
+    let an_hour_ago = (chrono::Utc::now() - chrono::Duration::hours(1))
+        .with_timezone(&chrono::FixedOffset::east_opt(14 * 3600).unwrap())
+        .to_rfc3339();
+    let stats = client
+        .get_match_stats(Some(&an_hour_ago))
+        .await
+        .expect("Failed to get stats");
+    assert_eq!(stats.total, 1);
+}
+
+#[tokio::test]
+async fn test_record_command_match_prunes_old_attempts() {
+    let (client, _temp) = setup_client().await;
+
+    let expired =
+        chrono::Utc::now() - chrono::Duration::days(i64::from(COMMAND_MATCH_RETENTION_DAYS) + 1);
+    client
+        .execute(
+            "INSERT INTO command_match (id, outcome, confidence, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params!["old", "fuzzy", 0.7, expired.to_rfc3339()],
+        )
+        .await
+        .unwrap();
+
+    client.record_command_match("exact", Some(1.0)).await.unwrap();
+
+    let stats = client.get_match_stats(None).await.expect("Failed to get stats");
+    assert_eq!(stats.total, 1);
+    assert_eq!(stats.outcomes[0].outcome, "exact");
+}
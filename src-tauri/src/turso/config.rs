@@ -0,0 +1,345 @@
+// Configuration export/import using Turso/libsql
+//
+// Serializes voice commands, dictionary entries and window contexts into one
+// document for backup and sharing, and restores it. IDs are preserved so the
+// command and dictionary references held by window contexts stay valid.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use super::client::{TursoClient, TursoError};
+use crate::dictionary::DictionaryEntry;
+use crate::voice_commands::registry::CommandDefinition;
+use crate::window_context::WindowContext;
+
+/// Version written to exported documents; newer documents are rejected on import
+pub const CONFIG_EXPORT_VERSION: u32 = 1;
+
+/// Complete configuration document
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigExport {
+    /// Document format version
+    pub version: u32,
+    /// When the document was exported (RFC 3339)
+    pub exported_at: String,
+    /// All voice commands
+    #[serde(default)]
+    pub commands: Vec<CommandDefinition>,
+    /// All dictionary entries
+    #[serde(default)]
+    pub dictionary_entries: Vec<DictionaryEntry>,
+    /// All window contexts
+    #[serde(default)]
+    pub window_contexts: Vec<WindowContext>,
+}
+
+/// A window context reference to a command or dictionary entry that doesn't exist
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DanglingReference {
+    /// ID of the context holding the reference
+    pub context_id: Uuid,
+    /// Name of the context holding the reference
+    pub context_name: String,
+    /// What is referenced ("command" or "dictionaryEntry")
+    pub kind: String,
+    /// The missing ID
+    pub reference_id: String,
+}
+
+/// Outcome of a config import
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigImportSummary {
+    /// Voice commands written
+    pub commands: usize,
+    /// Dictionary entries written
+    pub dictionary_entries: usize,
+    /// Window contexts written
+    pub window_contexts: usize,
+    /// References dropped from imported contexts because their target doesn't exist
+    pub dangling_references: Vec<DanglingReference>,
+}
+
+/// Find context references that point at neither a known command nor a known entry
+pub fn find_dangling_references(
+    contexts: &[WindowContext],
+    command_ids: &HashSet<Uuid>,
+    dictionary_entry_ids: &HashSet<String>,
+) -> Vec<DanglingReference> {
+    let mut dangling = Vec::new();
+    for context in contexts {
+        let dangling_reference = |kind: &str, reference_id: String| DanglingReference {
+            context_id: context.id,
+            context_name: context.name.clone(),
+            kind: kind.to_string(),
+            reference_id,
+        };
+        for id in context
+            .command_ids
+            .iter()
+            .filter(|id| !command_ids.contains(id))
+        {
+            dangling.push(dangling_reference("command", id.to_string()));
+        }
+        for id in context
+            .dictionary_entry_ids
+            .iter()
+            .filter(|id| !dictionary_entry_ids.contains(*id))
+        {
+            dangling.push(dangling_reference("dictionaryEntry", id.clone()));
+        }
+    }
+    dangling
+}
+
+impl TursoClient {
+    /// Export all voice commands, dictionary entries and window contexts.
+    pub async fn export_config(&self) -> Result<ConfigExport, TursoError> {
+        Ok(ConfigExport {
+            version: CONFIG_EXPORT_VERSION,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            commands: self.list_voice_commands().await.map_err(store_error)?,
+            dictionary_entries: self.list_dictionary_entries().await.map_err(store_error)?,
+            window_contexts: self.list_window_contexts().await.map_err(store_error)?,
+        })
+    }
+
+    /// Import a configuration document, keeping its IDs.
+    ///
+    /// With `replace`, all existing commands, entries and contexts are deleted
+    /// first. Otherwise items are merged: an item whose ID already exists is
+    /// updated and the rest are added. Context references that resolve to
+    /// neither an imported nor an existing item are dropped and reported.
+    ///
+    /// The document is validated before anything is written, and the writes
+    /// run in one transaction, so a failed import leaves the configuration as
+    /// it was.
+    ///
+    /// # Arguments
+    /// * `config` - The document to import
+    /// * `replace` - Whether to delete the current configuration first
+    pub async fn import_config(
+        &self,
+        config: ConfigExport,
+        replace: bool,
+    ) -> Result<ConfigImportSummary, TursoError> {
+        validate_config(&config)?;
+
+        // Hold the shared connection for the whole import, so no other write
+        // can land inside the transaction and be lost if it rolls back. The
+        // writes go through a client bound to the held connection.
+        let (conn, scoped) = self.hold_connection().await;
+
+        // All or nothing: a failure part way through leaves the current
+        // configuration untouched
+        let tx = conn.transaction().await?;
+        match scoped.write_config(&config, replace).await {
+            Ok(summary) => {
+                if let Err(e) = tx.commit().await {
+                    if !conn.is_autocommit() {
+                        if let Err(rollback_error) = conn.execute("ROLLBACK", ()).await {
+                            crate::error!("Failed to roll back config import: {}", rollback_error);
+                        }
+                    }
+                    return Err(e.into());
+                }
+                Ok(summary)
+            }
+            Err(e) => {
+                if let Err(rollback_error) = tx.rollback().await {
+                    crate::error!("Failed to roll back config import: {}", rollback_error);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Write a validated document; run inside the import transaction.
+    async fn write_config(
+        &self,
+        config: &ConfigExport,
+        replace: bool,
+    ) -> Result<ConfigImportSummary, TursoError> {
+        if replace {
+            // Contexts first, since they reference the others
+            for context in self.list_window_contexts().await.map_err(store_error)? {
+                self.delete_window_context(context.id)
+                    .await
+                    .map_err(store_error)?;
+            }
+            for cmd in self.list_voice_commands().await.map_err(store_error)? {
+                self.delete_voice_command(cmd.id)
+                    .await
+                    .map_err(store_error)?;
+            }
+            for entry in self.list_dictionary_entries().await.map_err(store_error)? {
+                self.delete_dictionary_entry(&entry.id)
+                    .await
+                    .map_err(store_error)?;
+            }
+        }
+
+        for entry in &config.dictionary_entries {
+            if self
+                .dictionary_entry_exists(&entry.id)
+                .await
+                .map_err(store_error)?
+            {
+                self.update_dictionary_entry(
+                    entry.id.clone(),
+                    entry.trigger.clone(),
+                    entry.expansion.clone(),
+                    entry.suffix.clone(),
+                    entry.auto_enter,
+                    entry.disable_suffix,
+                    entry.complete_match_only,
+                )
+                .await
+                .map_err(store_error)?;
+            } else {
+                self.insert_dictionary_entry(entry)
+                    .await
+                    .map_err(store_error)?;
+            }
+        }
+
+        for cmd in &config.commands {
+            if self
+                .voice_command_exists(cmd.id)
+                .await
+                .map_err(store_error)?
+            {
+                self.update_voice_command(cmd).await.map_err(store_error)?;
+            } else {
+                self.add_voice_command(cmd).await.map_err(store_error)?;
+            }
+        }
+
+        // References may point at items imported above or already present
+        let command_ids: HashSet<Uuid> = self
+            .list_voice_commands()
+            .await
+            .map_err(store_error)?
+            .into_iter()
+            .map(|cmd| cmd.id)
+            .collect();
+        let dictionary_entry_ids: HashSet<String> = self
+            .list_dictionary_entries()
+            .await
+            .map_err(store_error)?
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect();
+        let dangling_references =
+            find_dangling_references(&config.window_contexts, &command_ids, &dictionary_entry_ids);
+        for reference in &dangling_references {
+            crate::warn!(
+                "Window context '{}' references missing {} {}, dropping it",
+                reference.context_name,
+                reference.kind,
+                reference.reference_id
+            );
+        }
+
+        for context in &config.window_contexts {
+            let mut context = context.clone();
+            context.command_ids.retain(|id| command_ids.contains(id));
+            context
+                .dictionary_entry_ids
+                .retain(|id| dictionary_entry_ids.contains(id));
+
+            if self
+                .window_context_exists(context.id)
+                .await
+                .map_err(store_error)?
+            {
+                self.update_window_context(context)
+                    .await
+                    .map_err(store_error)?;
+            } else {
+                self.insert_window_context(&context)
+                    .await
+                    .map_err(store_error)?;
+            }
+        }
+
+        Ok(ConfigImportSummary {
+            commands: config.commands.len(),
+            dictionary_entries: config.dictionary_entries.len(),
+            window_contexts: config.window_contexts.len(),
+            dangling_references,
+        })
+    }
+}
+
+/// Check a document before anything is written
+///
+/// Rejects newer versions, commands and entries with an empty trigger, and
+/// IDs or dictionary triggers that appear more than once.
+pub fn validate_config(config: &ConfigExport) -> Result<(), TursoError> {
+    if config.version > CONFIG_EXPORT_VERSION {
+        return Err(TursoError::Query(format!(
+            "Config version {} is newer than supported version {}",
+            config.version, CONFIG_EXPORT_VERSION
+        )));
+    }
+
+    let invalid = |message: String| Err(TursoError::Query(message));
+
+    let mut command_ids = HashSet::new();
+    for cmd in &config.commands {
+        if cmd.trigger.trim().is_empty() {
+            return invalid(format!("Command {} has an empty trigger", cmd.id));
+        }
+        if !command_ids.insert(cmd.id) {
+            return invalid(format!("Command {} appears more than once", cmd.id));
+        }
+    }
+
+    let mut entry_ids = HashSet::new();
+    let mut entry_triggers = HashSet::new();
+    for entry in &config.dictionary_entries {
+        if entry.trigger.trim().is_empty() {
+            return invalid(format!(
+                "Dictionary entry {} has an empty trigger",
+                entry.id
+            ));
+        }
+        if !entry_ids.insert(entry.id.as_str()) {
+            return invalid(format!(
+                "Dictionary entry {} appears more than once",
+                entry.id
+            ));
+        }
+        if !entry_triggers.insert(entry.trigger.as_str()) {
+            return invalid(format!(
+                "Dictionary trigger '{}' appears more than once",
+                entry.trigger
+            ));
+        }
+    }
+
+    let mut context_ids = HashSet::new();
+    for context in &config.window_contexts {
+        if !context_ids.insert(context.id) {
+            return invalid(format!(
+                "Window context {} appears more than once",
+                context.id
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Wrap an error from one of the configuration tables
+fn store_error(error: impl std::fmt::Display) -> TursoError {
+    TursoError::Query(error.to_string())
+}
+
+#[cfg(test)]
+#[path = "config_test.rs"]
+mod tests;
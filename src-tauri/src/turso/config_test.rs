@@ -0,0 +1,240 @@
+use super::*;
+use crate::turso::initialize_schema;
+use crate::voice_commands::registry::ActionType;
+use crate::window_context::{OverrideMode, WindowMatcher};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+async fn setup_client() -> (TursoClient, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+fn make_command(trigger: &str) -> CommandDefinition {
+    CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: trigger.to_string(),
+        action_type: ActionType::OpenApp,
+        parameters: HashMap::new(),
+        enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
+    }
+}
+
+fn make_context(
+    name: &str,
+    command_ids: Vec<Uuid>,
+    dictionary_entry_ids: Vec<String>,
+) -> WindowContext {
+    WindowContext {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        matcher: WindowMatcher {
+            app_name: "Terminal".to_string(),
+            title_pattern: None,
+            bundle_id: None,
+        },
+        command_mode: OverrideMode::Merge,
+        dictionary_mode: OverrideMode::Replace,
+        command_ids,
+        dictionary_entry_ids,
+        enabled: true,
+        priority: 1,
+        text_transforms: None,
+        dictation_disabled: false,
+    }
+}
+
+/// Populate a client with one command, one entry and a context referencing both
+async fn populate(client: &TursoClient) -> (CommandDefinition, DictionaryEntry, WindowContext) {
+    let cmd = make_command("open slack");
+    client.add_voice_command(&cmd).await.unwrap();
+    let entry = client
+        .add_dictionary_entry(
+            "brb".to_string(),
+            "be right back".to_string(),
+            None,
+            false,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    let context = make_context("Terminal", vec![cmd.id], vec![entry.id.clone()]);
+    client.insert_window_context(&context).await.unwrap();
+    (cmd, entry, context)
+}
+
+#[test]
+fn test_find_dangling_references() {
+    let known_command = Uuid::new_v4();
+    let missing_command = Uuid::new_v4();
+    let context = make_context(
+        "Editor",
+        vec![known_command, missing_command],
+        vec!["known".to_string(), "missing".to_string()],
+    );
+    let command_ids = HashSet::from([known_command]);
+    let entry_ids = HashSet::from(["known".to_string()]);
+
+    let dangling = find_dangling_references(&[context.clone()], &command_ids, &entry_ids);
+
+    let refs: Vec<_> = dangling
+        .iter()
+        .map(|r| (r.kind.as_str(), r.reference_id.clone()))
+        .collect();
+    assert_eq!(
+        refs,
+        vec![
+            ("command", missing_command.to_string()),
+            ("dictionaryEntry", "missing".to_string())
+        ]
+    );
+    assert!(dangling.iter().all(|r| r.context_id == context.id));
+}
+
+#[tokio::test]
+async fn test_export_import_roundtrip_preserves_ids() {
+    let (source, _source_temp) = setup_client().await;
+    let (cmd, entry, context) = populate(&source).await;
+    let exported = source.export_config().await.expect("Failed to export");
+    assert_eq!(exported.version, CONFIG_EXPORT_VERSION);
+
+    // Through JSON, as the command does
+    let json = serde_json::to_string(&exported).unwrap();
+    let parsed: ConfigExport = serde_json::from_str(&json).unwrap();
+
+    let (target, _target_temp) = setup_client().await;
+    let summary = target
+        .import_config(parsed, false)
+        .await
+        .expect("Failed to import");
+
+    assert_eq!(
+        (
+            summary.commands,
+            summary.dictionary_entries,
+            summary.window_contexts
+        ),
+        (1, 1, 1)
+    );
+    assert!(summary.dangling_references.is_empty());
+    assert_eq!(target.list_voice_commands().await.unwrap(), vec![cmd]);
+    assert_eq!(target.list_dictionary_entries().await.unwrap(), vec![entry]);
+    assert_eq!(target.list_window_contexts().await.unwrap(), vec![context]);
+}
+
+#[tokio::test]
+async fn test_import_drops_and_reports_dangling_references() {
+    let (client, _temp) = setup_client().await;
+    let missing = Uuid::new_v4();
+    let config = ConfigExport {
+        version: CONFIG_EXPORT_VERSION,
+        exported_at: String::new(),
+        commands: Vec::new(),
+        dictionary_entries: Vec::new(),
+        window_contexts: vec![make_context("Orphan", vec![missing], Vec::new())],
+    };
+
+    let summary = client
+        .import_config(config, false)
+        .await
+        .expect("Failed to import");
+
+    assert_eq!(summary.dangling_references.len(), 1);
+    assert_eq!(
+        summary.dangling_references[0].reference_id,
+        missing.to_string()
+    );
+    let contexts = client.list_window_contexts().await.unwrap();
+    assert!(contexts[0].command_ids.is_empty());
+}
+
+#[tokio::test]
+async fn test_import_replace_removes_existing_config() {
+    let (client, _temp) = setup_client().await;
+    populate(&client).await;
+    let replacement = make_command("open mail");
+    let config = ConfigExport {
+        version: CONFIG_EXPORT_VERSION,
+        exported_at: String::new(),
+        commands: vec![replacement.clone()],
+        dictionary_entries: Vec::new(),
+        window_contexts: Vec::new(),
+    };
+
+    client
+        .import_config(config, true)
+        .await
+        .expect("Failed to import");
+
+    assert_eq!(
+        client.list_voice_commands().await.unwrap(),
+        vec![replacement]
+    );
+    assert!(client.list_dictionary_entries().await.unwrap().is_empty());
+    assert!(client.list_window_contexts().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_import_rejects_newer_version() {
+    let (client, _temp) = setup_client().await;
+    let config = ConfigExport {
+        version: CONFIG_EXPORT_VERSION + 1,
+        exported_at: String::new(),
+        commands: Vec::new(),
+        dictionary_entries: Vec::new(),
+        window_contexts: Vec::new(),
+    };
+
+    assert!(client.import_config(config, false).await.is_err());
+}
+
+#[test]
+fn test_validate_config_rejects_duplicates_and_empty_triggers() {
+    let cmd = make_command("open slack");
+    let config = |commands: Vec<CommandDefinition>| ConfigExport {
+        version: CONFIG_EXPORT_VERSION,
+        exported_at: String::new(),
+        commands,
+        dictionary_entries: Vec::new(),
+        window_contexts: Vec::new(),
+    };
+
+    assert!(validate_config(&config(vec![cmd.clone()])).is_ok());
+    assert!(validate_config(&config(vec![cmd.clone(), cmd])).is_err());
+    assert!(validate_config(&config(vec![make_command("  ")])).is_err());
+}
+
+#[tokio::test]
+async fn test_failed_import_rolls_back() {
+    let (client, _temp) = setup_client().await;
+    let (cmd, entry, context) = populate(&client).await;
+    let mut added = entry.clone();
+    added.id = "added".to_string();
+    added.trigger = "afk".to_string();
+    let mut clashing = entry.clone();
+    clashing.id = "clashing".to_string();
+    let config = ConfigExport {
+        version: CONFIG_EXPORT_VERSION,
+        exported_at: String::new(),
+        commands: vec![make_command("open mail")],
+        // The second insert fails: its trigger is taken by the existing entry
+        dictionary_entries: vec![added, clashing],
+        window_contexts: Vec::new(),
+    };
+
+    assert!(client.import_config(config, false).await.is_err());
+
+    assert_eq!(client.list_voice_commands().await.unwrap(), vec![cmd]);
+    assert_eq!(client.list_dictionary_entries().await.unwrap(), vec![entry]);
+    assert_eq!(client.list_window_contexts().await.unwrap(), vec![context]);
+}
@@ -28,6 +28,9 @@ pub mod event_names {
     /// Emitted when recordings table changes
     pub const RECORDINGS_UPDATED: &str = "recordings_updated";
 
+    /// Emitted once after a batch of recordings is deleted
+    pub const RECORDINGS_DELETED: &str = "recordings_deleted";
+
     /// Emitted when transcriptions table changes
     pub const TRANSCRIPTIONS_UPDATED: &str = "transcriptions_updated";
 }
@@ -36,9 +39,9 @@ pub mod event_names {
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VoiceCommandsUpdatedPayload {
-    /// Type of change: "add", "update", "delete", or "sync"
+    /// Type of change: "add", "update", "delete", "sync", or "import"
     pub action: String,
-    /// ID of the affected command
+    /// ID of the affected command (empty for "import")
     pub command_id: String,
 }
 
@@ -54,6 +57,16 @@ pub struct RecordingsUpdatedPayload {
     pub timestamp: String,
 }
 
+/// Payload for recordings_deleted event
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingsDeletedPayload {
+    /// File paths of the recordings that were removed
+    pub file_paths: Vec<String>,
+    /// ISO 8601 timestamp
+    pub timestamp: String,
+}
+
 /// Payload for transcriptions_updated event
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -72,8 +85,8 @@ pub struct TranscriptionsUpdatedPayload {
 ///
 /// # Arguments
 /// * `app_handle` - Tauri AppHandle for event emission
-/// * `action` - Type of change: "add", "update", or "delete"
-/// * `entry_id` - ID of the affected dictionary entry
+/// * `action` - Type of change: "add", "update", "delete", or "import"
+/// * `entry_id` - ID of the affected dictionary entry (empty for "import")
 pub fn emit_dictionary_updated(app_handle: &AppHandle, action: &str, entry_id: &str) {
     let payload = dictionary_events::DictionaryUpdatedPayload {
         action: action.to_string(),
@@ -88,8 +101,8 @@ pub fn emit_dictionary_updated(app_handle: &AppHandle, action: &str, entry_id: &
 ///
 /// # Arguments
 /// * `app_handle` - Tauri AppHandle for event emission
-/// * `action` - Type of change: "add", "update", or "delete"
-/// * `context_id` - ID of the affected window context
+/// * `action` - Type of change: "add", "update", "delete", or "import"
+/// * `context_id` - ID of the affected window context (empty for "import")
 pub fn emit_window_contexts_updated(app_handle: &AppHandle, action: &str, context_id: &str) {
     let payload = window_context_events::WindowContextsUpdatedPayload {
         action: action.to_string(),
@@ -121,6 +134,21 @@ pub fn emit_recordings_updated(
     }
 }
 
+/// Emit recordings_deleted event after a batch delete.
+///
+/// # Arguments
+/// * `app_handle` - Tauri AppHandle for event emission
+/// * `file_paths` - Paths of the recordings that were removed
+pub fn emit_recordings_deleted(app_handle: &AppHandle, file_paths: Vec<String>) {
+    let payload = RecordingsDeletedPayload {
+        file_paths,
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    };
+    if let Err(e) = app_handle.emit(event_names::RECORDINGS_DELETED, payload) {
+        crate::warn!("Failed to emit recordings_deleted event: {}", e);
+    }
+}
+
 /// Emit transcriptions_updated event after a transcription change.
 ///
 /// # Arguments
@@ -149,8 +177,8 @@ pub fn emit_transcriptions_updated(
 ///
 /// # Arguments
 /// * `app_handle` - Tauri AppHandle for event emission
-/// * `action` - Type of change: "add", "update", or "delete"
-/// * `command_id` - ID of the affected command
+/// * `action` - Type of change: "add", "update", "delete", or "import"
+/// * `command_id` - ID of the affected command (empty for "import")
 pub fn emit_voice_commands_updated(app_handle: &AppHandle, action: &str, command_id: &str) {
     let payload = VoiceCommandsUpdatedPayload {
         action: action.to_string(),
@@ -30,6 +30,17 @@ fn test_recordings_payload_serialization() {
     assert!(!json.contains("recording_id"));
 }
 
+#[test]
+fn test_recordings_deleted_payload_serialization() {
+    let payload = RecordingsDeletedPayload {
+        file_paths: vec!["/tmp/a.wav".to_string(), "/tmp/b.wav".to_string()],
+        timestamp: "2025-01-01T00:00:00Z".to_string(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("\"filePaths\":[\"/tmp/a.wav\",\"/tmp/b.wav\"]"));
+    assert!(!json.contains("file_paths"));
+}
+
 #[test]
 fn test_transcriptions_payload_serialization() {
     let payload = TranscriptionsUpdatedPayload {
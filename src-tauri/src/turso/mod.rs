@@ -4,10 +4,13 @@
 // It provides a simple, embedded solution for all data persistence.
 
 mod client;
+mod command_match;
+mod config;
 mod dictionary;
 pub mod events;
 mod recording;
 mod schema;
+mod usage;
 mod voice_command;
 mod window_context;
 
@@ -16,4 +19,7 @@ mod window_context;
 pub use recording::{RecordingRecord, RecordingStoreError, TranscriptionRecord, TranscriptionStoreError};
 
 pub use client::TursoClient;
+pub use command_match::MatchStats;
+pub use config::{ConfigExport, ConfigImportSummary};
 pub use schema::initialize_schema;
+pub use usage::UsageSummary;
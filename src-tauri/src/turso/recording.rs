@@ -6,6 +6,7 @@ use libsql::params;
 
 use super::client::TursoClient;
 use crate::audio::StopReason;
+use crate::recording::TriggerSource;
 
 /// Recording metadata stored in Turso
 #[derive(Debug, Clone)]
@@ -16,6 +17,7 @@ pub struct RecordingRecord {
     pub duration_secs: f64,
     pub sample_count: u64,
     pub stop_reason: Option<StopReason>,
+    pub trigger_source: Option<TriggerSource>,
     pub created_at: String,
     pub active_window_app_name: Option<String>,
     pub active_window_bundle_id: Option<String>,
@@ -54,11 +56,14 @@ pub struct TranscriptionRecord {
     pub model_version: String,
     pub duration_ms: u64,
     pub created_at: String,
+    /// User correction of the transcribed text, if any
+    pub edited_text: Option<String>,
 }
 
 /// Error type for transcription operations
 #[derive(Debug, Clone)]
 pub enum TranscriptionStoreError {
+    NotFound(String),
     PersistenceError(String),
     LoadError(String),
 }
@@ -66,6 +71,7 @@ pub enum TranscriptionStoreError {
 impl std::fmt::Display for TranscriptionStoreError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            TranscriptionStoreError::NotFound(id) => write!(f, "Transcription not found: {}", id),
             TranscriptionStoreError::PersistenceError(msg) => {
                 write!(f, "Transcription persistence error: {}", msg)
             }
@@ -91,6 +97,7 @@ impl TursoClient {
     /// * `duration_secs` - Duration in seconds
     /// * `sample_count` - Number of audio samples
     /// * `stop_reason` - Why recording stopped
+    /// * `trigger_source` - What started the recording
     /// * `active_window_app_name` - App name when recording started
     /// * `active_window_bundle_id` - Bundle ID when recording started
     /// * `active_window_title` - Window title when recording started
@@ -101,28 +108,32 @@ impl TursoClient {
         duration_secs: f64,
         sample_count: u64,
         stop_reason: Option<StopReason>,
+        trigger_source: Option<TriggerSource>,
         active_window_app_name: Option<String>,
         active_window_bundle_id: Option<String>,
         active_window_title: Option<String>,
     ) -> Result<RecordingRecord, RecordingStoreError> {
         let created_at = chrono::Utc::now().to_rfc3339();
-        let stop_reason_str = stop_reason.as_ref().map(|r| format!("{:?}", r));
+        let stop_reason_str = stop_reason.as_ref().map(|r| r.as_str().to_string());
+        let trigger_source_str = trigger_source.map(|t| t.as_str().to_string());
 
         self.execute(
             r#"INSERT INTO recording
                (id, file_path, duration_secs, sample_count, stop_reason, created_at,
-                active_window_app_name, active_window_bundle_id, active_window_title)
-               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)"#,
+                active_window_app_name, active_window_bundle_id, active_window_title,
+                trigger_source)
+               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)"#,
             params![
                 id.clone(),
                 file_path.clone(),
                 duration_secs,
-                sample_count as i64,
+                sample_count_to_db(sample_count),
                 stop_reason_str,
                 created_at.clone(),
                 active_window_app_name.clone(),
                 active_window_bundle_id.clone(),
-                active_window_title.clone()
+                active_window_title.clone(),
+                trigger_source_str
             ],
         )
         .await
@@ -134,6 +145,7 @@ impl TursoClient {
             duration_secs,
             sample_count,
             stop_reason,
+            trigger_source,
             created_at,
             active_window_app_name,
             active_window_bundle_id,
@@ -146,7 +158,8 @@ impl TursoClient {
         let mut rows = self
             .query(
                 r#"SELECT id, file_path, duration_secs, sample_count, stop_reason, created_at,
-                          active_window_app_name, active_window_bundle_id, active_window_title
+                          active_window_app_name, active_window_bundle_id, active_window_title,
+                          trigger_source
                    FROM recording
                    ORDER BY created_at DESC"#,
                 (),
@@ -175,7 +188,8 @@ impl TursoClient {
         let mut rows = self
             .query(
                 r#"SELECT id, file_path, duration_secs, sample_count, stop_reason, created_at,
-                          active_window_app_name, active_window_bundle_id, active_window_title
+                          active_window_app_name, active_window_bundle_id, active_window_title,
+                          trigger_source
                    FROM recording
                    WHERE file_path = ?1"#,
                 params![file_path.to_string()],
@@ -219,6 +233,56 @@ impl TursoClient {
         Ok(())
     }
 
+    /// Update the file path of a recording (e.g., after a rename on disk).
+    ///
+    /// Transcriptions reference the recording by ID, so nothing else changes.
+    pub async fn update_recording_path(
+        &self,
+        old_path: &str,
+        new_path: &str,
+    ) -> Result<(), RecordingStoreError> {
+        let exists = self.recording_exists_by_path(old_path).await?;
+        if !exists {
+            return Err(RecordingStoreError::NotFound(old_path.to_string()));
+        }
+
+        self.execute(
+            "UPDATE recording SET file_path = ?1 WHERE file_path = ?2",
+            params![new_path.to_string(), old_path.to_string()],
+        )
+        .await
+        .map_err(|e| RecordingStoreError::PersistenceError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Set (or clear) the stop reason of a recording.
+    ///
+    /// Lets tooling annotate recordings whose reason wasn't captured, such as
+    /// imported or reconciled ones.
+    pub async fn update_recording_stop_reason(
+        &self,
+        file_path: &str,
+        stop_reason: Option<StopReason>,
+    ) -> Result<(), RecordingStoreError> {
+        let exists = self.recording_exists_by_path(file_path).await?;
+        if !exists {
+            return Err(RecordingStoreError::NotFound(file_path.to_string()));
+        }
+
+        self.execute(
+            "UPDATE recording SET stop_reason = ?1 WHERE file_path = ?2",
+            params![
+                stop_reason.map(|r| r.as_str().to_string()),
+                file_path.to_string()
+            ],
+        )
+        .await
+        .map_err(|e| RecordingStoreError::PersistenceError(e.to_string()))?;
+
+        Ok(())
+    }
+
     /// Check if a recording exists by file path.
     async fn recording_exists_by_path(&self, file_path: &str) -> Result<bool, RecordingStoreError> {
         let mut rows = self
@@ -285,16 +349,83 @@ impl TursoClient {
             model_version,
             duration_ms,
             created_at,
+            edited_text: None,
         })
     }
 
+    /// Store a user correction for a transcription.
+    ///
+    /// The original text is kept; the correction is stored in `edited_text`.
+    /// An empty correction clears the edit.
+    ///
+    /// # Arguments
+    /// * `id` - ID of the transcription to correct
+    /// * `text` - Corrected text
+    pub async fn update_transcription_text(
+        &self,
+        id: &str,
+        text: &str,
+    ) -> Result<(), TranscriptionStoreError> {
+        let edited_text = if text.trim().is_empty() {
+            None
+        } else {
+            Some(text.to_string())
+        };
+
+        let updated = self
+            .execute(
+                "UPDATE transcription SET edited_text = ?1 WHERE id = ?2",
+                params![edited_text, id.to_string()],
+            )
+            .await
+            .map_err(|e| TranscriptionStoreError::PersistenceError(e.to_string()))?;
+
+        if updated == 0 {
+            return Err(TranscriptionStoreError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// List (original, edited) text pairs for all corrected transcriptions.
+    pub async fn list_transcription_corrections(
+        &self,
+    ) -> Result<Vec<(String, String)>, TranscriptionStoreError> {
+        let mut rows = self
+            .query(
+                r#"SELECT text, edited_text
+                   FROM transcription
+                   WHERE edited_text IS NOT NULL"#,
+                (),
+            )
+            .await
+            .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?;
+
+        let mut corrections = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?
+        {
+            let text: String = row
+                .get(0)
+                .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?;
+            let edited_text: String = row
+                .get(1)
+                .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?;
+            corrections.push((text, edited_text));
+        }
+
+        Ok(corrections)
+    }
+
     /// List all transcriptions.
     pub async fn list_transcriptions(
         &self,
     ) -> Result<Vec<TranscriptionRecord>, TranscriptionStoreError> {
         let mut rows = self
             .query(
-                r#"SELECT id, recording_id, text, language, model_version, duration_ms, created_at
+                r#"SELECT id, recording_id, text, language, model_version, duration_ms, created_at,
+                          edited_text
                    FROM transcription
                    ORDER BY created_at DESC"#,
                 (),
@@ -315,6 +446,32 @@ impl TursoClient {
         Ok(transcriptions)
     }
 
+    /// Get a transcription by ID.
+    pub async fn get_transcription_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<TranscriptionRecord>, TranscriptionStoreError> {
+        let mut rows = self
+            .query(
+                r#"SELECT id, recording_id, text, language, model_version, duration_ms, created_at,
+                          edited_text
+                   FROM transcription
+                   WHERE id = ?1"#,
+                params![id.to_string()],
+            )
+            .await
+            .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?;
+
+        match rows
+            .next()
+            .await
+            .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?
+        {
+            Some(row) => Ok(Some(parse_transcription_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
     /// Get transcriptions by recording ID.
     /// Uses the idx_transcription_recording_id index for efficient lookup.
     pub async fn get_transcriptions_by_recording(
@@ -323,7 +480,8 @@ impl TursoClient {
     ) -> Result<Vec<TranscriptionRecord>, TranscriptionStoreError> {
         let mut rows = self
             .query(
-                r#"SELECT id, recording_id, text, language, model_version, duration_ms, created_at
+                r#"SELECT id, recording_id, text, language, model_version, duration_ms, created_at,
+                          edited_text
                    FROM transcription
                    WHERE recording_id = ?1
                    ORDER BY created_at DESC"#,
@@ -346,6 +504,16 @@ impl TursoClient {
     }
 }
 
+/// Convert a sample count to SQLite's signed INTEGER, saturating at `i64::MAX`
+fn sample_count_to_db(sample_count: u64) -> i64 {
+    i64::try_from(sample_count).unwrap_or(i64::MAX)
+}
+
+/// Convert a stored sample count back to u64, treating negative values as 0
+fn sample_count_from_db(sample_count: i64) -> u64 {
+    u64::try_from(sample_count).unwrap_or(0)
+}
+
 /// Parse a database row into a RecordingRecord
 fn parse_recording_row(row: &libsql::Row) -> Result<RecordingRecord, RecordingStoreError> {
     let id: String = row
@@ -375,15 +543,20 @@ fn parse_recording_row(row: &libsql::Row) -> Result<RecordingRecord, RecordingSt
     let active_window_title: Option<String> = row
         .get(8)
         .map_err(|e| RecordingStoreError::LoadError(e.to_string()))?;
+    let trigger_source_str: Option<String> = row
+        .get(9)
+        .map_err(|e| RecordingStoreError::LoadError(e.to_string()))?;
 
     let stop_reason = stop_reason_str.and_then(|s| parse_stop_reason(&s));
+    let trigger_source = trigger_source_str.and_then(|s| parse_trigger_source(&s));
 
     Ok(RecordingRecord {
         id,
         file_path,
         duration_secs,
-        sample_count: sample_count as u64,
+        sample_count: sample_count_from_db(sample_count),
         stop_reason,
+        trigger_source,
         created_at,
         active_window_app_name,
         active_window_bundle_id,
@@ -414,6 +587,9 @@ fn parse_transcription_row(row: &libsql::Row) -> Result<TranscriptionRecord, Tra
     let created_at: String = row
         .get(6)
         .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?;
+    let edited_text: Option<String> = row
+        .get(7)
+        .map_err(|e| TranscriptionStoreError::LoadError(e.to_string()))?;
 
     Ok(TranscriptionRecord {
         id,
@@ -423,19 +599,29 @@ fn parse_transcription_row(row: &libsql::Row) -> Result<TranscriptionRecord, Tra
         model_version,
         duration_ms: duration_ms as u64,
         created_at,
+        edited_text,
     })
 }
 
 /// Parse StopReason from string
 fn parse_stop_reason(s: &str) -> Option<StopReason> {
-    match s {
-        "BufferFull" => Some(StopReason::BufferFull),
-        "LockError" => Some(StopReason::LockError),
-        "StreamError" => Some(StopReason::StreamError),
-        "ResampleOverflow" => Some(StopReason::ResampleOverflow),
-        "SilenceAfterSpeech" => Some(StopReason::SilenceAfterSpeech),
-        "NoSpeechTimeout" => Some(StopReason::NoSpeechTimeout),
-        _ => None,
+    match s.parse() {
+        Ok(reason) => Some(reason),
+        Err(e) => {
+            crate::warn!("Ignoring stored stop reason: {}", e);
+            None
+        }
+    }
+}
+
+/// Parse TriggerSource from string
+fn parse_trigger_source(s: &str) -> Option<TriggerSource> {
+    match s.parse() {
+        Ok(source) => Some(source),
+        Err(e) => {
+            crate::warn!("Ignoring stored trigger source: {}", e);
+            None
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::audio::StopReason;
-use crate::turso::{initialize_schema, TursoClient};
+use crate::recording::TriggerSource;
+use crate::turso::{initialize_schema, RecordingStoreError, TranscriptionStoreError, TursoClient};
 use tempfile::TempDir;
 
 async fn setup_client() -> (TursoClient, TempDir) {
@@ -28,6 +29,7 @@ async fn test_add_recording() {
             5.5,
             88200,
             Some(StopReason::SilenceAfterSpeech),
+            Some(TriggerSource::Hotkey),
             Some("Safari".to_string()),
             Some("com.apple.Safari".to_string()),
             Some("Google - Safari".to_string()),
@@ -40,7 +42,75 @@ async fn test_add_recording() {
     assert!((recording.duration_secs - 5.5).abs() < 0.001);
     assert_eq!(recording.sample_count, 88200);
     assert!(matches!(recording.stop_reason, Some(StopReason::SilenceAfterSpeech)));
+    assert_eq!(recording.trigger_source, Some(TriggerSource::Hotkey));
     assert_eq!(recording.active_window_app_name, Some("Safari".to_string()));
+
+    let stored = client
+        .get_recording_by_path("/path/to/recording.wav")
+        .await
+        .expect("Failed to get recording")
+        .expect("Recording should exist");
+    assert_eq!(stored.trigger_source, Some(TriggerSource::Hotkey));
+}
+
+#[tokio::test]
+async fn test_two_hour_recording_roundtrip() {
+    let (client, _temp) = setup_client().await;
+
+    // 2 hours at 48kHz
+    let sample_count = crate::audio::samples_for_duration_ms(2 * 60 * 60 * 1000, 48000);
+    let duration_secs = crate::audio::duration_secs(sample_count, 48000);
+
+    client
+        .add_recording(
+            "rec-long".to_string(),
+            "/path/to/long.wav".to_string(),
+            duration_secs,
+            sample_count,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add recording");
+
+    let loaded = client
+        .get_recording_by_path("/path/to/long.wav")
+        .await
+        .expect("Failed to load recording")
+        .expect("Recording should exist");
+    assert_eq!(loaded.sample_count, 345_600_000);
+    assert!((loaded.duration_secs - 7200.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_sample_count_beyond_u32_roundtrip() {
+    let (client, _temp) = setup_client().await;
+    let sample_count = u32::MAX as u64 + 1_000;
+
+    client
+        .add_recording(
+            "rec-huge".to_string(),
+            "/path/to/huge.wav".to_string(),
+            crate::audio::duration_secs(sample_count, 48000),
+            sample_count,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add recording");
+
+    let loaded = client
+        .get_recording_by_path("/path/to/huge.wav")
+        .await
+        .expect("Failed to load recording")
+        .expect("Recording should exist");
+    assert_eq!(loaded.sample_count, sample_count);
 }
 
 #[tokio::test]
@@ -57,6 +127,7 @@ async fn test_add_recording_minimal() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add recording");
@@ -89,6 +160,7 @@ async fn test_list_recordings_ordered_by_date() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add old");
@@ -106,6 +178,7 @@ async fn test_list_recordings_ordered_by_date() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add new");
@@ -131,6 +204,7 @@ async fn test_get_recording_by_path() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add");
@@ -170,6 +244,7 @@ async fn test_delete_recording_by_path() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add");
@@ -187,6 +262,54 @@ async fn test_delete_recording_by_path() {
     assert!(result.is_none());
 }
 
+#[tokio::test]
+async fn test_update_recording_path_keeps_id() {
+    let (client, _temp) = setup_client().await;
+
+    client
+        .add_recording(
+            "rec-rename".to_string(),
+            "/path/to/old.wav".to_string(),
+            1.0,
+            16000,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add");
+
+    client
+        .update_recording_path("/path/to/old.wav", "/path/to/new.wav")
+        .await
+        .expect("Failed to update path");
+
+    assert!(client
+        .get_recording_by_path("/path/to/old.wav")
+        .await
+        .unwrap()
+        .is_none());
+    let renamed = client
+        .get_recording_by_path("/path/to/new.wav")
+        .await
+        .unwrap()
+        .expect("Renamed recording should exist");
+    assert_eq!(renamed.id, "rec-rename");
+}
+
+#[tokio::test]
+async fn test_update_recording_path_not_found() {
+    let (client, _temp) = setup_client().await;
+
+    let result = client
+        .update_recording_path("/path/to/missing.wav", "/path/to/new.wav")
+        .await;
+
+    assert!(matches!(result, Err(RecordingStoreError::NotFound(_))));
+}
+
 #[tokio::test]
 async fn test_delete_recording_not_found() {
     let (client, _temp) = setup_client().await;
@@ -207,6 +330,7 @@ async fn test_stop_reason_roundtrip() {
         StopReason::ResampleOverflow,
         StopReason::SilenceAfterSpeech,
         StopReason::NoSpeechTimeout,
+        StopReason::Recovered,
     ];
 
     for (i, stop_reason) in stop_reasons.iter().enumerate() {
@@ -220,13 +344,14 @@ async fn test_stop_reason_roundtrip() {
                 None,
                 None,
                 None,
+                None,
             )
             .await
             .expect("Failed to add");
     }
 
     let recordings = client.list_recordings().await.expect("Failed to list");
-    assert_eq!(recordings.len(), 6);
+    assert_eq!(recordings.len(), 7);
 
     // Check that stop reasons roundtrip correctly
     for recording in &recordings {
@@ -234,6 +359,62 @@ async fn test_stop_reason_roundtrip() {
     }
 }
 
+#[tokio::test]
+async fn test_update_recording_stop_reason() {
+    let (client, _temp) = setup_client().await;
+    client
+        .add_recording(
+            "rec-1".to_string(),
+            "/path/1.wav".to_string(),
+            1.0,
+            16000,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add");
+
+    client
+        .update_recording_stop_reason("/path/1.wav", Some(StopReason::Recovered))
+        .await
+        .expect("Failed to set stop reason");
+    let recording = client
+        .get_recording_by_path("/path/1.wav")
+        .await
+        .expect("Failed to get")
+        .expect("Recording missing");
+    assert_eq!(recording.stop_reason, Some(StopReason::Recovered));
+
+    client
+        .update_recording_stop_reason("/path/1.wav", None)
+        .await
+        .expect("Failed to clear stop reason");
+    let recording = client
+        .get_recording_by_path("/path/1.wav")
+        .await
+        .expect("Failed to get")
+        .expect("Recording missing");
+    assert_eq!(recording.stop_reason, None);
+
+    let missing = client
+        .update_recording_stop_reason("/nonexistent.wav", Some(StopReason::Recovered))
+        .await;
+    assert!(matches!(missing, Err(RecordingStoreError::NotFound(_))));
+}
+
+#[test]
+fn test_stop_reason_names_are_stable() {
+    for reason in [StopReason::ResampleOverflow, StopReason::Recovered] {
+        let json = serde_json::to_value(&reason).unwrap();
+        assert_eq!(json, serde_json::json!(reason.as_str()));
+        assert_eq!(reason.as_str().parse::<StopReason>(), Ok(reason));
+    }
+    assert!("Bogus".parse::<StopReason>().is_err());
+}
+
 // ============================================================
 // Transcription Tests
 // ============================================================
@@ -253,6 +434,7 @@ async fn test_add_transcription() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add recording");
@@ -277,6 +459,50 @@ async fn test_add_transcription() {
     assert_eq!(transcription.duration_ms, 250);
 }
 
+#[tokio::test]
+async fn test_get_transcription_by_id() {
+    let (client, _temp) = setup_client().await;
+
+    client
+        .add_recording(
+            "rec-1".to_string(),
+            "/path/recording.wav".to_string(),
+            5.0,
+            80000,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add recording");
+
+    client
+        .add_transcription(
+            "trans-1".to_string(),
+            "rec-1".to_string(),
+            "Hello world".to_string(),
+            None,
+            "parakeet-tdt".to_string(),
+            250,
+        )
+        .await
+        .expect("Failed to add transcription");
+
+    let found = client
+        .get_transcription_by_id("trans-1")
+        .await
+        .expect("Failed to get transcription");
+    assert_eq!(found.map(|t| t.text), Some("Hello world".to_string()));
+
+    let missing = client
+        .get_transcription_by_id("nonexistent")
+        .await
+        .expect("Failed to get transcription");
+    assert!(missing.is_none());
+}
+
 #[tokio::test]
 async fn test_list_transcriptions() {
     let (client, _temp) = setup_client().await;
@@ -292,6 +518,7 @@ async fn test_list_transcriptions() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add recording");
@@ -345,6 +572,7 @@ async fn test_get_transcriptions_by_recording() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add recording 1");
@@ -359,6 +587,7 @@ async fn test_get_transcriptions_by_recording() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add recording 2");
@@ -432,6 +661,7 @@ async fn test_cascade_delete() {
             None,
             None,
             None,
+            None,
         )
         .await
         .expect("Failed to add recording");
@@ -481,3 +711,76 @@ async fn test_cascade_delete() {
         .expect("Failed to get");
     assert!(trans_after.is_empty());
 }
+
+#[tokio::test]
+async fn test_update_transcription_text_keeps_original() {
+    let (client, _temp) = setup_client().await;
+
+    client
+        .add_recording(
+            "rec-edit".to_string(),
+            "/path/edit.wav".to_string(),
+            1.0,
+            16000,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add recording");
+    client
+        .add_transcription(
+            "trans-edit".to_string(),
+            "rec-edit".to_string(),
+            "ping cooper".to_string(),
+            None,
+            "parakeet-tdt".to_string(),
+            100,
+        )
+        .await
+        .expect("Failed to add transcription");
+
+    client
+        .update_transcription_text("trans-edit", "ping Kuper")
+        .await
+        .expect("Failed to update transcription");
+
+    let updated = client
+        .get_transcription_by_id("trans-edit")
+        .await
+        .expect("Failed to get")
+        .expect("Transcription should exist");
+    assert_eq!(updated.text, "ping cooper");
+    assert_eq!(updated.edited_text, Some("ping Kuper".to_string()));
+
+    let corrections = client
+        .list_transcription_corrections()
+        .await
+        .expect("Failed to list corrections");
+    assert_eq!(
+        corrections,
+        vec![("ping cooper".to_string(), "ping Kuper".to_string())]
+    );
+
+    // Empty text clears the correction
+    client
+        .update_transcription_text("trans-edit", "")
+        .await
+        .expect("Failed to clear correction");
+    let cleared = client
+        .get_transcription_by_id("trans-edit")
+        .await
+        .expect("Failed to get")
+        .expect("Transcription should exist");
+    assert_eq!(cleared.edited_text, None);
+}
+
+#[tokio::test]
+async fn test_update_transcription_text_not_found() {
+    let (client, _temp) = setup_client().await;
+
+    let result = client.update_transcription_text("missing", "text").await;
+    assert!(matches!(result, Err(TranscriptionStoreError::NotFound(_))));
+}
@@ -6,7 +6,7 @@
 use super::client::{TursoClient, TursoError};
 
 /// Current schema version
-const SCHEMA_VERSION: i32 = 2;
+const SCHEMA_VERSION: i32 = 10;
 
 /// SQL statements to create all tables (each as a separate string)
 const CREATE_TABLES: &[&str] = &[
@@ -34,7 +34,9 @@ const CREATE_TABLES: &[&str] = &[
         dictionary_entry_ids_json TEXT NOT NULL,
         enabled INTEGER NOT NULL DEFAULT 1,
         priority INTEGER NOT NULL DEFAULT 0,
-        created_at TEXT NOT NULL
+        created_at TEXT NOT NULL,
+        text_transforms_json TEXT,
+        dictation_disabled INTEGER NOT NULL DEFAULT 0
     )"#,
     // Recording metadata
     r#"CREATE TABLE IF NOT EXISTS recording (
@@ -46,7 +48,8 @@ const CREATE_TABLES: &[&str] = &[
         created_at TEXT NOT NULL,
         active_window_app_name TEXT,
         active_window_bundle_id TEXT,
-        active_window_title TEXT
+        active_window_title TEXT,
+        trigger_source TEXT
     )"#,
     // Transcription results linked to recordings
     r#"CREATE TABLE IF NOT EXISTS transcription (
@@ -57,6 +60,7 @@ const CREATE_TABLES: &[&str] = &[
         model_version TEXT NOT NULL,
         duration_ms INTEGER NOT NULL,
         created_at TEXT NOT NULL,
+        edited_text TEXT,
         FOREIGN KEY (recording_id) REFERENCES recording(id) ON DELETE CASCADE
     )"#,
     // Index for efficient transcription lookups by recording
@@ -68,6 +72,16 @@ const CREATE_TABLES: &[&str] = &[
         action_type TEXT NOT NULL,
         parameters_json TEXT NOT NULL,
         enabled INTEGER NOT NULL DEFAULT 1,
+        priority INTEGER NOT NULL DEFAULT 0,
+        aliases_json TEXT NOT NULL DEFAULT '[]',
+        requires_confirmation INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL
+    )"#,
+    // Voice command match outcomes, for match statistics
+    r#"CREATE TABLE IF NOT EXISTS command_match (
+        id TEXT PRIMARY KEY,
+        outcome TEXT NOT NULL,
+        confidence REAL,
         created_at TEXT NOT NULL
     )"#,
 ];
@@ -163,7 +177,14 @@ async fn run_migrations(
     for version in (from_version + 1)..=to_version {
         match version {
             2 => migrate_v1_to_v2(client).await?,
-            // 3 => migrate_v2_to_v3(client).await?,
+            3 => migrate_v2_to_v3(client).await?,
+            4 => migrate_v3_to_v4(client).await?,
+            5 => migrate_v4_to_v5(client).await?,
+            6 => migrate_v5_to_v6(client).await?,
+            7 => migrate_v6_to_v7(client).await?,
+            8 => migrate_v7_to_v8(client).await?,
+            9 => migrate_v8_to_v9(client).await?,
+            10 => migrate_v9_to_v10(client).await?,
             _ => {
                 // No migration needed for this version
                 crate::debug!("No migration needed for version {}", version);
@@ -187,6 +208,106 @@ async fn migrate_v1_to_v2(client: &TursoClient) -> Result<(), TursoError> {
     Ok(())
 }
 
+/// Migrate from schema version 2 to 3.
+/// Adds priority column to voice_command table.
+async fn migrate_v2_to_v3(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v2 -> v3: adding priority column to voice_command");
+    client
+        .execute(
+            "ALTER TABLE voice_command ADD COLUMN priority INTEGER NOT NULL DEFAULT 0",
+            (),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 3 to 4.
+/// Adds edited_text column to transcription table.
+async fn migrate_v3_to_v4(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v3 -> v4: adding edited_text column to transcription");
+    client
+        .execute("ALTER TABLE transcription ADD COLUMN edited_text TEXT", ())
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 4 to 5.
+/// Adds command_match table for match statistics.
+async fn migrate_v4_to_v5(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v4 -> v5: adding command_match table");
+    client
+        .execute(
+            r#"CREATE TABLE IF NOT EXISTS command_match (
+                id TEXT PRIMARY KEY,
+                outcome TEXT NOT NULL,
+                confidence REAL,
+                created_at TEXT NOT NULL
+            )"#,
+            (),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 5 to 6.
+/// Adds text_transforms_json column to window_context table.
+async fn migrate_v5_to_v6(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v5 -> v6: adding text_transforms_json column to window_context");
+    client
+        .execute("ALTER TABLE window_context ADD COLUMN text_transforms_json TEXT", ())
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 6 to 7.
+/// Adds dictation_disabled column to window_context table.
+async fn migrate_v6_to_v7(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v6 -> v7: adding dictation_disabled column to window_context");
+    client
+        .execute(
+            "ALTER TABLE window_context ADD COLUMN dictation_disabled INTEGER NOT NULL DEFAULT 0",
+            (),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 7 to 8.
+/// Adds aliases_json column to voice_command table.
+async fn migrate_v7_to_v8(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v7 -> v8: adding aliases_json column to voice_command");
+    client
+        .execute(
+            "ALTER TABLE voice_command ADD COLUMN aliases_json TEXT NOT NULL DEFAULT '[]'",
+            (),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 8 to 9.
+/// Adds requires_confirmation column to voice_command table.
+async fn migrate_v8_to_v9(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v8 -> v9: adding requires_confirmation column to voice_command");
+    client
+        .execute(
+            "ALTER TABLE voice_command ADD COLUMN requires_confirmation INTEGER NOT NULL DEFAULT 0",
+            (),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 9 to 10.
+/// Adds trigger_source column to recording table.
+async fn migrate_v9_to_v10(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v9 -> v10: adding trigger_source column to recording");
+    client
+        .execute("ALTER TABLE recording ADD COLUMN trigger_source TEXT", ())
+        .await?;
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "schema_test.rs"]
 mod tests;
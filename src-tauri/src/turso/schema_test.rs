@@ -14,7 +14,7 @@ async fn test_schema_creates_all_tables() {
     initialize_schema(&client).await.expect("Failed to initialize schema");
 
     // Verify all tables exist
-    let tables = ["dictionary_entry", "window_context", "recording", "transcription", "voice_command", "schema_version"];
+    let tables = ["dictionary_entry", "window_context", "recording", "transcription", "voice_command", "command_match", "schema_version"];
 
     for table in tables {
         let mut rows = client
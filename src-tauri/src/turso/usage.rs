@@ -0,0 +1,100 @@
+// Usage summary using Turso/libsql
+//
+// Read-only aggregations over the recording and transcription tables for the
+// dashboard: how much has been dictated and roughly how much typing it saved.
+
+use serde::Serialize;
+
+use super::client::{TursoClient, TursoError};
+
+/// Typing speed assumed when estimating time saved (average typist)
+pub const TYPING_WORDS_PER_MINUTE: f64 = 40.0;
+
+/// Aggregate usage across all recordings
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageSummary {
+    /// Number of stored recordings
+    pub total_recordings: u64,
+    /// Number of stored transcriptions (including re-transcriptions)
+    pub total_transcriptions: u64,
+    /// Words in the latest transcription of each recording (user edits preferred)
+    pub total_words: u64,
+    /// Total duration of all recordings in seconds
+    pub total_audio_secs: f64,
+    /// Estimated seconds saved versus typing the words at `TYPING_WORDS_PER_MINUTE`
+    pub typing_time_saved_secs: f64,
+}
+
+/// Estimate the typing time saved by dictating `words` in `spoken_secs`
+///
+/// Never negative: dictation slower than typing counts as no time saved.
+pub fn typing_time_saved_secs(words: u64, spoken_secs: f64) -> f64 {
+    let typing_secs = words as f64 / TYPING_WORDS_PER_MINUTE * 60.0;
+    (typing_secs - spoken_secs).max(0.0)
+}
+
+impl TursoClient {
+    /// Summarize usage across all recordings and transcriptions.
+    ///
+    /// Words are counted from each recording's latest transcription only, so
+    /// re-transcribing a recording doesn't count its words twice.
+    pub async fn get_usage_summary(&self) -> Result<UsageSummary, TursoError> {
+        let query_error = |e: libsql::Error| TursoError::Query(e.to_string());
+
+        let mut rows = self
+            .query(
+                r#"SELECT
+                     (SELECT COUNT(*) FROM recording),
+                     (SELECT COALESCE(SUM(duration_secs), 0.0) FROM recording),
+                     (SELECT COUNT(*) FROM transcription)"#,
+                (),
+            )
+            .await?;
+        let row = rows
+            .next()
+            .await
+            .map_err(query_error)?
+            .ok_or_else(|| TursoError::Query("Usage totals returned no row".to_string()))?;
+        let total_recordings: i64 = row.get(0).map_err(query_error)?;
+        let total_audio_secs: f64 = row.get(1).map_err(query_error)?;
+        let total_transcriptions: i64 = row.get(2).map_err(query_error)?;
+
+        // Latest transcription per recording, with the recording's duration
+        let mut rows = self
+            .query(
+                r#"SELECT COALESCE(t.edited_text, t.text), r.duration_secs
+                   FROM transcription t
+                   JOIN recording r ON r.id = t.recording_id
+                   WHERE t.id = (
+                       SELECT t2.id FROM transcription t2
+                       WHERE t2.recording_id = t.recording_id
+                       ORDER BY t2.created_at DESC, t2.id DESC
+                       LIMIT 1
+                   )"#,
+                (),
+            )
+            .await?;
+
+        let mut total_words = 0u64;
+        let mut transcribed_secs = 0.0;
+        while let Some(row) = rows.next().await.map_err(query_error)? {
+            let text: String = row.get(0).map_err(query_error)?;
+            let duration_secs: f64 = row.get(1).map_err(query_error)?;
+            total_words += text.split_whitespace().count() as u64;
+            transcribed_secs += duration_secs;
+        }
+
+        Ok(UsageSummary {
+            total_recordings: total_recordings as u64,
+            total_transcriptions: total_transcriptions as u64,
+            total_words,
+            total_audio_secs,
+            typing_time_saved_secs: typing_time_saved_secs(total_words, transcribed_secs),
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "usage_test.rs"]
+mod tests;
@@ -0,0 +1,93 @@
+use super::typing_time_saved_secs;
+use crate::turso::{initialize_schema, TursoClient};
+use tempfile::TempDir;
+
+async fn setup_client() -> (TursoClient, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+async fn add_recording(client: &TursoClient, id: &str, duration_secs: f64) {
+    client
+        .add_recording(
+            id.to_string(),
+            format!("/tmp/{}.wav", id),
+            duration_secs,
+            (duration_secs * 16000.0) as u64,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to add recording");
+}
+
+async fn add_transcription(client: &TursoClient, id: &str, recording_id: &str, text: &str) {
+    client
+        .add_transcription(
+            id.to_string(),
+            recording_id.to_string(),
+            text.to_string(),
+            None,
+            "test-model".to_string(),
+            100,
+        )
+        .await
+        .expect("Failed to add transcription");
+}
+
+#[test]
+fn test_typing_time_saved() {
+    // 40 words take a minute to type
+    assert_eq!(typing_time_saved_secs(40, 10.0), 50.0);
+    assert_eq!(typing_time_saved_secs(0, 0.0), 0.0);
+    // Slow dictation never counts as negative time saved
+    assert_eq!(typing_time_saved_secs(1, 30.0), 0.0);
+}
+
+#[tokio::test]
+async fn test_usage_summary_empty() {
+    let (client, _temp) = setup_client().await;
+
+    let summary = client.get_usage_summary().await.expect("Failed to get summary");
+
+    assert_eq!(summary.total_recordings, 0);
+    assert_eq!(summary.total_transcriptions, 0);
+    assert_eq!(summary.total_words, 0);
+    assert_eq!(summary.total_audio_secs, 0.0);
+    assert_eq!(summary.typing_time_saved_secs, 0.0);
+}
+
+#[tokio::test]
+async fn test_usage_summary_counts_latest_transcription_words() {
+    let (client, _temp) = setup_client().await;
+
+    add_recording(&client, "rec-1", 4.0).await;
+    add_recording(&client, "rec-2", 6.0).await;
+    add_recording(&client, "rec-untranscribed", 2.0).await;
+    add_transcription(&client, "t-1", "rec-1", "first attempt").await;
+    add_transcription(&client, "t-2", "rec-1", "hello there general kenobi").await;
+    add_transcription(&client, "t-3", "rec-2", "one two three").await;
+    client
+        .update_transcription_text("t-3", "one two three four five six")
+        .await
+        .expect("Failed to edit transcription");
+
+    let summary = client.get_usage_summary().await.expect("Failed to get summary");
+
+    assert_eq!(summary.total_recordings, 3);
+    assert_eq!(summary.total_transcriptions, 3);
+    // Latest of rec-1 (4 words) plus the edited text of rec-2 (6 words)
+    assert_eq!(summary.total_words, 10);
+    assert_eq!(summary.total_audio_secs, 12.0);
+    // 10 words typed take 15s; they were spoken in 10s
+    assert_eq!(summary.typing_time_saved_secs, 5.0);
+}
@@ -28,20 +28,25 @@ impl TursoClient {
 
         let created_at = chrono::Utc::now().to_rfc3339();
 
-        // Serialize parameters to JSON
+        // Serialize parameters and aliases to JSON
         let parameters_json = serde_json::to_string(&cmd.parameters)
             .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+        let aliases_json = aliases_to_json(&cmd.aliases)?;
 
         self.execute(
             r#"INSERT INTO voice_command
-               (id, trigger, action_type, parameters_json, enabled, created_at)
-               VALUES (?1, ?2, ?3, ?4, ?5, ?6)"#,
+               (id, trigger, action_type, parameters_json, enabled, priority, aliases_json,
+                requires_confirmation, created_at)
+               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)"#,
             params![
                 cmd.id.to_string(),
                 cmd.trigger.clone(),
                 action_type_to_string(&cmd.action_type),
                 parameters_json,
                 cmd.enabled as i32,
+                cmd.priority,
+                aliases_json,
+                cmd.requires_confirmation as i32,
                 created_at
             ],
         )
@@ -91,19 +96,24 @@ impl TursoClient {
             )));
         }
 
-        // Serialize parameters to JSON
+        // Serialize parameters and aliases to JSON
         let parameters_json = serde_json::to_string(&cmd.parameters)
             .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+        let aliases_json = aliases_to_json(&cmd.aliases)?;
 
         self.execute(
             r#"UPDATE voice_command
-               SET trigger = ?1, action_type = ?2, parameters_json = ?3, enabled = ?4
-               WHERE id = ?5"#,
+               SET trigger = ?1, action_type = ?2, parameters_json = ?3, enabled = ?4,
+                   priority = ?5, aliases_json = ?6, requires_confirmation = ?7
+               WHERE id = ?8"#,
             params![
                 cmd.trigger.clone(),
                 action_type_to_string(&cmd.action_type),
                 parameters_json,
                 cmd.enabled as i32,
+                cmd.priority,
+                aliases_json,
+                cmd.requires_confirmation as i32,
                 cmd.id.to_string()
             ],
         )
@@ -141,7 +151,7 @@ impl TursoClient {
     pub async fn list_voice_commands(&self) -> Result<Vec<CommandDefinition>, RegistryError> {
         let mut rows = self
             .query(
-                "SELECT id, trigger, action_type, parameters_json, enabled FROM voice_command ORDER BY created_at",
+                "SELECT id, trigger, action_type, parameters_json, enabled, priority, aliases_json, requires_confirmation FROM voice_command ORDER BY created_at",
                 (),
             )
             .await
@@ -158,6 +168,9 @@ impl TursoClient {
             let action_type_str: String = row.get(2).map_err(|e| RegistryError::LoadError(e.to_string()))?;
             let parameters_json: String = row.get(3).map_err(|e| RegistryError::LoadError(e.to_string()))?;
             let enabled: i32 = row.get(4).map_err(|e| RegistryError::LoadError(e.to_string()))?;
+            let priority: i32 = row.get(5).map_err(|e| RegistryError::LoadError(e.to_string()))?;
+            let aliases_json: String = row.get(6).map_err(|e| RegistryError::LoadError(e.to_string()))?;
+            let requires_confirmation: i32 = row.get(7).map_err(|e| RegistryError::LoadError(e.to_string()))?;
 
             let id = Uuid::parse_str(&id_str)
                 .map_err(|e| RegistryError::LoadError(format!("Invalid UUID: {}", e)))?;
@@ -165,12 +178,18 @@ impl TursoClient {
             let parameters: HashMap<String, String> = serde_json::from_str(&parameters_json)
                 .map_err(|e| RegistryError::LoadError(format!("Invalid parameters JSON: {}", e)))?;
 
+            let aliases: Vec<String> = serde_json::from_str(&aliases_json)
+                .map_err(|e| RegistryError::LoadError(format!("Invalid aliases JSON: {}", e)))?;
+
             commands.push(CommandDefinition {
                 id,
                 trigger,
                 action_type: string_to_action_type(&action_type_str),
                 parameters,
                 enabled: enabled != 0,
+                priority,
+                aliases,
+                requires_confirmation: requires_confirmation != 0,
             });
         }
 
@@ -178,7 +197,7 @@ impl TursoClient {
     }
 
     /// Check if a voice command exists by ID.
-    async fn voice_command_exists(&self, id: Uuid) -> Result<bool, RegistryError> {
+    pub(super) async fn voice_command_exists(&self, id: Uuid) -> Result<bool, RegistryError> {
         let mut rows = self
             .query(
                 "SELECT 1 FROM voice_command WHERE id = ?1",
@@ -195,6 +214,16 @@ impl TursoClient {
     }
 }
 
+/// Serialize aliases for storage, dropping blank entries
+fn aliases_to_json(aliases: &[String]) -> Result<String, RegistryError> {
+    let aliases: Vec<&str> = aliases
+        .iter()
+        .map(|alias| alias.trim())
+        .filter(|alias| !alias.is_empty())
+        .collect();
+    serde_json::to_string(&aliases).map_err(|e| RegistryError::PersistenceError(e.to_string()))
+}
+
 /// Convert ActionType to string for database storage
 fn action_type_to_string(action_type: &ActionType) -> String {
     match action_type {
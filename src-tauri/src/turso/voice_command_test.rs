@@ -22,6 +22,9 @@ fn make_command(trigger: &str, action_type: ActionType) -> CommandDefinition {
         action_type,
         parameters: HashMap::new(),
         enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
     }
 }
 
@@ -57,6 +60,9 @@ async fn test_add_voice_command_with_parameters() {
         action_type: ActionType::OpenApp,
         parameters: params,
         enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
     };
 
     client
@@ -69,6 +75,40 @@ async fn test_add_voice_command_with_parameters() {
     assert_eq!(commands[0].parameters.get("path"), Some(&"/Applications/Slack.app".to_string()));
 }
 
+#[tokio::test]
+async fn test_aliases_roundtrip_and_update() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("open mail", ActionType::OpenApp);
+    cmd.aliases = vec!["launch mail".to_string(), "  ".to_string()];
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert_eq!(commands[0].aliases, vec!["launch mail".to_string()]);
+
+    cmd.aliases = vec!["start mail".to_string()];
+    client.update_voice_command(&cmd).await.expect("Failed to update");
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert_eq!(commands[0].aliases, vec!["start mail".to_string()]);
+}
+
+#[tokio::test]
+async fn test_requires_confirmation_roundtrip_and_update() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("quit all apps", ActionType::Custom);
+    cmd.requires_confirmation = true;
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert!(commands[0].requires_confirmation);
+
+    cmd.requires_confirmation = false;
+    client.update_voice_command(&cmd).await.expect("Failed to update");
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert!(!commands[0].requires_confirmation);
+}
+
 #[tokio::test]
 async fn test_add_voice_command_empty_trigger_fails() {
     let (client, _temp) = setup_client().await;
@@ -231,6 +271,9 @@ async fn test_action_type_roundtrip() {
             action_type: action_type.clone(),
             parameters: HashMap::new(),
             enabled: true,
+            priority: 0,
+            aliases: Vec::new(),
+            requires_confirmation: false,
         };
         client.add_voice_command(&cmd).await.expect("Failed to add");
     }
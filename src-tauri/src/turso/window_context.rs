@@ -6,6 +6,7 @@ use libsql::params;
 use uuid::Uuid;
 
 use super::client::TursoClient;
+use crate::transcription::transforms::TextTransforms;
 use crate::window_context::{OverrideMode, WindowContext, WindowContextStoreError, WindowMatcher};
 
 impl TursoClient {
@@ -23,6 +24,8 @@ impl TursoClient {
     /// * `dictionary_entry_ids` - List of dictionary entry IDs to use
     /// * `enabled` - Whether the context is active
     /// * `priority` - Priority for matching (higher = matched first)
+    /// * `text_transforms` - Text transforms overriding the global ones (None = use global)
+    /// * `dictation_disabled` - Store transcriptions without command matching or output
     ///
     /// # Returns
     /// The created WindowContext with generated ID
@@ -36,51 +39,70 @@ impl TursoClient {
         dictionary_entry_ids: Vec<String>,
         enabled: bool,
         priority: i32,
+        text_transforms: Option<TextTransforms>,
+        dictation_disabled: bool,
     ) -> Result<WindowContext, WindowContextStoreError> {
-        let id = Uuid::new_v4();
+        let context = WindowContext {
+            id: Uuid::new_v4(),
+            name,
+            matcher,
+            command_mode,
+            dictionary_mode,
+            command_ids,
+            dictionary_entry_ids,
+            enabled,
+            priority,
+            text_transforms,
+            dictation_disabled,
+        };
+        self.insert_window_context(&context).await?;
+        Ok(context)
+    }
+
+    /// Insert a window context, keeping its ID.
+    ///
+    /// Used by `add_window_context` and by config import, where IDs must be
+    /// preserved across export and import.
+    pub async fn insert_window_context(
+        &self,
+        context: &WindowContext,
+    ) -> Result<(), WindowContextStoreError> {
         let created_at = chrono::Utc::now().to_rfc3339();
 
         // Serialize UUIDs as strings in JSON
-        let command_ids_json = serde_json::to_string(&command_ids)
+        let command_ids_json = serde_json::to_string(&context.command_ids)
             .map_err(|e| WindowContextStoreError::PersistenceError(e.to_string()))?;
-        let dictionary_entry_ids_json = serde_json::to_string(&dictionary_entry_ids)
+        let dictionary_entry_ids_json = serde_json::to_string(&context.dictionary_entry_ids)
             .map_err(|e| WindowContextStoreError::PersistenceError(e.to_string()))?;
+        let text_transforms_json = serialize_text_transforms(context.text_transforms.as_ref())?;
 
         self.execute(
             r#"INSERT INTO window_context
                (id, name, matcher_app_name, matcher_title_pattern, matcher_bundle_id,
                 command_mode, dictionary_mode, command_ids_json, dictionary_entry_ids_json,
-                enabled, priority, created_at)
-               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)"#,
+                enabled, priority, created_at, text_transforms_json, dictation_disabled)
+               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)"#,
             params![
-                id.to_string(),
-                name.clone(),
-                matcher.app_name.clone(),
-                matcher.title_pattern.clone(),
-                matcher.bundle_id.clone(),
-                override_mode_to_string(command_mode),
-                override_mode_to_string(dictionary_mode),
+                context.id.to_string(),
+                context.name.clone(),
+                context.matcher.app_name.clone(),
+                context.matcher.title_pattern.clone(),
+                context.matcher.bundle_id.clone(),
+                override_mode_to_string(context.command_mode),
+                override_mode_to_string(context.dictionary_mode),
                 command_ids_json,
                 dictionary_entry_ids_json,
-                enabled as i32,
-                priority,
-                created_at
+                context.enabled as i32,
+                context.priority,
+                created_at,
+                text_transforms_json,
+                context.dictation_disabled as i32
             ],
         )
         .await
         .map_err(|e| WindowContextStoreError::PersistenceError(e.to_string()))?;
 
-        Ok(WindowContext {
-            id,
-            name,
-            matcher,
-            command_mode,
-            dictionary_mode,
-            command_ids,
-            dictionary_entry_ids,
-            enabled,
-            priority,
-        })
+        Ok(())
     }
 
     /// Update an existing window context.
@@ -105,13 +127,15 @@ impl TursoClient {
             .map_err(|e| WindowContextStoreError::PersistenceError(e.to_string()))?;
         let dictionary_entry_ids_json = serde_json::to_string(&context.dictionary_entry_ids)
             .map_err(|e| WindowContextStoreError::PersistenceError(e.to_string()))?;
+        let text_transforms_json = serialize_text_transforms(context.text_transforms.as_ref())?;
 
         self.execute(
             r#"UPDATE window_context
                SET name = ?1, matcher_app_name = ?2, matcher_title_pattern = ?3, matcher_bundle_id = ?4,
                    command_mode = ?5, dictionary_mode = ?6, command_ids_json = ?7, dictionary_entry_ids_json = ?8,
-                   enabled = ?9, priority = ?10
-               WHERE id = ?11"#,
+                   enabled = ?9, priority = ?10, text_transforms_json = ?11,
+                   dictation_disabled = ?12
+               WHERE id = ?13"#,
             params![
                 context.name.clone(),
                 context.matcher.app_name.clone(),
@@ -123,6 +147,8 @@ impl TursoClient {
                 dictionary_entry_ids_json,
                 context.enabled as i32,
                 context.priority,
+                text_transforms_json,
+                context.dictation_disabled as i32,
                 context.id.to_string()
             ],
         )
@@ -162,7 +188,7 @@ impl TursoClient {
             .query(
                 r#"SELECT id, name, matcher_app_name, matcher_title_pattern, matcher_bundle_id,
                           command_mode, dictionary_mode, command_ids_json, dictionary_entry_ids_json,
-                          enabled, priority
+                          enabled, priority, text_transforms_json, dictation_disabled
                    FROM window_context
                    ORDER BY priority DESC"#,
                 (),
@@ -195,7 +221,7 @@ impl TursoClient {
             .query(
                 r#"SELECT id, name, matcher_app_name, matcher_title_pattern, matcher_bundle_id,
                           command_mode, dictionary_mode, command_ids_json, dictionary_entry_ids_json,
-                          enabled, priority
+                          enabled, priority, text_transforms_json, dictation_disabled
                    FROM window_context
                    WHERE id = ?1"#,
                 params![id.to_string()],
@@ -217,7 +243,7 @@ impl TursoClient {
     }
 
     /// Check if a window context exists by ID.
-    async fn window_context_exists(&self, id: Uuid) -> Result<bool, WindowContextStoreError> {
+    pub(super) async fn window_context_exists(&self, id: Uuid) -> Result<bool, WindowContextStoreError> {
         let mut rows = self
             .query(
                 "SELECT 1 FROM window_context WHERE id = ?1",
@@ -247,6 +273,8 @@ fn parse_window_context_row(row: &libsql::Row) -> Result<WindowContext, WindowCo
     let dictionary_entry_ids_json: String = row.get(8).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
     let enabled: i32 = row.get(9).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
     let priority: i32 = row.get(10).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
+    let text_transforms_json: Option<String> = row.get(11).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
+    let dictation_disabled: i32 = row.get(12).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
 
     let id = Uuid::parse_str(&id_str)
         .map_err(|e| WindowContextStoreError::LoadError(format!("Invalid UUID: {}", e)))?;
@@ -257,6 +285,11 @@ fn parse_window_context_row(row: &libsql::Row) -> Result<WindowContext, WindowCo
     let dictionary_entry_ids: Vec<String> = serde_json::from_str(&dictionary_entry_ids_json)
         .map_err(|e| WindowContextStoreError::LoadError(format!("Invalid dictionary_entry_ids JSON: {}", e)))?;
 
+    let text_transforms: Option<TextTransforms> = text_transforms_json
+        .map(|json| serde_json::from_str(&json))
+        .transpose()
+        .map_err(|e| WindowContextStoreError::LoadError(format!("Invalid text_transforms JSON: {}", e)))?;
+
     Ok(WindowContext {
         id,
         name,
@@ -271,9 +304,21 @@ fn parse_window_context_row(row: &libsql::Row) -> Result<WindowContext, WindowCo
         dictionary_entry_ids,
         enabled: enabled != 0,
         priority,
+        text_transforms,
+        dictation_disabled: dictation_disabled != 0,
     })
 }
 
+/// Serialize optional text transforms for database storage (NULL = use global)
+fn serialize_text_transforms(
+    text_transforms: Option<&TextTransforms>,
+) -> Result<Option<String>, WindowContextStoreError> {
+    text_transforms
+        .map(|transforms| serde_json::to_string(&transforms))
+        .transpose()
+        .map_err(|e| WindowContextStoreError::PersistenceError(e.to_string()))
+}
+
 /// Convert OverrideMode to string for database storage
 fn override_mode_to_string(mode: OverrideMode) -> String {
     match mode {
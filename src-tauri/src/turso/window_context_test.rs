@@ -36,6 +36,8 @@ async fn test_add_window_context() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add context");
@@ -67,6 +69,8 @@ async fn test_add_window_context_with_full_matcher() {
             vec!["dict1".to_string(), "dict2".to_string()],
             false,
             10,
+            None,
+            false,
         )
         .await
         .expect("Failed to add context");
@@ -105,6 +109,8 @@ async fn test_list_window_contexts_ordered_by_priority() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add low priority");
@@ -119,6 +125,8 @@ async fn test_list_window_contexts_ordered_by_priority() {
             vec![],
             true,
             100,
+            None,
+            false,
         )
         .await
         .expect("Failed to add high priority");
@@ -133,6 +141,8 @@ async fn test_list_window_contexts_ordered_by_priority() {
             vec![],
             true,
             50,
+            None,
+            false,
         )
         .await
         .expect("Failed to add medium priority");
@@ -159,6 +169,8 @@ async fn test_get_window_context() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add");
@@ -199,6 +211,8 @@ async fn test_update_window_context() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add");
@@ -217,6 +231,8 @@ async fn test_update_window_context() {
         dictionary_entry_ids: vec!["entry1".to_string()],
         enabled: false,
         priority: 99,
+        text_transforms: None,
+        dictation_disabled: false,
     };
 
     client
@@ -252,6 +268,8 @@ async fn test_update_window_context_not_found() {
         dictionary_entry_ids: vec![],
         enabled: true,
         priority: 0,
+        text_transforms: None,
+        dictation_disabled: false,
     };
 
     let result = client.update_window_context(context.clone()).await;
@@ -276,6 +294,8 @@ async fn test_delete_window_context() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add");
@@ -323,6 +343,8 @@ async fn test_json_serialization_roundtrip() {
             dictionary_entry_ids.clone(),
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add");
@@ -352,6 +374,8 @@ async fn test_override_mode_roundtrip() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add merge context");
@@ -367,6 +391,8 @@ async fn test_override_mode_roundtrip() {
             vec![],
             true,
             0,
+            None,
+            false,
         )
         .await
         .expect("Failed to add replace context");
@@ -381,3 +407,91 @@ async fn test_override_mode_roundtrip() {
     assert!(matches!(replace_ctx.command_mode, OverrideMode::Replace));
     assert!(matches!(replace_ctx.dictionary_mode, OverrideMode::Replace));
 }
+
+#[tokio::test]
+async fn test_text_transforms_round_trip() {
+    let (client, _temp) = setup_client().await;
+
+    let transforms = crate::transcription::transforms::TextTransforms {
+        strip_trailing_period: true,
+        lowercase_first: true,
+        ..Default::default()
+    };
+    let ctx = client
+        .add_window_context(
+            "Terminal".to_string(),
+            make_matcher("Terminal"),
+            OverrideMode::Merge,
+            OverrideMode::Merge,
+            vec![],
+            vec![],
+            true,
+            0,
+            Some(transforms.clone()),
+            false,
+        )
+        .await
+        .expect("Failed to add context");
+
+    let fetched = client
+        .get_window_context(ctx.id)
+        .await
+        .expect("Failed to get")
+        .expect("Context should exist");
+    assert_eq!(fetched.text_transforms, Some(transforms));
+
+    client
+        .update_window_context(WindowContext {
+            text_transforms: None,
+            ..fetched
+        })
+        .await
+        .expect("Failed to update");
+
+    let cleared = client
+        .get_window_context(ctx.id)
+        .await
+        .expect("Failed to get")
+        .expect("Context should exist");
+    assert_eq!(cleared.text_transforms, None);
+}
+
+#[tokio::test]
+async fn test_dictation_disabled_round_trip() {
+    let (client, _temp) = setup_client().await;
+
+    let ctx = client
+        .add_window_context(
+            "Banking".to_string(),
+            make_matcher("Bank"),
+            OverrideMode::Merge,
+            OverrideMode::Merge,
+            vec![],
+            vec![],
+            true,
+            0,
+            None,
+            true,
+        )
+        .await
+        .expect("Failed to add context");
+    assert!(ctx.dictation_disabled);
+
+    let fetched = client
+        .get_window_context(ctx.id)
+        .await
+        .expect("Failed to get")
+        .expect("Context should exist");
+    assert!(fetched.dictation_disabled);
+
+    client
+        .update_window_context(WindowContext {
+            dictation_disabled: false,
+            ..fetched
+        })
+        .await
+        .expect("Failed to update");
+
+    let listed = client.list_window_contexts().await.expect("Failed to list");
+    assert!(!listed[0].dictation_disabled);
+}
@@ -55,6 +55,12 @@ pub trait SettingsAccess {
 
 }
 
+impl SettingsAccess for AppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        Some(self)
+    }
+}
+
 #[cfg(test)]
 #[path = "settings_test.rs"]
 mod tests;
@@ -1,5 +1,6 @@
 // Text input action - types text using macOS keyboard simulation
 
+use crate::keyboard::synth::{ControlCharHandling, TypingMode, TypingOptions};
 use crate::keyboard_capture::permissions::check_accessibility_permission;
 use crate::voice_commands::executor::{Action, ActionError, ActionErrorCode, ActionResult};
 use async_trait::async_trait;
@@ -8,17 +9,59 @@ use std::collections::HashMap;
 /// Default delay between key presses in milliseconds
 pub const DEFAULT_TYPING_DELAY_MS: u64 = 10;
 
-/// Type a string of text with configurable delay between characters
+/// Read the typing options from the action parameters
+///
+/// - `delay_ms`: delay after each event (default `DEFAULT_TYPING_DELAY_MS`)
+/// - `mode`: "per_character" (default) or "burst" to send text like a paste
+/// - `control_chars`: "keys" (default) presses Return/Tab for newlines and
+///   tabs, "text" types them as characters, "strip" drops them
+/// - `clear_modifiers`: "true" to send every event with no modifiers held
+pub fn typing_options(parameters: &HashMap<String, String>) -> Result<TypingOptions, ActionError> {
+    let invalid = |name: &str, value: &str| ActionError {
+        code: ActionErrorCode::InvalidParameter,
+        message: format!("Invalid '{}' parameter: {}", name, value),
+    };
+
+    let delay_ms = parameters
+        .get("delay_ms")
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_TYPING_DELAY_MS);
+    let mode = match parameters.get("mode").map(String::as_str) {
+        None | Some("per_character") => TypingMode::PerCharacter,
+        Some("burst") => TypingMode::Burst,
+        Some(other) => return Err(invalid("mode", other)),
+    };
+    let control_chars = match parameters.get("control_chars").map(String::as_str) {
+        None | Some("keys") => ControlCharHandling::Keys,
+        Some("text") => ControlCharHandling::Text,
+        Some("strip") => ControlCharHandling::Strip,
+        Some(other) => return Err(invalid("control_chars", other)),
+    };
+    let clear_modifiers = match parameters.get("clear_modifiers").map(String::as_str) {
+        None | Some("false") => false,
+        Some("true") => true,
+        Some(other) => return Err(invalid("clear_modifiers", other)),
+    };
+
+    Ok(TypingOptions {
+        delay_ms,
+        mode,
+        control_chars,
+        clear_modifiers,
+    })
+}
+
+/// Type a string of text with the given options
 #[cfg(target_os = "macos")]
-fn type_text_with_delay(text: &str, delay_ms: u64) -> Result<(), ActionError> {
-    crate::keyboard::synth::type_unicode_text(text, delay_ms).map_err(|msg| ActionError {
+fn type_text_with_options(text: &str, options: &TypingOptions) -> Result<(), ActionError> {
+    crate::keyboard::synth::type_unicode_text(text, options).map_err(|msg| ActionError {
         code: ActionErrorCode::EventError,
         message: msg,
     })
 }
 
 #[cfg(not(target_os = "macos"))]
-fn type_text_with_delay(_text: &str, _delay_ms: u64) -> Result<(), ActionError> {
+fn type_text_with_options(_text: &str, _options: &TypingOptions) -> Result<(), ActionError> {
     Err(ActionError {
         code: ActionErrorCode::UnsupportedPlatform,
         message: "Text input is only supported on macOS".to_string(),
@@ -85,11 +128,8 @@ impl Action for TextInputAction {
             });
         }
 
-        // Get optional delay parameter (default to DEFAULT_TYPING_DELAY_MS)
-        let delay_ms = parameters
-            .get("delay_ms")
-            .and_then(|v| v.parse::<u64>().ok())
-            .unwrap_or(DEFAULT_TYPING_DELAY_MS);
+        // Get optional delay, mode, control character and modifier parameters
+        let options = typing_options(parameters)?;
 
         // Clone text for the blocking task
         let text_owned = text.clone();
@@ -98,7 +138,7 @@ impl Action for TextInputAction {
         // Run blocking keyboard simulation on a dedicated thread pool
         // This prevents blocking the tokio async runtime
         tokio::task::spawn_blocking(move || {
-            type_text_with_delay(&text_owned, delay_ms)
+            type_text_with_options(&text_owned, &options)
         })
         .await
         .map_err(|e| ActionError {
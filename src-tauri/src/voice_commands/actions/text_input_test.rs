@@ -109,6 +109,39 @@ async fn test_configurable_delay() {
     }
 }
 
+#[test]
+fn test_typing_options_defaults() {
+    let options = typing_options(&params("hi")).unwrap();
+    assert_eq!(options.delay_ms, DEFAULT_TYPING_DELAY_MS);
+    assert_eq!(options.mode, TypingMode::PerCharacter);
+    assert_eq!(options.control_chars, ControlCharHandling::Keys);
+    assert!(!options.clear_modifiers);
+}
+
+#[test]
+fn test_typing_options_from_parameters() {
+    let mut p = params_with_delay("hi", 0);
+    p.insert("mode".to_string(), "burst".to_string());
+    p.insert("control_chars".to_string(), "strip".to_string());
+    p.insert("clear_modifiers".to_string(), "true".to_string());
+
+    let options = typing_options(&p).unwrap();
+    assert_eq!(options.delay_ms, 0);
+    assert_eq!(options.mode, TypingMode::Burst);
+    assert_eq!(options.control_chars, ControlCharHandling::Strip);
+    assert!(options.clear_modifiers);
+}
+
+#[test]
+fn test_typing_options_rejects_unknown_values() {
+    let mut p = params("hi");
+    p.insert("control_chars".to_string(), "tabs".to_string());
+
+    let error = typing_options(&p).unwrap_err();
+    assert_eq!(error.code, ActionErrorCode::InvalidParameter);
+    assert!(error.message.contains("control_chars"));
+}
+
 #[test]
 fn test_check_accessibility_permission_callable() {
     // Just verify the function is callable (doesn't panic)
@@ -0,0 +1,277 @@
+// Confirmation of destructive commands
+//
+// A matched command flagged `requires_confirmation` doesn't run straight away.
+// It is held here until the user confirms or cancels it, and is cancelled if
+// neither happens within the configured timeout.
+
+use crate::emit_or_warn;
+use crate::events::{
+    command_events, current_timestamp, CommandConfirmationCancelledPayload,
+    CommandConfirmationRequiredPayload, CommandExecutedPayload, CommandFailedPayload,
+};
+use crate::last_error::last_error;
+use crate::util::get_settings_file;
+use crate::voice_commands::executor::{ActionResult, ExecutorState};
+use crate::voice_commands::registry::CommandDefinition;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+/// Settings key for how long a command waits for confirmation in milliseconds
+pub const CONFIRMATION_TIMEOUT_MS_KEY: &str = "commands.confirmationTimeoutMs";
+
+/// Default confirmation timeout in milliseconds
+pub const DEFAULT_CONFIRMATION_TIMEOUT_MS: u64 = 15_000;
+
+/// Cancellation reason when the user cancels the command
+pub const CONFIRMATION_CANCELLED: &str = "cancelled";
+
+/// Cancellation reason when nobody confirmed the command in time
+pub const CONFIRMATION_TIMED_OUT: &str = "timeout";
+
+/// Cancellation reason when a newer match took the command's place
+pub const CONFIRMATION_REPLACED: &str = "replaced";
+
+/// Resolve the confirmation timeout from its settings value
+///
+/// A timeout of 0 would cancel every command before it could be confirmed, so
+/// it falls back to the default like an unset value.
+pub fn confirmation_timeout_from_setting(value: Option<u64>) -> Duration {
+    match value {
+        Some(ms) if ms > 0 => Duration::from_millis(ms),
+        _ => Duration::from_millis(DEFAULT_CONFIRMATION_TIMEOUT_MS),
+    }
+}
+
+/// A matched command waiting for the user to confirm it
+#[derive(Debug, Clone)]
+pub struct PendingConfirmation {
+    /// Identifies this confirmation so a stale timer can't cancel a newer one
+    pub id: u64,
+    /// The command to run once confirmed
+    pub command: CommandDefinition,
+    /// The transcribed text that matched
+    pub transcription: String,
+    /// Match confidence, passed on to the action
+    pub confidence: f64,
+}
+
+/// Holds the command currently waiting for confirmation
+///
+/// Only one command waits at a time; a newer match replaces it, and the
+/// replaced command is dropped without running.
+pub struct ConfirmationTracker {
+    pending: Mutex<Option<PendingConfirmation>>,
+    next_id: AtomicU64,
+}
+
+impl ConfirmationTracker {
+    pub const fn new() -> Self {
+        Self {
+            pending: Mutex::new(None),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Hold a command for confirmation, replacing any older one
+    ///
+    /// Returns the new confirmation's id and the command it replaced, if any.
+    pub fn begin(
+        &self,
+        command: CommandDefinition,
+        transcription: String,
+        confidence: f64,
+    ) -> (u64, Option<PendingConfirmation>) {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let replaced = self.pending.lock().ok().and_then(|mut pending| {
+            pending.replace(PendingConfirmation {
+                id,
+                command,
+                transcription,
+                confidence,
+            })
+        });
+        (id, replaced)
+    }
+
+    /// Take the pending command if it is the one with `command_id`
+    pub fn confirm(&self, command_id: &str) -> Option<PendingConfirmation> {
+        let mut pending = self.pending.lock().ok()?;
+        if pending
+            .as_ref()
+            .is_some_and(|p| p.command.id.to_string() == command_id)
+        {
+            pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Drop the pending command without running it
+    pub fn cancel(&self) -> Option<PendingConfirmation> {
+        self.pending.lock().ok()?.take()
+    }
+
+    /// Take the pending command if it is still the one with `id`
+    pub fn take_if_pending(&self, id: u64) -> Option<PendingConfirmation> {
+        let mut pending = self.pending.lock().ok()?;
+        if pending.as_ref().is_some_and(|p| p.id == id) {
+            pending.take()
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for ConfirmationTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide confirmation tracker shared by all transcription paths
+static CONFIRMATIONS: ConfirmationTracker = ConfirmationTracker::new();
+
+/// Get the process-wide confirmation tracker
+pub fn confirmations() -> &'static ConfirmationTracker {
+    &CONFIRMATIONS
+}
+
+/// Hold a matched command until the user confirms it, cancelling it on timeout
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn request_confirmation(
+    app_handle: &AppHandle,
+    command: CommandDefinition,
+    transcription: String,
+    confidence: f64,
+) {
+    let timeout = confirmation_timeout_from_setting(
+        app_handle
+            .store(get_settings_file(app_handle))
+            .ok()
+            .and_then(|s| s.get(CONFIRMATION_TIMEOUT_MS_KEY))
+            .and_then(|v| v.as_u64()),
+    );
+
+    crate::info!("Command '{}' is waiting for confirmation", command.trigger);
+    emit_or_warn!(
+        app_handle,
+        command_events::COMMAND_CONFIRMATION_REQUIRED,
+        CommandConfirmationRequiredPayload {
+            transcription: transcription.clone(),
+            command_id: command.id.to_string(),
+            trigger: command.trigger.clone(),
+            timeout_ms: timeout.as_millis() as u64,
+        }
+    );
+
+    let (id, replaced) = confirmations().begin(command, transcription, confidence);
+    if let Some(replaced) = replaced {
+        crate::info!(
+            "Command '{}' was replaced by a newer match, cancelling",
+            replaced.command.trigger
+        );
+        emit_cancelled(app_handle, &replaced, CONFIRMATION_REPLACED);
+    }
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(timeout).await;
+        if let Some(pending) = confirmations().take_if_pending(id) {
+            crate::info!(
+                "Command '{}' was not confirmed in time, cancelling",
+                pending.command.trigger
+            );
+            emit_cancelled(&app_handle, &pending, CONFIRMATION_TIMED_OUT);
+        }
+    });
+}
+
+/// Emit command_confirmation_cancelled for a command that won't run
+#[cfg_attr(coverage_nightly, coverage(off))]
+fn emit_cancelled(app_handle: &AppHandle, pending: &PendingConfirmation, reason: &str) {
+    emit_or_warn!(
+        app_handle,
+        command_events::COMMAND_CONFIRMATION_CANCELLED,
+        CommandConfirmationCancelledPayload {
+            command_id: pending.command.id.to_string(),
+            trigger: pending.command.trigger.clone(),
+            reason: reason.to_string(),
+            timestamp: current_timestamp(),
+        }
+    );
+}
+
+/// Run the command waiting for confirmation
+///
+/// Fails when `command_id` isn't the command currently waiting, e.g. because
+/// it already timed out or was replaced by a newer match.
+#[tauri::command]
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn confirm_command(
+    app_handle: AppHandle,
+    executor_state: tauri::State<'_, ExecutorState>,
+    command_id: String,
+) -> Result<ActionResult, String> {
+    let pending = confirmations()
+        .confirm(&command_id)
+        .ok_or_else(|| format!("Command {} is not awaiting confirmation", command_id))?;
+    let command = pending.command;
+
+    crate::info!("Command '{}' confirmed", command.trigger);
+    let result = executor_state
+        .dispatcher
+        .execute_with_transcription(&command, &pending.transcription, pending.confidence)
+        .await;
+
+    match &result {
+        Ok(action_result) => {
+            emit_or_warn!(
+                app_handle,
+                command_events::COMMAND_EXECUTED,
+                CommandExecutedPayload {
+                    command_id: command.id.to_string(),
+                    trigger: command.trigger.clone(),
+                    message: action_result.message.clone(),
+                }
+            );
+        }
+        Err(action_error) => {
+            crate::error!("Confirmed command failed: {}", action_error);
+            last_error().record_command_failure(&command.trigger, &action_error.message);
+            emit_or_warn!(
+                app_handle,
+                command_events::COMMAND_FAILED,
+                CommandFailedPayload {
+                    command_id: command.id.to_string(),
+                    trigger: command.trigger.clone(),
+                    error_code: action_error.code.to_string(),
+                    error_message: action_error.message.clone(),
+                }
+            );
+        }
+    }
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Cancel the command waiting for confirmation
+///
+/// Returns false when no command was waiting.
+#[tauri::command]
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn cancel_command(app_handle: AppHandle) -> bool {
+    match confirmations().cancel() {
+        Some(pending) => {
+            crate::info!("Command '{}' cancelled", pending.command.trigger);
+            emit_cancelled(&app_handle, &pending, CONFIRMATION_CANCELLED);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "confirmation_test.rs"]
+mod tests;
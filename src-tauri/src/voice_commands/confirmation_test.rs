@@ -0,0 +1,83 @@
+use super::*;
+use crate::voice_commands::registry::ActionType;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+fn create_command(trigger: &str) -> CommandDefinition {
+    CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: trigger.to_string(),
+        action_type: ActionType::Custom,
+        parameters: HashMap::new(),
+        enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: true,
+    }
+}
+
+#[test]
+fn test_timeout_defaults_and_ignores_zero() {
+    let default = Duration::from_millis(DEFAULT_CONFIRMATION_TIMEOUT_MS);
+    assert_eq!(confirmation_timeout_from_setting(None), default);
+    assert_eq!(confirmation_timeout_from_setting(Some(0)), default);
+    assert_eq!(
+        confirmation_timeout_from_setting(Some(5000)),
+        Duration::from_secs(5)
+    );
+}
+
+#[test]
+fn test_confirm_takes_matching_command_once() {
+    let tracker = ConfirmationTracker::new();
+    let cmd = create_command("quit all apps");
+    let command_id = cmd.id.to_string();
+    tracker.begin(cmd, "quit all apps".to_string(), 0.9);
+
+    let pending = tracker.confirm(&command_id).unwrap();
+    assert_eq!(pending.command.trigger, "quit all apps");
+    assert_eq!(pending.confidence, 0.9);
+    assert!(tracker.confirm(&command_id).is_none());
+}
+
+#[test]
+fn test_confirm_ignores_other_command() {
+    let tracker = ConfirmationTracker::new();
+    tracker.begin(create_command("quit all apps"), "quit".to_string(), 1.0);
+
+    assert!(tracker.confirm(&Uuid::new_v4().to_string()).is_none());
+    assert!(tracker.cancel().is_some());
+}
+
+#[test]
+fn test_cancel_drops_pending_command() {
+    let tracker = ConfirmationTracker::new();
+    assert!(tracker.cancel().is_none());
+
+    let cmd = create_command("run cleanup");
+    let command_id = cmd.id.to_string();
+    tracker.begin(cmd, "run cleanup".to_string(), 1.0);
+    assert!(tracker.cancel().is_some());
+    assert!(tracker.confirm(&command_id).is_none());
+}
+
+#[test]
+fn test_stale_timer_does_not_cancel_newer_command() {
+    let tracker = ConfirmationTracker::new();
+    let (first, _) = tracker.begin(create_command("quit all apps"), "quit".to_string(), 1.0);
+    let (second, _) = tracker.begin(create_command("run cleanup"), "cleanup".to_string(), 1.0);
+
+    assert!(tracker.take_if_pending(first).is_none());
+    let pending = tracker.take_if_pending(second).unwrap();
+    assert_eq!(pending.command.trigger, "run cleanup");
+}
+
+#[test]
+fn test_begin_returns_replaced_command() {
+    let tracker = ConfirmationTracker::new();
+    let (_, replaced) = tracker.begin(create_command("quit all apps"), "quit".to_string(), 1.0);
+    assert!(replaced.is_none());
+
+    let (_, replaced) = tracker.begin(create_command("run cleanup"), "cleanup".to_string(), 1.0);
+    assert_eq!(replaced.unwrap().command.trigger, "quit all apps");
+}
@@ -0,0 +1,362 @@
+// Ambiguous match handling
+//
+// The ambiguity mode decides whether an ambiguous command match is shown to
+// the user at all, or resolved straight away by running the top candidate or
+// treating the transcription as dictation.
+//
+// An ambiguous command match that is shown waits for the user to pick a candidate in the
+// disambiguation UI. If nobody picks one within the configured timeout the
+// ambiguity is resolved automatically, either by running the top candidate or
+// by sending the transcription to the output sinks, and the pending ambiguity
+// is cleared. A pick or dismissal from the UI resolves it before the timer fires.
+
+use crate::emit_or_warn;
+use crate::events::{
+    command_events, current_timestamp, CommandAutoResolvedPayload, CommandExecutedPayload,
+    CommandFailedPayload,
+};
+use crate::last_error::last_error;
+use crate::transcription::clipboard::app_clipboard;
+use crate::transcription::deliver_output;
+use crate::util::get_settings_file;
+use crate::voice_commands::confirmation::request_confirmation;
+use crate::voice_commands::executor::ActionDispatcher;
+use crate::voice_commands::registry::CommandDefinition;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+/// Settings key for how ambiguous matches are handled ("alwaysAsk", "autoTop" or "clipboard")
+pub const AMBIGUITY_MODE_KEY: &str = "commands.ambiguityMode";
+
+/// Settings key for the auto-resolve timeout in milliseconds (0 waits forever)
+pub const AMBIGUITY_TIMEOUT_MS_KEY: &str = "commands.ambiguityTimeoutMs";
+
+/// Settings key for what auto-resolution does ("topCandidate" or "clipboard")
+pub const AMBIGUITY_TIMEOUT_ACTION_KEY: &str = "commands.ambiguityTimeoutAction";
+
+/// Default auto-resolve timeout in milliseconds
+pub const DEFAULT_AMBIGUITY_TIMEOUT_MS: u64 = 30_000;
+
+/// Resolve the auto-resolve timeout from its settings value
+///
+/// Returns None when auto-resolution is disabled (a timeout of 0).
+pub fn ambiguity_timeout_from_setting(value: Option<u64>) -> Option<Duration> {
+    match value.unwrap_or(DEFAULT_AMBIGUITY_TIMEOUT_MS) {
+        0 => None,
+        ms => Some(Duration::from_millis(ms)),
+    }
+}
+
+/// How an ambiguous command match is handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AmbiguityMode {
+    /// Show the disambiguation UI, auto-resolving it after the timeout
+    #[default]
+    AlwaysAsk,
+    /// Execute the highest-scoring candidate without asking
+    AutoTop,
+    /// Treat the transcription as dictation and deliver it to the output sinks
+    Clipboard,
+}
+
+impl AmbiguityMode {
+    /// Resolve the ambiguity mode from its settings value
+    ///
+    /// Unset or unrecognised values fall back to asking.
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            Some("autoTop") => Self::AutoTop,
+            Some("clipboard") => Self::Clipboard,
+            Some("alwaysAsk") | None => Self::AlwaysAsk,
+            Some(other) => {
+                crate::warn!("Ignoring unknown ambiguity mode '{}', always asking", other);
+                Self::AlwaysAsk
+            }
+        }
+    }
+
+    /// Read the configured ambiguity mode from settings
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn from_settings(app_handle: &AppHandle) -> Self {
+        Self::from_setting(
+            app_handle
+                .store(get_settings_file(app_handle))
+                .ok()
+                .and_then(|s| s.get(AMBIGUITY_MODE_KEY))
+                .and_then(|v| v.as_str().map(String::from))
+                .as_deref(),
+        )
+    }
+}
+
+/// Get how ambiguous command matches are handled
+#[tauri::command]
+pub fn get_ambiguity_mode(app_handle: AppHandle) -> AmbiguityMode {
+    AmbiguityMode::from_settings(&app_handle)
+}
+
+/// Set how ambiguous command matches are handled
+#[tauri::command]
+pub fn set_ambiguity_mode(app_handle: AppHandle, mode: AmbiguityMode) -> Result<(), String> {
+    let store = app_handle
+        .store(get_settings_file(&app_handle))
+        .map_err(|_| "Failed to access settings store.".to_string())?;
+    store.set(
+        AMBIGUITY_MODE_KEY,
+        serde_json::to_value(mode).unwrap_or_default(),
+    );
+    store
+        .save()
+        .map_err(|e| format!("Failed to save settings: {}", e))?;
+
+    crate::info!("Ambiguity mode set to {:?}", mode);
+    Ok(())
+}
+
+/// What happens to an ambiguous match nobody resolved in time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AmbiguityTimeoutAction {
+    /// Execute the highest-scoring candidate
+    TopCandidate,
+    /// Treat the transcription as dictation and deliver it to the output sinks
+    #[default]
+    Clipboard,
+}
+
+impl AmbiguityTimeoutAction {
+    /// Resolve the timeout action from its settings value
+    ///
+    /// Unset or unrecognised values fall back to the clipboard.
+    pub fn from_setting(value: Option<&str>) -> Self {
+        match value {
+            Some("topCandidate") => Self::TopCandidate,
+            Some("clipboard") | None => Self::Clipboard,
+            Some(other) => {
+                crate::warn!(
+                    "Ignoring unknown ambiguity timeout action '{}', using clipboard",
+                    other
+                );
+                Self::Clipboard
+            }
+        }
+    }
+}
+
+/// An ambiguous match waiting for the user
+#[derive(Debug, Clone)]
+pub struct PendingAmbiguity {
+    /// Identifies this ambiguity so a stale timer can't resolve a newer one
+    pub id: u64,
+    /// The transcribed text that matched ambiguously
+    pub transcription: String,
+    /// Highest-scoring candidate and its score
+    pub top_candidate: Option<(CommandDefinition, f64)>,
+}
+
+/// Holds the ambiguous match currently waiting for the user
+///
+/// Only one ambiguity is pending at a time; a newer one replaces it, matching
+/// the disambiguation UI which only shows the latest.
+pub struct AmbiguityTracker {
+    pending: Mutex<Option<PendingAmbiguity>>,
+    next_id: AtomicU64,
+}
+
+impl AmbiguityTracker {
+    pub const fn new() -> Self {
+        Self {
+            pending: Mutex::new(None),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Record a new pending ambiguity, replacing any older one
+    pub fn begin(
+        &self,
+        transcription: String,
+        top_candidate: Option<(CommandDefinition, f64)>,
+    ) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        if let Ok(mut pending) = self.pending.lock() {
+            *pending = Some(PendingAmbiguity {
+                id,
+                transcription,
+                top_candidate,
+            });
+        }
+        id
+    }
+
+    /// Clear the pending ambiguity because the user resolved it
+    ///
+    /// Returns false when nothing was pending.
+    pub fn resolve(&self) -> bool {
+        self.pending
+            .lock()
+            .map(|mut pending| pending.take().is_some())
+            .unwrap_or(false)
+    }
+
+    /// Take the pending ambiguity if it is still the one with `id`
+    pub fn take_if_pending(&self, id: u64) -> Option<PendingAmbiguity> {
+        let mut pending = self.pending.lock().ok()?;
+        if pending.as_ref().is_some_and(|p| p.id == id) {
+            pending.take()
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for AmbiguityTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide ambiguity tracker shared by all transcription paths
+static AMBIGUITIES: AmbiguityTracker = AmbiguityTracker::new();
+
+/// Get the process-wide ambiguity tracker
+pub fn ambiguities() -> &'static AmbiguityTracker {
+    &AMBIGUITIES
+}
+
+/// Dismiss the pending ambiguity without executing a candidate
+///
+/// Stops it from being auto-resolved; returns false when nothing was pending.
+#[tauri::command]
+pub fn dismiss_command_ambiguity() -> bool {
+    ambiguities().resolve()
+}
+
+/// Track an ambiguous match and auto-resolve it if the user doesn't in time
+///
+/// Without a top candidate the "topCandidate" action falls back to the clipboard.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn schedule_auto_resolve(
+    app_handle: &AppHandle,
+    dispatcher: Arc<ActionDispatcher>,
+    transcription: String,
+    top_candidate: Option<(CommandDefinition, f64)>,
+) {
+    let store = app_handle.store(get_settings_file(app_handle)).ok();
+    let timeout = ambiguity_timeout_from_setting(
+        store
+            .as_ref()
+            .and_then(|s| s.get(AMBIGUITY_TIMEOUT_MS_KEY))
+            .and_then(|v| v.as_u64()),
+    );
+    let action = AmbiguityTimeoutAction::from_setting(
+        store
+            .as_ref()
+            .and_then(|s| s.get(AMBIGUITY_TIMEOUT_ACTION_KEY))
+            .and_then(|v| v.as_str().map(String::from))
+            .as_deref(),
+    );
+
+    let id = ambiguities().begin(transcription, top_candidate);
+    let Some(timeout) = timeout else {
+        return;
+    };
+
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(timeout).await;
+        let Some(pending) = ambiguities().take_if_pending(id) else {
+            return;
+        };
+        auto_resolve(&app_handle, &dispatcher, pending, action).await;
+    });
+}
+
+/// Resolve a pending ambiguity nobody picked a candidate for
+#[cfg_attr(coverage_nightly, coverage(off))]
+async fn auto_resolve(
+    app_handle: &AppHandle,
+    dispatcher: &ActionDispatcher,
+    pending: PendingAmbiguity,
+    action: AmbiguityTimeoutAction,
+) {
+    let (action, command_id) = match (action, pending.top_candidate) {
+        (AmbiguityTimeoutAction::TopCandidate, Some((cmd, confidence))) => {
+            crate::info!(
+                "Ambiguous match timed out, executing top candidate: {}",
+                cmd.trigger
+            );
+            if cmd.requires_confirmation {
+                // Auto-resolution picks the command but it still needs confirming
+                request_confirmation(
+                    app_handle,
+                    cmd.clone(),
+                    pending.transcription.clone(),
+                    confidence,
+                );
+            } else {
+                match dispatcher
+                    .execute_with_transcription(&cmd, &pending.transcription, confidence)
+                    .await
+                {
+                    Ok(action_result) => {
+                        emit_or_warn!(
+                            app_handle,
+                            command_events::COMMAND_EXECUTED,
+                            CommandExecutedPayload {
+                                command_id: cmd.id.to_string(),
+                                trigger: cmd.trigger.clone(),
+                                message: action_result.message,
+                            }
+                        );
+                    }
+                    Err(action_error) => {
+                        crate::error!("Auto-resolved command failed: {}", action_error);
+                        last_error().record_command_failure(&cmd.trigger, &action_error.message);
+                        emit_or_warn!(
+                            app_handle,
+                            command_events::COMMAND_FAILED,
+                            CommandFailedPayload {
+                                command_id: cmd.id.to_string(),
+                                trigger: cmd.trigger.clone(),
+                                error_code: action_error.code.to_string(),
+                                error_message: action_error.message.clone(),
+                            }
+                        );
+                    }
+                }
+            }
+            (
+                AmbiguityTimeoutAction::TopCandidate,
+                Some(cmd.id.to_string()),
+            )
+        }
+        _ => {
+            crate::info!("Ambiguous match timed out, delivering transcription as text");
+            if !crate::shutdown::is_shutting_down() {
+                let clipboard = app_clipboard(app_handle);
+                deliver_output(app_handle, clipboard, pending.transcription.clone(), false).await;
+            }
+            (AmbiguityTimeoutAction::Clipboard, None)
+        }
+    };
+
+    emit_or_warn!(
+        app_handle,
+        command_events::COMMAND_AUTO_RESOLVED,
+        CommandAutoResolvedPayload {
+            transcription: pending.transcription,
+            action,
+            command_id,
+            timestamp: current_timestamp(),
+        }
+    );
+}
+
+#[cfg(test)]
+#[path = "disambiguation_test.rs"]
+mod tests;
@@ -0,0 +1,128 @@
+use super::*;
+use crate::voice_commands::registry::ActionType;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+fn create_command(trigger: &str) -> CommandDefinition {
+    CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: trigger.to_string(),
+        action_type: ActionType::OpenApp,
+        parameters: HashMap::new(),
+        enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
+    }
+}
+
+#[test]
+fn test_timeout_defaults_and_zero_disables() {
+    assert_eq!(
+        ambiguity_timeout_from_setting(None),
+        Some(Duration::from_millis(DEFAULT_AMBIGUITY_TIMEOUT_MS))
+    );
+    assert_eq!(
+        ambiguity_timeout_from_setting(Some(5000)),
+        Some(Duration::from_secs(5))
+    );
+    assert_eq!(ambiguity_timeout_from_setting(Some(0)), None);
+}
+
+#[test]
+fn test_ambiguity_mode_from_setting() {
+    assert_eq!(AmbiguityMode::from_setting(None), AmbiguityMode::AlwaysAsk);
+    assert_eq!(
+        AmbiguityMode::from_setting(Some("alwaysAsk")),
+        AmbiguityMode::AlwaysAsk
+    );
+    assert_eq!(
+        AmbiguityMode::from_setting(Some("autoTop")),
+        AmbiguityMode::AutoTop
+    );
+    assert_eq!(
+        AmbiguityMode::from_setting(Some("clipboard")),
+        AmbiguityMode::Clipboard
+    );
+    assert_eq!(
+        AmbiguityMode::from_setting(Some("bogus")),
+        AmbiguityMode::AlwaysAsk
+    );
+}
+
+#[test]
+fn test_ambiguity_mode_round_trips_through_json() {
+    let value = serde_json::to_value(AmbiguityMode::AutoTop).unwrap();
+    assert_eq!(value, serde_json::json!("autoTop"));
+    assert_eq!(
+        AmbiguityMode::from_setting(value.as_str()),
+        AmbiguityMode::AutoTop
+    );
+}
+
+#[test]
+fn test_timeout_action_from_setting() {
+    assert_eq!(
+        AmbiguityTimeoutAction::from_setting(Some("topCandidate")),
+        AmbiguityTimeoutAction::TopCandidate
+    );
+    assert_eq!(
+        AmbiguityTimeoutAction::from_setting(Some("clipboard")),
+        AmbiguityTimeoutAction::Clipboard
+    );
+    assert_eq!(
+        AmbiguityTimeoutAction::from_setting(None),
+        AmbiguityTimeoutAction::Clipboard
+    );
+    assert_eq!(
+        AmbiguityTimeoutAction::from_setting(Some("bogus")),
+        AmbiguityTimeoutAction::Clipboard
+    );
+}
+
+#[test]
+fn test_timeout_action_serializes_camel_case() {
+    assert_eq!(
+        serde_json::to_value(AmbiguityTimeoutAction::TopCandidate).unwrap(),
+        serde_json::json!("topCandidate")
+    );
+}
+
+#[test]
+fn test_unresolved_ambiguity_is_taken_once() {
+    let tracker = AmbiguityTracker::new();
+    let cmd = create_command("open slack");
+
+    let id = tracker.begin("open slak".to_string(), Some((cmd.clone(), 0.8)));
+    let pending = tracker
+        .take_if_pending(id)
+        .expect("ambiguity should be pending");
+    assert_eq!(pending.transcription, "open slak");
+    assert_eq!(pending.top_candidate.map(|(c, _)| c.id), Some(cmd.id));
+
+    assert!(tracker.take_if_pending(id).is_none());
+    assert!(!tracker.resolve());
+}
+
+#[test]
+fn test_resolved_ambiguity_is_not_auto_resolved() {
+    let tracker = AmbiguityTracker::new();
+
+    let id = tracker.begin("open slak".to_string(), None);
+    assert!(tracker.resolve());
+    assert!(tracker.take_if_pending(id).is_none());
+}
+
+#[test]
+fn test_newer_ambiguity_replaces_older_timer() {
+    let tracker = AmbiguityTracker::new();
+
+    let first = tracker.begin("first".to_string(), None);
+    let second = tracker.begin("second".to_string(), None);
+
+    assert!(tracker.take_if_pending(first).is_none());
+    let pending = tracker
+        .take_if_pending(second)
+        .expect("newer ambiguity should be pending");
+    assert_eq!(pending.transcription, "second");
+}
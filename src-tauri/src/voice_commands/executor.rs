@@ -1,9 +1,14 @@
 // Action executor - dispatches commands to action implementations
 
 use crate::events::{command_events, CommandExecutedPayload, CommandFailedPayload};
+use crate::last_error::last_error;
 use crate::voice_commands::actions::{AppLauncherAction, TextInputAction};
+use crate::voice_commands::disambiguation::ambiguities;
+use crate::voice_commands::last_command::last_command;
+use crate::voice_commands::matcher::regex_trigger_captures;
 use crate::voice_commands::registry::{ActionType, CommandDefinition};
 use async_trait::async_trait;
+use regex::Regex;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -83,10 +88,20 @@ pub struct ActionError {
     pub message: String,
 }
 
+/// Reserved parameter holding the full transcription that triggered a command
+pub const TRANSCRIPTION_PARAM: &str = "__transcription";
+
+/// Reserved parameter holding the match confidence (0.0 - 1.0) of a triggered command
+pub const CONFIDENCE_PARAM: &str = "__confidence";
+
 /// Trait for action implementations
 #[async_trait]
 pub trait Action: Send + Sync {
     /// Execute the action with the given parameters
+    ///
+    /// When run from a transcription, the parameters also carry the reserved
+    /// `TRANSCRIPTION_PARAM` and `CONFIDENCE_PARAM` keys; actions that don't
+    /// need them can ignore them.
     async fn execute(&self, parameters: &HashMap<String, String>) -> Result<ActionResult, ActionError>;
 }
 
@@ -184,11 +199,101 @@ impl ActionDispatcher {
 
     /// Execute a command asynchronously
     pub async fn execute(&self, command: &CommandDefinition) -> Result<ActionResult, ActionError> {
+        self.execute_action(&command.action_type, &command.parameters).await
+    }
+
+    /// Execute a single action directly, without a stored command
+    pub async fn execute_action(
+        &self,
+        action_type: &ActionType,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let action = self.get_action(action_type);
+        action.execute(parameters).await
+    }
+
+    /// Execute a command triggered by a transcription
+    ///
+    /// Parameter values may reference `{transcription}` (the full transcribed
+    /// text) and `{1}`, `{2}`, ... (capture groups of a regex trigger such as
+    /// `re:note (.*)`). Placeholders are substituted before the action runs,
+    /// escaped for the action type (see `escape_template_value`).
+    /// The transcription and match confidence are also passed to the action
+    /// under `TRANSCRIPTION_PARAM` and `CONFIDENCE_PARAM`. The command is
+    /// remembered as the session's last command so it can be re-run.
+    pub async fn execute_with_transcription(
+        &self,
+        command: &CommandDefinition,
+        transcription: &str,
+        confidence: f64,
+    ) -> Result<ActionResult, ActionError> {
+        last_command().record(command, transcription, confidence);
+
+        // Captures come from whichever phrase matched, the trigger or an alias
+        let captures = std::iter::once(&command.trigger)
+            .chain(command.aliases.iter())
+            .find_map(|phrase| regex_trigger_captures(phrase, transcription))
+            .unwrap_or_default();
+        let mut parameters = apply_parameter_templates(
+            &command.parameters,
+            transcription,
+            &captures,
+            &command.action_type,
+        );
+        parameters.insert(TRANSCRIPTION_PARAM.to_string(), transcription.to_string());
+        parameters.insert(CONFIDENCE_PARAM.to_string(), format!("{:.3}", confidence));
+
         let action = self.get_action(&command.action_type);
-        action.execute(&command.parameters).await
+        action.execute(&parameters).await
+    }
+}
+
+/// Escape a spoken value before it is substituted into an action parameter
+///
+/// Custom scripts get the value as a single-quoted shell word, so speech
+/// can't inject shell syntax. Typed text drops control characters, so speech
+/// can't press Return or Tab. Other actions use the value as is.
+pub fn escape_template_value(action_type: &ActionType, value: &str) -> String {
+    match action_type {
+        ActionType::Custom => format!("'{}'", value.replace('\'', "'\\''")),
+        ActionType::TypeText => value.chars().filter(|c| !c.is_control()).collect(),
+        ActionType::OpenApp | ActionType::SystemControl => value.to_string(),
     }
 }
 
+/// Substitute `{transcription}` and `{N}` placeholders in parameter values
+///
+/// `{0}` refers to the full regex match. Capture indices beyond the available
+/// groups are replaced with an empty string; other braces are left untouched.
+/// Substituted values are escaped for `action_type`.
+pub fn apply_parameter_templates(
+    parameters: &HashMap<String, String>,
+    transcription: &str,
+    captures: &[String],
+    action_type: &ActionType,
+) -> HashMap<String, String> {
+    let placeholder = Regex::new(r"\{(transcription|\d+)\}").expect("valid placeholder regex");
+
+    parameters
+        .iter()
+        .map(|(key, value)| {
+            let templated = placeholder.replace_all(value, |caps: &regex::Captures| {
+                let value = match &caps[1] {
+                    "transcription" => transcription,
+                    index => index
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|i| captures.get(i))
+                        .map(String::as_str)
+                        .unwrap_or_default(),
+                };
+                escape_template_value(action_type, value)
+            });
+            (key.clone(), templated.into_owned())
+        })
+        .collect()
+}
+
 /// State for the executor
 pub struct ExecutorState {
     pub dispatcher: Arc<ActionDispatcher>,
@@ -229,6 +334,9 @@ pub async fn test_command(
         .find(|c| c.id == uuid)
         .ok_or_else(|| format!("Command not found: {}", id))?;
 
+    // Picking a candidate in the disambiguation UI resolves the pending ambiguity
+    ambiguities().resolve();
+
     let result = executor_state.dispatcher.execute(&command).await;
 
     match &result {
@@ -241,6 +349,7 @@ pub async fn test_command(
             let _ = app_handle.emit(command_events::COMMAND_EXECUTED, payload);
         }
         Err(action_error) => {
+            last_error().record_command_failure(&command.trigger, &action_error.message);
             let payload = CommandFailedPayload {
                 command_id: command.id.to_string(),
                 trigger: command.trigger.clone(),
@@ -254,6 +363,28 @@ pub async fn test_command(
     result.map_err(|e| e.to_string())
 }
 
+/// Test a single action without binding it to a command
+///
+/// Dispatches the action directly, bypassing the matcher and without emitting
+/// command events, so the settings UI can validate an action (e.g. a custom
+/// script) before saving it.
+#[tauri::command]
+pub async fn test_action(
+    executor_state: tauri::State<'_, ExecutorState>,
+    action_type: String,
+    parameters: HashMap<String, String>,
+) -> Result<ActionResult, ActionError> {
+    let action_type: ActionType = action_type.parse().map_err(|message| ActionError {
+        code: ActionErrorCode::InvalidParameter,
+        message,
+    })?;
+
+    executor_state
+        .dispatcher
+        .execute_action(&action_type, &parameters)
+        .await
+}
+
 #[cfg(test)]
 #[path = "executor_test.rs"]
 mod tests;
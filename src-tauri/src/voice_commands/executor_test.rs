@@ -58,6 +58,9 @@ fn create_test_command(action_type: ActionType) -> CommandDefinition {
             ("script".to_string(), "custom.sh".to_string()),
         ]),
         enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
     }
 }
 
@@ -253,3 +256,208 @@ async fn test_open_app_dispatches_to_app_launcher() {
     assert_eq!(mock.count(), 1);
 }
 
+
+/// Mock action that records the parameters it was executed with
+struct RecordingAction {
+    received: TokioMutex<Option<HashMap<String, String>>>,
+}
+
+#[async_trait]
+impl Action for RecordingAction {
+    async fn execute(&self, parameters: &HashMap<String, String>) -> Result<ActionResult, ActionError> {
+        *self.received.lock().await = Some(parameters.clone());
+        Ok(ActionResult {
+            message: "recorded".to_string(),
+            data: None,
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_execute_with_transcription_substitutes_templates() {
+    let recorder = Arc::new(RecordingAction {
+        received: TokioMutex::new(None),
+    });
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(SystemControlAction),
+        recorder.clone(),
+    );
+
+    let command = CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: "re:note (.*)".to_string(),
+        action_type: ActionType::Custom,
+        parameters: HashMap::from([
+            ("script".to_string(), "echo {1} >> notes.txt".to_string()),
+            ("raw".to_string(), "{transcription}".to_string()),
+        ]),
+        enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
+    };
+
+    let result = dispatcher
+        .execute_with_transcription(&command, "Note buy milk.", 1.0)
+        .await;
+    assert!(result.is_ok());
+
+    let received = recorder.received.lock().await.clone().unwrap();
+    assert_eq!(received.get("script").unwrap(), "echo 'buy milk' >> notes.txt");
+    assert_eq!(received.get("raw").unwrap(), "'Note buy milk.'");
+}
+
+#[tokio::test]
+async fn test_execute_with_transcription_takes_captures_from_matching_alias() {
+    let recorder = Arc::new(RecordingAction {
+        received: TokioMutex::new(None),
+    });
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(SystemControlAction),
+        recorder.clone(),
+    );
+
+    let command = CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: "re:note (.*)".to_string(),
+        action_type: ActionType::Custom,
+        parameters: HashMap::from([("script".to_string(), "echo {1}".to_string())]),
+        enabled: true,
+        priority: 0,
+        aliases: vec!["re:remember (.*)".to_string()],
+        requires_confirmation: false,
+    };
+
+    let result = dispatcher
+        .execute_with_transcription(&command, "Remember buy milk.", 1.0)
+        .await;
+    assert!(result.is_ok());
+
+    let received = recorder.received.lock().await.clone().unwrap();
+    assert_eq!(received.get("script").unwrap(), "echo 'buy milk'");
+}
+
+#[tokio::test]
+async fn test_execute_with_transcription_passes_reserved_context() {
+    let recorder = Arc::new(RecordingAction {
+        received: TokioMutex::new(None),
+    });
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(SystemControlAction),
+        recorder.clone(),
+    );
+
+    let mut command = create_test_command(ActionType::Custom);
+    // A stored parameter can't spoof the reserved keys
+    command
+        .parameters
+        .insert(TRANSCRIPTION_PARAM.to_string(), "stale".to_string());
+
+    dispatcher
+        .execute_with_transcription(&command, "run test command please", 0.875)
+        .await
+        .unwrap();
+
+    let received = recorder.received.lock().await.clone().unwrap();
+    assert_eq!(received.get(TRANSCRIPTION_PARAM).unwrap(), "run test command please");
+    assert_eq!(received.get(CONFIDENCE_PARAM).unwrap(), "0.875");
+    assert_eq!(received.get("script").unwrap(), "custom.sh");
+}
+
+#[tokio::test]
+async fn test_execute_without_transcription_has_no_reserved_context() {
+    let recorder = Arc::new(RecordingAction {
+        received: TokioMutex::new(None),
+    });
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(SystemControlAction),
+        recorder.clone(),
+    );
+
+    dispatcher
+        .execute(&create_test_command(ActionType::Custom))
+        .await
+        .unwrap();
+
+    let received = recorder.received.lock().await.clone().unwrap();
+    assert!(!received.contains_key(TRANSCRIPTION_PARAM));
+    assert!(!received.contains_key(CONFIDENCE_PARAM));
+}
+
+#[tokio::test]
+async fn test_execute_action_dispatches_without_command() {
+    let custom = Arc::new(RecordingAction {
+        received: TokioMutex::new(None),
+    });
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(SystemControlAction),
+        custom.clone(),
+    );
+
+    let parameters = HashMap::from([("script".to_string(), "echo hi".to_string())]);
+    let result = dispatcher.execute_action(&ActionType::Custom, &parameters).await;
+    assert!(result.is_ok());
+
+    let received = custom.received.lock().await.clone().unwrap();
+    assert_eq!(received.get("script").unwrap(), "echo hi");
+}
+
+#[tokio::test]
+async fn test_execute_action_returns_action_error() {
+    let failing = Arc::new(MockAction::new_failure(
+        ActionErrorCode::ExecutionError,
+        "script exited with status 1",
+    ));
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(MockAction::new_success("unused")),
+        Arc::new(SystemControlAction),
+        failing.clone(),
+    );
+
+    let error = dispatcher
+        .execute_action(&ActionType::Custom, &HashMap::new())
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ActionErrorCode::ExecutionError);
+    assert_eq!(failing.count(), 1);
+}
+
+#[test]
+fn test_apply_parameter_templates_handles_missing_groups_and_literals() {
+    let parameters = HashMap::from([
+        ("a".to_string(), "{2}".to_string()),
+        ("b".to_string(), "{name} stays".to_string()),
+    ]);
+    let captures = vec!["full".to_string(), "one".to_string()];
+
+    let templated =
+        apply_parameter_templates(&parameters, "hello", &captures, &ActionType::OpenApp);
+
+    assert_eq!(templated.get("a").unwrap(), "");
+    assert_eq!(templated.get("b").unwrap(), "{name} stays");
+}
+
+#[test]
+fn test_escape_template_value_quotes_shell_and_strips_keystrokes() {
+    assert_eq!(
+        escape_template_value(&ActionType::Custom, "it's; rm -rf ~"),
+        "'it'\\''s; rm -rf ~'"
+    );
+    assert_eq!(escape_template_value(&ActionType::Custom, ""), "''");
+    assert_eq!(
+        escape_template_value(&ActionType::TypeText, "line one\nline\ttwo"),
+        "line onelinetwo"
+    );
+    assert_eq!(escape_template_value(&ActionType::OpenApp, "Mail"), "Mail");
+}
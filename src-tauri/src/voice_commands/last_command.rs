@@ -0,0 +1,127 @@
+// Last executed command
+//
+// Every command dispatched from a transcription is remembered here so it can
+// be run again without speaking it. Only the current session is tracked; the
+// slot starts empty on launch.
+
+use crate::emit_or_warn;
+use crate::events::{command_events, CommandExecutedPayload, CommandFailedPayload};
+use crate::last_error::last_error;
+use crate::voice_commands::executor::{ActionResult, ExecutorState};
+use crate::voice_commands::registry::CommandDefinition;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// A command that was dispatched, with the transcription that triggered it
+#[derive(Debug, Clone)]
+pub struct LastCommand {
+    /// The matched command
+    pub command: CommandDefinition,
+    /// The transcribed text that matched
+    pub transcription: String,
+    /// Match confidence, passed on to the action
+    pub confidence: f64,
+}
+
+/// Holds the most recently dispatched command
+pub struct LastCommandTracker {
+    last: Mutex<Option<LastCommand>>,
+}
+
+impl LastCommandTracker {
+    pub const fn new() -> Self {
+        Self {
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Remember a dispatched command, replacing the previous one
+    pub fn record(&self, command: &CommandDefinition, transcription: &str, confidence: f64) {
+        if let Ok(mut last) = self.last.lock() {
+            *last = Some(LastCommand {
+                command: command.clone(),
+                transcription: transcription.to_string(),
+                confidence,
+            });
+        }
+    }
+
+    /// The most recently dispatched command, if any
+    pub fn get(&self) -> Option<LastCommand> {
+        self.last.lock().ok()?.clone()
+    }
+}
+
+impl Default for LastCommandTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Process-wide last command shared by all dispatchers
+static LAST_COMMAND: LastCommandTracker = LastCommandTracker::new();
+
+/// Get the process-wide last command tracker
+pub fn last_command() -> &'static LastCommandTracker {
+    &LAST_COMMAND
+}
+
+/// Run the last executed command again
+///
+/// The command is dispatched with its original transcription and confidence,
+/// and emits `command_executed` or `command_failed` like a spoken command.
+/// Fails when no command has been executed this session.
+#[tauri::command]
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn rerun_last_command(
+    app_handle: AppHandle,
+    executor_state: tauri::State<'_, ExecutorState>,
+) -> Result<ActionResult, String> {
+    let LastCommand {
+        command,
+        transcription,
+        confidence,
+    } = last_command()
+        .get()
+        .ok_or_else(|| "No command has been executed this session".to_string())?;
+
+    crate::info!("Re-running command '{}'", command.trigger);
+    let result = executor_state
+        .dispatcher
+        .execute_with_transcription(&command, &transcription, confidence)
+        .await;
+
+    match &result {
+        Ok(action_result) => {
+            emit_or_warn!(
+                app_handle,
+                command_events::COMMAND_EXECUTED,
+                CommandExecutedPayload {
+                    command_id: command.id.to_string(),
+                    trigger: command.trigger.clone(),
+                    message: action_result.message.clone(),
+                }
+            );
+        }
+        Err(action_error) => {
+            crate::error!("Re-run command failed: {}", action_error);
+            last_error().record_command_failure(&command.trigger, &action_error.message);
+            emit_or_warn!(
+                app_handle,
+                command_events::COMMAND_FAILED,
+                CommandFailedPayload {
+                    command_id: command.id.to_string(),
+                    trigger: command.trigger.clone(),
+                    error_code: action_error.code.to_string(),
+                    error_message: action_error.message.clone(),
+                }
+            );
+        }
+    }
+
+    result.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+#[path = "last_command_test.rs"]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+use crate::voice_commands::registry::ActionType;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+fn create_command(trigger: &str) -> CommandDefinition {
+    CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: trigger.to_string(),
+        action_type: ActionType::Custom,
+        parameters: HashMap::new(),
+        enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
+    }
+}
+
+#[test]
+fn test_tracker_starts_empty() {
+    let tracker = LastCommandTracker::new();
+    assert!(tracker.get().is_none());
+}
+
+#[test]
+fn test_record_replaces_previous_command() {
+    let tracker = LastCommandTracker::new();
+    tracker.record(&create_command("open slack"), "open slack", 1.0);
+    let second = create_command("close window");
+    tracker.record(&second, "close the window", 0.85);
+
+    let last = tracker.get().expect("a command was recorded");
+    assert_eq!(last.command.id, second.id);
+    assert_eq!(last.transcription, "close the window");
+    assert_eq!(last.confidence, 0.85);
+}
@@ -1,14 +1,34 @@
 // Fuzzy matcher - matches transcribed text against commands
 
-use crate::voice_commands::registry::CommandDefinition;
+use crate::voice_commands::registry::{compiled_trigger_regex, CommandDefinition};
 use serde::Serialize;
 use strsim::normalized_levenshtein;
 use std::collections::HashMap;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 use uuid::Uuid;
 
 /// Default similarity threshold for fuzzy matching (0.0 to 1.0)
 pub const DEFAULT_THRESHOLD: f64 = 0.8;
 
+/// Default number of candidates returned for an ambiguous match
+pub const DEFAULT_MAX_AMBIGUOUS_CANDIDATES: usize = 5;
+
+/// Settings key for the ambiguous candidate limit
+pub const MAX_AMBIGUOUS_CANDIDATES_KEY: &str = "commands.maxAmbiguousCandidates";
+
+/// Settings key for matching triggers regardless of letter case
+pub const IGNORE_CASE_KEY: &str = "commands.ignoreCase";
+
+/// Settings key for matching triggers regardless of accents ("café" = "cafe")
+pub const IGNORE_ACCENTS_KEY: &str = "commands.ignoreAccents";
+
+/// Settings key for matching triggers regardless of punctuation
+pub const IGNORE_PUNCTUATION_KEY: &str = "commands.ignorePunctuation";
+
+/// Prefix marking a trigger as a regular expression, e.g. "re:note (.*)"
+pub const REGEX_TRIGGER_PREFIX: &str = "re:";
+
 /// Result of matching transcribed text against commands
 #[derive(Debug, Clone, Serialize)]
 pub enum MatchResult {
@@ -25,12 +45,39 @@ pub enum MatchResult {
     },
     /// Multiple commands match with similar confidence
     Ambiguous {
+        /// Best candidates by score, at most `max_candidates`
         candidates: Vec<MatchCandidate>,
+        /// Whether lower-scoring candidates were dropped to respect the limit
+        truncated: bool,
     },
     /// No match found
     NoMatch,
 }
 
+impl MatchResult {
+    /// Outcome label recorded in match statistics
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            MatchResult::Exact { .. } => "exact",
+            MatchResult::Fuzzy { .. } => "fuzzy",
+            MatchResult::Ambiguous { .. } => "ambiguous",
+            MatchResult::NoMatch => "no_match",
+        }
+    }
+
+    /// Confidence of the result (best candidate score when ambiguous)
+    pub fn confidence(&self) -> Option<f64> {
+        match self {
+            MatchResult::Exact { .. } => Some(1.0),
+            MatchResult::Fuzzy { score, .. } => Some(*score),
+            MatchResult::Ambiguous { candidates, .. } => {
+                candidates.iter().map(|c| c.score).reduce(f64::max)
+            }
+            MatchResult::NoMatch => None,
+        }
+    }
+}
+
 /// A matched command with essential info
 #[derive(Debug, Clone, Serialize)]
 pub struct MatchedCommand {
@@ -44,6 +91,90 @@ pub struct MatchCandidate {
     pub command: MatchedCommand,
     pub score: f64,
     pub parameters: HashMap<String, String>,
+    /// Priority of the matched command, used to break ties
+    pub priority: i32,
+}
+
+/// How input and triggers are normalized before they are compared
+///
+/// Applies to exact, fuzzy and parameterized matching. Regex triggers
+/// (prefixed with `REGEX_TRIGGER_PREFIX`) are matched against the raw input
+/// and always ignore case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizationOptions {
+    /// Compare in lowercase
+    pub lowercase: bool,
+    /// Drop diacritics after NFD decomposition, e.g. when the model drops them
+    pub strip_accents: bool,
+    /// Drop punctuation and symbols, keeping letters, digits and whitespace
+    pub strip_punctuation: bool,
+}
+
+impl Default for NormalizationOptions {
+    fn default() -> Self {
+        Self {
+            lowercase: true,
+            strip_accents: false,
+            strip_punctuation: false,
+        }
+    }
+}
+
+impl NormalizationOptions {
+    /// Normalize text for comparison, trimming surrounding whitespace
+    pub fn apply(&self, input: &str) -> String {
+        input
+            .trim()
+            .chars()
+            .flat_map(|c| self.normalize_char(c))
+            .collect()
+    }
+
+    /// Normalize one character, which may expand to several or to none
+    fn normalize_char(&self, c: char) -> Vec<char> {
+        if self.strip_punctuation && is_punctuation(c) {
+            return Vec::new();
+        }
+        let chars: Vec<char> = if self.strip_accents {
+            std::iter::once(c)
+                .nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .collect()
+        } else {
+            vec![c]
+        };
+        if self.lowercase {
+            chars.into_iter().flat_map(char::to_lowercase).collect()
+        } else {
+            chars
+        }
+    }
+
+    /// Split `input` after the part that normalizes to `normalized_prefix`
+    ///
+    /// Returns the remainder of the original input, so parameter values keep
+    /// their case, accents and punctuation.
+    fn strip_prefix<'a>(&self, input: &'a str, normalized_prefix: &str) -> Option<&'a str> {
+        let input = input.trim();
+        let mut normalized = String::new();
+        for (index, c) in input.char_indices() {
+            if normalized.len() >= normalized_prefix.len() {
+                return (normalized == normalized_prefix).then(|| &input[index..]);
+            }
+            normalized.extend(self.normalize_char(c));
+            if !normalized_prefix.starts_with(normalized.as_str()) {
+                return None;
+            }
+        }
+        (normalized == normalized_prefix).then_some("")
+    }
+}
+
+/// Whether `c` counts as punctuation for normalization
+///
+/// Combining marks are kept so accents are only dropped by `strip_accents`.
+fn is_punctuation(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace() && !is_combining_mark(c)
 }
 
 /// Configuration for the matcher
@@ -53,6 +184,10 @@ pub struct MatcherConfig {
     pub threshold: f64,
     /// Maximum difference between top matches to consider ambiguous
     pub ambiguity_delta: f64,
+    /// Maximum candidates returned for an ambiguous match (at least 2)
+    pub max_candidates: usize,
+    /// How input and triggers are normalized before comparison
+    pub normalization: NormalizationOptions,
 }
 
 impl Default for MatcherConfig {
@@ -60,6 +195,8 @@ impl Default for MatcherConfig {
         Self {
             threshold: DEFAULT_THRESHOLD,
             ambiguity_delta: 0.1,
+            max_candidates: DEFAULT_MAX_AMBIGUOUS_CANDIDATES,
+            normalization: NormalizationOptions::default(),
         }
     }
 }
@@ -84,19 +221,19 @@ impl CommandMatcher {
     }
 
     /// Create a matcher with custom configuration
-    #[allow(dead_code)]
     pub fn with_config(config: MatcherConfig) -> Self {
         Self { config }
     }
 
-    /// Normalize input text: lowercase and trim whitespace
-    fn normalize(input: &str) -> String {
-        input.trim().to_lowercase()
+    /// Normalize text for comparison using the configured options
+    fn normalize(&self, input: &str) -> String {
+        self.config.normalization.apply(input)
     }
 
     /// Try to extract parameters from a parameterized trigger
     /// Returns (matched, parameters) if the trigger pattern matches
     fn try_extract_params(
+        &self,
         input: &str,
         trigger: &str,
     ) -> Option<(bool, HashMap<String, String>)> {
@@ -120,16 +257,14 @@ impl CommandMatcher {
             return None;
         }
 
-        // Check if input starts with the prefix
-        let normalized_input = Self::normalize(input);
-        let normalized_prefix = Self::normalize(prefix);
-
-        if !normalized_input.starts_with(&normalized_prefix) {
-            return None;
-        }
-
-        // Extract the parameter value
-        let param_value = input[prefix.len()..].trim().to_string();
+        // Check if input starts with the prefix and extract the parameter value
+        let normalized_prefix = self.normalize(prefix);
+        let param_value = self
+            .config
+            .normalization
+            .strip_prefix(input, &normalized_prefix)?
+            .trim()
+            .to_string();
 
         let mut params = HashMap::new();
         params.insert(param_name.to_string(), param_value);
@@ -137,7 +272,10 @@ impl CommandMatcher {
         Some((true, params))
     }
 
-    /// Match input against a single command
+    /// Match input against a single command's trigger and aliases
+    ///
+    /// Returns at most one candidate per command, scored by the best-matching
+    /// phrase, so aliases never compete with their own command.
     fn match_command(
         &self,
         input: &str,
@@ -147,11 +285,51 @@ impl CommandMatcher {
             return None;
         }
 
-        let normalized_input = Self::normalize(input);
-        let normalized_trigger = Self::normalize(&command.trigger);
+        std::iter::once(&command.trigger)
+            .chain(command.aliases.iter())
+            .filter_map(|phrase| self.match_phrase(input, phrase, command))
+            .reduce(|best, candidate| {
+                if candidate.score > best.score {
+                    candidate
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// Match input against one trigger phrase of a command
+    fn match_phrase(
+        &self,
+        input: &str,
+        phrase: &str,
+        command: &CommandDefinition,
+    ) -> Option<MatchCandidate> {
+        let normalized_input = self.normalize(input);
+        let normalized_trigger = self.normalize(phrase);
 
         // Try parameterized match first
-        if let Some((_, params)) = Self::try_extract_params(input, &command.trigger) {
+        if let Some((_, params)) = self.try_extract_params(input, phrase) {
+            return Some(MatchCandidate {
+                command: MatchedCommand {
+                    id: command.id,
+                    trigger: command.trigger.clone(),
+                },
+                score: 1.0,
+                parameters: params,
+                priority: command.priority,
+            });
+        }
+
+        // Regex trigger with capture groups like "re:note (.*)"; these only
+        // match as a whole and never fall through to fuzzy matching
+        if regex_trigger_pattern(phrase).is_some() {
+            let captures = regex_trigger_captures(phrase, input)?;
+            let params = captures
+                .into_iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, value)| (i.to_string(), value))
+                .collect();
             return Some(MatchCandidate {
                 command: MatchedCommand {
                     id: command.id,
@@ -159,6 +337,7 @@ impl CommandMatcher {
                 },
                 score: 1.0,
                 parameters: params,
+                priority: command.priority,
             });
         }
 
@@ -171,6 +350,7 @@ impl CommandMatcher {
                 },
                 score: 1.0,
                 parameters: HashMap::new(),
+                priority: command.priority,
             });
         }
 
@@ -185,6 +365,7 @@ impl CommandMatcher {
                 },
                 score,
                 parameters: HashMap::new(),
+                priority: command.priority,
             })
         } else {
             None
@@ -208,21 +389,7 @@ impl CommandMatcher {
 
         match candidates.len() {
             0 => MatchResult::NoMatch,
-            1 => {
-                let candidate = candidates.remove(0);
-                if candidate.score >= 1.0 - f64::EPSILON {
-                    MatchResult::Exact {
-                        command: candidate.command,
-                        parameters: candidate.parameters,
-                    }
-                } else {
-                    MatchResult::Fuzzy {
-                        command: candidate.command,
-                        score: candidate.score,
-                        parameters: candidate.parameters,
-                    }
-                }
-            }
+            1 => Self::single_result(candidates.remove(0)),
             _ => {
                 // Check if top matches are too close (ambiguous)
                 let top_score = candidates[0].score;
@@ -232,28 +399,84 @@ impl CommandMatcher {
                     .cloned()
                     .collect();
 
-                if close_matches.len() > 1 {
-                    MatchResult::Ambiguous {
-                        candidates: close_matches,
-                    }
+                if close_matches.len() <= 1 {
+                    return Self::single_result(candidates.remove(0));
+                }
+
+                // Break ties toward the highest-priority command(s)
+                let top_priority = close_matches
+                    .iter()
+                    .map(|c| c.priority)
+                    .max()
+                    .unwrap_or_default();
+                let mut top_matches: Vec<_> = close_matches
+                    .into_iter()
+                    .filter(|c| c.priority == top_priority)
+                    .collect();
+
+                if top_matches.len() == 1 {
+                    Self::single_result(top_matches.remove(0))
                 } else {
-                    let candidate = candidates.remove(0);
-                    if candidate.score >= 1.0 - f64::EPSILON {
-                        MatchResult::Exact {
-                            command: candidate.command,
-                            parameters: candidate.parameters,
-                        }
-                    } else {
-                        MatchResult::Fuzzy {
-                            command: candidate.command,
-                            score: candidate.score,
-                            parameters: candidate.parameters,
-                        }
+                    // Candidates are already in score order; keep the best N
+                    let limit = self.config.max_candidates.max(2);
+                    let truncated = top_matches.len() > limit;
+                    top_matches.truncate(limit);
+                    MatchResult::Ambiguous {
+                        candidates: top_matches,
+                        truncated,
                     }
                 }
             }
         }
     }
+
+    /// Build an Exact or Fuzzy result from a single winning candidate
+    fn single_result(candidate: MatchCandidate) -> MatchResult {
+        if candidate.score >= 1.0 - f64::EPSILON {
+            MatchResult::Exact {
+                command: candidate.command,
+                parameters: candidate.parameters,
+            }
+        } else {
+            MatchResult::Fuzzy {
+                command: candidate.command,
+                score: candidate.score,
+                parameters: candidate.parameters,
+            }
+        }
+    }
+}
+
+/// The regular expression of a trigger marked with `REGEX_TRIGGER_PREFIX`
+///
+/// Returns None for plain triggers, so triggers with punctuation keep their
+/// literal meaning.
+pub fn regex_trigger_pattern(trigger: &str) -> Option<&str> {
+    trigger
+        .trim()
+        .strip_prefix(REGEX_TRIGGER_PREFIX)
+        .map(str::trim)
+}
+
+/// Match input against a regex trigger with capture groups
+///
+/// The pattern is matched case-insensitively against the whole input,
+/// ignoring trailing sentence punctuation added by transcription. Compiled
+/// patterns are cached by the registry.
+///
+/// Returns all captures in group order (index 0 is the full match). Groups
+/// that did not participate in the match are returned as empty strings.
+pub fn regex_trigger_captures(trigger: &str, input: &str) -> Option<Vec<String>> {
+    let regex = compiled_trigger_regex(regex_trigger_pattern(trigger)?)?;
+
+    let input = input.trim().trim_end_matches(['.', '!', '?']).trim_end();
+    let captures = regex.captures(input)?;
+    Some(
+        captures
+            .iter()
+            .map(|group| group.map(|m| m.as_str().trim().to_string()).unwrap_or_default())
+            .collect(),
+    )
 }
 
 #[cfg(test)]
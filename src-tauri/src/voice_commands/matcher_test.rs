@@ -13,6 +13,9 @@ fn create_command(trigger: &str) -> CommandDefinition {
         action_type: ActionType::OpenApp,
         parameters: HashMap::new(),
         enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
     }
 }
 
@@ -104,19 +107,69 @@ fn test_ambiguous_similar_commands() {
     let config = MatcherConfig {
         threshold: 0.7,
         ambiguity_delta: 0.15,
+        ..Default::default()
     };
     let matcher = CommandMatcher::with_config(config);
     // Input that's similar to both: "slaik" is between "slack" and "slick"
     let result = matcher.match_commands("open slaik", &commands);
 
     match result {
-        MatchResult::Ambiguous { candidates } => {
+        MatchResult::Ambiguous { candidates, .. } => {
             assert!(candidates.len() >= 2, "Expected at least 2 ambiguous candidates");
         }
         _ => panic!("Expected Ambiguous result, got {:?}", result),
     }
 }
 
+#[test]
+fn test_priority_breaks_ambiguous_tie() {
+    let cmd1 = create_command("open slack");
+    let mut cmd2 = create_command("open slick");
+    cmd2.priority = 5;
+    let commands = vec![cmd1, cmd2];
+
+    let config = MatcherConfig {
+        threshold: 0.7,
+        ambiguity_delta: 0.15,
+        ..Default::default()
+    };
+    let matcher = CommandMatcher::with_config(config);
+    let result = matcher.match_commands("open slaik", &commands);
+
+    match result {
+        MatchResult::Fuzzy { command, .. } => {
+            assert_eq!(command.trigger, "open slick");
+        }
+        _ => panic!("Expected Fuzzy result for higher priority command, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_equal_priority_stays_ambiguous_among_top_priority() {
+    let mut cmd1 = create_command("open slack");
+    let mut cmd2 = create_command("open slick");
+    let cmd3 = create_command("open slock");
+    cmd1.priority = 2;
+    cmd2.priority = 2;
+    let commands = vec![cmd1, cmd2, cmd3];
+
+    let config = MatcherConfig {
+        threshold: 0.7,
+        ambiguity_delta: 0.15,
+        ..Default::default()
+    };
+    let matcher = CommandMatcher::with_config(config);
+    let result = matcher.match_commands("open slaik", &commands);
+
+    match result {
+        MatchResult::Ambiguous { candidates, .. } => {
+            assert_eq!(candidates.len(), 2);
+            assert!(candidates.iter().all(|c| c.priority == 2));
+        }
+        _ => panic!("Expected Ambiguous result, got {:?}", result),
+    }
+}
+
 #[test]
 fn test_whitespace_normalization() {
     let cmd = create_command("open slack");
@@ -164,6 +217,7 @@ fn test_custom_threshold() {
     let config = MatcherConfig {
         threshold: 0.99,
         ambiguity_delta: 0.1,
+        ..Default::default()
     };
     let matcher = CommandMatcher::with_config(config);
     let result = matcher.match_commands("opn slack", &commands);
@@ -189,3 +243,259 @@ fn test_best_match_selected_when_not_ambiguous() {
         _ => panic!("Expected Exact match, got {:?}", result),
     }
 }
+
+#[test]
+fn test_regex_trigger_extracts_capture_groups() {
+    let commands = vec![create_command("re:note (.*)")];
+
+    let matcher = CommandMatcher::new();
+    let result = matcher.match_commands("Note buy milk.", &commands);
+
+    match result {
+        MatchResult::Exact { parameters, .. } => {
+            assert_eq!(parameters.get("1").map(String::as_str), Some("buy milk"));
+        }
+        _ => panic!("Expected Exact match, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_plain_trigger_not_treated_as_regex() {
+    assert!(regex_trigger_captures("open slack.", "open slackX").is_none());
+    assert!(regex_trigger_captures("re:invalid (", "invalid (").is_none());
+
+    // Parentheses alone don't make a trigger a regex
+    let commands = vec![create_command("play (loud)")];
+    let matcher = CommandMatcher::new();
+    assert!(matches!(
+        matcher.match_commands("play loud", &commands),
+        MatchResult::Fuzzy { .. } | MatchResult::NoMatch
+    ));
+    assert!(matches!(
+        matcher.match_commands("play (loud)", &commands),
+        MatchResult::Exact { .. }
+    ));
+}
+
+#[test]
+fn test_unmatched_regex_trigger_is_not_fuzzy_matched() {
+    let commands = vec![create_command("re:note (.+)")];
+    let matcher = CommandMatcher::new();
+    assert!(matches!(
+        matcher.match_commands("re:note", &commands),
+        MatchResult::NoMatch
+    ));
+}
+
+#[test]
+fn test_match_result_outcome_and_confidence() {
+    let commands = vec![create_command("open slack")];
+    let matcher = CommandMatcher::new();
+
+    let exact = matcher.match_commands("open slack", &commands);
+    assert_eq!(exact.outcome(), "exact");
+    assert_eq!(exact.confidence(), Some(1.0));
+
+    let none = matcher.match_commands("something unrelated entirely", &commands);
+    assert_eq!(none.outcome(), "no_match");
+    assert_eq!(none.confidence(), None);
+}
+
+#[test]
+fn test_ambiguous_candidates_capped_at_limit() {
+    let commands = vec![
+        create_command("open slack"),
+        create_command("open slick"),
+        create_command("open slock"),
+    ];
+
+    let config = MatcherConfig {
+        threshold: 0.7,
+        ambiguity_delta: 0.15,
+        max_candidates: 2,
+        ..Default::default()
+    };
+    let matcher = CommandMatcher::with_config(config);
+    let result = matcher.match_commands("open slaik", &commands);
+
+    match result {
+        MatchResult::Ambiguous {
+            candidates,
+            truncated,
+        } => {
+            assert_eq!(candidates.len(), 2);
+            assert!(truncated);
+            assert!(candidates[0].score >= candidates[1].score);
+        }
+        _ => panic!("Expected Ambiguous result, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_ambiguous_not_truncated_within_limit() {
+    let commands = vec![create_command("open slack"), create_command("open slick")];
+
+    let config = MatcherConfig {
+        threshold: 0.7,
+        ambiguity_delta: 0.15,
+        max_candidates: 1,
+        ..Default::default()
+    };
+    let matcher = CommandMatcher::with_config(config);
+    let result = matcher.match_commands("open slaik", &commands);
+
+    // A limit below 2 is raised so the result stays a real choice
+    match result {
+        MatchResult::Ambiguous {
+            candidates,
+            truncated,
+        } => {
+            assert_eq!(candidates.len(), 2);
+            assert!(!truncated);
+        }
+        _ => panic!("Expected Ambiguous result, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_alias_matches_command() {
+    let mut cmd = create_command("open mail");
+    cmd.aliases = vec!["launch mail".to_string(), "start mail".to_string()];
+    let commands = vec![cmd.clone()];
+
+    let matcher = CommandMatcher::new();
+    match matcher.match_commands("Start mail", &commands) {
+        MatchResult::Exact { command, .. } => {
+            assert_eq!(command.id, cmd.id);
+            assert_eq!(command.trigger, "open mail");
+        }
+        other => panic!("Expected Exact match, got {:?}", other),
+    }
+
+    match matcher.match_commands("lanch mail", &commands) {
+        MatchResult::Fuzzy { command, .. } => assert_eq!(command.id, cmd.id),
+        other => panic!("Expected Fuzzy match, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_aliases_of_one_command_are_not_ambiguous() {
+    // "open mails" is close to the trigger and both aliases of one command
+    let mut cmd = create_command("open mail");
+    cmd.aliases = vec!["open male".to_string(), "open nails".to_string()];
+    let commands = vec![cmd.clone()];
+
+    let matcher = CommandMatcher::new();
+    match matcher.match_commands("open mails", &commands) {
+        MatchResult::Fuzzy { command, .. } => assert_eq!(command.id, cmd.id),
+        other => panic!("Expected a single Fuzzy match, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_alias_competes_with_other_commands() {
+    let mut mail = create_command("open mail");
+    mail.aliases = vec!["launch mail".to_string()];
+    let slack = create_command("launch slack");
+    let commands = vec![mail.clone(), slack];
+
+    let matcher = CommandMatcher::new();
+    match matcher.match_commands("launch mail", &commands) {
+        MatchResult::Exact { command, .. } => assert_eq!(command.id, mail.id),
+        other => panic!("Expected Exact match, got {:?}", other),
+    }
+}
+
+fn matcher_with(normalization: NormalizationOptions) -> CommandMatcher {
+    CommandMatcher::with_config(MatcherConfig {
+        normalization,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_case_sensitive_when_lowercase_disabled() {
+    let commands = vec![create_command("open Slack")];
+    let matcher = matcher_with(NormalizationOptions {
+        lowercase: false,
+        ..Default::default()
+    });
+
+    assert!(matches!(
+        matcher.match_commands("open Slack", &commands),
+        MatchResult::Exact { .. }
+    ));
+    assert!(!matches!(
+        matcher.match_commands("OPEN SLACK", &commands),
+        MatchResult::Exact { .. }
+    ));
+}
+
+#[test]
+fn test_accents_stripped_when_enabled() {
+    let commands = vec![create_command("open café notes")];
+
+    let default_matcher = CommandMatcher::new();
+    assert!(!matches!(
+        default_matcher.match_commands("open cafe notes", &commands),
+        MatchResult::Exact { .. }
+    ));
+
+    let matcher = matcher_with(NormalizationOptions {
+        strip_accents: true,
+        ..Default::default()
+    });
+    assert!(matches!(
+        matcher.match_commands("Open Cafe Notes", &commands),
+        MatchResult::Exact { .. }
+    ));
+    // Decomposed input matches a precomposed trigger too
+    assert!(matches!(
+        matcher.match_commands("open cafe\u{301} notes", &commands),
+        MatchResult::Exact { .. }
+    ));
+}
+
+#[test]
+fn test_punctuation_stripped_when_enabled() {
+    let commands = vec![create_command("open slack")];
+    let matcher = matcher_with(NormalizationOptions {
+        strip_punctuation: true,
+        ..Default::default()
+    });
+
+    assert!(matches!(
+        matcher.match_commands("Open Slack!", &commands),
+        MatchResult::Exact { .. }
+    ));
+}
+
+#[test]
+fn test_parameter_keeps_original_text_after_normalized_prefix() {
+    let mut cmd = create_command("écris {text}");
+    cmd.action_type = ActionType::TypeText;
+    let commands = vec![cmd];
+    let matcher = matcher_with(NormalizationOptions {
+        strip_accents: true,
+        ..Default::default()
+    });
+
+    match matcher.match_commands("Ecris Crème Brûlée", &commands) {
+        MatchResult::Exact { parameters, .. } => {
+            assert_eq!(parameters.get("text"), Some(&"Crème Brûlée".to_string()));
+        }
+        result => panic!("Expected Exact match with parameters, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_normalization_options_apply() {
+    let all = NormalizationOptions {
+        lowercase: true,
+        strip_accents: true,
+        strip_punctuation: true,
+    };
+    assert_eq!(all.apply("  Ça, Marche!  "), "ca marche");
+    let default = NormalizationOptions::default();
+    assert_eq!(default.apply(" Ça, Marche! "), "ça, marche!");
+}
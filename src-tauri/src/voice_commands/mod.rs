@@ -5,7 +5,10 @@
 #![cfg_attr(coverage_nightly, coverage(off))]
 
 pub mod actions;
+pub mod confirmation;
+pub mod disambiguation;
 pub mod executor;
+pub mod last_command;
 pub mod matcher;
 pub mod registry;
 
@@ -28,6 +31,12 @@ pub struct CommandDto {
     pub action_type: String,
     pub parameters: HashMap<String, String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub requires_confirmation: bool,
 }
 
 impl From<&CommandDefinition> for CommandDto {
@@ -44,6 +53,9 @@ impl From<&CommandDefinition> for CommandDto {
             action_type: action_type.to_string(),
             parameters: cmd.parameters.clone(),
             enabled: cmd.enabled,
+            priority: cmd.priority,
+            aliases: cmd.aliases.clone(),
+            requires_confirmation: cmd.requires_confirmation,
         }
     }
 }
@@ -61,6 +73,12 @@ pub struct AddCommandInput {
     pub action_type: String,
     pub parameters: HashMap<String, String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub requires_confirmation: bool,
 }
 
 /// Input for updating an existing command
@@ -71,6 +89,12 @@ pub struct UpdateCommandInput {
     pub action_type: String,
     pub parameters: HashMap<String, String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub requires_confirmation: bool,
 }
 
 /// Map RegistryError to user-friendly error messages
@@ -109,6 +133,9 @@ pub async fn add_command(
         action_type,
         parameters: input.parameters,
         enabled: input.enabled,
+        priority: input.priority,
+        aliases: input.aliases,
+        requires_confirmation: input.requires_confirmation,
     };
 
     turso_client
@@ -159,6 +186,9 @@ pub async fn update_command(
         action_type,
         parameters: input.parameters,
         enabled: input.enabled,
+        priority: input.priority,
+        aliases: input.aliases,
+        requires_confirmation: input.requires_confirmation,
     };
 
     turso_client
@@ -172,3 +202,27 @@ pub async fn update_command(
     crate::info!("Updated voice command: {}", input.id);
     Ok(CommandDto::from(&cmd))
 }
+
+/// Get aggregate command match statistics
+///
+/// Counts match attempts by outcome (exact, fuzzy, ambiguous, no_match) with
+/// their average confidence. `since` is an RFC 3339 timestamp; when given,
+/// only attempts from that time onwards are counted.
+#[tauri::command]
+pub async fn get_match_stats(
+    turso_client: tauri::State<'_, TursoClientState>,
+    since: Option<String>,
+) -> Result<crate::turso::MatchStats, String> {
+    let since = since
+        .map(|value| {
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .map(|time| time.with_timezone(&chrono::Utc).to_rfc3339())
+                .map_err(|e| format!("Invalid 'since' timestamp: {}", e))
+        })
+        .transpose()?;
+
+    turso_client
+        .get_match_stats(since.as_deref())
+        .await
+        .map_err(|e| format!("Failed to get match stats: {}", e))
+}
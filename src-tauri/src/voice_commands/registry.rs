@@ -2,8 +2,10 @@
 //
 // Commands are stored in Turso. Use TursoClient for all CRUD and queries.
 
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 use uuid::Uuid;
 
 /// Type of action to execute when a command matches
@@ -47,6 +49,15 @@ pub struct CommandDefinition {
     pub parameters: HashMap<String, String>,
     /// Whether the command is enabled
     pub enabled: bool,
+    /// Priority for breaking ties between equally good matches (higher wins)
+    #[serde(default)]
+    pub priority: i32,
+    /// Alternative phrases that also trigger the command (e.g., "launch slack")
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Whether the command waits for the user to confirm it before running
+    #[serde(default)]
+    pub requires_confirmation: bool,
 }
 
 /// Error types for voice command operations
@@ -74,3 +85,35 @@ impl std::fmt::Display for RegistryError {
 }
 
 impl std::error::Error for RegistryError {}
+
+/// Compiled regex triggers, keyed by pattern
+///
+/// Regex triggers are tried against every transcription, so each pattern is
+/// compiled once. Patterns that fail to compile are cached as `None`.
+static TRIGGER_REGEXES: OnceLock<Mutex<HashMap<String, Option<Regex>>>> = OnceLock::new();
+
+/// Get the compiled regex for a regex trigger pattern
+///
+/// The pattern must match the whole input and is case-insensitive. Returns
+/// None if the pattern is not a valid regular expression.
+pub fn compiled_trigger_regex(pattern: &str) -> Option<Regex> {
+    let cache = TRIGGER_REGEXES.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut cache = cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    cache
+        .entry(pattern.to_string())
+        .or_insert_with(|| {
+            match RegexBuilder::new(&format!("^(?:{})$", pattern))
+                .case_insensitive(true)
+                .build()
+            {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    crate::warn!("Invalid regex trigger '{}': {}", pattern, e);
+                    None
+                }
+            }
+        })
+        .clone()
+}
@@ -13,6 +13,9 @@ fn create_test_command(trigger: &str) -> CommandDefinition {
         action_type: ActionType::OpenApp,
         parameters: HashMap::new(),
         enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
     }
 }
 
@@ -38,6 +41,9 @@ fn test_command_definition_with_parameters() {
         action_type: ActionType::OpenApp,
         parameters: params.clone(),
         enabled: true,
+        priority: 0,
+        aliases: Vec::new(),
+        requires_confirmation: false,
     };
 
     // Verify parameters are stored correctly
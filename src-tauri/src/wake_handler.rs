@@ -9,8 +9,11 @@
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
+use parking_lot::Mutex;
+
 use tauri::{AppHandle, Emitter};
 
+use crate::events::model_events;
 use crate::parakeet::SharedTranscriptionModel;
 
 /// Static storage for the wake handler state.
@@ -21,7 +24,8 @@ static WAKE_HANDLER: OnceLock<WakeHandlerState> = OnceLock::new();
 struct WakeHandlerState {
     app_handle: AppHandle,
     shared_model: SharedTranscriptionModel,
-    model_path: PathBuf,
+    /// Updated when the user switches model directories at runtime
+    model_path: Mutex<PathBuf>,
 }
 
 // Ensure WakeHandlerState is Send + Sync for static storage
@@ -46,7 +50,7 @@ pub fn init_wake_handler(
     let state = WakeHandlerState {
         app_handle,
         shared_model,
-        model_path,
+        model_path: Mutex::new(model_path),
     };
 
     if WAKE_HANDLER.set(state).is_err() {
@@ -59,6 +63,16 @@ pub fn init_wake_handler(
     crate::info!("Wake handler initialized - listening for system wake events");
 }
 
+/// Update the model directory reloaded on system wake.
+///
+/// No-op if the wake handler has not been initialized (e.g., the model was
+/// not present at startup).
+pub fn set_model_path(model_path: PathBuf) {
+    if let Some(state) = WAKE_HANDLER.get() {
+        *state.model_path.lock() = model_path;
+    }
+}
+
 /// Callback invoked when the system wakes from sleep.
 ///
 /// This is called from the Swift layer via FFI. It spawns an async task
@@ -74,7 +88,7 @@ extern "C" fn on_system_wake() {
 
     let app_handle = state.app_handle.clone();
     let shared_model = state.shared_model.clone();
-    let model_path = state.model_path.clone();
+    let model_path = state.model_path.lock().clone();
 
     // Spawn async task to reload the model
     // We use tauri's async runtime to avoid blocking the callback
@@ -103,7 +117,7 @@ async fn reload_model_async(
     model_path: PathBuf,
 ) {
     // Emit reloading event
-    if let Err(e) = app_handle.emit("model_reloading", ()) {
+    if let Err(e) = app_handle.emit(model_events::MODEL_RELOADING, ()) {
         crate::warn!("Failed to emit model_reloading event: {}", e);
     }
 
@@ -163,21 +177,21 @@ async fn reload_model_async(
     match result {
         Ok(Ok(())) => {
             crate::info!("Model reloaded successfully after system wake");
-            if let Err(e) = app_handle.emit("model_reloaded", ()) {
+            if let Err(e) = app_handle.emit(model_events::MODEL_RELOADED, ()) {
                 crate::warn!("Failed to emit model_reloaded event: {}", e);
             }
         }
         Ok(Err(e)) => {
             let error_msg = format!("Failed to reload model: {}", e);
             crate::error!("{}", error_msg);
-            if let Err(e) = app_handle.emit("model_reload_failed", &error_msg) {
+            if let Err(e) = app_handle.emit(model_events::MODEL_RELOAD_FAILED, &error_msg) {
                 crate::warn!("Failed to emit model_reload_failed event: {}", e);
             }
         }
         Err(e) => {
             let error_msg = format!("Model reload task panicked: {}", e);
             crate::error!("{}", error_msg);
-            if let Err(e) = app_handle.emit("model_reload_failed", &error_msg) {
+            if let Err(e) = app_handle.emit(model_events::MODEL_RELOAD_FAILED, &error_msg) {
                 crate::warn!("Failed to emit model_reload_failed event: {}", e);
             }
         }
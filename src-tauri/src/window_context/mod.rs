@@ -11,4 +11,7 @@ pub use detector::{get_active_window, get_running_applications};
 pub use monitor::{MonitorConfig, WindowMonitor};
 pub use resolver::ContextResolver;
 pub use store::WindowContextStoreError;
-pub use types::{ActiveWindowInfo, OverrideMode, RunningApplication, WindowContext, WindowMatcher};
+pub use types::{
+    ActiveWindowInfo, DetectedWindow, OverrideMode, RunningApplication, WindowContext,
+    WindowMatcher,
+};
@@ -10,6 +10,7 @@
 
 use super::{OverrideMode, WindowMonitor};
 use crate::dictionary::DictionaryEntry;
+use crate::transcription::transforms::TextTransforms;
 use crate::turso::TursoClient;
 use crate::voice_commands::registry::CommandDefinition;
 use std::sync::{Arc, Mutex};
@@ -425,6 +426,58 @@ impl ContextResolver {
         }
     }
 
+    /// Get the text transforms configured on the active context, if any
+    ///
+    /// Returns None when no context is active, the context can't be loaded, or
+    /// it doesn't override the global transforms.
+    pub async fn get_context_text_transforms_async(&self) -> Option<TextTransforms> {
+        let context_id = match self.monitor.lock() {
+            Ok(monitor) => monitor.get_current_context()?,
+            Err(_) => {
+                crate::warn!("[ContextResolver] Failed to lock monitor, using global text transforms");
+                return None;
+            }
+        };
+
+        match self.client.get_window_context(context_id).await {
+            Ok(context) => context.and_then(|ctx| ctx.text_transforms),
+            Err(e) => {
+                crate::warn!(
+                    "[ContextResolver] Failed to get context from Turso: {}, using global text transforms",
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Whether the active context disables dictation (record only)
+    ///
+    /// Returns false when no context is active or the context can't be loaded.
+    pub async fn is_dictation_disabled_async(&self) -> bool {
+        let context_id = match self.monitor.lock() {
+            Ok(monitor) => match monitor.get_current_context() {
+                Some(id) => id,
+                None => return false,
+            },
+            Err(_) => {
+                crate::warn!("[ContextResolver] Failed to lock monitor, assuming dictation is enabled");
+                return false;
+            }
+        };
+
+        match self.client.get_window_context(context_id).await {
+            Ok(context) => context.is_some_and(|ctx| ctx.dictation_disabled),
+            Err(e) => {
+                crate::warn!(
+                    "[ContextResolver] Failed to get context from Turso: {}, assuming dictation is enabled",
+                    e
+                );
+                false
+            }
+        }
+    }
+
     /// Async version of get_effective_commands for use in async contexts.
     ///
     /// This avoids the runtime panic that occurs when calling the sync version
@@ -1,5 +1,6 @@
 // Window context types for context-sensitive commands
 
+use crate::transcription::transforms::TextTransforms;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -13,6 +14,40 @@ pub struct ActiveWindowInfo {
     pub pid: u32,
 }
 
+/// What active-window detection sees right now, field by field
+///
+/// Unlike `ActiveWindowInfo`, every field is optional so a field the OS can't
+/// provide comes back as null instead of failing the whole lookup. The strings
+/// are exactly what window matchers are compared against.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedWindow {
+    pub app_name: Option<String>,
+    pub bundle_id: Option<String>,
+    pub window_title: Option<String>,
+    /// Why nothing was detected, when detection failed outright
+    pub error: Option<String>,
+}
+
+impl DetectedWindow {
+    /// Build from a detection result, turning empty strings into nulls
+    pub fn from_detection(result: Result<ActiveWindowInfo, String>) -> Self {
+        let non_empty = |value: Option<String>| value.filter(|v| !v.is_empty());
+        match result {
+            Ok(info) => Self {
+                app_name: non_empty(Some(info.app_name)),
+                bundle_id: non_empty(info.bundle_id),
+                window_title: non_empty(info.window_title),
+                error: None,
+            },
+            Err(error) => Self {
+                error: Some(error),
+                ..Self::default()
+            },
+        }
+    }
+}
+
 /// Information about a running application
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -56,6 +91,13 @@ pub struct WindowContext {
     pub dictionary_entry_ids: Vec<String>,
     pub enabled: bool,
     pub priority: i32,
+    /// Text transforms replacing the global ones while this context is active
+    #[serde(default)]
+    pub text_transforms: Option<TextTransforms>,
+    /// Record only: transcriptions are stored but never matched against
+    /// commands or sent to the output sinks while this context is active
+    #[serde(default)]
+    pub dictation_disabled: bool,
 }
 
 #[cfg(test)]
@@ -4,3 +4,42 @@ use super::*;
 fn override_mode_defaults_to_merge() {
     assert_eq!(OverrideMode::default(), OverrideMode::Merge);
 }
+
+#[test]
+fn dictation_disabled_defaults_to_false_when_missing() {
+    let json = r#"{
+        "id": "6f1e9a34-5d2b-4c1a-9f3e-2b7c8d9e0a1b",
+        "name": "Terminal",
+        "matcher": {"appName": "Terminal"},
+        "commandMode": "merge",
+        "dictionaryMode": "merge",
+        "commandIds": [],
+        "dictionaryEntryIds": [],
+        "enabled": true,
+        "priority": 0
+    }"#;
+    let context: WindowContext = serde_json::from_str(json).unwrap();
+    assert!(!context.dictation_disabled);
+}
+
+#[test]
+fn detected_window_nulls_missing_fields() {
+    let detected = DetectedWindow::from_detection(Ok(ActiveWindowInfo {
+        app_name: "Terminal".to_string(),
+        bundle_id: Some("com.apple.Terminal".to_string()),
+        window_title: Some(String::new()),
+        pid: 42,
+    }));
+    assert_eq!(detected.app_name.as_deref(), Some("Terminal"));
+    assert_eq!(detected.bundle_id.as_deref(), Some("com.apple.Terminal"));
+    assert_eq!(detected.window_title, None);
+    assert_eq!(detected.error, None);
+}
+
+#[test]
+fn detected_window_reports_failed_detection() {
+    let detected = DetectedWindow::from_detection(Err("No frontmost application".to_string()));
+    assert_eq!(detected.app_name, None);
+    assert_eq!(detected.bundle_id, None);
+    assert_eq!(detected.error.as_deref(), Some("No frontmost application"));
+}
@@ -107,3 +107,127 @@ public func getDeviceIsDefault(index: Int) -> Bool {
     }
     return cachedDevices[index].isDefault
 }
+
+/// Check whether the app has been granted microphone access.
+/// Does not prompt; returns false when access is denied, restricted or undetermined.
+@_cdecl("swift_microphone_permission_granted")
+public func microphonePermissionGranted() -> Bool {
+    return AVCaptureDevice.authorizationStatus(for: .audio) == .authorized
+}
+
+/// Find the AudioDeviceID of the input device with the given name.
+/// An empty name selects the system default input device.
+private func getInputDeviceId(named deviceName: String) -> AudioDeviceID? {
+    if deviceName.isEmpty {
+        return getDefaultInputDeviceId()
+    }
+
+    let discoverySession = AVCaptureDevice.DiscoverySession(
+        deviceTypes: [.microphone, .builtInMicrophone, .externalUnknown],
+        mediaType: .audio,
+        position: .unspecified
+    )
+    guard let captureDevice = discoverySession.devices.first(where: { $0.localizedName == deviceName }) else {
+        return nil
+    }
+    return getAudioDeviceId(for: captureDevice.uniqueID)
+}
+
+/// Read a fixed-size device property, returning nil if the query fails.
+private func getDeviceProperty<T>(_ deviceId: AudioDeviceID, _ selector: AudioObjectPropertySelector, _ initial: T) -> T? {
+    var propertyAddress = AudioObjectPropertyAddress(
+        mSelector: selector,
+        mScope: kAudioObjectPropertyScopeGlobal,
+        mElement: kAudioObjectPropertyElementMain
+    )
+
+    var value = initial
+    var size = UInt32(MemoryLayout<T>.size)
+    let status = AudioObjectGetPropertyData(deviceId, &propertyAddress, 0, nil, &size, &value)
+
+    return status == noErr ? value : nil
+}
+
+/// Read an object-list property (e.g. process or device IDs), returning nil if the query fails.
+private func getObjectListProperty(
+    _ objectId: AudioObjectID,
+    _ selector: AudioObjectPropertySelector,
+    scope: AudioObjectPropertyScope = kAudioObjectPropertyScopeGlobal
+) -> [AudioObjectID]? {
+    var propertyAddress = AudioObjectPropertyAddress(
+        mSelector: selector,
+        mScope: scope,
+        mElement: kAudioObjectPropertyElementMain
+    )
+
+    var size: UInt32 = 0
+    guard AudioObjectGetPropertyDataSize(objectId, &propertyAddress, 0, nil, &size) == noErr else {
+        return nil
+    }
+    var ids = [AudioObjectID](repeating: 0, count: Int(size) / MemoryLayout<AudioObjectID>.size)
+    if ids.isEmpty {
+        return []
+    }
+    let status = AudioObjectGetPropertyData(objectId, &propertyAddress, 0, nil, &size, &ids)
+
+    return status == noErr ? ids : nil
+}
+
+/// Whether a process other than heycat is capturing from the device.
+///
+/// Uses the per-process audio objects, so our own engine is never counted.
+/// Returns nil if the process list can't be read.
+@available(macOS 14.2, *)
+private func otherProcessIsCapturing(from deviceId: AudioDeviceID) -> Bool? {
+    guard let processes = getObjectListProperty(
+        AudioObjectID(kAudioObjectSystemObject),
+        kAudioHardwarePropertyProcessObjectList
+    ) else {
+        return nil
+    }
+
+    let ownPid = getpid()
+    return processes.contains { process in
+        guard let pid = getDeviceProperty(process, kAudioProcessPropertyPID, pid_t(-1)),
+              pid != -1 && pid != ownPid else {
+            return false
+        }
+        guard (getDeviceProperty(process, kAudioProcessPropertyIsRunningInput, UInt32(0)) ?? 0) != 0 else {
+            return false
+        }
+        let devices = getObjectListProperty(process, kAudioProcessPropertyDevices, scope: kAudioObjectPropertyScopeInput)
+        return devices?.contains(deviceId) ?? false
+    }
+}
+
+/// Best-effort check whether another app is using the input device.
+///
+/// Busy means another process holds the device exclusively (hog mode) or is
+/// capturing from it. On macOS 14.2+ capturing processes are listed per
+/// process, excluding heycat's own engine. On older systems the device's
+/// running state is used, which can't tell other clients from our engine,
+/// so while the engine runs only hog mode counts.
+/// - Parameter deviceName: Device name, or empty for the system default
+/// - Returns: true if the device appears to be in use by another app
+@_cdecl("swift_is_microphone_busy")
+public func isMicrophoneBusy(deviceName: SRString) -> Bool {
+    guard let deviceId = getInputDeviceId(named: deviceName.toString()) else {
+        return false
+    }
+
+    if let hogPid = getDeviceProperty(deviceId, kAudioDevicePropertyHogMode, pid_t(-1)),
+       hogPid != -1 && hogPid != getpid() {
+        return true
+    }
+
+    if #available(macOS 14.2, *), let capturing = otherProcessIsCapturing(from: deviceId) {
+        return capturing
+    }
+
+    if audioEngineIsRunning() {
+        return false
+    }
+
+    let runningSomewhere = getDeviceProperty(deviceId, kAudioDevicePropertyDeviceIsRunningSomewhere, UInt32(0))
+    return (runningSomewhere ?? 0) != 0
+}
@@ -18,6 +18,34 @@ private class SharedAudioEngineManager {
 
     private let targetSampleRate: Double = 16000.0
 
+    // Resampler diagnostics for the current input device
+    private var sourceSampleRate: Double = 0
+    private var sourceChannelCount: UInt32 = 0
+    // Output frames the converter withheld on its first buffer (-1 until measured)
+    private var resamplerLatencyFrames: Int = -1
+    // I/O buffer size reported by the input device (0 if unknown)
+    private var deviceBufferFrames: UInt32 = 0
+
+    // Tap buffer (resample chunk) size bounds; mirror the Rust resample_chunk_size
+    private let defaultTapBufferFrames: AVAudioFrameCount = 1024
+    private let minTapBufferFrames: AVAudioFrameCount = 256
+    private let maxTapBufferFrames: AVAudioFrameCount = 4096
+    // Software gain applied to input samples before conversion (1.0 = unchanged)
+    private var inputGain: Float = 1.0
+    // Input is replaced with silence until this uptime (e.g. while a start cue plays)
+    private var inputMutedUntilNanos: UInt64 = 0
+    // Channel taken from multi-channel input (0 mix, 1 left, 2 right, 3 auto)
+    private var channelSelection: Int = 0
+    // Switch the device to 16kHz when it supports it, so no conversion is needed
+    private var preferNativeSampleRate = false
+    // Device and nominal rate it ran at before being switched to 16kHz, restored on stop
+    private var replacedNominalSampleRate: (deviceId: AudioDeviceID, rate: Float64)?
+    // Sample format of the capture file (16 int, 32 float); samples are float32 either way
+    private var wavBitDepth: Int = 16
+    // Per-channel energy for "auto", accumulated over the first second after (re)start
+    private var autoChannelEnergy: [Double] = [0, 0]
+    private var autoChannelFrames: Int = 0
+
     // File-based capture (replaces in-memory array to avoid dropped samples)
     private var captureFile: AVAudioFile?
     private var captureFileURL: URL?
@@ -73,6 +101,10 @@ private class SharedAudioEngineManager {
                     Thread.sleep(forTimeInterval: 0.2)
                 }
 
+                if preferNativeSampleRate {
+                    selectNativeSampleRate(inputNode: inputNode)
+                }
+
                 // Remove any existing tap
                 inputNode.removeTap(onBus: 0)
 
@@ -100,7 +132,9 @@ private class SharedAudioEngineManager {
                 }
 
                 // Install tap with explicit format to avoid race condition with device changes
-                let bufferSize: AVAudioFrameCount = 1024
+                // Size the tap from the device's own I/O buffer so chunks track its callbacks
+                let reportedBufferFrames = deviceBufferFrameSize(inputNode: inputNode)
+                let bufferSize = tapBufferSize(deviceBufferFrames: reportedBufferFrames)
 
                 // Pre-initialize converter at engine start (not lazily on first buffer)
                 // This moves expensive initialization off the real-time audio thread
@@ -108,6 +142,13 @@ private class SharedAudioEngineManager {
                     (inputFormat.sampleRate != targetSampleRate || inputFormat.channelCount != 1)
                     ? AVAudioConverter(from: inputFormat, to: outputFormat)
                     : nil
+                resetResamplerInfo(
+                    sourceSampleRate: inputFormat.sampleRate,
+                    sourceChannelCount: inputFormat.channelCount,
+                    deviceBufferFrames: reportedBufferFrames,
+                    resampling: converter != nil
+                )
+                resetAutoChannel()
 
                 inputNode.installTap(onBus: 0, bufferSize: bufferSize, format: inputFormat) { [weak self] buffer, _ in
                     guard let self = self else { return }
@@ -147,6 +188,7 @@ private class SharedAudioEngineManager {
         audioEngine?.inputNode.removeTap(onBus: 0)
         audioEngine?.stop()
         audioEngine = nil
+        restoreNominalSampleRate()
 
         isRunning = false
 
@@ -203,6 +245,10 @@ private class SharedAudioEngineManager {
                 Thread.sleep(forTimeInterval: 0.2)
             }
 
+            if preferNativeSampleRate {
+                selectNativeSampleRate(inputNode: inputNode)
+            }
+
             inputNode.removeTap(onBus: 0)
 
             // Query the actual hardware format AFTER device change has propagated
@@ -227,7 +273,9 @@ private class SharedAudioEngineManager {
             }
 
             // Install tap with explicit format to avoid race condition with device changes
-            let bufferSize: AVAudioFrameCount = 1024
+            // Size the tap from the device's own I/O buffer so chunks track its callbacks
+            let reportedBufferFrames = deviceBufferFrameSize(inputNode: inputNode)
+            let bufferSize = tapBufferSize(deviceBufferFrames: reportedBufferFrames)
 
             // Pre-initialize converter at engine start (not lazily on first buffer)
             // This moves expensive initialization off the real-time audio thread
@@ -235,6 +283,13 @@ private class SharedAudioEngineManager {
                 (inputFormat.sampleRate != targetSampleRate || inputFormat.channelCount != 1)
                 ? AVAudioConverter(from: inputFormat, to: outputFormat)
                 : nil
+            resetResamplerInfo(
+                sourceSampleRate: inputFormat.sampleRate,
+                sourceChannelCount: inputFormat.channelCount,
+                deviceBufferFrames: reportedBufferFrames,
+                resampling: converter != nil
+            )
+            resetAutoChannel()
 
             inputNode.installTap(onBus: 0, bufferSize: bufferSize, format: inputFormat) { [weak self] buffer, _ in
                 guard let self = self else { return }
@@ -314,8 +369,19 @@ private class SharedAudioEngineManager {
                 return false
             }
 
+            // The file stores the configured format; AVAudioFile converts the float32 buffers on write
+            var fileSettings = format.settings
+            fileSettings[AVLinearPCMBitDepthKey] = wavBitDepth
+            fileSettings[AVLinearPCMIsFloatKey] = wavBitDepth == 32
+            fileSettings[AVLinearPCMIsNonInterleaved] = false
+
             do {
-                captureFile = try AVAudioFile(forWriting: fileURL, settings: format.settings)
+                captureFile = try AVAudioFile(
+                    forWriting: fileURL,
+                    settings: fileSettings,
+                    commonFormat: .pcmFormatFloat32,
+                    interleaved: false
+                )
                 captureFileURL = fileURL
                 captureFormat = format
                 isCapturing = true
@@ -359,6 +425,10 @@ private class SharedAudioEngineManager {
     private func processAudioBuffer(_ buffer: AVAudioPCMBuffer, converter: AVAudioConverter?, outputFormat: AVAudioFormat) {
         guard isRunning else { return }
 
+        applyInputGain(buffer)
+        applyChannelSelection(buffer)
+        applyInputMute(buffer)
+
         // Convert buffer if needed (for both level monitoring and capture)
         let processBuffer: AVAudioPCMBuffer?
 
@@ -379,6 +449,14 @@ private class SharedAudioEngineManager {
 
             converter.convert(to: convertedBuffer, error: &error, withInputFrom: inputBlock)
 
+            if error == nil {
+                measureResamplerLatency(
+                    inputFrames: buffer.frameLength,
+                    outputFrames: convertedBuffer.frameLength,
+                    inputRate: buffer.format.sampleRate
+                )
+            }
+
             if error != nil || convertedBuffer.frameLength == 0 {
                 return
             }
@@ -439,6 +517,268 @@ private class SharedAudioEngineManager {
         stateLock.unlock()
     }
 
+    /// Multiply input samples by the configured gain in place, clamped to [-1, 1].
+    private func applyInputGain(_ buffer: AVAudioPCMBuffer) {
+        stateLock.lock()
+        let gain = inputGain
+        stateLock.unlock()
+
+        guard gain != 1.0, let channelData = buffer.floatChannelData else { return }
+        let frameCount = Int(buffer.frameLength)
+        for ch in 0..<Int(buffer.format.channelCount) {
+            for i in 0..<frameCount {
+                channelData[ch][i] = min(max(channelData[ch][i] * gain, -1.0), 1.0)
+            }
+        }
+    }
+
+    /// Copy the selected channel over the others so the converter's downmix
+    /// keeps its full level. Mono input and "mix" are left untouched.
+    private func applyChannelSelection(_ buffer: AVAudioPCMBuffer) {
+        let channelCount = Int(buffer.format.channelCount)
+        guard channelCount >= 2, let channelData = buffer.floatChannelData else { return }
+        let frameCount = Int(buffer.frameLength)
+
+        stateLock.lock()
+        let selection = channelSelection
+        let deciding = selection == 3 && Double(autoChannelFrames) < sourceSampleRate
+        stateLock.unlock()
+
+        guard selection != 0 else { return }
+
+        // Sum the energy of the first two channels outside the lock so the
+        // audio thread doesn't hold it while looping over the buffer
+        var bufferEnergy: [Double] = [0, 0]
+        if deciding {
+            for ch in 0..<2 {
+                for i in 0..<frameCount {
+                    let sample = Double(channelData[ch][i])
+                    bufferEnergy[ch] += sample * sample
+                }
+            }
+        }
+
+        var source = 0
+        switch selection {
+        case 2:
+            source = 1
+        case 3:
+            // Pick the louder of the first two channels; fixed after one second
+            stateLock.lock()
+            if deciding {
+                autoChannelEnergy[0] += bufferEnergy[0]
+                autoChannelEnergy[1] += bufferEnergy[1]
+                autoChannelFrames += frameCount
+            }
+            source = autoChannelEnergy[1] > autoChannelEnergy[0] ? 1 : 0
+            stateLock.unlock()
+        default:
+            break
+        }
+
+        for ch in 0..<channelCount where ch != source {
+            channelData[ch].update(from: channelData[source], count: frameCount)
+        }
+    }
+
+    /// Silence the buffer while input is muted, so a cue playing on the
+    /// speakers doesn't end up in the recording.
+    private func applyInputMute(_ buffer: AVAudioPCMBuffer) {
+        stateLock.lock()
+        let mutedUntil = inputMutedUntilNanos
+        stateLock.unlock()
+
+        guard DispatchTime.now().uptimeNanoseconds < mutedUntil,
+              let channelData = buffer.floatChannelData else { return }
+        let frameCount = Int(buffer.frameLength)
+        for ch in 0..<Int(buffer.format.channelCount) {
+            channelData[ch].update(repeating: 0, count: frameCount)
+        }
+    }
+
+    /// Forget the "auto" channel decision so it is re-made for a new device.
+    private func resetAutoChannel() {
+        stateLock.lock()
+        autoChannelEnergy = [0, 0]
+        autoChannelFrames = 0
+        stateLock.unlock()
+    }
+
+    func setChannelSelection(_ selection: Int) {
+        stateLock.lock()
+        channelSelection = selection
+        autoChannelEnergy = [0, 0]
+        autoChannelFrames = 0
+        stateLock.unlock()
+    }
+
+    func setInputGain(_ gain: Float) {
+        stateLock.lock()
+        inputGain = gain
+        stateLock.unlock()
+    }
+
+    func muteInput(milliseconds: Int) {
+        let until = DispatchTime.now().uptimeNanoseconds + UInt64(max(milliseconds, 0)) * 1_000_000
+        stateLock.lock()
+        inputMutedUntilNanos = until
+        stateLock.unlock()
+    }
+
+    /// Takes effect the next time the engine starts or switches device.
+    func setPreferNativeSampleRate(_ enabled: Bool) {
+        audioQueue.sync {
+            preferNativeSampleRate = enabled
+        }
+    }
+
+    /// Takes effect the next time capture starts.
+    func setWavBitDepth(_ bits: Int) {
+        audioQueue.sync {
+            wavBitDepth = bits == 32 ? 32 : 16
+        }
+    }
+
+    /// Record the new input format and buffer size and forget the previous latency measurement.
+    private func resetResamplerInfo(
+        sourceSampleRate: Double,
+        sourceChannelCount: UInt32,
+        deviceBufferFrames: UInt32?,
+        resampling: Bool
+    ) {
+        stateLock.lock()
+        self.sourceSampleRate = sourceSampleRate
+        self.sourceChannelCount = sourceChannelCount
+        self.deviceBufferFrames = deviceBufferFrames ?? 0
+        resamplerLatencyFrames = resampling ? -1 : 0
+        stateLock.unlock()
+    }
+
+    /// Tap buffer size for the device's reported I/O buffer size, bounded to
+    /// [minTapBufferFrames, maxTapBufferFrames]; the default when it is unknown.
+    private func tapBufferSize(deviceBufferFrames: UInt32?) -> AVAudioFrameCount {
+        guard let frames = deviceBufferFrames, frames > 0 else {
+            return defaultTapBufferFrames
+        }
+        return min(max(frames, minTapBufferFrames), maxTapBufferFrames)
+    }
+
+    /// Read the I/O buffer size of the device behind the input node.
+    private func deviceBufferFrameSize(inputNode: AVAudioInputNode) -> UInt32? {
+        guard let deviceId = currentDeviceId(inputNode: inputNode) else { return nil }
+
+        var bufferSizeAddress = AudioObjectPropertyAddress(
+            mSelector: kAudioDevicePropertyBufferFrameSize,
+            mScope: kAudioObjectPropertyScopeGlobal,
+            mElement: kAudioObjectPropertyElementMain
+        )
+        var frames: UInt32 = 0
+        var framesSize = UInt32(MemoryLayout<UInt32>.size)
+        let status = AudioObjectGetPropertyData(deviceId, &bufferSizeAddress, 0, nil, &framesSize, &frames)
+        guard status == noErr, frames > 0 else { return nil }
+        return frames
+    }
+
+    /// Switch the device behind the input node to the target rate if it supports it.
+    /// The rate it ran at before is remembered and restored when the engine stops.
+    /// Returns false when the device can't run at 16kHz; the converter is used then.
+    @discardableResult
+    private func selectNativeSampleRate(inputNode: AVAudioInputNode) -> Bool {
+        guard let deviceId = currentDeviceId(inputNode: inputNode) else { return false }
+
+        var rateAddress = AudioObjectPropertyAddress(
+            mSelector: kAudioDevicePropertyNominalSampleRate,
+            mScope: kAudioObjectPropertyScopeGlobal,
+            mElement: kAudioObjectPropertyElementMain
+        )
+        var currentRate: Float64 = 0
+        var rateSize = UInt32(MemoryLayout<Float64>.size)
+        if AudioObjectGetPropertyData(deviceId, &rateAddress, 0, nil, &rateSize, &currentRate) == noErr,
+           currentRate == targetSampleRate {
+            return true
+        }
+
+        // Discover the rates the device supports; each entry is a min...max range
+        var availableAddress = AudioObjectPropertyAddress(
+            mSelector: kAudioDevicePropertyAvailableNominalSampleRates,
+            mScope: kAudioObjectPropertyScopeGlobal,
+            mElement: kAudioObjectPropertyElementMain
+        )
+        var dataSize: UInt32 = 0
+        guard AudioObjectGetPropertyDataSize(deviceId, &availableAddress, 0, nil, &dataSize) == noErr,
+              dataSize > 0 else { return false }
+        var ranges = [AudioValueRange](
+            repeating: AudioValueRange(),
+            count: Int(dataSize) / MemoryLayout<AudioValueRange>.size
+        )
+        guard AudioObjectGetPropertyData(deviceId, &availableAddress, 0, nil, &dataSize, &ranges) == noErr,
+              ranges.contains(where: { $0.mMinimum <= targetSampleRate && targetSampleRate <= $0.mMaximum })
+        else {
+            print("Input device doesn't support \(Int(targetSampleRate))Hz, resampling instead")
+            return false
+        }
+
+        var rate = targetSampleRate
+        let status = AudioObjectSetPropertyData(deviceId, &rateAddress, 0, nil, rateSize, &rate)
+        guard status == noErr else {
+            print("Failed to switch input device to \(Int(targetSampleRate))Hz (status \(status))")
+            return false
+        }
+        if currentRate > 0 {
+            replacedNominalSampleRate = (deviceId, currentRate)
+        }
+        // Allow Core Audio time to apply the new rate before the format is queried
+        Thread.sleep(forTimeInterval: 0.1)
+        return true
+    }
+
+    /// Put back the nominal rate a device ran at before selectNativeSampleRate switched it.
+    private func restoreNominalSampleRate() {
+        guard let replaced = replacedNominalSampleRate else { return }
+        replacedNominalSampleRate = nil
+
+        var rateAddress = AudioObjectPropertyAddress(
+            mSelector: kAudioDevicePropertyNominalSampleRate,
+            mScope: kAudioObjectPropertyScopeGlobal,
+            mElement: kAudioObjectPropertyElementMain
+        )
+        var rate = replaced.rate
+        let status = AudioObjectSetPropertyData(
+            replaced.deviceId, &rateAddress, 0, nil, UInt32(MemoryLayout<Float64>.size), &rate
+        )
+        if status != noErr {
+            print("Failed to restore input device to \(Int(replaced.rate))Hz (status \(status))")
+        }
+    }
+
+    /// Core Audio device currently behind the input node.
+    private func currentDeviceId(inputNode: AVAudioInputNode) -> AudioDeviceID? {
+        guard let audioUnit = inputNode.audioUnit else { return nil }
+
+        var deviceId = AudioDeviceID(0)
+        var deviceIdSize = UInt32(MemoryLayout<AudioDeviceID>.size)
+        let status = AudioUnitGetProperty(
+            audioUnit,
+            kAudioOutputUnitProperty_CurrentDevice,
+            kAudioUnitScope_Global,
+            0,
+            &deviceId,
+            &deviceIdSize
+        )
+        guard status == noErr, deviceId != 0 else { return nil }
+        return deviceId
+    }
+
+    /// Measure converter latency from its first buffer: the output frames it
+    /// holds back while priming are the shortfall against the ideal ratio.
+    private func measureResamplerLatency(inputFrames: AVAudioFrameCount, outputFrames: AVAudioFrameCount, inputRate: Double) {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        guard resamplerLatencyFrames < 0 else { return }
+        let expected = Int(Double(inputFrames) * (targetSampleRate / inputRate))
+        resamplerLatencyFrames = max(0, expected - Int(outputFrames))
+    }
+
     // MARK: - State Queries
 
     func getLevel() -> UInt8 {
@@ -473,6 +813,30 @@ private class SharedAudioEngineManager {
         return 0
     }
 
+    func getSourceSampleRate() -> Double {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        return isRunning ? sourceSampleRate : 0
+    }
+
+    func getSourceChannelCount() -> Int {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        return isRunning ? Int(sourceChannelCount) : 0
+    }
+
+    func getDeviceBufferFrames() -> Int {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        return isRunning ? Int(deviceBufferFrames) : 0
+    }
+
+    func getResamplerLatencyFrames() -> Int {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        return resamplerLatencyFrames
+    }
+
     func getLastError() -> String? {
         stateLock.lock()
         defer { stateLock.unlock() }
@@ -634,6 +998,62 @@ public func audioEngineGetSampleCount() -> Int {
     return SharedAudioEngineManager.shared.getSampleCount()
 }
 
+/// Get the input device's native sample rate (0 if the engine isn't running).
+@_cdecl("swift_audio_engine_get_source_sample_rate")
+public func audioEngineGetSourceSampleRate() -> Double {
+    return SharedAudioEngineManager.shared.getSourceSampleRate()
+}
+
+/// Get the resampler latency in 16kHz output frames (-1 until measured, 0 when not resampling).
+@_cdecl("swift_audio_engine_get_resampler_latency_frames")
+public func audioEngineGetResamplerLatencyFrames() -> Int {
+    return SharedAudioEngineManager.shared.getResamplerLatencyFrames()
+}
+
+/// Get the input device's I/O buffer size in frames (0 if unknown or the engine isn't running).
+@_cdecl("swift_audio_engine_get_device_buffer_frames")
+public func audioEngineGetDeviceBufferFrames() -> Int {
+    return SharedAudioEngineManager.shared.getDeviceBufferFrames()
+}
+
+/// Get the channel count of the input device (0 if the engine isn't running).
+@_cdecl("swift_audio_engine_get_source_channel_count")
+public func audioEngineGetSourceChannelCount() -> Int {
+    return SharedAudioEngineManager.shared.getSourceChannelCount()
+}
+
+/// Set the software input gain applied before resampling (1.0 = unchanged).
+@_cdecl("swift_audio_engine_set_input_gain")
+public func audioEngineSetInputGain(gain: Double) {
+    SharedAudioEngineManager.shared.setInputGain(Float(gain))
+}
+
+/// Replace input with silence for the given number of milliseconds from now.
+@_cdecl("swift_audio_engine_mute_input")
+public func audioEngineMuteInput(milliseconds: Int) {
+    SharedAudioEngineManager.shared.muteInput(milliseconds: milliseconds)
+}
+
+/// Prefer running the input device at 16kHz so capture skips resampling.
+/// Applied the next time the engine starts or switches device.
+@_cdecl("swift_audio_engine_set_prefer_native_sample_rate")
+public func audioEngineSetPreferNativeSampleRate(enabled: Bool) {
+    SharedAudioEngineManager.shared.setPreferNativeSampleRate(enabled)
+}
+
+/// Set the sample format of capture files (16 int or 32 float).
+/// Applied the next time capture starts.
+@_cdecl("swift_audio_engine_set_wav_bit_depth")
+public func audioEngineSetWavBitDepth(bits: Int) {
+    SharedAudioEngineManager.shared.setWavBitDepth(bits)
+}
+
+/// Set which channel of multi-channel input is captured (0 mix, 1 left, 2 right, 3 auto).
+@_cdecl("swift_audio_engine_set_channel_selection")
+public func audioEngineSetChannelSelection(selection: Int) {
+    SharedAudioEngineManager.shared.setChannelSelection(selection)
+}
+
 /// Get the last error message, if any.
 @_cdecl("swift_audio_engine_get_error")
 public func audioEngineGetError() -> SRString {
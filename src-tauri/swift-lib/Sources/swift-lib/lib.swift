@@ -220,3 +220,25 @@ public func registerDeviceChangeCallback(callbackPtr: UnsafeRawPointer) {
 public func unregisterDeviceChangeCallback() {
     AudioDeviceChangeManager.shared.unregisterCallback()
 }
+
+// MARK: - Screen Capture Detection
+
+/// Signature of the window server's screen-watcher query.
+private typealias ScreenWatcherPresentFn = @convention(c) () -> Bool
+
+/// CGSIsScreenWatcherPresent, looked up once at runtime.
+/// It is private API, so a missing symbol disables detection instead of failing to link.
+private let screenWatcherPresent: ScreenWatcherPresentFn? = {
+    guard let handle = dlopen(nil, RTLD_NOW),
+          let symbol = dlsym(handle, "CGSIsScreenWatcherPresent") else {
+        return nil
+    }
+    return unsafeBitCast(symbol, to: ScreenWatcherPresentFn.self)
+}()
+
+/// Check whether the screen is being captured (screen recording, screen sharing or mirroring).
+/// - Returns: true if the window server reports an active screen watcher
+@_cdecl("swift_is_screen_captured")
+public func swiftIsScreenCaptured() -> Bool {
+    screenWatcherPresent?() ?? false
+}
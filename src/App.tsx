@@ -7,9 +7,15 @@ import { invoke } from "@tauri-apps/api/core";
 import { queryClient } from "./lib/queryClient";
 import { router } from "./routes";
 import { setupEventBridge } from "./lib/eventBridge";
-import { useAppStore } from "./stores/appStore";
+import {
+  useAppStore,
+  useAccessibilityPrompt,
+  usePastePaused,
+} from "./stores/appStore";
 import { initializeSettings } from "./hooks/useSettings";
-import { ToastProvider } from "./components/overlays";
+import { ToastProvider, useToast } from "./components/overlays";
+import { useCommandConfirmation } from "./hooks/useCommandConfirmation";
+import { useInputDeviceStatus } from "./hooks/useInputDeviceStatus";
 
 /**
  * Shows the main window and closes the splash window.
@@ -45,11 +51,13 @@ async function showApp() {
  * 1. Loads settings from Tauri Store into Zustand
  * 2. Sets up the Event Bridge for backend events
  * 3. Reveals the app when initialization is complete
+ * 4. Warns once if app data is stored in a fallback location
  *
  * This is placed inside the QueryClientProvider so it has access to the query client.
  */
 function AppInitializer({ children }: { children: ReactNode }) {
   const hasRevealed = useRef(false);
+  const { toast } = useToast();
 
   useEffect(() => {
     let isMounted = true;
@@ -88,6 +96,21 @@ function AppInitializer({ children }: { children: ReactNode }) {
           hasRevealed.current = true;
           showApp();
         }
+
+        // Warn if the OS data directory was unavailable
+        try {
+          const fallbackDir = await invoke<string | null>("get_data_dir_fallback");
+          if (fallbackDir && isMounted) {
+            toast({
+              type: "warning",
+              title: "Using fallback data location",
+              description: `The system data directory is unavailable. Recordings, models and the history database are stored in ${fallbackDir}.`,
+              duration: null,
+            });
+          }
+        } catch (e) {
+          console.warn("[heycat] Failed to check data directory:", e);
+        }
       } else {
         // Component unmounted during setup - clean up immediately
         cleanupFn();
@@ -105,6 +128,113 @@ function AppInitializer({ children }: { children: ReactNode }) {
   return <>{children}</>;
 }
 
+/**
+ * Shows a toast for a voice command waiting for confirmation.
+ * The toast runs the command from its action and goes away once the command
+ * is confirmed, cancelled or times out.
+ */
+function CommandConfirmationPrompt() {
+  const { pending, confirm } = useCommandConfirmation();
+  const { toast, dismiss } = useToast();
+
+  useEffect(() => {
+    if (!pending) return;
+    const id = toast({
+      type: "warning",
+      title: "Confirm voice command",
+      description: `"${pending.trigger}" runs only if confirmed within ${Math.round(pending.timeout_ms / 1000)} seconds.`,
+      action: { label: "Run command", onClick: confirm },
+      duration: pending.timeout_ms,
+    });
+    return () => dismiss(id);
+  }, [pending, confirm, toast, dismiss]);
+
+  return null;
+}
+
+/**
+ * Shows a toast when a paste or keystroke failed for lack of Accessibility
+ * permission, with a button that opens the System Settings pane to grant it.
+ */
+function AccessibilityPermissionPrompt() {
+  const prompt = useAccessibilityPrompt();
+  const { toast, dismiss } = useToast();
+
+  useEffect(() => {
+    if (!prompt) return;
+    const { dismissAccessibilityPrompt } = useAppStore.getState();
+    const id = toast({
+      type: "warning",
+      title: "Accessibility permission required",
+      description: prompt.message,
+      action: {
+        label: "Open System Settings",
+        onClick: () => {
+          dismissAccessibilityPrompt();
+          invoke("open_accessibility_preferences").catch((e) => {
+            console.error("[heycat] Failed to open Accessibility settings:", e);
+          });
+        },
+      },
+      duration: null,
+    });
+    return () => dismiss(id);
+  }, [prompt, toast, dismiss]);
+
+  return null;
+}
+
+/**
+ * Shows that auto-paste was paused and the transcription is on the clipboard,
+ * e.g. because the screen is being recorded or shared.
+ */
+function PastePausedIndicator() {
+  const notice = usePastePaused();
+  const { toast, dismiss } = useToast();
+
+  useEffect(() => {
+    if (!notice) return;
+    const reason =
+      notice.reason === "screen_capture"
+        ? "Your screen is being recorded or shared, so"
+        : "Auto-paste is paused, so";
+    const id = toast({
+      type: "info",
+      title: "Paste paused",
+      description: `${reason} the transcription was copied to the clipboard without pasting.`,
+    });
+    return () => dismiss(id);
+  }, [notice, toast, dismiss]);
+
+  return null;
+}
+
+/**
+ * Shows a warning toast while no audio input device is present.
+ * The toast stays until a device appears, e.g. a USB mic that finished
+ * enumerating after launch.
+ */
+function NoInputDeviceWarning() {
+  const { missing } = useInputDeviceStatus();
+  const { toast, dismiss } = useToast();
+
+  useEffect(() => {
+    if (!missing) return;
+    const retry = missing.retry_interval_secs
+      ? " heycat will keep looking for one."
+      : "";
+    const id = toast({
+      type: "warning",
+      title: "No microphone found",
+      description: `Connect a microphone to record.${retry}`,
+      duration: null,
+    });
+    return () => dismiss(id);
+  }, [missing, toast, dismiss]);
+
+  return null;
+}
+
 /**
  * Root App component with provider hierarchy.
  *
@@ -119,6 +249,10 @@ function App() {
     <QueryClientProvider client={queryClient}>
       <ToastProvider>
         <AppInitializer>
+          <CommandConfirmationPrompt />
+          <NoInputDeviceWarning />
+          <AccessibilityPermissionPrompt />
+          <PastePausedIndicator />
           <RouterProvider router={router} />
         </AppInitializer>
       </ToastProvider>
@@ -1,4 +1,5 @@
 import { type HTMLAttributes, forwardRef, useState, useEffect } from "react";
+import { invoke } from "@tauri-apps/api/core";
 import { StatusPill } from "./StatusPill";
 import { useAppStatus } from "../../hooks/useAppStatus";
 
@@ -11,7 +12,7 @@ export interface ConnectedStatusPillProps
 /**
  * StatusPill connected to app state hooks.
  * Automatically derives status from useRecording, useTranscription, and useListening hooks.
- * Includes auto-incrementing duration timer when recording.
+ * Includes a duration timer when recording, synced to the backend's elapsed time.
  */
 export const ConnectedStatusPill = forwardRef<
   HTMLDivElement,
@@ -20,7 +21,7 @@ export const ConnectedStatusPill = forwardRef<
   const { status, isRecording } = useAppStatus();
   const [duration, setDuration] = useState(0);
 
-  // Auto-increment duration while recording
+  // Sync duration with the backend while recording, counting locally if unavailable
   useEffect(() => {
     if (!isRecording) {
       setDuration(0);
@@ -28,7 +29,13 @@ export const ConnectedStatusPill = forwardRef<
     }
 
     const interval = setInterval(() => {
-      setDuration((prev) => prev + 1);
+      invoke<number | null>("get_recording_elapsed")
+        .then((elapsed) => {
+          if (elapsed !== null) {
+            setDuration(Math.floor(elapsed));
+          }
+        })
+        .catch(() => setDuration((prev) => prev + 1));
     }, 1000);
 
     return () => clearInterval(interval);
@@ -62,8 +62,10 @@ export { useAudioDevices } from "./useAudioDevices";
 export { useAudioLevelMonitor } from "./useAudioLevelMonitor";
 export { useAudioPlayback } from "./useAudioPlayback";
 export { useCatOverlay } from "./useCatOverlay";
+export { useCommandConfirmation } from "./useCommandConfirmation";
 export { useDictionary } from "./useDictionary";
 export { useDisambiguation } from "./useDisambiguation";
+export { useInputDeviceStatus } from "./useInputDeviceStatus";
 export { useMultiModelStatus } from "./useMultiModelStatus";
 export { useRecording } from "./useRecording";
 export { useSettings } from "./useSettings";
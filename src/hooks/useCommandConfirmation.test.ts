@@ -0,0 +1,110 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from "vitest";
+import { renderHook, act, waitFor } from "@testing-library/react";
+import { useCommandConfirmation } from "./useCommandConfirmation";
+
+// Mock Tauri APIs
+const mockListen = vi.fn();
+const mockUnlisten = vi.fn();
+const mockInvoke = vi.fn();
+
+vi.mock("@tauri-apps/api/event", () => ({
+  listen: (...args: unknown[]) => mockListen(...args),
+}));
+
+vi.mock("@tauri-apps/api/core", () => ({
+  invoke: (...args: unknown[]) => mockInvoke(...args),
+}));
+
+const requiredPayload = {
+  transcription: "quit all apps",
+  command_id: "1",
+  trigger: "quit all apps",
+  timeout_ms: 15000,
+};
+
+type Callback = (event: { payload: unknown }) => void;
+
+describe("useCommandConfirmation", () => {
+  let callbacks: Record<string, Callback>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    callbacks = {};
+    mockListen.mockImplementation((eventName: string, callback: Callback) => {
+      callbacks[eventName] = callback;
+      return Promise.resolve(mockUnlisten);
+    });
+    mockInvoke.mockResolvedValue(undefined);
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  async function renderWithPending() {
+    const hook = renderHook(() => useCommandConfirmation());
+    await waitFor(() => {
+      expect(callbacks.command_confirmation_required).toBeDefined();
+      expect(callbacks.command_confirmation_cancelled).toBeDefined();
+    });
+    act(() => {
+      callbacks.command_confirmation_required({ payload: requiredPayload });
+    });
+    return hook;
+  }
+
+  it("tracks the command waiting for confirmation", async () => {
+    const { result } = await renderWithPending();
+
+    expect(result.current.pending).toEqual(requiredPayload);
+  });
+
+  it("clears the pending command when it is cancelled", async () => {
+    const { result } = await renderWithPending();
+
+    act(() => {
+      callbacks.command_confirmation_cancelled({
+        payload: { command_id: "1", trigger: "quit all apps", reason: "timeout", timestamp: "" },
+      });
+    });
+
+    expect(result.current.pending).toBeNull();
+  });
+
+  it("keeps a newer command when the replaced one is cancelled", async () => {
+    const { result } = await renderWithPending();
+
+    act(() => {
+      callbacks.command_confirmation_required({
+        payload: { ...requiredPayload, command_id: "2", trigger: "run cleanup" },
+      });
+      callbacks.command_confirmation_cancelled({
+        payload: { command_id: "1", trigger: "quit all apps", reason: "replaced", timestamp: "" },
+      });
+    });
+
+    expect(result.current.pending?.command_id).toBe("2");
+  });
+
+  it("confirm runs the pending command", async () => {
+    const { result } = await renderWithPending();
+
+    await act(async () => {
+      await result.current.confirm();
+    });
+
+    expect(result.current.pending).toBeNull();
+    expect(mockInvoke).toHaveBeenCalledWith("confirm_command", { commandId: "1" });
+  });
+
+  it("cancel drops the pending command", async () => {
+    const { result } = await renderWithPending();
+
+    await act(async () => {
+      await result.current.cancel();
+    });
+
+    expect(result.current.pending).toBeNull();
+    expect(mockInvoke).toHaveBeenCalledWith("cancel_command");
+  });
+});
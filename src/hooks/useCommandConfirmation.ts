@@ -0,0 +1,99 @@
+import { useState, useEffect, useCallback } from "react";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
+
+/** Payload for command_confirmation_required event */
+export interface CommandConfirmationRequiredPayload {
+  transcription: string;
+  command_id: string;
+  trigger: string;
+  timeout_ms: number;
+}
+
+/** Payload for command_confirmation_cancelled event */
+interface CommandConfirmationCancelledPayload {
+  command_id: string;
+  trigger: string;
+  reason: string;
+  timestamp: string;
+}
+
+/** Return type of the useCommandConfirmation hook */
+export interface UseCommandConfirmationResult {
+  /** The command waiting for confirmation, null when none is */
+  pending: CommandConfirmationRequiredPayload | null;
+  /** Run the waiting command */
+  confirm: () => Promise<void>;
+  /** Drop the waiting command without running it */
+  cancel: () => Promise<void>;
+}
+
+/**
+ * Custom hook for commands that must be confirmed before they run
+ * Listens to backend command_confirmation_required events and clears the
+ * pending command once it is confirmed, cancelled or times out
+ */
+export function useCommandConfirmation(): UseCommandConfirmationResult {
+  const [pending, setPending] = useState<CommandConfirmationRequiredPayload | null>(null);
+
+  useEffect(() => {
+    const unlistenFns: UnlistenFn[] = [];
+
+    /* v8 ignore start -- @preserve */
+    const setupListeners = async () => {
+      const unlistenRequired = await listen<CommandConfirmationRequiredPayload>(
+        "command_confirmation_required",
+        (event) => {
+          setPending(event.payload);
+        }
+      );
+      unlistenFns.push(unlistenRequired);
+
+      // Clear only if the cancelled command is still the one shown, since a
+      // replaced command is cancelled after its replacement was announced
+      const unlistenCancelled = await listen<CommandConfirmationCancelledPayload>(
+        "command_confirmation_cancelled",
+        (event) => {
+          setPending((current) =>
+            current?.command_id === event.payload.command_id ? null : current
+          );
+        }
+      );
+      unlistenFns.push(unlistenCancelled);
+    };
+
+    setupListeners();
+    /* v8 ignore stop */
+
+    return () => {
+      /* v8 ignore start -- @preserve */
+      unlistenFns.forEach((unlisten) => unlisten());
+      /* v8 ignore stop */
+    };
+  }, []);
+
+  const confirm = useCallback(async () => {
+    if (!pending) return;
+    setPending(null);
+    try {
+      await invoke("confirm_command", { commandId: pending.command_id });
+    } catch (error) {
+      console.error("[heycat] Failed to confirm command:", error);
+    }
+  }, [pending]);
+
+  const cancel = useCallback(async () => {
+    setPending(null);
+    try {
+      await invoke("cancel_command");
+    } catch (error) {
+      console.error("[heycat] Failed to cancel command:", error);
+    }
+  }, []);
+
+  return {
+    pending,
+    confirm,
+    cancel,
+  };
+}
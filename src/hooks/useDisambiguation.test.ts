@@ -206,5 +206,6 @@ describe("useDisambiguation", () => {
     expect(result.current.isAmbiguous).toBe(false);
     expect(result.current.transcription).toBeNull();
     expect(result.current.candidates).toEqual([]);
+    expect(mockInvoke).toHaveBeenCalledWith("dismiss_command_ambiguity");
   });
 });
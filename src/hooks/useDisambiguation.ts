@@ -68,6 +68,14 @@ export function useDisambiguation(): UseDisambiguationResult {
         setCandidates([]);
       });
       unlistenFns.push(unlistenFailed);
+
+      // And command_auto_resolved when the backend times the ambiguity out
+      const unlistenAutoResolved = await listen("command_auto_resolved", () => {
+        setIsAmbiguous(false);
+        setTranscription(null);
+        setCandidates([]);
+      });
+      unlistenFns.push(unlistenAutoResolved);
     };
 
     setupListeners();
@@ -96,6 +104,10 @@ export function useDisambiguation(): UseDisambiguationResult {
   }, []);
 
   const dismiss = useCallback(() => {
+    // Stop the backend from auto-resolving the dismissed ambiguity
+    invoke("dismiss_command_ambiguity").catch((error) => {
+      console.error("[heycat] Failed to dismiss command ambiguity:", error);
+    });
     setIsAmbiguous(false);
     setTranscription(null);
     setCandidates([]);
@@ -0,0 +1,73 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from "vitest";
+import { renderHook, act, waitFor } from "@testing-library/react";
+import { useInputDeviceStatus } from "./useInputDeviceStatus";
+
+// Mock Tauri APIs
+const mockListen = vi.fn();
+const mockUnlisten = vi.fn();
+const mockInvoke = vi.fn();
+
+vi.mock("@tauri-apps/api/event", () => ({
+  listen: (...args: unknown[]) => mockListen(...args),
+}));
+
+vi.mock("@tauri-apps/api/core", () => ({
+  invoke: (...args: unknown[]) => mockInvoke(...args),
+}));
+
+const missingPayload = {
+  retry_interval_secs: 5,
+  timestamp: "2026-01-01T00:00:00Z",
+};
+
+type Callback = (event: { payload: unknown }) => void;
+
+describe("useInputDeviceStatus", () => {
+  let callbacks: Record<string, Callback>;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    callbacks = {};
+    mockListen.mockImplementation((eventName: string, callback: Callback) => {
+      callbacks[eventName] = callback;
+      return Promise.resolve(mockUnlisten);
+    });
+    mockInvoke.mockResolvedValue(null);
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it("shows a warning emitted before the app was listening", async () => {
+    mockInvoke.mockResolvedValue(missingPayload);
+
+    const { result } = renderHook(() => useInputDeviceStatus());
+
+    await waitFor(() => {
+      expect(result.current.missing).toEqual(missingPayload);
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("get_input_device_status");
+  });
+
+  it("clears the warning once a device appears", async () => {
+    const { result } = renderHook(() => useInputDeviceStatus());
+    await waitFor(() => {
+      expect(callbacks.no_input_device).toBeDefined();
+      expect(callbacks.input_device_available).toBeDefined();
+      expect(mockInvoke).toHaveBeenCalled();
+    });
+
+    act(() => {
+      callbacks.no_input_device({ payload: missingPayload });
+    });
+    expect(result.current.missing).toEqual(missingPayload);
+
+    act(() => {
+      callbacks.input_device_available({
+        payload: { device_name: "USB Mic", timestamp: "" },
+      });
+    });
+    expect(result.current.missing).toBeNull();
+  });
+});
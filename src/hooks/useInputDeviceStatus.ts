@@ -0,0 +1,64 @@
+import { useState, useEffect } from "react";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
+
+/** Payload for no_input_device event */
+export interface NoInputDevicePayload {
+  retry_interval_secs: number | null;
+  timestamp: string;
+}
+
+/** Return type of the useInputDeviceStatus hook */
+export interface UseInputDeviceStatusResult {
+  /** Startup warning while no input device is present, null once one is */
+  missing: NoInputDevicePayload | null;
+}
+
+/**
+ * Custom hook for the startup "no input device" warning
+ * The backend emits no_input_device before the webview is listening, so the
+ * current status is also fetched on mount; input_device_available clears it
+ */
+export function useInputDeviceStatus(): UseInputDeviceStatusResult {
+  const [missing, setMissing] = useState<NoInputDevicePayload | null>(null);
+
+  useEffect(() => {
+    const unlistenFns: UnlistenFn[] = [];
+
+    /* v8 ignore start -- @preserve */
+    const setupListeners = async () => {
+      const unlistenMissing = await listen<NoInputDevicePayload>(
+        "no_input_device",
+        (event) => {
+          setMissing(event.payload);
+        }
+      );
+      unlistenFns.push(unlistenMissing);
+
+      const unlistenAvailable = await listen("input_device_available", () => {
+        setMissing(null);
+      });
+      unlistenFns.push(unlistenAvailable);
+
+      try {
+        const status = await invoke<NoInputDevicePayload | null>(
+          "get_input_device_status"
+        );
+        setMissing(status);
+      } catch (error) {
+        console.error("[heycat] Failed to get input device status:", error);
+      }
+    };
+
+    setupListeners();
+    /* v8 ignore stop */
+
+    return () => {
+      /* v8 ignore start -- @preserve */
+      unlistenFns.forEach((unlisten) => unlisten());
+      /* v8 ignore stop */
+    };
+  }, []);
+
+  return { missing };
+}
@@ -16,6 +16,12 @@ const createMockUnlisten = () => {
   return unlisten;
 };
 
+// Mock the settings loader (reads the Tauri store)
+const mockInitializeSettings = vi.fn(() => Promise.resolve());
+vi.mock("../../hooks/useSettings", () => ({
+  initializeSettings: () => mockInitializeSettings(),
+}));
+
 // Mock the Tauri event API
 vi.mock("@tauri-apps/api/event", () => ({
   listen: vi.fn((eventName: string, callback: EventCallback) => {
@@ -34,11 +40,12 @@ function emitMockEvent(eventName: string, payload: unknown = {}) {
 
 describe("eventBridge", () => {
   let queryClient: QueryClient;
-  let mockStore: Pick<AppState, "setOverlayMode" | "transcriptionStarted" | "transcriptionCompleted" | "transcriptionError">;
+  let mockStore: Pick<AppState, "setOverlayMode" | "transcriptionStarted" | "transcriptionCompleted" | "transcriptionError" | "accessibilityPermissionRequired" | "setPastePaused">;
 
   beforeEach(() => {
     eventHandlers.clear();
     mockUnlistenFns.length = 0;
+    mockInitializeSettings.mockClear();
     queryClient = new QueryClient({
       defaultOptions: {
         queries: { retry: false },
@@ -49,6 +56,8 @@ describe("eventBridge", () => {
       transcriptionStarted: vi.fn() as AppState["transcriptionStarted"],
       transcriptionCompleted: vi.fn() as AppState["transcriptionCompleted"],
       transcriptionError: vi.fn() as AppState["transcriptionError"],
+      accessibilityPermissionRequired: vi.fn() as AppState["accessibilityPermissionRequired"],
+      setPastePaused: vi.fn() as AppState["setPastePaused"],
     };
   });
 
@@ -172,6 +181,27 @@ describe("eventBridge", () => {
     });
   });
 
+  describe("settings events trigger query invalidation", () => {
+    it("settings_changed invalidates all backend queries", async () => {
+      const invalidateSpy = vi.spyOn(queryClient, "invalidateQueries");
+      await setupEventBridge(queryClient, mockStore);
+
+      emitMockEvent(eventNames.SETTINGS_CHANGED);
+
+      expect(invalidateSpy).toHaveBeenCalledWith({
+        queryKey: ["tauri"],
+      });
+    });
+
+    it("settings_changed reloads the cached settings", async () => {
+      await setupEventBridge(queryClient, mockStore);
+
+      emitMockEvent(eventNames.SETTINGS_CHANGED);
+
+      expect(mockInitializeSettings).toHaveBeenCalledTimes(1);
+    });
+  });
+
   describe("hotkey events log warnings", () => {
     it("key_blocking_unavailable logs warning to console", async () => {
       const warnSpy = vi.spyOn(console, "warn").mockImplementation(() => {});
@@ -208,5 +238,26 @@ describe("eventBridge", () => {
 
       expect(mockStore.setOverlayMode).toHaveBeenCalledWith(null);
     });
+
+    it("accessibility_permission_required event stores the prompt", async () => {
+      await setupEventBridge(queryClient, mockStore);
+
+      const payload = {
+        action: "paste",
+        message: "heycat needs Accessibility permission to paste.",
+      };
+      emitMockEvent(eventNames.ACCESSIBILITY_PERMISSION_REQUIRED, payload);
+
+      expect(mockStore.accessibilityPermissionRequired).toHaveBeenCalledWith(payload);
+    });
+
+    it("paste_paused event stores the notice", async () => {
+      await setupEventBridge(queryClient, mockStore);
+
+      const payload = { reason: "screen_capture", timestamp: "2026-01-01T00:00:00Z" };
+      emitMockEvent(eventNames.PASTE_PAUSED, payload);
+
+      expect(mockStore.setPastePaused).toHaveBeenCalledWith(payload);
+    });
   });
 });
@@ -11,7 +11,8 @@
 import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 import type { QueryClient } from "@tanstack/react-query";
 import { queryKeys } from "./queryKeys";
-import type { AppState } from "../stores/appStore";
+import type { AccessibilityPrompt, AppState, PastePausedNotice } from "../stores/appStore";
+import { initializeSettings } from "../hooks/useSettings";
 
 /**
  * Event names emitted by the Rust backend.
@@ -44,6 +45,15 @@ export const eventNames = {
   // Hotkey events
   KEY_BLOCKING_UNAVAILABLE: "key_blocking_unavailable",
 
+  // Permission events
+  ACCESSIBILITY_PERMISSION_REQUIRED: "accessibility_permission_required",
+
+  // Output events
+  PASTE_PAUSED: "paste_paused",
+
+  // Settings events
+  SETTINGS_CHANGED: "settings_changed",
+
   // Database events (from Turso)
   RECORDINGS_UPDATED: "recordings_updated",
   TRANSCRIPTIONS_UPDATED: "transcriptions_updated",
@@ -62,6 +72,8 @@ export type OverlayModePayload = string | null;
 export interface TranscriptionCompletedPayload {
   text: string;
   duration_ms: number;
+  /** Guessed language of the text (ISO 639-1), null if undetermined */
+  language: string | null;
 }
 
 /** Payload for transcription_error event */
@@ -75,6 +87,12 @@ export interface KeyBlockingUnavailablePayload {
   timestamp: string;
 }
 
+/** Payload for accessibility_permission_required event */
+export type AccessibilityPermissionRequiredPayload = AccessibilityPrompt;
+
+/** Payload for paste_paused event */
+export type PastePausedPayload = PastePausedNotice;
+
 /** Payload for recordings_updated event (from Turso) */
 export interface RecordingsUpdatedPayload {
   changeType: string;
@@ -106,7 +124,7 @@ export interface TranscriptionsUpdatedPayload {
  */
 export async function setupEventBridge(
   queryClient: QueryClient,
-  store: Pick<AppState, "setOverlayMode" | "transcriptionStarted" | "transcriptionCompleted" | "transcriptionError">
+  store: Pick<AppState, "setOverlayMode" | "transcriptionStarted" | "transcriptionCompleted" | "transcriptionError" | "accessibilityPermissionRequired" | "setPastePaused">
 ): Promise<() => void> {
   const unlistenFns: UnlistenFn[] = [];
 
@@ -186,6 +204,19 @@ export async function setupEventBridge(
     })
   );
 
+  // Settings reloaded from disk - invalidate all backend queries since any
+  // setting-derived value may have changed, and refresh the cached settings
+  unlistenFns.push(
+    await listen(eventNames.SETTINGS_CHANGED, () => {
+      queryClient.invalidateQueries({
+        queryKey: ["tauri"],
+      });
+      initializeSettings().catch((error) => {
+        console.error("[heycat] Failed to refresh settings:", error);
+      });
+    })
+  );
+
   // Hotkey events - log warnings for edge cases
   unlistenFns.push(
     await listen<KeyBlockingUnavailablePayload>(eventNames.KEY_BLOCKING_UNAVAILABLE, (event) => {
@@ -255,6 +286,23 @@ export async function setupEventBridge(
     })
   );
 
+  // Accessibility permission missing - prompt the user to grant it
+  unlistenFns.push(
+    await listen<AccessibilityPermissionRequiredPayload>(
+      eventNames.ACCESSIBILITY_PERMISSION_REQUIRED,
+      (event) => {
+        store.accessibilityPermissionRequired(event.payload);
+      }
+    )
+  );
+
+  // Transcription copied without pasting - show that paste is paused
+  unlistenFns.push(
+    await listen<PastePausedPayload>(eventNames.PASTE_PAUSED, (event) => {
+      store.setPastePaused(event.payload);
+    })
+  );
+
   // Return cleanup function that unsubscribes all listeners
   return () => {
     unlistenFns.forEach((unlisten) => unlisten());
@@ -33,6 +33,12 @@ export const queryKeys = {
 
     /** Query key for get_recording_shortcut command */
     recordingShortcut: ["tauri", "recording_shortcut"] as const,
+
+    /** Query key for get_match_log_settings command */
+    matchLogSettings: ["tauri", "get_match_log_settings"] as const,
+
+    /** Query key for get_transcription_timeout command */
+    transcriptionTimeout: ["tauri", "get_transcription_timeout"] as const,
   },
   dictionary: {
     /** Base key for all dictionary queries */
@@ -220,6 +220,9 @@ describe("Commands", () => {
         action_type: "open_app",
         parameters: { app: "Slack" },
         enabled: false,
+        requires_confirmation: false,
+        priority: 0,
+        aliases: [],
       },
     });
   });
@@ -328,6 +331,9 @@ describe("Commands", () => {
           action_type: "open_app",
           parameters: { app: "Spotify" },
           enabled: true,
+          requires_confirmation: false,
+          priority: 0,
+          aliases: [],
         },
       });
     });
@@ -22,6 +22,19 @@ export interface CommandDto {
   action_type: string;
   parameters: Record<string, string>;
   enabled: boolean;
+  /** Tie-break priority for equally good matches (higher wins) */
+  priority?: number;
+  /** Alternative phrases that also trigger this command */
+  aliases?: string[];
+  /** Whether the command waits for confirmation before it runs */
+  requires_confirmation?: boolean;
+}
+
+/** Command settings edited alongside the action */
+export interface CommandOptions {
+  requires_confirmation: boolean;
+  priority: number;
+  aliases: string[];
 }
 
 export interface CommandsProps {
@@ -96,7 +109,8 @@ export function Commands(_props: CommandsProps) {
     trigger: string,
     actionType: string,
     parameters: Record<string, string>,
-    contextIds: string[]
+    contextIds: string[],
+    options: CommandOptions
   ) => {
     try {
       let commandId: string;
@@ -110,6 +124,7 @@ export function Commands(_props: CommandsProps) {
             action_type: actionType,
             parameters,
             enabled: editingCommand.enabled,
+            ...options,
           },
         });
         commandId = updatedCommand.id;
@@ -128,6 +143,7 @@ export function Commands(_props: CommandsProps) {
             action_type: actionType,
             parameters,
             enabled: true,
+            ...options,
           },
         });
         commandId = newCommand.id;
@@ -232,6 +248,9 @@ export function Commands(_props: CommandsProps) {
           action_type: command.action_type,
           parameters: command.parameters,
           enabled: !command.enabled,
+          requires_confirmation: command.requires_confirmation ?? false,
+          priority: command.priority ?? 0,
+          aliases: command.aliases ?? [],
         },
       });
       // Invalidate to refetch with updated enabled state
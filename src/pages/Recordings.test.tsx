@@ -126,6 +126,16 @@ describe("Recordings", () => {
     ).toBeDefined();
   });
 
+  it("notes when history persistence is disabled", async () => {
+    mockInvoke.mockResolvedValue({ ...emptyPaginatedResponse, persistence_enabled: false });
+
+    render(<Recordings />, { wrapper: createWrapper() });
+
+    await waitFor(() => {
+      expect(screen.getByText(/History is turned off/i)).toBeDefined();
+    });
+  });
+
   it("displays recordings list with play button, filename, and metadata", async () => {
     mockInvoke.mockResolvedValue(createPaginatedResponse(sampleRecordings));
 
@@ -246,6 +256,10 @@ describe("Recordings", () => {
     expect(screen.getByText("Transcription")).toBeDefined();
     expect(screen.getByText("Hello, this is a test transcription.")).toBeDefined();
 
+    // Should show what started the recording
+    expect(screen.getByText("Started By")).toBeDefined();
+    expect(screen.getByText("Hotkey")).toBeDefined();
+
     // Should show action buttons
     expect(screen.getByRole("button", { name: /copy transcription text/i })).toBeDefined();
     expect(screen.getByRole("button", { name: /open file in system/i })).toBeDefined();
@@ -79,6 +79,7 @@ export function Recordings(_props: RecordingsProps) {
   const recordings = paginatedResponse?.recordings ?? [];
   const totalCount = paginatedResponse?.total_count ?? 0;
   const hasMore = paginatedResponse?.has_more ?? false;
+  const persistenceEnabled = paginatedResponse?.persistence_enabled ?? true;
   const totalPages = Math.ceil(totalCount / PAGE_SIZE);
 
   const error = queryError
@@ -265,6 +266,17 @@ export function Recordings(_props: RecordingsProps) {
         </p>
       </header>
 
+      {!persistenceEnabled && (
+        <Card>
+          <CardContent>
+            <p className="text-sm text-text-secondary" role="note">
+              History is turned off. New recordings and transcriptions are not saved, and their
+              audio is deleted once transcribed.
+            </p>
+          </CardContent>
+        </Card>
+      )}
+
       {/* Search & Filter Bar */}
       <RecordingsFilters
         searchQuery={searchQuery}
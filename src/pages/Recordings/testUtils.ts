@@ -32,6 +32,7 @@ export const sampleRecordings: RecordingInfo[] = [
     created_at: "2024-01-15T14:30:00Z",
     file_size_bytes: 3600000,
     transcription: "Hello, this is a test transcription.",
+    trigger_source: "Hotkey",
   },
   {
     filename: "meeting_notes.wav",
@@ -10,6 +10,15 @@ const mockInvoke = vi.fn().mockImplementation((command: string) => {
   if (command === "get_recording_shortcut") {
     return Promise.resolve("CmdOrControl+Shift+R");
   }
+  if (command === "get_match_log_settings") {
+    return Promise.resolve({ enabled: true, path: "/Users/me/heycat/match-log.jsonl" });
+  }
+  if (command === "get_transcription_timeout") {
+    return Promise.resolve(90);
+  }
+  if (command === "set_transcription_timeout") {
+    return Promise.resolve(600);
+  }
   return Promise.resolve(undefined);
 });
 vi.mock("@tauri-apps/api/core", () => ({
@@ -201,6 +210,31 @@ describe("Settings Page", () => {
     });
   });
 
+  describe("Command Match Log", () => {
+    it("shows the match log file path", async () => {
+      render(<Settings />, { wrapper: createWrapper() });
+
+      const input = await screen.findByDisplayValue("/Users/me/heycat/match-log.jsonl");
+      expect(input).toBeDefined();
+      expect(screen.getByText("Log Match Outcomes")).toBeDefined();
+    });
+
+    it("saves an edited match log path", async () => {
+      const user = userEvent.setup();
+      render(<Settings />, { wrapper: createWrapper() });
+
+      const input = await screen.findByDisplayValue("/Users/me/heycat/match-log.jsonl");
+      await user.clear(input);
+      await user.type(input, "/tmp/matches.jsonl");
+      await user.click(screen.getByRole("button", { name: "Save" }));
+
+      expect(mockInvoke).toHaveBeenCalledWith("set_match_log_settings", {
+        enabled: true,
+        path: "/tmp/matches.jsonl",
+      });
+    });
+  });
+
   describe("Audio Tab", () => {
     it("displays audio device selection", async () => {
       const user = userEvent.setup();
@@ -268,6 +302,19 @@ describe("Settings Page", () => {
         })
       );
     });
+
+    it("saves an edited transcription timeout", async () => {
+      const user = userEvent.setup();
+      render(<Settings />, { wrapper: createWrapper() });
+
+      await user.click(screen.getByRole("tab", { name: "Transcription" }));
+      const input = await screen.findByDisplayValue("90");
+      await user.clear(input);
+      await user.type(input, "600");
+      await user.click(screen.getByRole("button", { name: "Save" }));
+
+      expect(mockInvoke).toHaveBeenCalledWith("set_transcription_timeout", { secs: 600 });
+    });
   });
 
   describe("About Tab", () => {
@@ -12,7 +12,7 @@ import {
   MultiSelect,
 } from "../../components/ui";
 import type { MultiSelectOption } from "../../components/ui";
-import type { CommandDto } from "../Commands";
+import type { CommandDto, CommandOptions } from "../Commands";
 import type { WindowContext } from "../../types/windowContext";
 
 type ActionType =
@@ -50,7 +50,8 @@ export interface CommandModalProps {
     trigger: string,
     actionType: string,
     parameters: Record<string, string>,
-    contextIds: string[]
+    contextIds: string[],
+    options: CommandOptions
   ) => Promise<void>;
 }
 
@@ -67,6 +68,10 @@ export function CommandModal({
   const [actionType, setActionType] = useState<ActionType>("open_app");
   const [parameters, setParameters] = useState<Record<string, string>>({});
   const [selectedContextIds, setSelectedContextIds] = useState<string[]>([]);
+  const [requiresConfirmation, setRequiresConfirmation] = useState(false);
+  const [priority, setPriority] = useState("0");
+  // One alias per line
+  const [aliases, setAliases] = useState("");
   const [errors, setErrors] = useState<Record<string, string>>({});
   const [saving, setSaving] = useState(false);
   const [showAdvanced, setShowAdvanced] = useState(false);
@@ -92,10 +97,15 @@ export function CommandModal({
         setActionType(command.action_type as ActionType);
         setParameters(command.parameters);
         setSelectedContextIds(assignedContextIds);
-        // Show advanced if any advanced params are set
+        setRequiresConfirmation(command.requires_confirmation ?? false);
+        setPriority(String(command.priority ?? 0));
+        setAliases((command.aliases ?? []).join("\n"));
+        // Show advanced if any advanced options are set
         setShowAdvanced(
           Boolean(
-            command.parameters.confirmation ||
+            command.requires_confirmation ||
+              command.priority ||
+              command.aliases?.length ||
               command.parameters.conditions ||
               command.parameters.custom_params
           )
@@ -105,6 +115,9 @@ export function CommandModal({
         setActionType("open_app");
         setParameters({});
         setSelectedContextIds([]);
+        setRequiresConfirmation(false);
+        setPriority("0");
+        setAliases("");
         setShowAdvanced(false);
       }
       setErrors({});
@@ -120,6 +133,10 @@ export function CommandModal({
       newErrors.trigger = "This trigger phrase already exists";
     }
 
+    if (!Number.isInteger(Number(priority))) {
+      newErrors.priority = "Priority must be a whole number";
+    }
+
     switch (actionType) {
       case "open_app":
         if (!parameters.app?.trim()) {
@@ -154,7 +171,14 @@ export function CommandModal({
 
     try {
       setSaving(true);
-      await onSave(trigger.trim(), actionType, parameters, selectedContextIds);
+      await onSave(trigger.trim(), actionType, parameters, selectedContextIds, {
+        requires_confirmation: requiresConfirmation,
+        priority: Number(priority),
+        aliases: aliases
+          .split("\n")
+          .map((alias) => alias.trim())
+          .filter(Boolean),
+      });
     } catch {
       // Error handled by parent via toast
     } finally {
@@ -401,6 +425,44 @@ export function CommandModal({
                     />
                   </FormField>
 
+                  {/* Aliases */}
+                  <FormField>
+                    <Label htmlFor="aliases">Aliases</Label>
+                    <Textarea
+                      id="aliases"
+                      value={aliases}
+                      onChange={(e) => setAliases(e.target.value)}
+                      placeholder="Other phrases for this command, one per line"
+                      rows={3}
+                    />
+                  </FormField>
+
+                  {/* Priority */}
+                  <FormField error={errors.priority}>
+                    <Label htmlFor="priority">Priority</Label>
+                    <Input
+                      id="priority"
+                      type="number"
+                      step="1"
+                      error={Boolean(errors.priority)}
+                      value={priority}
+                      onChange={(e) => {
+                        setPriority(e.target.value);
+                        if (errors.priority) {
+                          setErrors((prev) => {
+                            const next = { ...prev };
+                            delete next.priority;
+                            return next;
+                          });
+                        }
+                      }}
+                      placeholder="0"
+                    />
+                    <p className="text-xs text-text-secondary">
+                      Higher wins when several commands match equally well
+                    </p>
+                  </FormField>
+
                   {/* Confirmation Toggle */}
                   <div className="flex items-center justify-between">
                     <div>
@@ -412,13 +474,8 @@ export function CommandModal({
                     <input
                       id="confirmation"
                       type="checkbox"
-                      checked={parameters.confirmation === "true"}
-                      onChange={(e) =>
-                        updateParameter(
-                          "confirmation",
-                          e.target.checked ? "true" : ""
-                        )
-                      }
+                      checked={requiresConfirmation}
+                      onChange={(e) => setRequiresConfirmation(e.target.checked)}
                       className="h-4 w-4 rounded border-border text-heycat-orange focus:ring-heycat-teal"
                     />
                   </div>
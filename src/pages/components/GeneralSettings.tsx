@@ -3,7 +3,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { useQuery, useQueryClient } from "@tanstack/react-query";
 import * as RadioGroupPrimitive from "@radix-ui/react-radio-group";
 import { queryKeys } from "../../lib/queryKeys";
-import { Card, CardContent, LabeledToggle, Button } from "../../components/ui";
+import { Card, CardContent, LabeledToggle, Button, Input } from "../../components/ui";
 import { useSettings, RecordingMode } from "../../hooks/useSettings";
 import { useRecordingState } from "../../hooks/useRecording";
 import { useToast } from "../../components/overlays";
@@ -13,6 +13,12 @@ export interface GeneralSettingsProps {
   className?: string;
 }
 
+/** Local JSONL log of command match outcomes (from get_match_log_settings) */
+interface MatchLogSettings {
+  enabled: boolean;
+  path: string | null;
+}
+
 // Convert backend shortcut format to display format
 function backendToDisplay(shortcut: string): string {
   return shortcut
@@ -45,6 +51,13 @@ export function GeneralSettings({ className = "" }: GeneralSettingsProps) {
 
   const currentShortcut = backendShortcut ? backendToDisplay(backendShortcut) : "⌘⇧R";
 
+  // Fetch match log settings via React Query
+  const { data: matchLog } = useQuery({
+    queryKey: queryKeys.tauri.matchLogSettings,
+    queryFn: () => invoke<MatchLogSettings>("get_match_log_settings"),
+  });
+  const [matchLogPathDraft, setMatchLogPathDraft] = useState<string | null>(null);
+
   // Shortcut editor modal state
   const [isShortcutEditorOpen, setIsShortcutEditorOpen] = useState(false);
 
@@ -75,6 +88,30 @@ export function GeneralSettings({ className = "" }: GeneralSettingsProps) {
     });
   };
 
+  const saveMatchLogSettings = async (enabled: boolean, path: string | null) => {
+    try {
+      const updated = await invoke<MatchLogSettings>("set_match_log_settings", {
+        enabled,
+        path,
+      });
+      queryClient.setQueryData(queryKeys.tauri.matchLogSettings, updated);
+      setMatchLogPathDraft(null);
+      toast({
+        type: "success",
+        title: "Setting saved",
+        description: updated.enabled
+          ? `Match outcomes are logged to ${updated.path ?? "the data directory"}.`
+          : "Match log disabled.",
+      });
+    } catch (error) {
+      toast({
+        type: "error",
+        title: "Failed to update match log",
+        description: String(error),
+      });
+    }
+  };
+
   const handleRecordingModeChange = async (mode: RecordingMode) => {
     try {
       // Update backend via Tauri command
@@ -253,6 +290,52 @@ export function GeneralSettings({ className = "" }: GeneralSettingsProps) {
         </Card>
       </section>
 
+      {/* Command Match Log Section */}
+      <section>
+        <h2 className="text-xs font-semibold text-text-secondary uppercase tracking-wider mb-4">
+          Command Match Log
+        </h2>
+        <Card>
+          <CardContent className="space-y-4">
+            <LabeledToggle
+              label="Log Match Outcomes"
+              description="Append each transcription and how it matched your commands to a local JSONL file. Nothing is sent over the network."
+              checked={matchLog?.enabled ?? false}
+              onCheckedChange={(checked) => saveMatchLogSettings(checked, null)}
+            />
+            <div className="pt-4 border-t border-border space-y-2">
+              <label
+                htmlFor="match-log-path"
+                className="text-sm font-medium text-text-primary"
+              >
+                Log File
+              </label>
+              <div className="flex items-center gap-2">
+                <Input
+                  id="match-log-path"
+                  value={matchLogPathDraft ?? matchLog?.path ?? ""}
+                  onChange={(e) => setMatchLogPathDraft(e.target.value)}
+                  placeholder="Default location in the HeyCat data folder"
+                />
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  disabled={matchLogPathDraft === null}
+                  onClick={() =>
+                    saveMatchLogSettings(matchLog?.enabled ?? false, matchLogPathDraft)
+                  }
+                >
+                  Save
+                </Button>
+              </div>
+              <p className="text-xs text-text-secondary">
+                Clear the path and save to use the default location.
+              </p>
+            </div>
+          </CardContent>
+        </Card>
+      </section>
+
       {/* Shortcut Editor Modal */}
       <ShortcutEditor
         open={isShortcutEditorOpen}
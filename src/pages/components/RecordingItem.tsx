@@ -13,12 +13,24 @@ export interface RecordingInfo {
   active_window_app_name?: string;
   active_window_bundle_id?: string;
   active_window_title?: string;
+  /** What started the recording, absent for older recordings */
+  trigger_source?: TriggerSource;
 }
 
+/** What started a recording (matches TriggerSource in src-tauri/src/recording/state.rs) */
+export type TriggerSource = "Hotkey" | "Button";
+
+const TRIGGER_SOURCE_LABELS: Record<TriggerSource, string> = {
+  Hotkey: "Hotkey",
+  Button: "Record button",
+};
+
 export interface PaginatedRecordingsResponse {
   recordings: RecordingInfo[];
   total_count: number;
   has_more: boolean;
+  /** False when recordings.persistTranscriptions is off and nothing new is stored */
+  persistence_enabled?: boolean;
 }
 
 export interface RecordingItemProps {
@@ -168,6 +180,18 @@ export function RecordingItem({
               </div>
             )}
 
+            {/* Trigger Section */}
+            {recording.trigger_source && (
+              <div>
+                <h4 className="text-xs font-semibold text-text-secondary uppercase tracking-wider mb-2">
+                  Started By
+                </h4>
+                <p className="text-sm text-text-primary">
+                  {TRIGGER_SOURCE_LABELS[recording.trigger_source]}
+                </p>
+              </div>
+            )}
+
             {/* Action Buttons */}
             <div className="flex items-center gap-2">
               {isDeleting ? (
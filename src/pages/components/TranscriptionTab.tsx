@@ -1,8 +1,14 @@
-import { useCallback } from "react";
+import { useCallback, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { useQuery, useQueryClient } from "@tanstack/react-query";
 import { Check, Download, Loader2 } from "lucide-react";
-import { Card, CardContent, Button } from "../../components/ui";
+import { Card, CardContent, Button, Input } from "../../components/ui";
 import { useMultiModelStatus } from "../../hooks/useMultiModelStatus";
 import { useToast } from "../../components/overlays";
+import { queryKeys } from "../../lib/queryKeys";
+
+/** Longest transcription timeout accepted by the backend, in seconds */
+const MAX_TRANSCRIPTION_TIMEOUT_SECS = 3600;
 
 export interface TranscriptionTabProps {
   className?: string;
@@ -19,6 +25,36 @@ function formatBytes(bytes: number): string {
 export function TranscriptionTab({ className = "" }: TranscriptionTabProps) {
   const { models, downloadModel, refreshStatus } = useMultiModelStatus();
   const { toast } = useToast();
+  const queryClient = useQueryClient();
+
+  // Fetch the transcription timeout via React Query
+  const { data: timeoutSecs } = useQuery({
+    queryKey: queryKeys.tauri.transcriptionTimeout,
+    queryFn: () => invoke<number>("get_transcription_timeout"),
+  });
+  const [timeoutDraft, setTimeoutDraft] = useState<string | null>(null);
+
+  const saveTimeout = useCallback(async () => {
+    if (timeoutDraft === null) return;
+    try {
+      const secs = await invoke<number>("set_transcription_timeout", {
+        secs: Number(timeoutDraft),
+      });
+      queryClient.setQueryData(queryKeys.tauri.transcriptionTimeout, secs);
+      setTimeoutDraft(null);
+      toast({
+        type: "success",
+        title: "Setting saved",
+        description: `Transcriptions time out after ${secs} seconds.`,
+      });
+    } catch (error) {
+      toast({
+        type: "error",
+        title: "Failed to update timeout",
+        description: String(error),
+      });
+    }
+  }, [timeoutDraft, queryClient, toast]);
 
   const handleDownload = useCallback(async () => {
     toast({
@@ -155,6 +191,45 @@ export function TranscriptionTab({ className = "" }: TranscriptionTabProps) {
           </CardContent>
         </Card>
       </section>
+
+      <section>
+        <h2 className="text-xs font-semibold text-text-secondary uppercase tracking-wider mb-4">
+          Timeout
+        </h2>
+
+        <Card>
+          <CardContent className="space-y-2">
+            <label
+              htmlFor="transcription-timeout"
+              className="text-sm font-medium text-text-primary"
+            >
+              Transcription Timeout (seconds)
+            </label>
+            <div className="flex items-center gap-2">
+              <Input
+                id="transcription-timeout"
+                type="number"
+                min={1}
+                max={MAX_TRANSCRIPTION_TIMEOUT_SECS}
+                value={timeoutDraft ?? timeoutSecs?.toString() ?? ""}
+                onChange={(e) => setTimeoutDraft(e.target.value)}
+              />
+              <Button
+                variant="ghost"
+                size="sm"
+                disabled={timeoutDraft === null || timeoutDraft === ""}
+                onClick={saveTimeout}
+              >
+                Save
+              </Button>
+            </div>
+            <p className="text-xs text-text-secondary">
+              Raise this if long recordings time out (up to{" "}
+              {MAX_TRANSCRIPTION_TIMEOUT_SECS} seconds).
+            </p>
+          </CardContent>
+        </Card>
+      </section>
     </div>
   );
 }
@@ -14,6 +14,8 @@ describe("appStore", () => {
         error: null,
         durationMs: null,
       },
+      accessibilityPrompt: null,
+      pastePaused: null,
     });
   });
 
@@ -144,4 +146,31 @@ describe("appStore", () => {
       expect(useAppStore.getState().settingsCache).toBeNull();
     });
   });
+
+  describe("accessibility prompt", () => {
+    it("accessibilityPermissionRequired stores the prompt until dismissed", () => {
+      const prompt = {
+        action: "paste",
+        message: "heycat needs Accessibility permission to paste.",
+      };
+
+      useAppStore.getState().accessibilityPermissionRequired(prompt);
+      expect(useAppStore.getState().accessibilityPrompt).toEqual(prompt);
+
+      useAppStore.getState().dismissAccessibilityPrompt();
+      expect(useAppStore.getState().accessibilityPrompt).toBeNull();
+    });
+  });
+
+  describe("paste paused", () => {
+    it("setPastePaused stores and clears the notice", () => {
+      const notice = { reason: "screen_capture", timestamp: "2026-01-01T00:00:00Z" };
+
+      useAppStore.getState().setPastePaused(notice);
+      expect(useAppStore.getState().pastePaused).toEqual(notice);
+
+      useAppStore.getState().setPastePaused(null);
+      expect(useAppStore.getState().pastePaused).toBeNull();
+    });
+  });
 });
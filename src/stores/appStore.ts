@@ -12,6 +12,24 @@ export interface TranscriptionState {
   durationMs: number | null;
 }
 
+/**
+ * Keystroke that couldn't be simulated because Accessibility permission is missing.
+ * Mirrors the accessibility_permission_required event payload.
+ */
+export interface AccessibilityPrompt {
+  action: string;
+  message: string;
+}
+
+/**
+ * Transcription copied but not pasted, e.g. while the screen is shared.
+ * Mirrors the paste_paused event payload.
+ */
+export interface PastePausedNotice {
+  reason: string;
+  timestamp: string;
+}
+
 /**
  * Global app state managed by Zustand.
  *
@@ -23,6 +41,8 @@ export interface TranscriptionState {
  * - settingsCache: In-memory cache of settings from Tauri Store
  * - isSettingsLoaded: Hydration flag indicating settings have been loaded
  * - transcription: Current transcription state (updated via events)
+ * - accessibilityPrompt: Pending Accessibility permission prompt (updated via events)
+ * - pastePaused: Latest skipped auto-paste (updated via events)
  */
 export interface AppState {
   // Client state only - NO server state here
@@ -30,6 +50,8 @@ export interface AppState {
   settingsCache: AppSettings | null;
   isSettingsLoaded: boolean;
   transcription: TranscriptionState;
+  accessibilityPrompt: AccessibilityPrompt | null;
+  pastePaused: PastePausedNotice | null;
 
   // Actions
   setOverlayMode: (mode: string | null) => void;
@@ -41,6 +63,9 @@ export interface AppState {
   transcriptionStarted: () => void;
   transcriptionCompleted: (text: string, durationMs: number) => void;
   transcriptionError: (error: string) => void;
+  accessibilityPermissionRequired: (prompt: AccessibilityPrompt) => void;
+  dismissAccessibilityPrompt: () => void;
+  setPastePaused: (notice: PastePausedNotice | null) => void;
 }
 
 const initialTranscriptionState: TranscriptionState = {
@@ -55,6 +80,8 @@ export const useAppStore = create<AppState>((set) => ({
   settingsCache: null,
   isSettingsLoaded: false,
   transcription: initialTranscriptionState,
+  accessibilityPrompt: null,
+  pastePaused: null,
 
   setOverlayMode: (mode) => set({ overlayMode: mode }),
 
@@ -96,6 +123,13 @@ export const useAppStore = create<AppState>((set) => ({
         error,
       },
     })),
+
+  accessibilityPermissionRequired: (prompt) =>
+    set({ accessibilityPrompt: prompt }),
+
+  dismissAccessibilityPrompt: () => set({ accessibilityPrompt: null }),
+
+  setPastePaused: (notice) => set({ pastePaused: notice }),
 }));
 
 // Optimized selectors - components using these will only re-render
@@ -104,3 +138,6 @@ export const useOverlayMode = () => useAppStore((s) => s.overlayMode);
 export const useSettingsCache = () => useAppStore((s) => s.settingsCache);
 export const useIsSettingsLoaded = () => useAppStore((s) => s.isSettingsLoaded);
 export const useTranscriptionState = () => useAppStore((s) => s.transcription);
+export const useAccessibilityPrompt = () =>
+  useAppStore((s) => s.accessibilityPrompt);
+export const usePastePaused = () => useAppStore((s) => s.pastePaused);